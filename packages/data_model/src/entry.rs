@@ -11,13 +11,32 @@ use {
 mod auth;
 pub use auth::*;
 
+pub mod encoding;
+
 
 /// The metadata for storing a [`Payload`](crate::Payload).
 ///
 /// The [`Ord`]ering of values of this type is based on the order of its fields, so that `Entry`s
 /// are first ordered by Namespace, then by Subspace, then by `Path`, and then, for the remaining
 /// fields, by the same ordering as [`cmp_newer_than`](Self::cmp_newer_than).
+///
+/// With the `serde` package-feature, this can be serialized and deserialized when its type
+/// parameters can be.  The `path` is (de)serialized as a sequence of byte-strings, one per
+/// `Component`, so that the serialized form doesn't depend on the type of `Path`, and so
+/// deserializing requires a `Path` type that can be collected from `Box<[u8]>` `Component`s.
 #[derive(Copy, Clone, Hash, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "NamespaceId: serde::Serialize, SubspaceId: serde::Serialize, \
+                     Path: crate::Path, PayloadDigest: serde::Serialize",
+        deserialize = "NamespaceId: serde::Deserialize<'de>, \
+                       SubspaceId: serde::Deserialize<'de>, \
+                       Path: FromIterator<alloc::boxed::Box<[u8]>>, \
+                       PayloadDigest: serde::Deserialize<'de>",
+    ))
+)]
 #[allow(clippy::exhaustive_structs)]
 pub struct Entry<NamespaceId, SubspaceId, Path, PayloadDigest>
 {
@@ -26,6 +45,7 @@ pub struct Entry<NamespaceId, SubspaceId, Path, PayloadDigest>
     /// Subspace to which this `Entry` belongs.
     pub subspace_id:    SubspaceId,
     /// Path to which this `Entry` was written.
+    #[cfg_attr(feature = "serde", serde(with = "crate::path::serde_components"))]
     pub path:           Path,
     /// Claimed creation time of this `Entry`.
     pub timestamp:      Timestamp,
@@ -36,6 +56,51 @@ pub struct Entry<NamespaceId, SubspaceId, Path, PayloadDigest>
     pub payload_length: u64,
 }
 
+impl<N, S, P, D> Entry<N, S, P, D>
+{
+    /// The same `Entry` but at another `path`, which can be of another type.
+    #[must_use]
+    #[inline]
+    pub fn with_path<P2>(
+        self,
+        path: P2,
+    ) -> Entry<N, S, P2, D>
+    {
+        Entry {
+            namespace_id: self.namespace_id,
+            subspace_id: self.subspace_id,
+            path,
+            timestamp: self.timestamp,
+            payload_digest: self.payload_digest,
+            payload_length: self.payload_length,
+        }
+    }
+
+    /// The same `Entry` but with another `timestamp`.
+    #[must_use]
+    #[inline]
+    pub fn with_timestamp(
+        self,
+        timestamp: Timestamp,
+    ) -> Self
+    {
+        Self { timestamp, ..self }
+    }
+
+    /// The same `Entry` but for another `Payload`, as given by its `payload_digest` and
+    /// `payload_length`.
+    #[must_use]
+    #[inline]
+    pub fn with_payload_meta(
+        self,
+        payload_digest: D,
+        payload_length: u64,
+    ) -> Self
+    {
+        Self { payload_digest, payload_length, ..self }
+    }
+}
+
 impl<N, S, P, D: Ord> Entry<N, S, P, D>
 {
     /// An `Entry` `e1` is _newer_ than another `Entry` `e2` if
@@ -1,5 +1,9 @@
 use {
-    crate::ParamsEntry,
+    crate::{
+        path::Extra as _,
+        Entry,
+        ParamsEntry,
+    },
     core::{
         borrow::Borrow,
         cmp::Ordering,
@@ -28,6 +32,12 @@ use {
 ///
 /// The fields are private, to prevent constructing arbitrary values that might not uphold the
 /// requirement.
+///
+/// For the same reason, this doesn't implement `Deserialize` (with the `serde` package-feature),
+/// because that would bypass `is_authorised_write`.  Instead, deserialize its [`Entry`](
+/// crate::Entry) and `AuthorisationToken` separately, and then either use [`Self::new`] to check
+/// them or, when they're known to have already been checked, use `Self::new_unchecked` (with the
+/// `unchecked-construction` package-feature).
 pub struct AuthorisedEntry<Params, Path, AuthToken>
 where
     Params: crate::Params + ?Sized,
@@ -60,6 +70,35 @@ where
             .then_some(Self { entry, auth_token })
     }
 
+    /// Construct without checking the requirement that the
+    /// [`AuthorisationToken`](crate::Params::AuthorisationToken) authorises the
+    /// [`Entry`](crate::Entry), i.e. without calling [`Params::is_authorised_write`](
+    /// crate::Params::is_authorised_write).
+    ///
+    /// **WARNING**: The caller asserts that the write was already authorised, by
+    /// `is_authorised_write` of this same `Params`, e.g. because the pair was deserialized from
+    /// where only an `AuthorisedEntry` was serialized, or because it's being joined from another
+    /// `Store` that already validated it.  If that's not true, then the guarantee of this type is
+    /// violated, and everything that relies on it (e.g. a `Store` accepting only authorised
+    /// writes) will be wrong.  This isn't `unsafe`, because it can't cause undefined behavior,
+    /// but it should be treated with similar care.  When in doubt, use [`Self::new`] instead.
+    ///
+    /// In debug builds, the requirement is still checked, and a violation panics.
+    #[cfg(feature = "unchecked-construction")]
+    #[must_use]
+    #[inline]
+    pub fn new_unchecked(
+        entry: ParamsEntry<Params, Path>,
+        auth_token: AuthToken,
+    ) -> Self
+    {
+        debug_assert!(
+            Params::is_authorised_write(&entry, auth_token.borrow()),
+            "caller must ensure the `Entry` was already authorised"
+        );
+        Self { entry, auth_token }
+    }
+
     /// The [`Entry`](crate::Entry) that was authorised.
     #[inline]
     pub fn entry(&self) -> &ParamsEntry<Params, Path>
@@ -84,6 +123,44 @@ where
     {
         (self.entry, self.auth_token)
     }
+
+    /// Convert to another type of [`Path`](crate::Path), with the same `Component`s, keeping the
+    /// same [`AuthorisationToken`](crate::Params::AuthorisationToken).
+    ///
+    /// Only the representation of the `Path` changes, not the bytes of its `Component`s, and so
+    /// the `Entry` is the same one that was authorised and `is_authorised_write` isn't called
+    /// again.
+    #[must_use]
+    #[inline]
+    pub fn map_path<P2, C>(self) -> AuthorisedEntry<Params, P2, AuthToken>
+    where
+        P2: crate::Path + FromIterator<C>,
+        for<'b> &'b [u8]: Into<C>,
+    {
+        let path = P2::from_path(&self.entry.path);
+        AuthorisedEntry { entry: self.entry.with_path(path), auth_token: self.auth_token }
+    }
+
+    /// Borrow the `Path` and `AuthorisationToken`, cloning the rest, as the same authorised
+    /// `Entry` (and so `is_authorised_write` isn't called again).
+    #[must_use]
+    pub(crate) fn as_ref(&self) -> AuthorisedEntry<Params, &Path, &Params::AuthorisationToken>
+    where Params::PayloadDigest: Clone
+    {
+        let Entry { namespace_id, subspace_id, path, timestamp, payload_digest, payload_length } =
+            &self.entry;
+        AuthorisedEntry {
+            entry:      Entry {
+                namespace_id:   namespace_id.clone(),
+                subspace_id:    subspace_id.clone(),
+                path,
+                timestamp:      *timestamp,
+                payload_digest: payload_digest.clone(),
+                payload_length: *payload_length,
+            },
+            auth_token: self.auth_token.borrow(),
+        }
+    }
 }
 
 
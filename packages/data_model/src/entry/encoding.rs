@@ -0,0 +1,397 @@
+//! The canonical byte encoding of [`Entry`](crate::Entry)s, e.g. for persisting them or sending
+//! them over a wire.
+//!
+//! This follows the Willow encoding of `Entry`s: the fields are encoded in order, with the
+//! `path` as by [`path::encoding`](crate::path::encoding) (and so sized by the limits of a
+//! [`Params`](crate::Params)), and with the `timestamp` and `payload_length` as 8 bytes,
+//! big-endian.  The encodings of the `namespace_id`, `subspace_id`, and `payload_digest` are
+//! specific to the `Params`, and so they are given by the caller.
+//!
+//! There is also an encoding of an `Entry` relative to a reference `Entry`, which is smaller
+//! when they're similar, e.g. when sending many `Entry`s that are near each other.
+
+#![allow(clippy::big_endian_bytes)] // Our layouts are defined as big-endian.
+
+use {
+    crate::path::{
+        self,
+        PathLimitError,
+    },
+    core::fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+
+/// Errors possibly returned by the decoding functions of [this module](self).
+#[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[allow(clippy::exhaustive_enums)]
+pub enum DecodeError
+{
+    /// The input ended before all of its encoded parts.
+    UnexpectedEnd,
+    /// The input had a header byte, of a relative encoding, that is invalid.
+    InvalidHeader(u8),
+    /// The given decoder of a [`NamespaceId`](crate::Params::NamespaceId) failed.
+    InvalidNamespace,
+    /// The given decoder of a [`SubspaceId`](crate::Params::SubspaceId) failed.
+    InvalidSubspace,
+    /// The input encoded an invalid `Path`.
+    Path(path::encoding::DecodeError),
+    /// The given decoder of a [`PayloadDigest`](crate::Params::PayloadDigest) failed.
+    InvalidPayloadDigest,
+    /// The input had more bytes after all of its encoded parts.
+    TrailingBytes,
+}
+
+impl Display for DecodeError
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        match self {
+            DecodeError::UnexpectedEnd => write!(f, "decoding failed due to unexpected end"),
+            DecodeError::InvalidHeader(header) => {
+                write!(f, "decoding failed due to invalid header {header:#04x}")
+            },
+            DecodeError::InvalidNamespace => {
+                write!(f, "decoding failed due to invalid namespace")
+            },
+            DecodeError::InvalidSubspace => write!(f, "decoding failed due to invalid subspace"),
+            DecodeError::Path(e) => write!(f, "decoding failed due to path: {e}"),
+            DecodeError::InvalidPayloadDigest => {
+                write!(f, "decoding failed due to invalid payload digest")
+            },
+            DecodeError::TrailingBytes => write!(f, "decoding failed due to trailing bytes"),
+        }
+    }
+}
+
+
+#[cfg(feature = "alloc")]
+pub use alloc::*;
+
+#[cfg(feature = "alloc")]
+mod alloc
+{
+    use {
+        super::{
+            DecodeError,
+            PathLimitError,
+        },
+        crate::{
+            path::{
+                encoding::{
+                    decode_path_from,
+                    decode_path_relative_from,
+                    encode_path_relative_to,
+                    encode_path_to,
+                    Reader,
+                },
+                Component,
+            },
+            ParamsEntry,
+            Path,
+        },
+        alloc::{
+            boxed::Box,
+            vec::Vec,
+        },
+    };
+
+
+    /// Encode `entry` as:
+    /// 1. Its `namespace_id`, as appended by `encode_namespace`.
+    /// 2. Its `subspace_id`, as appended by `encode_subspace`.
+    /// 3. Its `path`, as by [`encode_path`](crate::path::encoding::encode_path).
+    /// 4. Its `timestamp`, as 8 bytes, big-endian.
+    /// 5. Its `payload_digest`, as appended by `encode_digest`.
+    /// 6. Its `payload_length`, as 8 bytes, big-endian.
+    ///
+    /// Each of the given encoders must append the bytes of the given value to the given `Vec`,
+    /// and it must be deterministic, injective, and prefix-free (e.g. fixed-width, or
+    /// length-prefixed), because the decoder of each is given the rest of the input and must
+    /// find where its encoding ends.
+    ///
+    /// # Errors
+    /// If the `path` exceeds the limits of `Params`, because then it might not be representable.
+    #[inline]
+    pub fn encode_entry<Params>(
+        entry: &ParamsEntry<Params, impl Path>,
+        encode_namespace: impl FnOnce(&Params::NamespaceId, &mut Vec<u8>),
+        encode_subspace: impl FnOnce(&Params::SubspaceId, &mut Vec<u8>),
+        encode_digest: impl FnOnce(&Params::PayloadDigest, &mut Vec<u8>),
+    ) -> Result<Vec<u8>, PathLimitError>
+    where
+        Params: crate::Params + ?Sized,
+    {
+        let mut encoded = Vec::new();
+        encode_namespace(&entry.namespace_id, &mut encoded);
+        encode_subspace(&entry.subspace_id, &mut encoded);
+        encode_path_to::<Params>(&entry.path, &mut encoded)?;
+        encoded.extend_from_slice(&entry.timestamp.μs_since_epoch.to_be_bytes());
+        encode_digest(&entry.payload_digest, &mut encoded);
+        encoded.extend_from_slice(&entry.payload_length.to_be_bytes());
+        Ok(encoded)
+    }
+
+    /// The inverse of [`encode_entry`].
+    ///
+    /// Each of the given decoders is given the rest of the input, from which it must consume,
+    /// by advancing the slice, exactly the bytes that were appended by its encoder, and it
+    /// returns `None` if they're invalid.
+    ///
+    /// # Errors
+    /// If `bytes` is not exactly an encoding of an `Entry`, if the encoded `path` exceeds the
+    /// limits of `Params`, or if any of the decoders fails.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn decode_entry<'b, Params>(
+        bytes: &'b [u8],
+        decode_namespace: impl FnOnce(&mut &'b [u8]) -> Option<Params::NamespaceId>,
+        decode_subspace: impl FnOnce(&mut &'b [u8]) -> Option<Params::SubspaceId>,
+        decode_digest: impl FnOnce(&mut &'b [u8]) -> Option<Params::PayloadDigest>,
+    ) -> Result<ParamsEntry<Params, Vec<Component<Box<[u8]>>>>, DecodeError>
+    where
+        Params: crate::Params + ?Sized,
+        Params::PayloadDigest: Clone,
+    {
+        let mut reader = Reader::new(bytes);
+        let namespace_id =
+            decode_namespace(reader.remaining_mut()).ok_or(DecodeError::InvalidNamespace)?;
+        let subspace_id =
+            decode_subspace(reader.remaining_mut()).ok_or(DecodeError::InvalidSubspace)?;
+        let path = decode_path_from::<Params>(&mut reader).map_err(DecodeError::Path)?;
+        let timestamp = take_u64(&mut reader)?.into();
+        let payload_digest =
+            decode_digest(reader.remaining_mut()).ok_or(DecodeError::InvalidPayloadDigest)?;
+        let payload_length = take_u64(&mut reader)?;
+        reader.finish().ok().ok_or(DecodeError::TrailingBytes)?;
+        Ok(crate::Entry {
+            namespace_id,
+            subspace_id,
+            path,
+            timestamp,
+            payload_digest,
+            payload_length,
+        })
+    }
+
+
+    const SAME_NAMESPACE: u8 = 0x80;
+    const SAME_SUBSPACE: u8 = 0x40;
+    const SAME_DIGEST: u8 = 0x20;
+    const SAME_LENGTH: u8 = 0x10;
+    const DELTA_WIDTH: u8 = 0x0F;
+
+    /// Encode `entry` relative to `reference`, as:
+    /// 1. A header, as 1 byte, of bit-flags that are set when the field of `entry` equals that of
+    ///    `reference`: `0x80` for `namespace_id`, `0x40` for `subspace_id`, `0x20` for
+    ///    `payload_digest`, and `0x10` for `payload_length`; and, in the low 4 bits, the amount
+    ///    of bytes of the timestamp delta.
+    /// 2. Only when not equal: the `namespace_id`, as appended by `encode_namespace`.
+    /// 3. Only when not equal: the `subspace_id`, as appended by `encode_subspace`.
+    /// 4. The `path`, relative to that of `reference`, as by
+    ///    [`encode_path_relative`](crate::path::encoding::encode_path_relative).
+    /// 5. The difference of the `timestamp` from that of `reference` (wrapping, as a 64-bit
+    ///    two's-complement integer), zig-zag encoded so that small differences either way are
+    ///    small, as the least amount of bytes (0 to 8) that can represent it, big-endian.
+    /// 6. Only when not equal: the `payload_digest`, as appended by `encode_digest`.
+    /// 7. Only when not equal: the `payload_length`, as 8 bytes, big-endian.
+    ///
+    /// The given encoders have the same requirements as for [`encode_entry`].  The decoder must
+    /// use the same `reference`.
+    ///
+    /// # Errors
+    /// If the `path` exceeds the limits of `Params`, because then it might not be representable.
+    #[inline]
+    pub fn encode_entry_relative<Params>(
+        entry: &ParamsEntry<Params, impl Path>,
+        reference: &ParamsEntry<Params, impl Path>,
+        encode_namespace: impl FnOnce(&Params::NamespaceId, &mut Vec<u8>),
+        encode_subspace: impl FnOnce(&Params::SubspaceId, &mut Vec<u8>),
+        encode_digest: impl FnOnce(&Params::PayloadDigest, &mut Vec<u8>),
+    ) -> Result<Vec<u8>, PathLimitError>
+    where
+        Params: crate::Params + ?Sized,
+    {
+        let same_namespace = entry.namespace_id == reference.namespace_id;
+        let same_subspace = entry.subspace_id == reference.subspace_id;
+        let same_digest = entry.payload_digest == reference.payload_digest;
+        let same_length = entry.payload_length == reference.payload_length;
+        let delta = zig_zag(
+            entry.timestamp.μs_since_epoch.wrapping_sub(reference.timestamp.μs_since_epoch),
+        );
+        let delta_bytes = delta.to_be_bytes();
+        let delta_width = delta_bytes.iter().skip_while(|&&byte| byte == 0).count();
+
+        let mut header = u8::try_from(delta_width).unwrap_or(DELTA_WIDTH);
+        for (same, flag) in [
+            (same_namespace, SAME_NAMESPACE),
+            (same_subspace, SAME_SUBSPACE),
+            (same_digest, SAME_DIGEST),
+            (same_length, SAME_LENGTH),
+        ] {
+            if same {
+                header |= flag;
+            }
+        }
+        let mut encoded = Vec::from([header]);
+        if !same_namespace {
+            encode_namespace(&entry.namespace_id, &mut encoded);
+        }
+        if !same_subspace {
+            encode_subspace(&entry.subspace_id, &mut encoded);
+        }
+        encode_path_relative_to::<Params>(&entry.path, &reference.path, &mut encoded)?;
+        let skip = delta_bytes.len().saturating_sub(delta_width);
+        encoded.extend_from_slice(delta_bytes.get(skip ..).unwrap_or_default());
+        if !same_digest {
+            encode_digest(&entry.payload_digest, &mut encoded);
+        }
+        if !same_length {
+            encoded.extend_from_slice(&entry.payload_length.to_be_bytes());
+        }
+        Ok(encoded)
+    }
+
+    /// The inverse of [`encode_entry_relative`], which takes the fields that are equal from
+    /// `reference`.
+    ///
+    /// The given decoders have the same requirements as for [`decode_entry`], and they're only
+    /// called for the fields that aren't equal.  The `PayloadDigest` must be `Clone`, to take it
+    /// from `reference` when it's equal.
+    ///
+    /// # Errors
+    /// If `bytes` is not exactly a relative encoding of an `Entry`, if its header is invalid
+    /// (including when its timestamp delta isn't in the least amount of bytes, so that each
+    /// `Entry` has only one encoding), if the encoded `path` shares more `Component`s than that
+    /// of `reference` has or exceeds the limits of `Params`, or if any of the decoders fails.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn decode_entry_relative<'b, Params>(
+        bytes: &'b [u8],
+        reference: &ParamsEntry<Params, impl Path>,
+        decode_namespace: impl FnOnce(&mut &'b [u8]) -> Option<Params::NamespaceId>,
+        decode_subspace: impl FnOnce(&mut &'b [u8]) -> Option<Params::SubspaceId>,
+        decode_digest: impl FnOnce(&mut &'b [u8]) -> Option<Params::PayloadDigest>,
+    ) -> Result<ParamsEntry<Params, Vec<Component<Box<[u8]>>>>, DecodeError>
+    where
+        Params: crate::Params + ?Sized,
+        Params::PayloadDigest: Clone,
+    {
+        let mut reader = Reader::new(bytes);
+        let header = reader.take(1).ok().and_then(|h| h.first().copied());
+        let header = header.ok_or(DecodeError::UnexpectedEnd)?;
+        let delta_width = usize::from(header & DELTA_WIDTH);
+        if delta_width > 8 {
+            return Err(DecodeError::InvalidHeader(header));
+        }
+        let is_same = |flag| header & flag != 0;
+
+        let namespace_id = if is_same(SAME_NAMESPACE) {
+            reference.namespace_id.clone()
+        }
+        else {
+            decode_namespace(reader.remaining_mut()).ok_or(DecodeError::InvalidNamespace)?
+        };
+        let subspace_id = if is_same(SAME_SUBSPACE) {
+            reference.subspace_id.clone()
+        }
+        else {
+            decode_subspace(reader.remaining_mut()).ok_or(DecodeError::InvalidSubspace)?
+        };
+        let path = decode_path_relative_from::<Params>(&mut reader, &reference.path)
+            .map_err(DecodeError::Path)?;
+        let delta_bytes = reader.take(delta_width).ok().ok_or(DecodeError::UnexpectedEnd)?;
+        if delta_bytes.first() == Some(&0) {
+            return Err(DecodeError::InvalidHeader(header));
+        }
+        let mut delta = [0; 8];
+        if let Some(dest) = delta.get_mut(8_usize.saturating_sub(delta_width) ..) {
+            dest.copy_from_slice(delta_bytes);
+        }
+        let timestamp = reference
+            .timestamp
+            .μs_since_epoch
+            .wrapping_add(zag_zig(u64::from_be_bytes(delta)))
+            .into();
+        let payload_digest = if is_same(SAME_DIGEST) {
+            reference.payload_digest.clone()
+        }
+        else {
+            decode_digest(reader.remaining_mut()).ok_or(DecodeError::InvalidPayloadDigest)?
+        };
+        let payload_length =
+            if is_same(SAME_LENGTH) { reference.payload_length } else { take_u64(&mut reader)? };
+        reader.finish().ok().ok_or(DecodeError::TrailingBytes)?;
+        Ok(crate::Entry {
+            namespace_id,
+            subspace_id,
+            path,
+            timestamp,
+            payload_digest,
+            payload_length,
+        })
+    }
+
+
+    /// Map a two's-complement `value` to interleave the negatives with the positives: 0, -1, 1,
+    /// -2, 2, ... become 0, 1, 2, 3, 4, ....
+    fn zig_zag(value: u64) -> u64
+    {
+        let sign = 0_u64.wrapping_sub(value >> 63);
+        (value << 1) ^ sign
+    }
+
+    /// The inverse of [`zig_zag`].
+    fn zag_zig(value: u64) -> u64
+    {
+        let sign = 0_u64.wrapping_sub(value & 1);
+        (value >> 1) ^ sign
+    }
+
+    fn take_u64(reader: &mut Reader<'_>) -> Result<u64, DecodeError>
+    {
+        let bytes = reader.take(8).ok().ok_or(DecodeError::UnexpectedEnd)?;
+        bytes.try_into().map(u64::from_be_bytes).ok().ok_or(DecodeError::UnexpectedEnd)
+    }
+}
+
+
+#[cfg(any(feature = "std", feature = "anticipate", rust_lib_feature = "error_in_core"))]
+mod standard_error
+{
+    use super::DecodeError;
+
+    cfg_if::cfg_if! { if #[cfg(any(feature = "anticipate", rust_lib_feature = "error_in_core"))]
+    {
+        use core::error::Error;
+    }
+    else if #[cfg(feature = "std")]
+    {
+        use std::error::Error;
+    } }
+
+    impl Error for DecodeError
+    {
+        #[inline]
+        fn source(&self) -> Option<&(dyn Error + 'static)>
+        {
+            match self {
+                DecodeError::Path(e) => Some(e),
+                DecodeError::UnexpectedEnd
+                | DecodeError::InvalidHeader(_)
+                | DecodeError::InvalidNamespace
+                | DecodeError::InvalidSubspace
+                | DecodeError::InvalidPayloadDigest
+                | DecodeError::TrailingBytes => None,
+            }
+        }
+    }
+}
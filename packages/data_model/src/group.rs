@@ -31,3 +31,9 @@ pub use area::{
     Area,
     AreaOfInterest,
 };
+
+pub mod encoding;
+
+#[cfg(test)]
+#[cfg(feature = "proptests")]
+mod props;
@@ -1,12 +1,24 @@
-//! `Area`s are an alternative to [`ThreeDimRange`](crate::group::ThreeDimRange)s that can be used
+//! `Area`s are an alternative to [`ThreeDimRange`]s that can be used
 //! even when encrypting [`Path`]s and [`SubspaceId`](crate::Params::SubspaceId)s.
 
 use {
     crate::{
-        group::Range,
+        group::{
+            range::{
+                End,
+                Least,
+                Successor,
+            },
+            Range,
+            ThreeDimRange,
+        },
         EmptyPath,
         Entry,
+        Params,
         Path,
+        Store,
+        StoreAuthorisedEntry,
+        StoreExt,
         Timestamp,
     },
     core::borrow::Borrow,
@@ -16,9 +28,24 @@ use {
 pub mod of_interest;
 pub use of_interest::AreaOfInterest;
 
+mod three_dim;
+pub use three_dim::FromThreeDimRangeError;
+
 
 /// A grouping of [`Entry`]s.
+///
+/// With the `serde` package-feature, the `path` is (de)serialized like [`Entry::path`], i.e. as
+/// a sequence of byte-strings that doesn't depend on the type of `Path`.
 #[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "SubspaceId: serde::Serialize, Path: crate::Path",
+        deserialize = "SubspaceId: serde::Deserialize<'de>, \
+                       Path: FromIterator<alloc::boxed::Box<[u8]>>",
+    ))
+)]
 #[allow(clippy::exhaustive_structs)]
 pub struct Area<SubspaceId, Path>
 {
@@ -28,6 +55,7 @@ pub struct Area<SubspaceId, Path>
     pub subspace: Subspace<SubspaceId>,
     /// To be included in this `Area`, an `Entry`'s `path` must be
     /// [prefixed](Path::is_prefix_of) by this.
+    #[cfg_attr(feature = "serde", serde(with = "crate::path::serde_components"))]
     pub path:     Path,
     /// To be included in this `Area`, an `Entry`'s `timestamp` must be
     /// [included](Range::includes) in this.
@@ -38,6 +66,7 @@ pub struct Area<SubspaceId, Path>
 /// Determines whether [`Area::subspace`](struct.Area.html#structfield.subspace) denotes a single
 /// Subspace or all Subspaces.
 #[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(clippy::exhaustive_enums)]
 pub enum Subspace<SubspaceId>
 {
@@ -61,6 +90,44 @@ where S: Eq
             (Subspace::Id(_), Subspace::Any) => false,
         }
     }
+
+    /// Whether `id` is in `self`, i.e. whether `self` is `Any` or is `Id` of `id`.  This is the
+    /// same as the `subspaces` of the `ThreeDimRange` [converted from](Range::from) `self`
+    /// [including](Range::includes) `id`.
+    #[must_use]
+    #[inline]
+    pub fn includes_id(
+        &self,
+        id: impl Borrow<S>,
+    ) -> bool
+    {
+        match self {
+            Subspace::Any => true,
+            Subspace::Id(self_id) => self_id == id.borrow(),
+        }
+    }
+}
+
+/// The `Range` that includes exactly the same `SubspaceId`s: for `Id`, only it (which needs its
+/// [successor](Successor)), and for `Any`, the [full](Range::is_full) open `Range` (which needs
+/// the [least](Least)).
+///
+/// These, plus [`Ord`], are what a `SubspaceId` needs, beyond the `Clone + Eq` of
+/// [`Params::SubspaceId`], to be used with [`ThreeDimRange`]s.
+impl<S> From<Subspace<S>> for Range<S>
+where S: Successor + Least
+{
+    #[inline]
+    fn from(subspace: Subspace<S>) -> Self
+    {
+        match subspace {
+            Subspace::Id(id) => {
+                let end = id.successor().map_or(End::Open, End::Closed);
+                Range { start: id, end }
+            },
+            Subspace::Any => Range { start: S::least(), end: End::Open },
+        }
+    }
 }
 
 
@@ -151,20 +218,84 @@ where
         S: Clone,
         P: Default + Clone,
     {
-        let other = other.borrow();
+        self.overlap(other.borrow()).map_or_else(Self::empty, |(subspace, path, times)| {
+            // Might as well wait to clone until certain they're needed.
+            let subspace = match subspace {
+                Subspace::Id(id) => Subspace::Id(id.clone()),
+                Subspace::Any => Subspace::Any,
+            };
+            Self { subspace, path: path.clone(), times }
+        })
+    }
 
-        let Some(subspace) = (match (&self.subspace, &other.subspace) {
+    /// Whether `self` and `other` overlap, i.e. whether their [intersection](Self::intersection)
+    /// is nonempty.
+    ///
+    /// This is cheaper than checking `!self.intersection(other).is_empty()`, because it doesn't
+    /// need to clone the `subspace` nor `path`.
+    #[must_use]
+    #[inline]
+    pub fn intersects(
+        &self,
+        other: impl Borrow<Self>,
+    ) -> bool
+    {
+        self.overlap(other.borrow()).is_some()
+    }
+
+    /// Return an [`Iterator`] of the [`Entry`]s of `store`, and their
+    /// [`AuthorisationToken`](crate::Params::AuthorisationToken)s, that are
+    /// [included](Self::includes) in `self`.
+    ///
+    /// This filters all of [`Store::iter`], and so it's as efficient as that.
+    #[inline]
+    pub fn matching_entries<'s, Ext>(
+        &'s self,
+        store: &'s Store<<Ext::Params as Params>::NamespaceId, Ext>,
+    ) -> impl Iterator<Item = StoreAuthorisedEntry<Ext>> + 's
+    where
+        Ext: StoreExt,
+        Ext::Params: Params<SubspaceId = S>,
+    {
+        store.iter().filter(|auth_entry| self.includes::<Entry<_, _, _, _>>(auth_entry.entry()))
+    }
+
+    /// The [`ThreeDimRange`] that includes exactly the same [`Entry`]s as `self`.
+    ///
+    /// The `end` of its `paths` is the least `Path` that is greater than all those prefixed by
+    /// `self.path`, which has one more byte in its last `Component` and so might exceed the
+    /// limits of some [`Params`], but that is fine for merely delimiting the `Range`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn to_three_dim_range<C>(&self) -> ThreeDimRange<S, P>
+    where
+        S: Successor + Least + Clone,
+        P: Clone + FromIterator<C>,
+        for<'b> &'b [u8]: Into<C>,
+    {
+        let subspaces = self.subspace.clone().into();
+        let paths =
+            Range { start: self.path.clone(), end: three_dim::prefix_successor(&self.path) };
+        ThreeDimRange { subspaces, paths, times: self.times }
+    }
+
+    /// The parts of the nonempty intersection, borrowed instead of cloned, or `None` if it's
+    /// empty.
+    fn overlap<'l>(
+        &'l self,
+        other: &'l Self,
+    ) -> Option<(Subspace<&'l S>, &'l P, Range<Timestamp>)>
+    {
+        let subspace = match (&self.subspace, &other.subspace) {
             (Subspace::Any, Subspace::Any) => Some(Subspace::Any),
             (Subspace::Any, Subspace::Id(other_id)) => Some(Subspace::Id(other_id)),
             (Subspace::Id(self_id), Subspace::Any) => Some(Subspace::Id(self_id)),
             (Subspace::Id(self_id), Subspace::Id(other_id)) =>
                 (self_id == other_id).then_some(Subspace::Id(self_id)),
-        })
-        else {
-            return Self::empty();
-        };
+        }?;
 
-        let Some(path) = (if self.path.is_prefix_of(&other.path) {
+        let path = if self.path.is_prefix_of(&other.path) {
             Some(&other.path)
         }
         else if other.path.is_prefix_of(&self.path) {
@@ -172,26 +303,12 @@ where
         }
         else {
             None
-        })
-        else {
-            return Self::empty();
-        };
+        }?;
 
         #[allow(clippy::needless_borrows_for_generic_args)]
         let times = self.times.intersection(&other.times);
 
-        if times.is_empty() {
-            Self::empty()
-        }
-        else {
-            // Might as well wait to clone until certain they're needed.
-            let subspace = match subspace {
-                Subspace::Id(id) => Subspace::Id(id.clone()),
-                Subspace::Any => Subspace::Any,
-            };
-            let path = path.clone();
-            Self { subspace, path, times }
-        }
+        (!times.is_empty()).then_some((subspace, path, times))
     }
 }
 
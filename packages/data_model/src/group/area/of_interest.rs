@@ -25,6 +25,14 @@ use {
 
 /// A grouping of [`Entry`]s that are among the newest in some [`Store`].
 #[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "Area<SubspaceId, Path>: serde::Serialize",
+        deserialize = "Area<SubspaceId, Path>: serde::Deserialize<'de>",
+    ))
+)]
 #[allow(clippy::exhaustive_structs)]
 pub struct AreaOfInterest<SubspaceId, Path>
 {
@@ -47,6 +55,7 @@ pub struct AreaOfInterest<SubspaceId, Path>
 /// The [`Ord`]ering of values of this type is based on the order of its fields, so that
 /// `Unlimited` is greater than any `Limit`.
 #[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(clippy::exhaustive_enums)]
 pub enum Max
 {
@@ -112,6 +121,49 @@ where
         same_namespace() && within_area() && within_limits.await
     }
 
+    /// Whether `self` could ever include any [`Entry`].  This is `false` if and only if
+    /// `self.area` is [empty](Area::is_empty), in which case `self.max_count` and
+    /// `self.max_size` are meaningless.
+    #[must_use]
+    #[inline]
+    pub fn is_satisfiable(&self) -> bool
+    {
+        !self.area.is_empty()
+    }
+
+    /// Whether `self` includes no [`Entry`]s regardless of the `Store`, i.e. the opposite of
+    /// [`Self::is_satisfiable`].
+    ///
+    /// This derives solely from `self.area`, because a zero `max_count` or `max_size` isn't
+    /// representable by [`Max`].
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool
+    {
+        !self.is_satisfiable()
+    }
+
+    /// Return `self` unchanged if it's [satisfiable](Self::is_satisfiable), else return the
+    /// canonical unsatisfiable `AreaOfInterest`, whose `area` is [`Area::empty`] and whose
+    /// `max_count` and `max_size` are `Unlimited`.
+    ///
+    /// This makes all unsatisfiable `AreaOfInterest`s be equal to each other, which helps with
+    /// deduplicating and validating sets of them, e.g. before sending them to peers.
+    #[must_use]
+    #[inline]
+    pub fn normalize(self) -> Self
+    where P: Default
+    {
+        if self.is_satisfiable() { self } else { Self::unsatisfiable() }
+    }
+
+    /// The canonical unsatisfiable `AreaOfInterest`.  See [`Self::normalize`].
+    fn unsatisfiable() -> Self
+    where P: Default
+    {
+        Self { area: Area::empty(), max_count: Max::Unlimited, max_size: Max::Unlimited }
+    }
+
     /// Let `self` and `other` be `AreaOfInterest`s.  If there exists at least one [`Entry`]
     /// [included](Area::includes) in both `self.area`, and `other.area`, then we define the
     /// _(nonempty) intersection_ of `self`, and `other` as the `AreaOfInterest` whose
@@ -122,6 +174,9 @@ where
     /// - `max_size` is `self.max_size` if `other.max_size` is `Unlimited`, `other.max_size` if
     ///   `self.max_size` is `Unlimited`, or the minimum of `self.max_size` and `other.max_size`
     ///   otherwise.
+    ///
+    /// Otherwise, when the intersection is empty, this returns the canonical unsatisfiable
+    /// `AreaOfInterest` (as [`Self::normalize`] would), without cloning any `Path`.
     #[must_use]
     #[inline]
     pub fn intersection(
@@ -133,6 +188,9 @@ where
         P: Default + Clone,
     {
         let other = other.borrow();
+        if !self.area.intersects(&other.area) {
+            return Self::unsatisfiable();
+        }
         Self {
             area:      self.area.intersection(&other.area),
             max_count: self.max_count.min(other.max_count),
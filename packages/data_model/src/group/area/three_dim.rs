@@ -0,0 +1,206 @@
+//! Converting between [`Area`]s and [`ThreeDimRange`]s.
+//!
+//! Every `Area` can be expressed as a `ThreeDimRange` that includes exactly the same [`Entry`]s,
+//! but only some `ThreeDimRange`s can be expressed as `Area`s.  A `ThreeDimRange` is convertible
+//! exactly when both:
+//! - Its `subspaces` is [full](Range::is_full), which becomes [`Subspace::Any`], or its
+//!   `subspaces` includes only its `start`, which becomes [`Subspace::Id`].  The latter is when
+//!   the `end` is the [successor](Successor) of the `start`, or when the `end` is open and the
+//!   `start` has no successor.
+//! - Its `paths` includes exactly the [`Path`]s that are [prefixed](Path::is_prefix_of) by its
+//!   `start`, which becomes the `Area::path`.  That is when the `end` is open and the `start` is
+//!   the empty `Path`, or when the `end` has the same `Component`s as the `start` except that the
+//!   last has a `0` byte appended.  (Note that, because of the `Component`s, this is not the same
+//!   as [`Extra::successor`](crate::path::Extra::successor) which is constrained by limits.)
+//!
+//! The `times` are always convertible as-is.  Note that, as a consequence, an empty
+//! `ThreeDimRange` is convertible only when it's empty due to its `times`.
+//!
+//! [`Entry`]: crate::Entry
+
+use {
+    super::{
+        Area,
+        Subspace,
+    },
+    crate::{
+        group::{
+            range::{
+                End,
+                Least,
+                Successor,
+            },
+            Range,
+            ThreeDimRange,
+        },
+        Path,
+    },
+    core::fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+
+/// The `end` of the `Range` of all the `Path`s that are prefixed by `prefix`.
+#[cfg(feature = "alloc")]
+pub(super) fn prefix_successor<P, C>(prefix: &P) -> End<P>
+where
+    P: Path + FromIterator<C>,
+    for<'b> &'b [u8]: Into<C>,
+{
+    let components = prefix.components();
+    let Some(last_index) = components.len().checked_sub(1)
+    else {
+        return End::Open; // Every `Path` is prefixed by the empty one.
+    };
+    End::Closed(
+        components
+            .enumerate()
+            .map(|(index, component)| {
+                if index == last_index {
+                    let mut greater = alloc::vec::Vec::with_capacity(
+                        component.bytes().len().saturating_add(1),
+                    );
+                    greater.extend_from_slice(component.bytes());
+                    greater.push(0);
+                    greater.as_slice().into()
+                }
+                else {
+                    component.bytes().into()
+                }
+            })
+            .collect(),
+    )
+}
+
+
+/// Whether `end` is the `end` of the `Range` of all the `Path`s that are prefixed by `start`.
+fn is_prefix_successor(
+    start: &(impl Path + ?Sized),
+    end: &End<impl Path>,
+) -> bool
+{
+    match end {
+        End::Open => start.components().len() == 0,
+        End::Closed(end) => {
+            let (start, end) = (start.components(), end.components());
+            let Some(last_index) = start.len().checked_sub(1)
+            else {
+                return false; // The empty `Path` needs an open `end`.
+            };
+            start.len() == end.len()
+                && start.zip(end).enumerate().all(|(index, (start, end))| {
+                    let (start, end) = (start.bytes(), end.bytes());
+                    if index == last_index {
+                        end.split_last().is_some_and(|(&last, init)| last == 0 && init == start)
+                    }
+                    else {
+                        start == end
+                    }
+                })
+        },
+    }
+}
+
+
+/// The [`Subspace`] that is the same as the `subspaces`: `Any` if they're full, or `Id` of the
+/// `start` if they include only that, or `None` if they can't be expressed as a `Subspace`.
+fn subspace_of<S>(subspaces: &Range<S>) -> Option<Subspace<&S>>
+where S: Successor + Least + Ord
+{
+    if subspaces.is_full() {
+        return Some(Subspace::Any);
+    }
+    let successor = subspaces.start.successor();
+    let single = match (&subspaces.end, successor) {
+        (End::Closed(end), Some(successor)) => *end == successor,
+        (End::Open, None) => true,
+        (End::Closed(_), None) | (End::Open, Some(_)) => false,
+    };
+    single.then_some(Subspace::Id(&subspaces.start))
+}
+
+
+/// Converts when the `ThreeDimRange` can be expressed as an `Area`: when its `subspaces` is full
+/// or includes only its `start`, and its `paths` includes exactly the `Path`s prefixed by its
+/// `start`.
+impl<S, P> TryFrom<&ThreeDimRange<S, P>> for Area<S, P>
+where
+    S: Successor + Least + Ord + Clone,
+    P: Path + Clone,
+{
+    type Error = FromThreeDimRangeError;
+
+    #[inline]
+    fn try_from(range: &ThreeDimRange<S, P>) -> Result<Self, Self::Error>
+    {
+        let subspace = subspace_of(&range.subspaces);
+        let paths_convertible = is_prefix_successor(&range.paths.start, &range.paths.end);
+
+        match subspace {
+            Some(subspace) if paths_convertible => Ok(Self {
+                subspace: match subspace {
+                    Subspace::Id(id) => Subspace::Id(id.clone()),
+                    Subspace::Any => Subspace::Any,
+                },
+                path:     range.paths.start.clone(),
+                times:    range.times,
+            }),
+            _ => Err(FromThreeDimRangeError {
+                subspaces_convertible: subspace.is_some(),
+                paths_convertible,
+            }),
+        }
+    }
+}
+
+
+/// Error returned when a [`ThreeDimRange`] cannot be expressed as an [`Area`].
+///
+/// The fields tell which of the dimensions are not convertible (at least one is `false`).
+#[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct FromThreeDimRangeError
+{
+    /// Whether the `subspaces` is full or includes only a single `SubspaceId`.
+    pub subspaces_convertible: bool,
+    /// Whether the `paths` includes exactly the `Path`s prefixed by a single `Path`.
+    pub paths_convertible:     bool,
+}
+
+impl Display for FromThreeDimRangeError
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        write!(
+            f,
+            "3-D range is not expressible as an area (subspaces convertible: {}, paths \
+             convertible: {})",
+            self.subspaces_convertible, self.paths_convertible
+        )
+    }
+}
+
+
+#[cfg(any(feature = "std", feature = "anticipate", rust_lib_feature = "error_in_core"))]
+mod standard_error
+{
+    use super::FromThreeDimRangeError;
+
+    cfg_if::cfg_if! { if #[cfg(any(feature = "anticipate", rust_lib_feature = "error_in_core"))]
+    {
+        use core::error::Error;
+    }
+    else if #[cfg(feature = "std")]
+    {
+        use std::error::Error;
+    } }
+
+    impl Error for FromThreeDimRangeError {}
+}
@@ -0,0 +1,489 @@
+//! Canonical byte encodings of grouping types, e.g. to feed to a hash function for a
+//! fingerprint.
+//!
+//! These have fixed, documented layouts, with all integers as big-endian, so that the same value
+//! always encodes to the same bytes regardless of the backend or platform, and so that distinct
+//! values always encode to distinct bytes.
+
+#![allow(clippy::big_endian_bytes)] // Our layouts are defined as big-endian.
+
+use {
+    super::range::{
+        End,
+        Range,
+    },
+    crate::Timestamp,
+    core::fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+
+/// The size of the encoding of a [`Range<Timestamp>`].
+pub const TIMESTAMP_RANGE_LEN: usize = 17;
+
+const TAG_OPEN: u8 = 0;
+const TAG_CLOSED: u8 = 1;
+
+
+/// Encode a [`Range<Timestamp>`] as:
+/// 1. `start`, as 8 bytes, big-endian.
+/// 2. A tag, as 1 byte: `0` when [`Open`](End::Open), or `1` when [`Closed`](End::Closed).
+/// 3. The end value, as 8 bytes, big-endian, or all-zero when open.
+#[must_use]
+#[inline]
+pub fn encode_timestamp_range(range: &Range<Timestamp>) -> [u8; TIMESTAMP_RANGE_LEN]
+{
+    let (tag, end) = match range.end {
+        End::Open => (TAG_OPEN, 0),
+        End::Closed(end) => (TAG_CLOSED, end.μs_since_epoch),
+    };
+    let mut encoded = [0; TIMESTAMP_RANGE_LEN];
+    let (start_dest, rest) = encoded.split_at_mut(8);
+    let (tag_dest, end_dest) = rest.split_at_mut(1);
+    start_dest.copy_from_slice(&range.start.μs_since_epoch.to_be_bytes());
+    tag_dest.copy_from_slice(&[tag]);
+    end_dest.copy_from_slice(&end.to_be_bytes());
+    encoded
+}
+
+/// The inverse of [`encode_timestamp_range`].
+///
+/// # Errors
+/// If the tag is invalid, or if it's open but the end value isn't all-zero (so that each
+/// `Range` has only one encoding).
+#[inline]
+pub fn decode_timestamp_range(
+    bytes: &[u8; TIMESTAMP_RANGE_LEN]
+) -> Result<Range<Timestamp>, DecodeError>
+{
+    decode_timestamp_range_from(&mut Reader(bytes))
+}
+
+fn decode_timestamp_range_from(reader: &mut Reader<'_>) -> Result<Range<Timestamp>, DecodeError>
+{
+    let start = reader.take_u64()?.into();
+    let end = match (reader.take_u8()?, reader.take_u64()?) {
+        (TAG_OPEN, 0) => End::Open,
+        (TAG_OPEN, _) => return Err(DecodeError::NonCanonical),
+        (TAG_CLOSED, end) => End::Closed(end.into()),
+        (tag, _) => return Err(DecodeError::InvalidTag(tag)),
+    };
+    Ok(Range { start, end })
+}
+
+
+/// Errors possibly returned by the decoding functions of [this module](self).
+#[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[allow(clippy::exhaustive_enums)]
+pub enum DecodeError
+{
+    /// The input ended before all of its encoded parts.
+    UnexpectedEnd,
+    /// The input had a tag byte that is invalid for its position.
+    InvalidTag(u8),
+    /// The input had a value that is valid but isn't the only encoding of it, e.g. an open end
+    /// with a non-zero end value.
+    NonCanonical,
+    /// The given decoder of a [`SubspaceId`](crate::Params::SubspaceId) failed.
+    InvalidSubspace,
+    /// The input had more bytes after all of its encoded parts.
+    TrailingBytes,
+    /// The input had a relative value that is beyond what its enclosing value can represent.
+    OutOfBounds,
+}
+
+impl Display for DecodeError
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        match self {
+            DecodeError::UnexpectedEnd => write!(f, "decoding failed due to unexpected end"),
+            DecodeError::InvalidTag(tag) => write!(f, "decoding failed due to invalid tag {tag}"),
+            DecodeError::NonCanonical => write!(f, "decoding failed due to non-canonical value"),
+            DecodeError::InvalidSubspace => write!(f, "decoding failed due to invalid subspace"),
+            DecodeError::TrailingBytes => write!(f, "decoding failed due to trailing bytes"),
+            DecodeError::OutOfBounds => write!(f, "decoding failed due to out-of-bounds value"),
+        }
+    }
+}
+
+
+/// Error returned when trying to encode a value relative to another that doesn't enclose it.
+#[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct NotIncludedError;
+
+impl Display for NotIncludedError
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        write!(f, "value is not included in the value it's relative to")
+    }
+}
+
+
+/// Consumes the input from its front.
+struct Reader<'b>(&'b [u8]);
+
+impl<'b> Reader<'b>
+{
+    fn take(
+        &mut self,
+        len: usize,
+    ) -> Result<&'b [u8], DecodeError>
+    {
+        if len <= self.0.len() {
+            let (taken, rest) = self.0.split_at(len);
+            self.0 = rest;
+            Ok(taken)
+        }
+        else {
+            Err(DecodeError::UnexpectedEnd)
+        }
+    }
+
+    fn take_u8(&mut self) -> Result<u8, DecodeError>
+    {
+        let [byte] = self.take_array()?;
+        Ok(byte)
+    }
+
+    fn take_u64(&mut self) -> Result<u64, DecodeError>
+    {
+        self.take_array().map(u64::from_be_bytes)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], DecodeError>
+    {
+        self.take(N)?.try_into().map_err(|_e| DecodeError::UnexpectedEnd)
+    }
+
+    fn finish(self) -> Result<(), DecodeError>
+    {
+        if self.0.is_empty() { Ok(()) } else { Err(DecodeError::TrailingBytes) }
+    }
+}
+
+
+#[cfg(feature = "alloc")]
+pub use alloc::*;
+
+#[cfg(feature = "alloc")]
+mod alloc
+{
+    use {
+        super::{
+            decode_timestamp_range_from,
+            encode_timestamp_range,
+            DecodeError,
+            NotIncludedError,
+            Reader,
+            TAG_CLOSED,
+            TAG_OPEN,
+        },
+        crate::{
+            group::{
+                area::{
+                    Area,
+                    Subspace,
+                },
+                range::{
+                    End,
+                    Range,
+                },
+            },
+            Path,
+            Timestamp,
+        },
+        alloc::vec::Vec,
+        core::iter,
+    };
+
+    const TAG_ANY: u8 = 0;
+    const TAG_ID: u8 = 1;
+
+    fn encode_len(
+        len: usize,
+        dest: &mut Vec<u8>,
+    )
+    {
+        // `usize` is never wider than 64-bit in practice, and `Path`s and `SubspaceId`s are far
+        // smaller anyway.
+        let len = u64::try_from(len).unwrap_or(u64::MAX);
+        dest.extend_from_slice(&len.to_be_bytes());
+    }
+
+    fn decode_len(reader: &mut Reader<'_>) -> Result<usize, DecodeError>
+    {
+        // A length that doesn't fit in `usize` can't fit in the input either.
+        reader.take_u64()?.try_into().ok().ok_or(DecodeError::UnexpectedEnd)
+    }
+
+    /// Encode an [`Area`] as:
+    /// 1. A subspace tag, as 1 byte: `0` when [`Any`](Subspace::Any), or `1` when
+    ///    [`Id`](Subspace::Id).
+    /// 2. Only when `Id`: the length of the bytes appended by `encode_subspace`, as 8 bytes,
+    ///    big-endian, followed by those bytes.
+    /// 3. The amount of `Component`s of the `path`, as 8 bytes, big-endian, followed by, for
+    ///    each `Component`: its length, as 8 bytes, big-endian, followed by its bytes.
+    /// 4. The `times`, as by [`encode_timestamp_range`].
+    ///
+    /// `encode_subspace` must append the bytes of the given `SubspaceId` to the given `Vec`, and
+    /// it must be deterministic and injective (i.e. distinct `SubspaceId`s must give distinct
+    /// bytes) for the encoding of `Area`s to be also.
+    #[must_use]
+    #[inline]
+    pub fn encode_area<S, P>(
+        area: &Area<S, P>,
+        encode_subspace: impl FnOnce(&S, &mut Vec<u8>),
+    ) -> Vec<u8>
+    where
+        P: Path,
+    {
+        let mut encoded = Vec::new();
+        match &area.subspace {
+            Subspace::Any => encoded.push(TAG_ANY),
+            Subspace::Id(subspace_id) => {
+                encoded.push(TAG_ID);
+                let mut subspace = Vec::new();
+                encode_subspace(subspace_id, &mut subspace);
+                encode_len(subspace.len(), &mut encoded);
+                encoded.extend_from_slice(&subspace);
+            },
+        }
+        let components = area.path.components();
+        encode_len(components.len(), &mut encoded);
+        for component in components {
+            encode_len(component.bytes().len(), &mut encoded);
+            encoded.extend_from_slice(component.bytes());
+        }
+        encoded.extend_from_slice(&encode_timestamp_range(&area.times));
+        encoded
+    }
+
+    /// The inverse of [`encode_area`].
+    ///
+    /// The `Component`s of the resulting `path` borrow from `bytes`.  `decode_subspace` is given
+    /// exactly the bytes that were appended by the `encode_subspace` of `encode_area`, and it
+    /// returns `None` if they're invalid.
+    ///
+    /// # Errors
+    /// If `bytes` is not exactly an encoding of an `Area`, or if `decode_subspace` fails.
+    #[inline]
+    pub fn decode_area<'b, S, P>(
+        bytes: &'b [u8],
+        decode_subspace: impl FnOnce(&'b [u8]) -> Option<S>,
+    ) -> Result<Area<S, P>, DecodeError>
+    where
+        P: Path + FromIterator<&'b [u8]>,
+    {
+        let mut reader = Reader(bytes);
+        let subspace = match reader.take_u8()? {
+            TAG_ANY => Subspace::Any,
+            TAG_ID => {
+                let len = decode_len(&mut reader)?;
+                let subspace_bytes = reader.take(len)?;
+                Subspace::Id(decode_subspace(subspace_bytes).ok_or(DecodeError::InvalidSubspace)?)
+            },
+            tag => return Err(DecodeError::InvalidTag(tag)),
+        };
+        let count = decode_len(&mut reader)?;
+        let path = iter::repeat_with(|| {
+            let len = decode_len(&mut reader)?;
+            reader.take(len)
+        })
+        .take(count)
+        .collect::<Result<P, _>>()?;
+        let times = decode_timestamp_range_from(&mut reader)?;
+        reader.finish()?;
+        Ok(Area { subspace, path, times })
+    }
+
+    const TAG_SAME: u8 = 0;
+
+    fn encode_subspace_id<S>(
+        subspace_id: &S,
+        encode_subspace: impl FnOnce(&S, &mut Vec<u8>),
+        dest: &mut Vec<u8>,
+    )
+    {
+        let mut subspace = Vec::new();
+        encode_subspace(subspace_id, &mut subspace);
+        encode_len(subspace.len(), dest);
+        dest.extend_from_slice(&subspace);
+    }
+
+    /// Encode an [`Area`] relative to an `outer` one that includes it, which needs fewer bytes
+    /// than [`encode_area`] when the decoder already has `outer`, as:
+    /// 1. A subspace tag, as 1 byte: `0` when the same as `outer`'s, or `1` when
+    ///    [`Id`](Subspace::Id) but `outer`'s is [`Any`](Subspace::Any).
+    /// 2. Only when `1`: the length of the bytes appended by `encode_subspace`, as 8 bytes,
+    ///    big-endian, followed by those bytes.
+    /// 3. The amount of `Component`s of the `path` after those of `outer`'s `path` (which is a
+    ///    prefix of it), as 8 bytes, big-endian, followed by, for each of those `Component`s:
+    ///    its length, as 8 bytes, big-endian, followed by its bytes.
+    /// 4. The `times.start` minus `outer.times.start`, as 8 bytes, big-endian.
+    /// 5. A tag, as 1 byte: `0` when the `times.end` is [`Open`](End::Open), or `1` when
+    ///    [`Closed`](End::Closed).
+    /// 6. Only when `Closed`: if `outer`'s is `Closed` also, `outer`'s end value minus the end
+    ///    value, else the end value minus `outer.times.start`, as 8 bytes, big-endian.
+    ///
+    /// `encode_subspace` must be as for `encode_area`.
+    ///
+    /// # Errors
+    /// If `inner` is not within `outer` in each of its dimensions.  Every empty `Area` is
+    /// included in every other, but only those that are also within `outer` in this way can be
+    /// encoded relative to it.
+    #[inline]
+    pub fn encode_area_relative<S, P, Po>(
+        inner: &Area<S, P>,
+        outer: &Area<S, Po>,
+        encode_subspace: impl FnOnce(&S, &mut Vec<u8>),
+    ) -> Result<Vec<u8>, NotIncludedError>
+    where
+        S: Eq,
+        P: Path,
+        Po: Path,
+    {
+        let mut encoded = Vec::new();
+        match (&outer.subspace, &inner.subspace) {
+            (outer_subspace, inner_subspace) if outer_subspace == inner_subspace =>
+                encoded.push(TAG_SAME),
+            (Subspace::Any, Subspace::Id(subspace_id)) => {
+                encoded.push(TAG_ID);
+                encode_subspace_id(subspace_id, encode_subspace, &mut encoded);
+            },
+            _ => return Err(NotIncludedError),
+        }
+
+        if !outer.path.is_prefix_of(&inner.path) {
+            return Err(NotIncludedError);
+        }
+        let suffix = inner.path.components().skip(outer.path.components().len());
+        encode_len(suffix.len(), &mut encoded);
+        for component in suffix {
+            encode_len(component.bytes().len(), &mut encoded);
+            encoded.extend_from_slice(component.bytes());
+        }
+
+        let outer_start = outer.times.start.μs_since_epoch;
+        let start_delta =
+            inner.times.start.μs_since_epoch.checked_sub(outer_start).ok_or(NotIncludedError)?;
+        encoded.extend_from_slice(&start_delta.to_be_bytes());
+        match (outer.times.end, inner.times.end) {
+            (End::Open, End::Open) => encoded.push(TAG_OPEN),
+            (End::Closed(outer_end), End::Closed(inner_end)) => {
+                let end_delta = outer_end
+                    .μs_since_epoch
+                    .checked_sub(inner_end.μs_since_epoch)
+                    .ok_or(NotIncludedError)?;
+                encoded.push(TAG_CLOSED);
+                encoded.extend_from_slice(&end_delta.to_be_bytes());
+            },
+            (End::Open, End::Closed(inner_end)) => {
+                let end_delta =
+                    inner_end.μs_since_epoch.checked_sub(outer_start).ok_or(NotIncludedError)?;
+                encoded.push(TAG_CLOSED);
+                encoded.extend_from_slice(&end_delta.to_be_bytes());
+            },
+            (End::Closed(_), End::Open) => return Err(NotIncludedError),
+        }
+        Ok(encoded)
+    }
+
+    /// The inverse of [`encode_area_relative`], given the same `outer`.
+    ///
+    /// The resulting `path` is made of the `Component`s of `outer`'s `path` followed by the
+    /// decoded ones, all of which borrow from either `outer` or `bytes`.  `decode_subspace` must
+    /// be as for [`decode_area`].
+    ///
+    /// # Errors
+    /// If `bytes` is not exactly an encoding of an `Area` relative to `outer`, or if
+    /// `decode_subspace` fails.
+    #[inline]
+    pub fn decode_area_relative<'b, S, P, Po>(
+        bytes: &'b [u8],
+        outer: &'b Area<S, Po>,
+        decode_subspace: impl FnOnce(&'b [u8]) -> Option<S>,
+    ) -> Result<Area<S, P>, DecodeError>
+    where
+        S: Clone,
+        P: Path + FromIterator<&'b [u8]>,
+        Po: Path,
+    {
+        let mut reader = Reader(bytes);
+        let subspace = match (reader.take_u8()?, &outer.subspace) {
+            (TAG_SAME, outer_subspace) => outer_subspace.clone(),
+            (TAG_ID, Subspace::Any) => {
+                let len = decode_len(&mut reader)?;
+                let subspace_bytes = reader.take(len)?;
+                Subspace::Id(decode_subspace(subspace_bytes).ok_or(DecodeError::InvalidSubspace)?)
+            },
+            (tag, _) => return Err(DecodeError::InvalidTag(tag)),
+        };
+
+        let count = decode_len(&mut reader)?;
+        let suffix = iter::repeat_with(|| {
+            let len = decode_len(&mut reader)?;
+            reader.take(len)
+        })
+        .take(count);
+        let path = outer
+            .path
+            .components()
+            .map(|component| Ok(component.inner))
+            .chain(suffix)
+            .collect::<Result<P, _>>()?;
+
+        let outer_start = outer.times.start.μs_since_epoch;
+        let start =
+            outer_start.checked_add(reader.take_u64()?).ok_or(DecodeError::OutOfBounds)?;
+        let end = match (reader.take_u8()?, outer.times.end) {
+            (TAG_OPEN, End::Open) => End::Open,
+            (TAG_CLOSED, outer_end) => {
+                let delta = reader.take_u64()?;
+                let end = match outer_end {
+                    End::Closed(outer_end) => outer_end.μs_since_epoch.checked_sub(delta),
+                    End::Open => outer_start.checked_add(delta),
+                };
+                End::Closed(Timestamp::from(end.ok_or(DecodeError::OutOfBounds)?))
+            },
+            (tag, _) => return Err(DecodeError::InvalidTag(tag)),
+        };
+        reader.finish()?;
+        Ok(Area { subspace, path, times: Range { start: Timestamp::from(start), end } })
+    }
+}
+
+
+#[cfg(any(feature = "std", feature = "anticipate", rust_lib_feature = "error_in_core"))]
+mod standard_error
+{
+    use super::{
+        DecodeError,
+        NotIncludedError,
+    };
+
+    cfg_if::cfg_if! { if #[cfg(any(feature = "anticipate", rust_lib_feature = "error_in_core"))]
+    {
+        use core::error::Error;
+    }
+    else if #[cfg(feature = "std")]
+    {
+        use std::error::Error;
+    } }
+
+    impl Error for DecodeError {}
+    impl Error for NotIncludedError {}
+}
@@ -0,0 +1,166 @@
+//! Property-based tests of invariants of [`Range`]s and [`Area`]s that other code relies on.
+//!
+//! These generate small values, so that overlaps, containments, and empties are common, and so
+//! that the included values can simply be enumerated to check against.
+
+use {
+    super::{
+        area::Subspace,
+        range::End,
+        Area,
+        Range,
+    },
+    crate::{
+        Entry,
+        Timestamp,
+    },
+    alloc::borrow::Cow,
+    proptest::prelude::*,
+};
+
+
+/// Greater than all the `start` and `end` values that are generated.
+const BOUND: u8 = 8;
+
+type TestPath = Cow<'static, [&'static str]>;
+type TestArea = Area<u8, TestPath>;
+type TestEntry = Entry<(), u8, TestPath, ()>;
+
+
+fn range() -> impl Strategy<Value = Range<u8>>
+{
+    (0 .. BOUND, proptest::option::of(0 .. BOUND)).prop_map(|(start, end)| Range {
+        start,
+        end: end.map_or(End::Open, End::Closed),
+    })
+}
+
+fn times() -> impl Strategy<Value = Range<Timestamp>>
+{
+    range().prop_map(|Range { start, end }| Range {
+        start: u64::from(start).into(),
+        end:   match end {
+            End::Closed(end) => End::Closed(u64::from(end).into()),
+            End::Open => End::Open,
+        },
+    })
+}
+
+fn subspace() -> impl Strategy<Value = Subspace<u8>>
+{
+    prop_oneof![Just(Subspace::Any), (0 .. 2_u8).prop_map(Subspace::Id)]
+}
+
+fn path() -> impl Strategy<Value = TestPath>
+{
+    proptest::collection::vec(prop_oneof![Just("a"), Just("b")], 0 ..= 2).prop_map(Cow::Owned)
+}
+
+fn area() -> impl Strategy<Value = TestArea>
+{
+    (subspace(), path(), times()).prop_map(|(subspace, path, times)| Area {
+        subspace,
+        path,
+        times,
+    })
+}
+
+fn entry() -> impl Strategy<Value = TestEntry>
+{
+    (0 .. 2_u8, path(), 0 ..= u64::from(BOUND)).prop_map(|(subspace_id, path, timestamp)| Entry {
+        namespace_id: (),
+        subspace_id,
+        path,
+        timestamp: timestamp.into(),
+        payload_digest: (),
+        payload_length: 0,
+    })
+}
+
+/// All the values that any generated `Range` could include.
+fn values() -> impl Iterator<Item = u8>
+{
+    0 ..= BOUND
+}
+
+
+proptest! {
+    #[test]
+    fn range_intersection_commutative(a in range(), b in range())
+    {
+        prop_assert_eq!(a.intersection(b), b.intersection(a));
+    }
+
+    #[test]
+    fn range_intersection_empty_iff_disjoint(a in range(), b in range())
+    {
+        let overlap = values().any(|v| a.includes(v) && b.includes(v));
+        prop_assert_eq!(a.intersection(b).is_empty(), !overlap);
+    }
+
+    #[test]
+    fn range_intersection_includes_exactly_both(a in range(), b in range())
+    {
+        let i = a.intersection(b);
+        for v in values() {
+            prop_assert_eq!(i.includes(v), a.includes(v) && b.includes(v));
+        }
+    }
+
+    #[test]
+    fn range_includes_range_reflexive(a in range())
+    {
+        prop_assert!(a.includes_range(&a));
+    }
+
+    #[test]
+    fn range_includes_range_transitive(a in range(), b in range(), c in range())
+    {
+        if a.includes_range(&b) && b.includes_range(&c) {
+            prop_assert!(a.includes_range(&c));
+        }
+    }
+
+    #[test]
+    fn range_includes_range_iff_includes_all(a in range(), b in range())
+    {
+        let all = values().filter(|v| b.includes(v)).all(|v| a.includes(v));
+        prop_assert_eq!(a.includes_range(&b), all);
+    }
+
+    #[test]
+    fn area_intersection_commutative(a in area(), b in area())
+    {
+        prop_assert_eq!(a.intersection(&b), b.intersection(&a));
+    }
+
+    #[test]
+    fn area_intersection_included_in_both(a in area(), b in area())
+    {
+        let i = a.intersection(&b);
+        prop_assert!(a.includes::<TestArea>(&i));
+        prop_assert!(b.includes::<TestArea>(&i));
+    }
+
+    #[test]
+    fn area_intersection_includes_monotone(a in area(), bigger in area(), b in area())
+    {
+        if bigger.includes::<TestArea>(&a) {
+            prop_assert!(bigger.intersection(&b).includes::<TestArea>(a.intersection(&b)));
+        }
+    }
+
+    #[test]
+    fn area_intersection_includes_exactly_both(
+        a in area(),
+        b in area(),
+        entries in proptest::collection::vec(entry(), 0 .. 16),
+    )
+    {
+        let i = a.intersection(&b);
+        for e in &entries {
+            let in_both = a.includes::<TestEntry>(e) && b.includes::<TestEntry>(e);
+            prop_assert_eq!(i.includes::<TestEntry>(e), in_both);
+        }
+    }
+}
@@ -19,9 +19,13 @@ pub use three_dim::ThreeDimRange;
 mod least;
 pub use least::Least;
 
+mod successor;
+pub use successor::Successor;
+
 
 /// Determines whether a [`Range`] is _closed_ or _open_.
 #[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(clippy::exhaustive_enums)]
 pub enum End<T>
 {
@@ -31,10 +35,28 @@ pub enum End<T>
     Open,
 }
 
+impl<T> End<T>
+{
+    /// Apply `f` to the value of a [`Closed`](End::Closed) end, or leave an [`Open`](End::Open)
+    /// end open.
+    #[inline]
+    pub fn map<U>(
+        self,
+        f: impl FnOnce(T) -> U,
+    ) -> End<U>
+    {
+        match self {
+            End::Closed(end) => End::Closed(f(end)),
+            End::Open => End::Open,
+        }
+    }
+}
+
 
 /// A _range_ is a simple one-dimensional way of grouping [`Entry`](crate::Entry)s, and is either
 /// a _closed range_ or an _open range_.
 #[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(clippy::exhaustive_structs)]
 pub struct Range<T>
 {
@@ -65,12 +87,47 @@ impl<T> From<core::ops::RangeFrom<T>> for Range<T>
     }
 }
 
+/// The inclusive `end` becomes the closed end of its [successor](Successor), or, when `end` is
+/// the greatest value (which has no successor), the `Range` is open, which includes the same
+/// values.
+impl<T> From<core::ops::RangeInclusive<T>> for Range<T>
+where T: Successor
+{
+    #[inline]
+    fn from(value: core::ops::RangeInclusive<T>) -> Self
+    {
+        let (start, end) = value.into_inner();
+        Self { start, end: end.successor().map_or(End::Open, End::Closed) }
+    }
+}
+
+impl<T> From<core::ops::RangeTo<T>> for Range<T>
+where T: Least
+{
+    #[inline]
+    fn from(value: core::ops::RangeTo<T>) -> Self
+    {
+        Self { start: T::least(), end: End::Closed(value.end) }
+    }
+}
+
+/// The full `Range`, i.e. the [`default`](Self::default).
+impl<T> From<core::ops::RangeFull> for Range<T>
+where T: Least
+{
+    #[inline]
+    fn from(_value: core::ops::RangeFull) -> Self
+    {
+        Self::default()
+    }
+}
+
 impl From<core::ops::Range<u64>> for Range<Timestamp>
 {
     #[inline]
     fn from(value: core::ops::Range<u64>) -> Self
     {
-        Self { start: value.start.into(), end: End::Closed(value.end.into()) }
+        Range::<u64>::from(value).map(Timestamp::from)
     }
 }
 
@@ -79,10 +136,51 @@ impl From<core::ops::RangeFrom<u64>> for Range<Timestamp>
     #[inline]
     fn from(value: core::ops::RangeFrom<u64>) -> Self
     {
-        Self { start: value.start.into(), end: End::Open }
+        Range::<u64>::from(value).map(Timestamp::from)
     }
 }
 
+/// Like the generic `From<RangeInclusive<T>>`: an inclusive `end` of [`u64::MAX`] microseconds
+/// (which can't be represented as a closed end of `end + 1`) makes the `Range` open, which
+/// includes the same `Timestamp`s.
+impl From<core::ops::RangeInclusive<u64>> for Range<Timestamp>
+{
+    #[inline]
+    fn from(value: core::ops::RangeInclusive<u64>) -> Self
+    {
+        let (start, end) = value.into_inner();
+        (Timestamp::from(start) ..= Timestamp::from(end)).into()
+    }
+}
+
+impl From<core::ops::RangeTo<u64>> for Range<Timestamp>
+{
+    #[inline]
+    fn from(value: core::ops::RangeTo<u64>) -> Self
+    {
+        (.. Timestamp::from(value.end)).into()
+    }
+}
+
+
+impl<T> Range<T>
+{
+    /// Apply `f` to the `start` value and to the `end` value (if `self` is a closed range), to
+    /// convert it to a `Range<U>`, e.g. from a `Range<u64>` of microseconds to a
+    /// `Range<Timestamp>`.
+    ///
+    /// For the result to include the conversions of the same values that `self` includes, `f`
+    /// must be strictly monotonic (i.e. `a < b` implies `f(a) < f(b)`).  Otherwise, the result
+    /// might include different values, or be empty when `self` isn't.
+    #[inline]
+    pub fn map<U>(
+        self,
+        mut f: impl FnMut(T) -> U,
+    ) -> Range<U>
+    {
+        Range { start: f(self.start), end: self.end.map(f) }
+    }
+}
 
 impl<T> Range<T>
 where T: Ord
@@ -163,6 +261,56 @@ where T: Ord
         };
         Self { start, end }
     }
+
+    /// The union of `self` and `other`, when that is a single `Self`, i.e. when they overlap or
+    /// are adjacent (when one's `end` value is the other's `start` value).  Its `start` value is
+    /// the lesser of the `start` values, and its `end` value is the greater of the `end` values
+    /// (if both are closed ranges) or no `end` value (if either is an open range).
+    ///
+    /// Returns `None` when there's a gap between them, because then their union can't be a
+    /// single `Range`.  An empty `Range` is neither a gap nor extends the union, and so the union
+    /// with one is just the other.
+    #[must_use]
+    #[inline]
+    pub fn union(
+        &self,
+        other: impl Borrow<Self>,
+    ) -> Option<Self>
+    where
+        T: Clone,
+    {
+        let other = other.borrow();
+        if other.is_empty() {
+            return Some(self.clone());
+        }
+        if self.is_empty() {
+            return Some(other.clone());
+        }
+        let reaches = |from: &Self, to: &Self| match &from.end {
+            End::Closed(end) => to.start <= *end,
+            End::Open => true,
+        };
+        (reaches(self, other) && reaches(other, self)).then(|| {
+            let start = min(&self.start, &other.start).clone();
+            let end = match (&self.end, &other.end) {
+                (End::Closed(self_end), End::Closed(other_end)) =>
+                    End::Closed(max(self_end, other_end).clone()),
+                (End::Closed(_) | End::Open, End::Open) | (End::Open, End::Closed(_)) =>
+                    End::Open,
+            };
+            Self { start, end }
+        })
+    }
+
+    /// Whether `self` is the same as the [`Default`] `Range`, i.e. includes the entirety of
+    /// **all** the values of type `T`.
+    #[must_use]
+    #[inline]
+    pub fn is_full(&self) -> bool
+    where T: Least
+    {
+        self.start == T::least() && self.end == End::Open
+    }
 }
 
 
@@ -13,6 +13,11 @@ use {
 /// gives `0` for `i32`).  (If the standard `core` library ever gains an equivalent trait, that
 /// should be used instead, and, as a transition phase, this could be implemented for everything
 /// which implements that.)
+///
+/// When implementing this for a custom type, e.g. a
+/// [`SubspaceId`](crate::Params::SubspaceId), the value must be less than or equal to all others
+/// according to the type's [`Ord`], or else the _full_ `Range`s that start at it would not
+/// actually include all values.
 pub trait Least
 {
     /// The value that is less than all other values of `Self`.
@@ -56,6 +61,8 @@ impl_Least_MIN! { u8 i8 u16 i16 u32 i32 u64 i64 u128 i128 usize isize }
 
 impl_Least_str! { &str }
 
+impl_Least! { &[u8] = &[] }
+
 impl_Least! { char = '\0' }
 
 impl<const N: usize, L: Least + Copy> Least for [L; N]
@@ -100,10 +107,16 @@ mod alloc
 {
     use alloc::{
         borrow::Cow,
+        boxed::Box,
+        rc::Rc,
         string::String,
+        sync::Arc,
+        vec::Vec,
     };
 
-    impl_Least_str! { String Cow<'_, str> }
+    impl_Least_str! { String Cow<'_, str> Box<str> Rc<str> Arc<str> }
+
+    impl_Least_empty! { Vec<u8> Box<[u8]> Rc<[u8]> Arc<[u8]> }
 }
 
 
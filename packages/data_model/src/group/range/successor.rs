@@ -0,0 +1,102 @@
+use {
+    super::Least,
+    crate::Timestamp,
+};
+
+
+/// A type that implements this has, for each value, a _successor_ that is the least value greater
+/// than it, unless it's the greatest value.
+///
+/// This is needed for converting between a single value and the [`Range`](super::Range) that
+/// includes only it, e.g. between the `subspace` of an [`Area`](crate::group::Area) and the
+/// `subspaces` of a [`ThreeDimRange`](super::ThreeDimRange).
+pub trait Successor: Sized
+{
+    /// The least value that is greater than `self`, or `None` if `self` is the greatest.
+    fn successor(&self) -> Option<Self>;
+}
+
+
+macro_rules! impl_Successor_checked_add {
+    ($($ty:ty)*) => { $(
+        impl $crate::group::range::Successor for $ty
+        {
+            #[inline]
+            fn successor(&self) -> Option<Self>
+            {
+                self.checked_add(1)
+            }
+        }
+    )* }
+}
+
+
+impl_Successor_checked_add! { u8 i8 u16 i16 u32 i32 u64 i64 u128 i128 usize isize }
+
+/// Lexicographic, like the [`Ord`] of arrays: the last element is incremented, and when it's the
+/// greatest it becomes the least and the increment carries to the previous element.
+impl<const N: usize, T: Successor + Least + Clone> Successor for [T; N]
+{
+    #[inline]
+    fn successor(&self) -> Option<Self>
+    {
+        let mut successor = self.clone();
+        for elem in successor.iter_mut().rev() {
+            if let Some(next) = elem.successor() {
+                *elem = next;
+                return Some(successor);
+            }
+            *elem = T::least();
+        }
+        None
+    }
+}
+
+impl Successor for Timestamp
+{
+    #[inline]
+    fn successor(&self) -> Option<Self>
+    {
+        self.μs_since_epoch.successor().map(Into::into)
+    }
+}
+
+
+#[cfg(feature = "alloc")]
+mod alloc
+{
+    use {
+        super::Successor,
+        alloc::{
+            string::String,
+            vec::Vec,
+        },
+    };
+
+    /// The same `String` with a `'\0'` appended, which is the least `String` greater than it.
+    impl Successor for String
+    {
+        #[inline]
+        fn successor(&self) -> Option<Self>
+        {
+            let mut successor = self.clone();
+            successor.push('\0');
+            Some(successor)
+        }
+    }
+
+    /// The same bytes with a `0` appended, which is the least byte-string greater than it.
+    impl Successor for Vec<u8>
+    {
+        #[inline]
+        fn successor(&self) -> Option<Self>
+        {
+            let mut successor = self.clone();
+            successor.push(0);
+            Some(successor)
+        }
+    }
+}
+
+
+// TODO: impl for more types as appropriate.
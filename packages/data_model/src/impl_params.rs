@@ -0,0 +1,147 @@
+//! A macro for conveniently implementing [`Params`](crate::Params).
+
+
+/// Implement [`Params`](crate::Params) for a type, from just the choices of the types, the
+/// limits, and the hashing and authorisation functions.
+///
+/// The generated [`hash_payload`](crate::Params::hash_payload) seeks to the start of the
+/// `Payload` and gives each consecutive chunk of it to the `update` of the given hashing
+/// functions (via [`for_each_chunk`](crate::payload::Extra::for_each_chunk)), and so its
+/// [`HashPayloadError`](crate::Params::HashPayloadError) is
+/// [`ForEachChunkError`](crate::payload::ForEachChunkError).  The given
+/// [`is_authorised_write`](crate::Params::is_authorised_write) body is given the `entry` and
+/// the `auth_token` by the names bound by its closure-like parameters.
+///
+/// The limits must be non-zero `usize` constant expressions, which is checked at compile time.
+///
+/// # Example
+/// ```
+/// use std::{collections::hash_map::DefaultHasher, hash::Hasher as _};
+///
+/// struct MyParams;
+///
+/// sailce_data_model::impl_params! {
+///     MyParams {
+///         NamespaceId = String,
+///         SubspaceId = String,
+///         PayloadDigest = u64,
+///         AuthorisationToken = String,
+///         MAX_COMPONENT_LENGTH = 64,
+///         MAX_COMPONENT_COUNT = 16,
+///         MAX_PATH_LENGTH = 1024,
+///         hash_payload = {
+///             init: DefaultHasher::new(),
+///             update: |hasher, bytes| hasher.write(bytes),
+///             finish: |hasher| hasher.finish(),
+///         },
+///         is_authorised_write = |entry, auth_token| *auth_token == entry.subspace_id,
+///     }
+/// }
+/// ```
+///
+/// A zero limit fails to compile:
+/// ```compile_fail
+/// # struct MyParams;
+/// sailce_data_model::impl_params! {
+///     MyParams {
+///         NamespaceId = (),
+///         SubspaceId = (),
+///         PayloadDigest = (),
+///         AuthorisationToken = (),
+///         MAX_COMPONENT_LENGTH = 64,
+///         MAX_COMPONENT_COUNT = 0,
+///         MAX_PATH_LENGTH = 1024,
+///         hash_payload = { init: (), update: |_s, _b| (), finish: |_s| () },
+///         is_authorised_write = |_entry, _auth_token| true,
+///     }
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! impl_params {
+    (@non_zero $name:ident = $value:expr) => {
+        match ::core::num::NonZeroUsize::new($value) {
+            ::core::option::Option::Some(non_zero) => non_zero,
+            ::core::option::Option::None => {
+                ::core::panic!(::core::concat!(
+                    "`",
+                    ::core::stringify!($name),
+                    "` must be non-zero"
+                ))
+            },
+        }
+    };
+
+    (
+        $params:ty {
+            NamespaceId = $namespace_id:ty,
+            SubspaceId = $subspace_id:ty,
+            PayloadDigest = $payload_digest:ty,
+            AuthorisationToken = $auth_token:ty,
+            MAX_COMPONENT_LENGTH = $max_component_length:expr,
+            MAX_COMPONENT_COUNT = $max_component_count:expr,
+            MAX_PATH_LENGTH = $max_path_length:expr,
+            hash_payload = {
+                init: $init:expr,
+                update: |$update_state:ident, $bytes:ident| $update:expr,
+                finish: |$finish_state:ident| $finish:expr $(,)?
+            },
+            is_authorised_write = |$entry:ident, $token:ident| $is_authorised:expr $(,)?
+        }
+    ) => {
+        impl $crate::Params for $params
+        {
+            type AuthorisationToken = $auth_token;
+            type HashPayloadError<P: $crate::Payload + ?Sized> =
+                $crate::payload::ForEachChunkError<P::ReadError, P::SeekError>;
+            type NamespaceId = $namespace_id;
+            type PayloadDigest = $payload_digest;
+            type SubspaceId = $subspace_id;
+
+            const MAX_COMPONENT_COUNT: ::core::num::NonZeroUsize =
+                $crate::impl_params!(@non_zero MAX_COMPONENT_COUNT = $max_component_count);
+            const MAX_COMPONENT_LENGTH: ::core::num::NonZeroUsize =
+                $crate::impl_params!(@non_zero MAX_COMPONENT_LENGTH = $max_component_length);
+            const MAX_PATH_LENGTH: ::core::num::NonZeroUsize =
+                $crate::impl_params!(@non_zero MAX_PATH_LENGTH = $max_path_length);
+
+            async fn hash_payload<P: $crate::Payload + ?Sized>(
+                payload: &mut P
+            ) -> ::core::result::Result<Self::PayloadDigest, Self::HashPayloadError<P>>
+            {
+                const CHUNK_SIZE: ::core::num::NonZeroUsize =
+                    $crate::impl_params!(@non_zero CHUNK_SIZE = 4 * 1024);
+
+                let mut state = $init;
+                $crate::payload::Extra::for_each_chunk(
+                    payload,
+                    0 ..,
+                    CHUNK_SIZE,
+                    |$bytes: &[u8]| {
+                        let $update_state = &mut state;
+                        $update;
+                    },
+                    false,
+                )
+                .await?;
+                let $finish_state = state;
+                ::core::result::Result::Ok($finish)
+            }
+
+            fn is_authorised_write(
+                $entry: &$crate::ParamsEntry<Self, impl $crate::Path>,
+                $token: &Self::AuthorisationToken,
+            ) -> bool
+            {
+                $is_authorised
+            }
+        }
+
+        // Evaluate the limits, so that invalid ones are compile errors even if unused.
+        const _: [::core::num::NonZeroUsize; 3] = [
+            <$params as $crate::Params>::MAX_COMPONENT_COUNT,
+            <$params as $crate::Params>::MAX_COMPONENT_LENGTH,
+            <$params as $crate::Params>::MAX_PATH_LENGTH,
+        ];
+    };
+}
@@ -42,6 +42,8 @@ pub use entry::*;
 // closely related.
 pub mod group;
 
+mod impl_params;
+
 pub mod path;
 pub use path::{
     EmptyPath,
@@ -53,6 +55,8 @@ pub mod syncify; // Must be before all modules that use its macro.
 pub mod payload;
 pub use payload::Payload;
 
+pub mod reconcile;
+
 pub mod store;
 pub use store::{
     Store,
@@ -60,6 +64,9 @@ pub use store::{
     StoreExt,
 };
 
+mod timestamp;
+pub use timestamp::*;
+
 
 /// Willow is a higher-order protocol: you supply specific choices for its parameters, and you get
 /// a concrete protocol that you can then use.
@@ -71,6 +78,10 @@ pub trait Params
     /// Identifies Namespaces.
     type NamespaceId: Clone + Eq;
     /// Identifies Subspaces.
+    ///
+    /// Grouping [`Entry`]s by ranges of these, as by [`ThreeDimRange`](group::ThreeDimRange),
+    /// additionally needs [`Ord`], [`Least`](group::range::Least), and
+    /// [`Successor`](group::range::Successor).
     type SubspaceId: Clone + Eq;
     /// Content-addresses the data that Willow stores.
     type PayloadDigest: Ord;
@@ -97,6 +108,41 @@ pub trait Params
         payload: &mut P
     ) -> Result<Self::PayloadDigest, Self::HashPayloadError<P>>;
 
+    /// Computes the [`PayloadDigest`](Self::PayloadDigest) of the empty byte-string.
+    ///
+    /// This is convenient for creating [`Entry`]s that have no payload body.
+    ///
+    /// The result is a constant for a given `Params`, and so callers may wish to cache it.  But
+    /// it's not cached by this default implementation, because that would require some
+    /// shared-state storage which isn't available with `no_std`.  This still must go through
+    /// [`hash_payload`](Self::hash_payload), to be correct for the chosen hashing scheme, and so
+    /// any overriding implementation must return the same value as that would.
+    #[allow(async_fn_in_trait)] // TODO: re-evaluate
+    #[must_use]
+    #[inline]
+    async fn empty_payload_digest(
+    ) -> Result<Self::PayloadDigest, Self::HashPayloadError<payload::Empty>>
+    {
+        Self::hash_payload(&mut payload::Empty).await
+    }
+
+    /// Computes both the [`PayloadDigest`](Self::PayloadDigest) and the length of a `payload`,
+    /// as needed together for an [`Entry`]'s `payload_digest` and `payload_length`.
+    ///
+    /// The digest is from [`hash_payload`](Self::hash_payload), which is of the entire payload
+    /// and so is regardless of its current position, and the length is then the payload's
+    /// [`len`](Payload::len).
+    #[allow(async_fn_in_trait)] // TODO: re-evaluate
+    #[must_use]
+    #[inline]
+    async fn compute_digest<P: Payload + ?Sized>(
+        payload: &mut P
+    ) -> Result<(Self::PayloadDigest, u64), Self::HashPayloadError<P>>
+    {
+        let digest = Self::hash_payload(payload).await?;
+        Ok((digest, payload.len().await))
+    }
+
     /// Indicates whether the given `auth_token` proves write permission for the given `entry`.
     // TODO: Should this be `async`? To support impls that might take a while, block on I/O, etc?
     #[must_use]
@@ -104,24 +150,24 @@ pub trait Params
         entry: &ParamsEntry<Self, impl Path>,
         auth_token: &Self::AuthorisationToken,
     ) -> bool;
-}
-
 
-/// A time in microseconds since the [Unix epoch](https://en.wikipedia.org/wiki/Unix_epoch).
-#[derive(Default, Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
-#[allow(clippy::exhaustive_structs)]
-pub struct Timestamp
-{
-    /// The microseconds since the Unix epoch.
-    pub μs_since_epoch: u64,
-}
-
-impl From<u64> for Timestamp
-{
+    /// Check that the limits on [`Path`]s are consistent with each other, i.e. that a `Path`
+    /// with a single `Component` of [`MAX_COMPONENT_LENGTH`](Self::MAX_COMPONENT_LENGTH) is
+    /// within [`MAX_PATH_LENGTH`](Self::MAX_PATH_LENGTH), to catch a misconfigured choice of
+    /// limits early.  (Values that are each non-zero can't otherwise be unsatisfiable.)
+    ///
+    /// A `MAX_PATH_LENGTH` greater than what [`MAX_COMPONENT_COUNT`](Self::MAX_COMPONENT_COUNT)
+    /// maximal `Component`s could total is allowed, because that's how to not limit the total
+    /// beyond what the other limits imply (e.g. `usize::MAX`).
+    ///
+    /// This is `debug_assert`ed by [`from_path_limited`](path::Extra::from_path_limited).
+    ///
+    /// # Errors
+    /// If the limits are inconsistent.
     #[inline]
-    fn from(value: u64) -> Self
+    fn check_limits() -> Result<(), path::ParamsLimitError>
     {
-        Self { μs_since_epoch: value }
+        path::check_params::<Self>()
     }
 }
 
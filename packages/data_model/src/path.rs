@@ -9,18 +9,46 @@ use core::{
 
 mod blanket_impls;
 
+#[cfg(feature = "alloc")]
+mod buf;
+#[cfg(feature = "alloc")]
+pub use buf::PathBuf;
+
+mod common;
+pub use common::*;
+
+mod concat;
+pub use concat::Concat;
+
 mod errors;
 pub use errors::*;
 
+pub mod encoding;
+
 mod extra;
 pub use extra::*;
 
+mod limits;
+pub use limits::*;
+
+mod prefix;
+pub use prefix::Prefix;
+
+mod rev;
+pub use rev::ComponentsRev;
+
 mod str_conv;
 pub use str_conv::{
     StrComponent,
     Strish,
 };
 
+#[cfg(feature = "serde")]
+pub(crate) mod serde_components;
+
+#[cfg(feature = "alloc")]
+mod successor;
+
 
 /// A `Path` is a sequence of at most [`MAX_COMPONENT_COUNT`](crate::Params::MAX_COMPONENT_COUNT)
 /// many byte-strings, each of at most
@@ -125,12 +153,78 @@ where B: Borrow<[u8]>
 impl<B> Component<B>
 where B: Borrow<[u8]>
 {
+    /// Make a `Component` of `bytes`, without checking any limits, the same as constructing it
+    /// via its public field.  Use [`Self::try_new`] to check the limits of a
+    /// [`Params`](crate::Params).
+    #[must_use]
+    #[inline]
+    pub const fn new(bytes: B) -> Self
+    {
+        Self { inner: bytes }
+    }
+
+    /// Make a `Component` of `bytes`, if they're within the limits of `Params`, as checked by
+    /// [`component_is_valid`].
+    ///
+    /// # Errors
+    /// If `bytes` is longer than [`MAX_COMPONENT_LENGTH`](crate::Params::MAX_COMPONENT_LENGTH)
+    /// (or than [`MAX_PATH_LENGTH`](crate::Params::MAX_PATH_LENGTH), if that's less).
+    #[inline]
+    pub fn try_new<Params>(bytes: B) -> Result<Self, PathLimitError>
+    where Params: crate::Params + ?Sized
+    {
+        component_is_valid::<Params>(bytes.borrow())?;
+        Ok(Self::new(bytes))
+    }
+
     /// Get immutable reference to the bytes.
     #[inline]
     pub fn bytes(&self) -> &[u8]
     {
         <Self as Borrow<[u8]>>::borrow(self)
     }
+
+    /// The length of the bytes.
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize
+    {
+        self.bytes().len()
+    }
+
+    /// Whether `self` is the empty `Component`, which is valid in Willow.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool
+    {
+        self.bytes().is_empty()
+    }
+
+    /// Borrow the bytes of `self` without the leading `prefix`, if `self` starts with it, else
+    /// borrow all of them.  Doesn't allocate.
+    #[must_use]
+    #[inline]
+    pub fn trim_prefix(
+        &self,
+        prefix: &[u8],
+    ) -> Component<&[u8]>
+    {
+        let bytes = self.bytes();
+        Component { inner: bytes.strip_prefix(prefix).unwrap_or(bytes) }
+    }
+
+    /// Borrow the bytes of `self` without the trailing `suffix`, if `self` ends with it, else
+    /// borrow all of them.  Doesn't allocate.
+    #[must_use]
+    #[inline]
+    pub fn trim_suffix(
+        &self,
+        suffix: &[u8],
+    ) -> Component<&[u8]>
+    {
+        let bytes = self.bytes();
+        Component { inner: bytes.strip_suffix(suffix).unwrap_or(bytes) }
+    }
 }
 
 impl<Ba, Bb> PartialEq<Component<Bb>> for Component<Ba>
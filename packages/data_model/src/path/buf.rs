@@ -0,0 +1,155 @@
+use {
+    super::{
+        limits::{
+            check_component,
+            check_count,
+        },
+        Component,
+        Path,
+        PathLimitError,
+    },
+    alloc::{
+        boxed::Box,
+        vec::Vec,
+    },
+    core::{
+        fmt::{
+            self,
+            Debug,
+            Formatter,
+        },
+        marker::PhantomData,
+    },
+};
+
+
+/// An owned [`Path`] that is built incrementally, and that is always within the limits of
+/// `Params`.
+///
+/// Each [`push`](Self::push) checks the limits, including the running total of the lengths of
+/// all the `Component`s, and so a violation is reported by the exact push that would cause it
+/// instead of only later when the whole `Path` is finally used.
+pub struct PathBuf<Params>
+where Params: crate::Params + ?Sized
+{
+    components: Vec<Box<[u8]>>,
+    /// The sum of the lengths of `components`.
+    total_len:  usize,
+    params:     PhantomData<fn(&Params)>,
+}
+
+impl<Params> PathBuf<Params>
+where Params: crate::Params + ?Sized
+{
+    /// Make a new empty `PathBuf`, i.e. the empty `Path`.
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self
+    {
+        Self { components: Vec::new(), total_len: 0, params: PhantomData }
+    }
+
+    /// Append `component` to the end, if that keeps `self` within the limits of `Params`.
+    ///
+    /// # Errors
+    /// If appending would exceed any of the limits, and then `self` is unchanged.  The error
+    /// describes the `Path` as if `component` had been appended.
+    #[inline]
+    pub fn push(
+        &mut self,
+        component: impl Into<Box<[u8]>>,
+    ) -> Result<(), PathLimitError>
+    {
+        let component = component.into();
+        let index = self.components.len();
+        let total_len = self.total_len.saturating_add(component.len());
+        check_count::<Params>(index.saturating_add(1))?;
+        check_component::<Params>(index, component.len(), total_len)?;
+        self.components.push(component);
+        self.total_len = total_len;
+        Ok(())
+    }
+
+    /// Remove and return the last `Component`, or `None` if `self` is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<Box<[u8]>>
+    {
+        let component = self.components.pop()?;
+        self.total_len = self.total_len.saturating_sub(component.len());
+        Some(component)
+    }
+
+    /// The `Component`s, as a slice, which is itself a `Path`.
+    #[must_use]
+    #[inline]
+    pub fn as_path(&self) -> &[Box<[u8]>]
+    {
+        &self.components
+    }
+
+    /// The sum of the lengths of all the `Component`s, which is at most
+    /// [`MAX_PATH_LENGTH`](crate::Params::MAX_PATH_LENGTH).
+    #[must_use]
+    #[inline]
+    pub fn total_len(&self) -> usize
+    {
+        self.total_len
+    }
+
+    /// Return the `Component`s, to use them as an ordinary `Path` type.
+    #[must_use]
+    #[inline]
+    pub fn into_vec(self) -> Vec<Box<[u8]>>
+    {
+        self.components
+    }
+}
+
+impl<Params> Path for PathBuf<Params>
+where Params: crate::Params + ?Sized
+{
+    #[inline]
+    fn components(&self) -> impl ExactSizeIterator<Item = Component<&[u8]>>
+    {
+        self.as_path().components()
+    }
+}
+
+impl<Params> Default for PathBuf<Params>
+where Params: crate::Params + ?Sized
+{
+    #[inline]
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+/// Manually implemented, to avoid requiring `Params: Clone`.
+impl<Params> Clone for PathBuf<Params>
+where Params: crate::Params + ?Sized
+{
+    #[inline]
+    fn clone(&self) -> Self
+    {
+        Self {
+            components: self.components.clone(),
+            total_len:  self.total_len,
+            params:     PhantomData,
+        }
+    }
+}
+
+/// Manually implemented, to avoid requiring `Params: Debug`.
+impl<Params> Debug for PathBuf<Params>
+where Params: crate::Params + ?Sized
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        f.debug_tuple("PathBuf").field(&self.components).finish()
+    }
+}
@@ -0,0 +1,72 @@
+//! Finding what's common to multiple `Path`s, e.g. to choose an
+//! [`Area::path`](crate::group::Area::path) that includes all of them.
+
+use super::{
+    Extra as _,
+    Path,
+};
+
+
+/// Return how many of the first `Component`s are the same in all of `paths`, i.e. the amount of
+/// `Component`s of their longest common prefix.
+///
+/// The result can be used with [`Iterator::take`] on the [`components`](Path::components) of any
+/// of `paths`, or with [`Prefix::new`](super::Prefix::new), to get that prefix without
+/// allocating.  `Component`s are compared by their bytes.  Empty `paths` gives `0`, i.e. the
+/// empty `Path`, and a single path gives all of its `Component`s.
+#[must_use]
+#[inline]
+pub fn longest_common_prefix_len<'l, P>(paths: impl IntoIterator<Item = &'l P>) -> usize
+where P: Path + ?Sized + 'l
+{
+    first_and_common_len(paths).map_or(0, |(_, len)| len)
+}
+
+
+fn first_and_common_len<'l, P>(paths: impl IntoIterator<Item = &'l P>) -> Option<(&'l P, usize)>
+where P: Path + ?Sized + 'l
+{
+    let mut paths = paths.into_iter();
+    let first = paths.next()?;
+    let len = paths.fold(first.len(), |len, other| {
+        first.components().take(len).zip(other.components()).take_while(|(a, b)| a == b).count()
+    });
+    Some((first, len))
+}
+
+
+#[cfg(feature = "alloc")]
+pub use alloc::*;
+
+#[cfg(feature = "alloc")]
+mod alloc
+{
+    use {
+        super::{
+            first_and_common_len,
+            Path,
+        },
+        crate::path::Component,
+        alloc::{
+            boxed::Box,
+            vec::Vec,
+        },
+    };
+
+    /// Return a copy of the longest common prefix of all of `paths`.
+    ///
+    /// See [`longest_common_prefix_len`](super::longest_common_prefix_len), which doesn't
+    /// allocate, for the details.
+    #[must_use]
+    #[inline]
+    pub fn longest_common_prefix<'l, P>(
+        paths: impl IntoIterator<Item = &'l P>
+    ) -> Vec<Component<Box<[u8]>>>
+    where
+        P: Path + ?Sized + 'l,
+    {
+        first_and_common_len(paths).map_or_else(Vec::new, |(first, len)| {
+            first.components().take(len).map(|c| Component { inner: c.inner.into() }).collect()
+        })
+    }
+}
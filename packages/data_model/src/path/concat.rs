@@ -0,0 +1,104 @@
+use super::{
+    Component,
+    Extra as _,
+    Path,
+};
+
+
+/// A [`Path`] that is the `Component`s of some `Path` followed by those of another, without
+/// copying.
+///
+/// This is what's returned by [`Extra::concat`](super::Extra::concat).
+#[derive(Debug)]
+pub struct Concat<'l, A, B>
+where
+    A: Path + ?Sized,
+    B: Path + ?Sized,
+{
+    first:  &'l A,
+    second: &'l B,
+}
+
+impl<'l, A, B> Concat<'l, A, B>
+where
+    A: Path + ?Sized,
+    B: Path + ?Sized,
+{
+    /// Make a [`Concat`] of the `Component`s of `first` and then those of `second`.
+    #[must_use]
+    #[inline]
+    pub fn new(
+        first: &'l A,
+        second: &'l B,
+    ) -> Self
+    {
+        Self { first, second }
+    }
+}
+
+/// Manually implemented, to avoid requiring `A: Clone, B: Clone`.
+impl<A, B> Clone for Concat<'_, A, B>
+where
+    A: Path + ?Sized,
+    B: Path + ?Sized,
+{
+    #[inline]
+    fn clone(&self) -> Self
+    {
+        *self
+    }
+}
+
+/// Manually implemented, to avoid requiring `A: Copy, B: Copy`.
+impl<A, B> Copy for Concat<'_, A, B>
+where
+    A: Path + ?Sized,
+    B: Path + ?Sized,
+{
+}
+
+impl<A, B> Path for Concat<'_, A, B>
+where
+    A: Path + ?Sized,
+    B: Path + ?Sized,
+{
+    #[inline]
+    fn components(&self) -> impl ExactSizeIterator<Item = Component<&[u8]>>
+    {
+        // `Chain` isn't an `ExactSizeIterator` (because the sum of lengths could overflow in
+        // general), so this tracks the remaining amount itself.
+        Components {
+            remaining: self.first.len().saturating_add(self.second.len()),
+            chain:     self.first.components().chain(self.second.components()),
+        }
+    }
+}
+
+
+struct Components<I>
+{
+    remaining: usize,
+    chain:     I,
+}
+
+impl<'l, I> Iterator for Components<I>
+where I: Iterator<Item = Component<&'l [u8]>>
+{
+    type Item = Component<&'l [u8]>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let next = self.chain.next()?;
+        self.remaining = self.remaining.saturating_sub(1);
+        Some(next)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'l, I> ExactSizeIterator for Components<I> where I: Iterator<Item = Component<&'l [u8]>> {}
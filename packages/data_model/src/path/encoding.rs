@@ -0,0 +1,390 @@
+//! The canonical byte encoding of [`Path`](super::Path)s, e.g. for persisting them or sending
+//! them over a wire.
+//!
+//! As in the Willow encodings, the widths of the integers are sized by the limits of a
+//! [`Params`](crate::Params): the amount of `Component`s is encoded in the least amount of bytes
+//! that can represent [`MAX_COMPONENT_COUNT`](crate::Params::MAX_COMPONENT_COUNT), and the length
+//! of each `Component` in the least amount of bytes that can represent
+//! [`MAX_COMPONENT_LENGTH`](crate::Params::MAX_COMPONENT_LENGTH).  So a `Path` must be decoded
+//! with the same `Params` as it was encoded with.  All integers are big-endian.
+
+#![allow(clippy::big_endian_bytes)] // Our layouts are defined as big-endian.
+
+use {
+    super::PathLimitError,
+    core::{
+        fmt::{
+            self,
+            Display,
+            Formatter,
+        },
+        num::NonZeroUsize,
+    },
+};
+
+
+/// The amount of bytes of the encoding of the amount of `Component`s of a `Path`.
+#[must_use]
+#[inline]
+pub fn count_width<Params>() -> usize
+where Params: crate::Params + ?Sized
+{
+    width(Params::MAX_COMPONENT_COUNT)
+}
+
+/// The amount of bytes of the encoding of the length of each `Component` of a `Path`.
+#[must_use]
+#[inline]
+pub fn length_width<Params>() -> usize
+where Params: crate::Params + ?Sized
+{
+    width(Params::MAX_COMPONENT_LENGTH)
+}
+
+/// The least amount of bytes that can represent `max`.
+fn width(max: NonZeroUsize) -> usize
+{
+    let bits = usize::BITS.saturating_sub(max.leading_zeros());
+    usize::try_from(bits.div_ceil(8)).unwrap_or(usize::MAX)
+}
+
+
+/// Errors possibly returned by the decoding functions of [this module](self).
+#[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[allow(clippy::exhaustive_enums)]
+pub enum DecodeError
+{
+    /// The input ended before all of its encoded parts.
+    UnexpectedEnd,
+    /// The input encoded a `Path` that exceeds the limits of the `Params`.
+    ExceedsLimits(PathLimitError),
+    /// The input shared more leading `Component`s than the reference `Path` has.
+    ExceedsReference,
+    /// The input had more bytes after all of its encoded parts.
+    TrailingBytes,
+}
+
+impl Display for DecodeError
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        match self {
+            DecodeError::UnexpectedEnd => write!(f, "decoding failed due to unexpected end"),
+            DecodeError::ExceedsLimits(e) => write!(f, "decoding failed due to: {e}"),
+            DecodeError::ExceedsReference => {
+                write!(f, "decoding failed due to sharing more than the reference")
+            },
+            DecodeError::TrailingBytes => write!(f, "decoding failed due to trailing bytes"),
+        }
+    }
+}
+
+
+#[cfg(feature = "alloc")]
+pub use alloc::*;
+
+#[cfg(feature = "alloc")]
+mod alloc
+{
+    use {
+        super::{
+            count_width,
+            length_width,
+            DecodeError,
+            PathLimitError,
+        },
+        crate::path::{
+            limits::{
+                check_component,
+                check_count,
+            },
+            Component,
+            Extra as _,
+            Path,
+        },
+        alloc::{
+            boxed::Box,
+            vec::Vec,
+        },
+        core::borrow::Borrow,
+    };
+
+
+    /// Encode `path` as:
+    /// 1. The amount of its `Component`s, as [`count_width`] bytes.
+    /// 2. For each `Component`: its length, as [`length_width`] bytes, followed by its bytes.
+    ///
+    /// # Errors
+    /// If `path` exceeds the limits of `Params`, because then it might not be representable.
+    #[inline]
+    pub fn encode_path<Params>(path: &(impl Path + ?Sized)) -> Result<Vec<u8>, PathLimitError>
+    where Params: crate::Params + ?Sized
+    {
+        let mut encoded = Vec::new();
+        encode_path_to::<Params>(path, &mut encoded)?;
+        Ok(encoded)
+    }
+
+    /// The inverse of [`encode_path`].
+    ///
+    /// # Errors
+    /// If `bytes` is not exactly an encoding of a `Path`, or if the encoded `Path` exceeds the
+    /// limits of `Params`.
+    #[inline]
+    pub fn decode_path<Params>(bytes: &[u8]) -> Result<Vec<Component<Box<[u8]>>>, DecodeError>
+    where Params: crate::Params + ?Sized
+    {
+        let mut reader = Reader::new(bytes);
+        let path = decode_path_from::<Params>(&mut reader)?;
+        reader.finish()?;
+        Ok(path)
+    }
+
+
+    /// Encode `path` relative to `reference`, as:
+    /// 1. The amount of leading `Component`s that `path` shares with `reference`, as
+    ///    [`count_width`] bytes.
+    /// 2. The encoding, as by [`encode_path`], of only the rest of `path` after those.
+    ///
+    /// This is smaller than [`encode_path`] when `path` is similar to `reference`, e.g. when
+    /// storing or sending many `Path`s that are near each other, or within an
+    /// [`Area`](crate::group::Area) whose `path` is the `reference`.  The decoder must use the
+    /// same `reference`.
+    ///
+    /// # Errors
+    /// If `path` exceeds the limits of `Params`, because then it might not be representable.
+    #[inline]
+    pub fn encode_path_relative<Params>(
+        path: &(impl Path + ?Sized),
+        reference: &(impl Path + ?Sized),
+    ) -> Result<Vec<u8>, PathLimitError>
+    where
+        Params: crate::Params + ?Sized,
+    {
+        let mut encoded = Vec::new();
+        encode_path_relative_to::<Params>(path, reference, &mut encoded)?;
+        Ok(encoded)
+    }
+
+    /// The inverse of [`encode_path_relative`], which takes the shared leading `Component`s from
+    /// `reference`.
+    ///
+    /// # Errors
+    /// If `bytes` is not exactly a relative encoding of a `Path`, if it shares more `Component`s
+    /// than `reference` has, or if the encoded `Path` exceeds the limits of `Params`.
+    #[inline]
+    pub fn decode_path_relative<Params>(
+        bytes: &[u8],
+        reference: &(impl Path + ?Sized),
+    ) -> Result<Vec<Component<Box<[u8]>>>, DecodeError>
+    where
+        Params: crate::Params + ?Sized,
+    {
+        let mut reader = Reader::new(bytes);
+        let path = decode_path_relative_from::<Params>(&mut reader, reference)?;
+        reader.finish()?;
+        Ok(path)
+    }
+
+
+    /// Append the encoding of `path` to `dest`.  Nothing is appended if this errors.
+    pub(crate) fn encode_path_to<Params>(
+        path: &(impl Path + ?Sized),
+        dest: &mut Vec<u8>,
+    ) -> Result<(), PathLimitError>
+    where
+        Params: crate::Params + ?Sized,
+    {
+        path.validate::<Params>()?;
+        encode_components_to::<Params, _>(path.components(), dest);
+        Ok(())
+    }
+
+    /// Append the relative encoding of `path` to `dest`.  Nothing is appended if this errors.
+    pub(crate) fn encode_path_relative_to<Params>(
+        path: &(impl Path + ?Sized),
+        reference: &(impl Path + ?Sized),
+        dest: &mut Vec<u8>,
+    ) -> Result<(), PathLimitError>
+    where
+        Params: crate::Params + ?Sized,
+    {
+        path.validate::<Params>()?;
+        let shared =
+            path.components().zip(reference.components()).take_while(|(a, b)| a == b).count();
+        encode_uint(shared, count_width::<Params>(), dest);
+        encode_components_to::<Params, _>(path.components().skip(shared), dest);
+        Ok(())
+    }
+
+    /// Decode a relatively-encoded `Path` from the front of `reader`, leaving any bytes after
+    /// it.
+    pub(crate) fn decode_path_relative_from<Params>(
+        reader: &mut Reader<'_>,
+        reference: &(impl Path + ?Sized),
+    ) -> Result<Vec<Component<Box<[u8]>>>, DecodeError>
+    where
+        Params: crate::Params + ?Sized,
+    {
+        let shared = reader.take_uint(count_width::<Params>())?;
+        if shared > reference.components().len() {
+            return Err(DecodeError::ExceedsReference);
+        }
+        let rest = decode_path_from::<Params>(reader)?;
+        let path = reference
+            .components()
+            .take(shared)
+            .map(|c| Component { inner: c.inner.into() })
+            .chain(rest)
+            .collect::<Vec<_>>();
+        // The rest was only checked on its own, but the whole must be within the limits.
+        path.validate::<Params>().map_err(DecodeError::ExceedsLimits)?;
+        Ok(path)
+    }
+
+    /// The limits must've already been checked, so that all the amounts fit in their widths.
+    fn encode_components_to<Params, B>(
+        components: impl ExactSizeIterator<Item = Component<B>>,
+        dest: &mut Vec<u8>,
+    ) where
+        Params: crate::Params + ?Sized,
+        B: Borrow<[u8]>,
+    {
+        encode_uint(components.len(), count_width::<Params>(), dest);
+        for component in components {
+            encode_uint(component.bytes().len(), length_width::<Params>(), dest);
+            dest.extend_from_slice(component.bytes());
+        }
+    }
+
+    /// Decode a `Path` from the front of `reader`, leaving any bytes after it.
+    pub(crate) fn decode_path_from<Params>(
+        reader: &mut Reader<'_>
+    ) -> Result<Vec<Component<Box<[u8]>>>, DecodeError>
+    where
+        Params: crate::Params + ?Sized,
+    {
+        let count = reader.take_uint(count_width::<Params>())?;
+        check_count::<Params>(count).map_err(DecodeError::ExceedsLimits)?;
+        let mut total_len = 0_usize;
+        // Each component takes at least one byte of input, for its length, so this bounds the
+        // allocation by the input's size instead of by the untrusted count.
+        let mut path = Vec::with_capacity(count.min(reader.remaining().len()));
+        for index in 0 .. count {
+            // Checked before taking the bytes, so that a huge encoded length can't cause a huge
+            // allocation.
+            let len = reader.take_uint(length_width::<Params>())?;
+            total_len = total_len.saturating_add(len);
+            check_component::<Params>(index, len, total_len).map_err(DecodeError::ExceedsLimits)?;
+            path.push(Component { inner: reader.take(len)?.into() });
+        }
+        Ok(path)
+    }
+
+
+    /// Append the last `width` bytes of the big-endian `value`.  The limits ensure that `value`
+    /// fits.
+    fn encode_uint(
+        value: usize,
+        width: usize,
+        dest: &mut Vec<u8>,
+    )
+    {
+        let bytes = u64::try_from(value).unwrap_or(u64::MAX).to_be_bytes();
+        // `width` is at most the size of `usize`, which is never wider than 64-bit in practice.
+        let skip = bytes.len().saturating_sub(width);
+        dest.extend_from_slice(bytes.get(skip ..).unwrap_or_default());
+    }
+
+
+    /// Consumes the input from its front.
+    pub(crate) struct Reader<'b>(&'b [u8]);
+
+    impl<'b> Reader<'b>
+    {
+        pub(crate) fn new(bytes: &'b [u8]) -> Self
+        {
+            Self(bytes)
+        }
+
+        /// The input that hasn't been taken yet.
+        pub(crate) fn remaining(&self) -> &'b [u8]
+        {
+            self.0
+        }
+
+        /// For decoders that consume from the front of a `&[u8]` themselves.
+        pub(crate) fn remaining_mut(&mut self) -> &mut &'b [u8]
+        {
+            &mut self.0
+        }
+
+        pub(crate) fn take(
+            &mut self,
+            len: usize,
+        ) -> Result<&'b [u8], DecodeError>
+        {
+            if len <= self.0.len() {
+                let (taken, rest) = self.0.split_at(len);
+                self.0 = rest;
+                Ok(taken)
+            }
+            else {
+                Err(DecodeError::UnexpectedEnd)
+            }
+        }
+
+        /// Take a big-endian integer of `width` bytes.
+        fn take_uint(
+            &mut self,
+            width: usize,
+        ) -> Result<usize, DecodeError>
+        {
+            // A value that doesn't fit in `usize` can't be within the limits nor fit in the
+            // input.
+            let too_big = DecodeError::UnexpectedEnd;
+            self.take(width)?.iter().try_fold(0_usize, |value, &byte| {
+                value.checked_mul(256).and_then(|v| v.checked_add(byte.into())).ok_or(too_big)
+            })
+        }
+
+        pub(crate) fn finish(self) -> Result<(), DecodeError>
+        {
+            if self.0.is_empty() { Ok(()) } else { Err(DecodeError::TrailingBytes) }
+        }
+    }
+}
+
+
+#[cfg(any(feature = "std", feature = "anticipate", rust_lib_feature = "error_in_core"))]
+mod standard_error
+{
+    use super::DecodeError;
+
+    cfg_if::cfg_if! { if #[cfg(any(feature = "anticipate", rust_lib_feature = "error_in_core"))]
+    {
+        use core::error::Error;
+    }
+    else if #[cfg(feature = "std")]
+    {
+        use std::error::Error;
+    } }
+
+    impl Error for DecodeError
+    {
+        #[inline]
+        fn source(&self) -> Option<&(dyn Error + 'static)>
+        {
+            match self {
+                DecodeError::ExceedsLimits(e) => Some(e),
+                DecodeError::UnexpectedEnd
+                | DecodeError::ExceedsReference
+                | DecodeError::TrailingBytes => None,
+            }
+        }
+    }
+}
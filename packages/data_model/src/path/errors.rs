@@ -7,23 +7,44 @@ use core::fmt::{
 /// Error that occurs when a [`Path`](crate::Path) violated the limits on `Path`s required by a
 /// [`Params`](crate::Params), in a context where the limits are enforced.
 ///
-/// One or more of the `bool` fields will be `false` and this indicates which limit(s) were
-/// exceeded.
+/// Each variant indicates which limit was exceeded, with its `max` and the actual value that
+/// exceeded it.  When more than one limit is exceeded, the amount of
+/// [`Component`](crate::path::Component)s takes precedence, and otherwise it's whichever is
+/// exceeded at the earliest `Component`, with its own length taking precedence over the total.
 #[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
-#[allow(clippy::exhaustive_structs)]
-pub struct PathLimitError
+#[allow(clippy::exhaustive_enums)]
+pub enum PathLimitError
 {
-    /// Index of which [`Component`](crate::path::Component) caused this error.
-    pub index:                       usize,
-    /// Whether the [`Component`](crate::path::Component)'s size is less than or equal to
-    /// [`Params::MAX_COMPONENT_LENGTH`](crate::Params::MAX_COMPONENT_LENGTH).
-    pub within_max_component_length: bool,
-    /// Whether the amount of [`Component`](crate::path::Component)s is less than or equal to
+    /// The amount of `Component`s is greater than
     /// [`Params::MAX_COMPONENT_COUNT`](crate::Params::MAX_COMPONENT_COUNT).
-    pub within_max_component_count:  bool,
-    /// Whether the total of the sizes of the [`Component`](crate::path::Component)s is less
-    /// than or equal to [`Params::MAX_PATH_LENGTH`](crate::Params::MAX_PATH_LENGTH).
-    pub within_max_path_length:      bool,
+    TooManyComponents
+    {
+        /// The amount of `Component`s.
+        count: usize,
+        /// The `MAX_COMPONENT_COUNT`.
+        max:   usize,
+    },
+    /// The size of a `Component` is greater than
+    /// [`Params::MAX_COMPONENT_LENGTH`](crate::Params::MAX_COMPONENT_LENGTH).
+    ComponentTooLong
+    {
+        /// Index of which `Component` it is.
+        index:  usize,
+        /// The size of that `Component`.
+        length: usize,
+        /// The `MAX_COMPONENT_LENGTH`.
+        max:    usize,
+    },
+    /// The total of the sizes of the `Component`s is greater than
+    /// [`Params::MAX_PATH_LENGTH`](crate::Params::MAX_PATH_LENGTH).
+    TotalTooLong
+    {
+        /// The total, up to and including the `Component` at which it became greater, which is
+        /// all that's known of it in some contexts (e.g. decoding).
+        length: usize,
+        /// The `MAX_PATH_LENGTH`.
+        max:    usize,
+    },
 }
 
 impl Display for PathLimitError
@@ -34,17 +55,54 @@ impl Display for PathLimitError
         f: &mut fmt::Formatter<'_>,
     ) -> fmt::Result
     {
-        write!(f, "A `Path` exceeded limits at component {}:", self.index)?;
-        if !self.within_max_component_length {
-            write!(f, " component-length")?;
-        }
-        if !self.within_max_component_count {
-            write!(f, " component-count")?;
+        match self {
+            PathLimitError::TooManyComponents { count, max } => {
+                write!(f, "A `Path` has {count} components, max {max}")
+            },
+            PathLimitError::ComponentTooLong { index, length, max } => {
+                write!(f, "A `Path`'s component {index} is {length} bytes, max {max}")
+            },
+            PathLimitError::TotalTooLong { length, max } => {
+                write!(f, "A `Path` is at least {length} bytes in total, max {max}")
+            },
         }
-        if !self.within_max_path_length {
-            write!(f, " path-length")?;
+    }
+}
+
+
+/// Error that occurs when the limits on `Path`s of a [`Params`](crate::Params) are inconsistent
+/// with each other, as found by [`Params::check_limits`](crate::Params::check_limits).
+#[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[allow(clippy::exhaustive_enums)]
+pub enum ParamsLimitError
+{
+    /// [`Params::MAX_COMPONENT_LENGTH`](crate::Params::MAX_COMPONENT_LENGTH) is greater than
+    /// [`Params::MAX_PATH_LENGTH`](crate::Params::MAX_PATH_LENGTH), and so a `Component` of the
+    /// maximum length could never be in a valid `Path`.
+    ComponentLongerThanPath
+    {
+        /// The `MAX_COMPONENT_LENGTH`.
+        component: usize,
+        /// The `MAX_PATH_LENGTH`.
+        path:      usize,
+    },
+}
+
+impl Display for ParamsLimitError
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result
+    {
+        match self {
+            ParamsLimitError::ComponentLongerThanPath { component, path } => write!(
+                f,
+                "A `Params`'s max component length {component} is greater than its max path \
+                 length {path}"
+            ),
         }
-        Ok(())
     }
 }
 
@@ -52,7 +110,10 @@ impl Display for PathLimitError
 #[cfg(any(feature = "std", feature = "anticipate", rust_lib_feature = "error_in_core"))]
 mod standard_error
 {
-    use super::PathLimitError;
+    use super::{
+        ParamsLimitError,
+        PathLimitError,
+    };
 
     cfg_if::cfg_if! { if #[cfg(any(feature = "anticipate", rust_lib_feature = "error_in_core"))]
     {
@@ -65,4 +126,6 @@ mod standard_error
 
 
     impl Error for PathLimitError {}
+
+    impl Error for ParamsLimitError {}
 }
@@ -1,7 +1,15 @@
 use {
     super::{
         errors::PathLimitError,
+        limits::{
+            check_component,
+            check_count,
+        },
+        Component,
+        ComponentsRev,
+        Concat,
         Path,
+        Prefix,
     },
     core::cmp::Ordering,
 };
@@ -18,6 +26,26 @@ impl<T> Extra for T where T: Path + ?Sized {}
 /// don't want that.)
 pub trait Extra: Path
 {
+    /// The amount of `Component`s of `self`.
+    ///
+    /// This is [`ExactSizeIterator::len`] of [`Path::components`], which doesn't iterate, and so
+    /// this is cheap for all the `Path` types that we provide implementations for, e.g. for
+    /// arrays `[C; N]` this is just `N`.
+    #[must_use]
+    #[inline]
+    fn len(&self) -> usize
+    {
+        self.components().len()
+    }
+
+    /// Whether `self` has no `Component`s, i.e. is the empty `Path`.
+    #[must_use]
+    #[inline]
+    fn is_empty(&self) -> bool
+    {
+        self.len() == 0
+    }
+
     /// Create an instance of `Self` from another type of [`Path`].
     ///
     /// If `Self` can be created from an `Iterator` of an `Item` type that can be created from the
@@ -49,7 +77,7 @@ pub trait Extra: Path
     /// Like [`Self::from_path`] but enforce the limits of a [`Params`](crate::Params).
     ///
     /// # Errors
-    /// If the limits are exceeded by `other`.
+    /// If the limits are exceeded by `other`, as by [`Self::validate`].
     #[inline]
     fn from_path_limited<'l, Params, Po, C>(other: &'l Po) -> Result<Self, PathLimitError>
     where
@@ -58,39 +86,30 @@ pub trait Extra: Path
         Po: Path + ?Sized,
         &'l [u8]: Into<C>,
     {
-        let mut total_sz = 0_usize;
-
-        other
-            .components()
-            .enumerate()
-            .map(|(index, c)| {
-                let c_sz = c.bytes().len();
-                let mut total_overflowed = false;
-                match total_sz.checked_add(c_sz) {
-                    Some(t) => total_sz = t,
-                    None => total_overflowed = true,
-                }
-                let within_max_component_length = c_sz <= Params::MAX_COMPONENT_LENGTH.into();
-                let within_max_component_count = index <= Params::MAX_COMPONENT_COUNT.into();
-                let within_max_path_length =
-                    total_sz <= Params::MAX_PATH_LENGTH.into() && !total_overflowed;
-
-                if within_max_component_length
-                    && within_max_component_count
-                    && within_max_path_length
-                {
-                    Ok(c.inner.into())
-                }
-                else {
-                    Err(PathLimitError {
-                        index,
-                        within_max_component_length,
-                        within_max_component_count,
-                        within_max_path_length,
-                    })
-                }
-            })
-            .collect()
+        debug_assert!(Params::check_limits().is_ok(), "inconsistent `Params` limits");
+        other.validate::<Params>()?;
+        Ok(Self::from_path(other))
+    }
+
+    /// Check whether `self` is within the limits of a [`Params`](crate::Params), without
+    /// converting it, e.g. to give early and specific feedback before `put`ting an `Entry` at
+    /// it.
+    ///
+    /// # Errors
+    /// If the limits are exceeded by `self`.  The amount of `Component`s is checked first, and
+    /// then the first `Component` at which any other limit is exceeded is reported.
+    #[inline]
+    fn validate<Params>(&self) -> Result<(), PathLimitError>
+    where Params: crate::Params + ?Sized
+    {
+        check_count::<Params>(self.len())?;
+        let mut total_len = 0_usize;
+        for (index, component) in self.components().enumerate() {
+            let len = component.bytes().len();
+            total_len = total_len.saturating_add(len);
+            check_component::<Params>(index, len, total_len)?;
+        }
+        Ok(())
     }
 
     /// Return whether or not `self` and `other` are equal by their `Component`s.
@@ -105,6 +124,28 @@ pub trait Extra: Path
         self.components().eq(other.components())
     }
 
+    /// Return whether or not `self` and `other` are equal by their `Component`s after ignoring
+    /// any trailing empty `Component`s of each.  E.g. `["a"]`, `["a", ""]`, and `["a", "", ""]`
+    /// are all equal by this.
+    ///
+    /// **Note**: This is NOT the equality of Willow, which considers those to be distinct
+    /// `Path`s.  This is only an opt-in for applications that want to treat them as equivalent,
+    /// and it must not be used where protocol correctness matters (e.g. for prefix pruning or
+    /// for comparing `Entry`s).  Use [`Self::eq_components`] for that.
+    #[inline]
+    fn eq_ignoring_trailing_empty<Po>(
+        &self,
+        other: &Po,
+    ) -> bool
+    where
+        Po: Path + ?Sized,
+    {
+        let self_len = len_without_trailing_empty(self);
+        let other_len = len_without_trailing_empty(other);
+        self_len == other_len
+            && self.components().take(self_len).eq(other.components().take(other_len))
+    }
+
     /// Return how `self` and `other` compare lexicographically by their `Component`s.
     #[inline]
     fn cmp_components<Po>(
@@ -116,4 +157,163 @@ pub trait Extra: Path
     {
         self.components().cmp(other.components())
     }
+
+    /// Return whether `self` and `other` are equal as `Path`s, i.e. by the bytes of their
+    /// `Component`s, regardless of the types of `Path` and of `Component` that hold those.  E.g.
+    /// `["a", "b"]` equals `vec![b"a".to_vec(), b"b".to_vec()]` by this.
+    ///
+    /// This is the same as [`Self::eq_components`].  For types that also `impl` [`PartialEq`],
+    /// calling this as a method is ambiguous when both traits are in scope, and so it must then
+    /// be called like `Extra::eq(&a, &b)`.
+    #[inline]
+    fn eq<Po>(
+        &self,
+        other: &Po,
+    ) -> bool
+    where
+        Po: Path + ?Sized,
+    {
+        self.eq_components(other)
+    }
+
+    /// Return how `self` and `other` compare as `Path`s, i.e. lexicographically by the bytes of
+    /// their `Component`s, regardless of the types of `Path` and of `Component` that hold those.
+    /// This is the ordering of Willow, where a prefix is less than what it prefixes.
+    ///
+    /// This is the same as [`Self::cmp_components`].  For types that also `impl` [`Ord`],
+    /// calling this as a method is ambiguous when both traits are in scope, and so it must then
+    /// be called like `Extra::cmp(&a, &b)`.
+    #[inline]
+    fn cmp<Po>(
+        &self,
+        other: &Po,
+    ) -> Ordering
+    where
+        Po: Path + ?Sized,
+    {
+        self.cmp_components(other)
+    }
+
+    /// Iterate all the prefixes of `self`, from shortest to longest: the empty `Path`, then the
+    /// first `Component`, then the first two, and so on up to all of `self`.
+    ///
+    /// This enables checking whether something applies to any ancestor of a `Path`, e.g. whether
+    /// an authorisation token grants any of them, in the opposite direction of
+    /// [`Path::is_prefix_of`].  The yielded items borrow from `self` and don't allocate.
+    ///
+    /// This is the same as [`Self::prefixes`] but only as an `Iterator`.
+    #[inline]
+    fn ancestors(&self) -> impl Iterator<Item = impl Path + '_>
+    {
+        self.prefixes()
+    }
+
+    /// Iterate all the prefixes of `self`, from shortest to longest, where the item at index `i`
+    /// is the [`Prefix`] of the first `i` `Component`s.  Thus there is always one more item than
+    /// [`Self::len`], for the empty prefix.
+    ///
+    /// As an `ExactSizeIterator`, this enables e.g. taking only the strict prefixes, as needed to
+    /// look-up what could prefix-prune an `Entry` at `self`.  The yielded items borrow from
+    /// `self` and don't allocate.
+    #[inline]
+    fn prefixes(&self) -> impl ExactSizeIterator<Item = impl Path + '_>
+    {
+        // (`RangeInclusive` isn't an `ExactSizeIterator`.  A `Path` can't have `usize::MAX`
+        // `Component`s, in practice.)
+        (0 .. self.len().saturating_add(1)).map(|len| Prefix::new(self, len))
+    }
+
+    /// The last `Component` of `self`, or `None` if `self` is empty.
+    ///
+    /// Because [`Path::components`] isn't necessarily a `DoubleEndedIterator`, this skips the
+    /// others by the amount of them.
+    #[inline]
+    fn last_component(&self) -> Option<Component<&[u8]>>
+    {
+        let mut components = self.components();
+        let last = components.len().checked_sub(1)?;
+        components.nth(last)
+    }
+
+    /// The [`Prefix`] of all but the last `Component` of `self`, i.e. the parent of `self`, or
+    /// `None` if `self` is empty.  This borrows `self` and doesn't allocate.
+    #[inline]
+    fn parent(&self) -> Option<Prefix<'_, Self>>
+    {
+        self.len().checked_sub(1).map(|len| Prefix::new(self, len))
+    }
+
+    /// The [`Path`] of the `Component`s of `self` followed by those of `other`.
+    ///
+    /// This borrows both and doesn't allocate, and its amount of `Component`s is the sum of
+    /// both's.  Either can be the empty `Path`.  To make an owned `Path` of it, use
+    /// [`Self::concat_into`].
+    #[inline]
+    fn concat<'l, Po>(
+        &'l self,
+        other: &'l Po,
+    ) -> Concat<'l, Self, Po>
+    where
+        Po: Path + ?Sized,
+    {
+        Concat::new(self, other)
+    }
+
+    /// Like [`Self::concat`] but collect the `Component`s into a new instance of some `Path`
+    /// type, like how [`Self::from_path`] does.
+    #[inline]
+    fn concat_into<Po, T, C>(
+        &self,
+        other: &Po,
+    ) -> T
+    where
+        Po: Path + ?Sized,
+        T: FromIterator<C>,
+        for<'b> &'b [u8]: Into<C>,
+    {
+        self.components().chain(other.components()).map(|c| c.inner.into()).collect()
+    }
+
+    /// Return the least `Path` that is greater than `self`, by [`Self::cmp_components`], and
+    /// that is within the limits of a [`Params`](crate::Params), collected like how
+    /// [`Self::from_path`] does.  Or `None` if there is no such `Path`.
+    ///
+    /// This is the `successor` of Willow, e.g. for converting a prefix match into a half-open
+    /// range.  If `self` has fewer than the maximum amount of `Component`s, it's `self` with an
+    /// empty `Component` appended.  Otherwise, it's `self` with the last `Component` that can be
+    /// made greater replaced by its least greater byte-string (with a `0` byte appended if that's
+    /// within the limits, else with its last non-`0xFF` byte incremented and the bytes after
+    /// that dropped), and without the `Component`s after that.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn successor<Params, T, C>(&self) -> Option<T>
+    where
+        Params: crate::Params + ?Sized,
+        T: FromIterator<C>,
+        for<'b> &'b [u8]: Into<C>,
+    {
+        super::successor::successor::<Params, T, C>(self)
+    }
+
+    /// Iterate the `Component`s of `self` from last to first.
+    ///
+    /// This enables algorithms that match from the end, e.g. suffix-oriented routing.  See
+    /// [`ComponentsRev`] for the cost of this, which can be quadratic in the amount of
+    /// `Component`s depending on the type of `self`.
+    #[inline]
+    fn components_rev(&self) -> impl ExactSizeIterator<Item = Component<&[u8]>>
+    {
+        ComponentsRev::new(self)
+    }
+}
+
+
+/// The amount of `Component`s of `path` before any trailing empty ones.
+fn len_without_trailing_empty(path: &(impl Path + ?Sized)) -> usize
+{
+    path.components()
+        .enumerate()
+        .filter(|(_, c)| !c.bytes().is_empty())
+        .last()
+        .map_or(0, |(i, _)| i.saturating_add(1))
 }
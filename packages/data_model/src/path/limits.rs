@@ -0,0 +1,88 @@
+//! Checking the limits of a [`Params`](crate::Params) on parts of `Path`s, without needing to
+//! construct a whole `Path`.
+
+use super::{
+    ParamsLimitError,
+    PathLimitError,
+};
+
+
+/// Return the [`MAX_COMPONENT_LENGTH`](crate::Params::MAX_COMPONENT_LENGTH) of `Params`, as a
+/// plain `usize`.
+#[must_use]
+#[inline]
+pub const fn max_component_len<Params>() -> usize
+where Params: crate::Params + ?Sized
+{
+    Params::MAX_COMPONENT_LENGTH.get()
+}
+
+
+/// Check whether `bytes`, as a single candidate [`Component`](super::Component), is within the
+/// limits of `Params`.  This enables validating input before constructing a `Path` from it.
+///
+/// # Errors
+/// If `bytes` is longer than [`MAX_COMPONENT_LENGTH`](crate::Params::MAX_COMPONENT_LENGTH) or
+/// even than [`MAX_PATH_LENGTH`](crate::Params::MAX_PATH_LENGTH), as if `bytes` were the only
+/// component of a `Path` (i.e. the returned error's `index` is `0`).
+#[inline]
+pub const fn component_is_valid<Params>(bytes: &[u8]) -> Result<(), PathLimitError>
+where Params: crate::Params + ?Sized
+{
+    check_component::<Params>(0, bytes.len(), bytes.len())
+}
+
+
+/// Check the limits of `Params` for the `Component` at `index` of a `Path`, whose length is
+/// `length`, where `total_length` is the total of the lengths up to and including it.
+pub(crate) const fn check_component<Params>(
+    index: usize,
+    length: usize,
+    total_length: usize,
+) -> Result<(), PathLimitError>
+where
+    Params: crate::Params + ?Sized,
+{
+    let max_length = max_component_len::<Params>();
+    let max_total_length = Params::MAX_PATH_LENGTH.get();
+
+    if length > max_length {
+        Err(PathLimitError::ComponentTooLong { index, length, max: max_length })
+    }
+    else if total_length > max_total_length {
+        Err(PathLimitError::TotalTooLong { length: total_length, max: max_total_length })
+    }
+    else {
+        Ok(())
+    }
+}
+
+
+/// Check the limit of `Params` on the amount of `Component`s of a `Path`, whose amount is
+/// `count`.
+pub(crate) const fn check_count<Params>(count: usize) -> Result<(), PathLimitError>
+where Params: crate::Params + ?Sized
+{
+    let max = Params::MAX_COMPONENT_COUNT.get();
+
+    if count > max { Err(PathLimitError::TooManyComponents { count, max }) } else { Ok(()) }
+}
+
+
+/// The implementation of [`Params::check_limits`](crate::Params::check_limits).
+pub(crate) const fn check_params<Params>() -> Result<(), ParamsLimitError>
+where Params: crate::Params + ?Sized
+{
+    let max_component_length = max_component_len::<Params>();
+    let max_path_length = Params::MAX_PATH_LENGTH.get();
+
+    if max_component_length > max_path_length {
+        Err(ParamsLimitError::ComponentLongerThanPath {
+            component: max_component_length,
+            path:      max_path_length,
+        })
+    }
+    else {
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use super::{
+    Component,
+    Path,
+};
+
+
+/// A [`Path`] that is the first `len` many `Component`s of some other `Path`, without copying.
+///
+/// This is what's yielded by [`Extra::prefixes`](super::Extra::prefixes).
+#[derive(Debug)]
+pub struct Prefix<'l, P>
+where P: Path + ?Sized
+{
+    path: &'l P,
+    len:  usize,
+}
+
+impl<'l, P> Prefix<'l, P>
+where P: Path + ?Sized
+{
+    /// Make a [`Prefix`] of the first `len` many `Component`s of `path`.  If `len` is greater
+    /// than the amount of `Component`s of `path`, then all of them are the `Prefix`.
+    #[must_use]
+    #[inline]
+    pub fn new(
+        path: &'l P,
+        len: usize,
+    ) -> Self
+    {
+        Self { path, len }
+    }
+}
+
+/// Manually implemented, to avoid requiring `P: Clone`.
+impl<P> Clone for Prefix<'_, P>
+where P: Path + ?Sized
+{
+    #[inline]
+    fn clone(&self) -> Self
+    {
+        *self
+    }
+}
+
+/// Manually implemented, to avoid requiring `P: Copy`.
+impl<P> Copy for Prefix<'_, P> where P: Path + ?Sized {}
+
+impl<P> Path for Prefix<'_, P>
+where P: Path + ?Sized
+{
+    #[inline]
+    fn components(&self) -> impl ExactSizeIterator<Item = Component<&[u8]>>
+    {
+        self.path.components().take(self.len)
+    }
+}
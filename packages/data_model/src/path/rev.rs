@@ -0,0 +1,66 @@
+use super::{
+    Component,
+    Extra as _,
+    Path,
+};
+
+
+/// An `Iterator` of the `Component`s of some [`Path`] from last to first, without copying.
+///
+/// This is what's returned by [`Extra::components_rev`](super::Extra::components_rev).
+///
+/// Because [`Path::components`] is only required to be an `ExactSizeIterator`, not a
+/// `DoubleEndedIterator`, each step uses [`Iterator::nth`] on a fresh forward `Iterator`.  So,
+/// for `Path` types whose `nth` is `O(n)`, iterating all of the `Component`s is `O(n²)`, but no
+/// allocation is needed.
+#[derive(Debug)]
+pub struct ComponentsRev<'l, P>
+where P: Path + ?Sized
+{
+    path:      &'l P,
+    remaining: usize,
+}
+
+impl<'l, P> ComponentsRev<'l, P>
+where P: Path + ?Sized
+{
+    /// Make a [`ComponentsRev`] that starts from the last `Component` of `path`.
+    #[must_use]
+    #[inline]
+    pub fn new(path: &'l P) -> Self
+    {
+        Self { path, remaining: path.len() }
+    }
+}
+
+/// Manually implemented, to avoid requiring `P: Clone`.
+impl<P> Clone for ComponentsRev<'_, P>
+where P: Path + ?Sized
+{
+    #[inline]
+    fn clone(&self) -> Self
+    {
+        Self { path: self.path, remaining: self.remaining }
+    }
+}
+
+impl<'l, P> Iterator for ComponentsRev<'l, P>
+where P: Path + ?Sized
+{
+    type Item = Component<&'l [u8]>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        self.remaining = self.remaining.checked_sub(1)?;
+        self.path.components().nth(self.remaining)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<P> ExactSizeIterator for ComponentsRev<'_, P> where P: Path + ?Sized {}
@@ -0,0 +1,176 @@
+//! (De)serializing any [`Path`] as a sequence of byte-strings, one per `Component`, so that the
+//! serialized form is independent of the types of `Path` and of `Component`.
+//!
+//! For use with `#[serde(with = "...")]` on fields of `Path` types.
+
+use {
+    super::Path,
+    alloc::{
+        boxed::Box,
+        vec::Vec,
+    },
+    core::{
+        fmt::{
+            self,
+            Formatter,
+        },
+        marker::PhantomData,
+    },
+    serde::{
+        de::{
+            self,
+            SeqAccess,
+            Visitor,
+        },
+        ser::SerializeSeq as _,
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+    },
+};
+
+
+pub(crate) fn serialize<P, S>(
+    path: &P,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    P: Path + ?Sized,
+    S: Serializer,
+{
+    let components = path.components();
+    let mut seq = serializer.serialize_seq(Some(components.len()))?;
+    for component in components {
+        seq.serialize_element(&Bytes(component.bytes()))?;
+    }
+    seq.end()
+}
+
+pub(crate) fn deserialize<'de, P, D>(deserializer: D) -> Result<P, D::Error>
+where
+    P: FromIterator<Box<[u8]>>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_seq(ComponentsVisitor(PhantomData))
+}
+
+
+struct Bytes<'b>(&'b [u8]);
+
+impl Serialize for Bytes<'_>
+{
+    #[inline]
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+
+struct ComponentsVisitor<P>(PhantomData<fn() -> P>);
+
+impl<'de, P> Visitor<'de> for ComponentsVisitor<P>
+where P: FromIterator<Box<[u8]>>
+{
+    type Value = P;
+
+    #[inline]
+    fn expecting(
+        &self,
+        formatter: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        formatter.write_str("a sequence of byte-strings")
+    }
+
+    #[inline]
+    fn visit_seq<A>(
+        self,
+        mut seq: A,
+    ) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // Collected first, because `FromIterator` can't propagate the errors.  (Not
+        // pre-allocated by `size_hint`, because that's untrusted input.)
+        let mut components = Vec::new();
+        while let Some(ByteBuf(component)) = seq.next_element()? {
+            components.push(component);
+        }
+        Ok(components.into_iter().collect())
+    }
+}
+
+
+struct ByteBuf(Box<[u8]>);
+
+impl<'de> Deserialize<'de> for ByteBuf
+{
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_byte_buf(ByteBufVisitor)
+    }
+}
+
+struct ByteBufVisitor;
+
+impl<'de> Visitor<'de> for ByteBufVisitor
+{
+    type Value = ByteBuf;
+
+    #[inline]
+    fn expecting(
+        &self,
+        formatter: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        formatter.write_str("a byte-string")
+    }
+
+    #[inline]
+    fn visit_bytes<E>(
+        self,
+        v: &[u8],
+    ) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(ByteBuf(v.into()))
+    }
+
+    #[inline]
+    fn visit_byte_buf<E>(
+        self,
+        v: Vec<u8>,
+    ) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(ByteBuf(v.into_boxed_slice()))
+    }
+
+    /// For formats, like JSON, that serialize byte-strings as sequences of numbers.
+    #[inline]
+    fn visit_seq<A>(
+        self,
+        mut seq: A,
+    ) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = Vec::new();
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(ByteBuf(bytes.into_boxed_slice()))
+    }
+}
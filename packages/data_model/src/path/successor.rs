@@ -0,0 +1,61 @@
+use {
+    super::Path,
+    alloc::vec::Vec,
+    core::iter,
+};
+
+
+/// See [`Extra::successor`](super::Extra::successor).
+pub(super) fn successor<'l, Params, T, C>(path: &'l (impl Path + ?Sized)) -> Option<T>
+where
+    Params: crate::Params + ?Sized,
+    T: FromIterator<C>,
+    for<'b> &'b [u8]: Into<C>,
+{
+    let components = path.components().map(|c| c.inner).collect::<Vec<&'l [u8]>>();
+
+    // Appending an empty `Component` gives the least `Path` that is greater, when possible.
+    if components.len() < Params::MAX_COMPONENT_COUNT.get() {
+        let with_empty = components.iter().copied().chain(iter::once(&[][..]));
+        return Some(with_empty.map(Into::into).collect());
+    }
+
+    // Otherwise, the last `Component` that can be made greater, within the limits, is made the
+    // least greater, and all after it are dropped (which gives the least `Path` with that).
+    let mut before_len = components.iter().fold(0_usize, |sum, c| sum.saturating_add(c.len()));
+    for (index, component) in components.iter().enumerate().rev() {
+        before_len = before_len.saturating_sub(component.len());
+        let max_len = Params::MAX_COMPONENT_LENGTH
+            .get()
+            .min(Params::MAX_PATH_LENGTH.get().saturating_sub(before_len));
+        if let Some(greater) = component_successor(component, max_len) {
+            let before = components.iter().take(index).copied();
+            return Some(before.chain(iter::once(greater.as_slice())).map(Into::into).collect());
+        }
+    }
+    None
+}
+
+
+/// The least byte-string that is greater than `bytes` and that is at most `max_len` long.
+fn component_successor(
+    bytes: &[u8],
+    max_len: usize,
+) -> Option<Vec<u8>>
+{
+    if bytes.len() < max_len {
+        let mut greater = Vec::with_capacity(bytes.len().saturating_add(1));
+        greater.extend_from_slice(bytes);
+        greater.push(0);
+        Some(greater)
+    }
+    else {
+        // Increment the last byte that isn't already the maximum, and drop those after it.
+        let truncated = bytes.get(.. max_len).unwrap_or(bytes);
+        let last = truncated.iter().rposition(|&byte| byte != u8::MAX)?;
+        let mut greater = truncated.get(..= last)?.to_vec();
+        let byte = greater.last_mut()?;
+        *byte = byte.saturating_add(1);
+        Some(greater)
+    }
+}
@@ -7,6 +7,38 @@
 mod errors;
 pub use errors::*;
 
+mod empty;
+pub use empty::*;
+
+mod header;
+pub use header::*;
+
+mod in_memory;
+pub use in_memory::*;
+
+#[cfg(feature = "std")]
+mod file;
+#[cfg(feature = "std")]
+pub use file::*;
+
+#[cfg(feature = "mmap")]
+mod mmap;
+#[cfg(feature = "mmap")]
+pub use mmap::*;
+
+#[cfg(any(feature = "tokio", feature = "futures-io"))]
+mod compat;
+
+#[cfg(feature = "tokio")]
+mod tokio_compat;
+#[cfg(feature = "tokio")]
+pub use tokio_compat::*;
+
+#[cfg(feature = "futures-io")]
+mod futures_compat;
+#[cfg(feature = "futures-io")]
+pub use futures_compat::*;
+
 pub mod extra;
 pub use extra::*;
 
@@ -0,0 +1,59 @@
+//! Helpers shared by the adapters of `Payload` to other crates' I/O traits.
+
+use {
+    crate::payload,
+    alloc::boxed::Box,
+    core::{
+        future::Future,
+        pin::pin,
+        task::{
+            Context,
+            Poll,
+        },
+    },
+    std::{
+        error::Error,
+        io,
+    },
+};
+
+
+/// Poll the given future once, which must give its output, because there's nowhere to keep an
+/// in-progress future across calls of the adapters' `poll_*` methods.
+///
+/// # Panics
+/// If the future is pending.  Looping until it's ready instead would hang the executor's thread
+/// for a future that's waiting on something.
+#[allow(clippy::panic)] // Documented requirement of the adapters.
+pub(super) fn complete<F: Future>(
+    future: F,
+    cx: &mut Context<'_>,
+) -> F::Output
+{
+    match pin!(future).poll(cx) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("`Payload` future must be immediately ready for this adapter"),
+    }
+}
+
+
+/// Convert a `SeekFrom` given to an adapter, which is an error if it's beyond the end.
+pub(super) fn seek_from(pos: io::SeekFrom) -> io::Result<payload::SeekFrom>
+{
+    pos.try_into().map_err(|_offset| {
+        io::Error::new(io::ErrorKind::InvalidInput, "seeking beyond the end of the `Payload`")
+    })
+}
+
+/// Convert an error of `Payload::read`.
+pub(super) fn read_error(error: impl Into<Box<dyn Error + Send + Sync>>) -> io::Error
+{
+    io::Error::other(error)
+}
+
+/// Convert an error of `Payload::seek`.  The main reason that it fails is seeking beyond the end
+/// or before the start, which `std::io::Seek` reports as `InvalidInput`.
+pub(super) fn seek_error(error: impl Into<Box<dyn Error + Send + Sync>>) -> io::Error
+{
+    io::Error::new(io::ErrorKind::InvalidInput, error)
+}
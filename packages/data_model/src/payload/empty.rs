@@ -0,0 +1,60 @@
+use {
+    super::{
+        EmptySeekError,
+        Payload,
+        SeekFrom,
+    },
+    core::convert::Infallible,
+};
+
+
+/// The [`Payload`] of length 0.
+///
+/// This is always available, including with `no_std`, and is useful for computing the digest of
+/// the empty byte-string (see [`Params::empty_payload_digest`](crate::Params::empty_payload_digest))
+/// without needing some other type of `Payload`.
+#[derive(Default, Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct Empty;
+
+/// This upholds the requirement that the methods won't block, because there's nothing to wait
+/// for.
+impl Payload for Empty
+{
+    type ReadError = Infallible;
+    type SeekError = EmptySeekError;
+
+    #[inline]
+    async fn read(
+        &mut self,
+        _buf: &mut [u8],
+    ) -> Result<usize, Self::ReadError>
+    {
+        Ok(0)
+    }
+
+    #[inline]
+    async fn seek(
+        &mut self,
+        pos: SeekFrom,
+    ) -> Result<u64, Self::SeekError>
+    {
+        match pos {
+            SeekFrom::Start(0) | SeekFrom::End(0) | SeekFrom::Current(0) => Ok(0),
+            SeekFrom::Start(_) | SeekFrom::End(_) | SeekFrom::Current(_) =>
+                Err(EmptySeekError { pos }),
+        }
+    }
+
+    #[inline]
+    async fn len(&self) -> u64
+    {
+        0
+    }
+
+    #[inline]
+    async fn is_empty(&self) -> bool
+    {
+        true
+    }
+}
@@ -1,10 +1,13 @@
-use core::{
-    fmt::{
-        self,
-        Display,
-        Formatter,
+use {
+    super::SeekFrom,
+    core::{
+        fmt::{
+            self,
+            Display,
+            Formatter,
+        },
+        num::NonZeroU64,
     },
-    num::NonZeroU64,
 };
 
 
@@ -37,8 +40,8 @@ pub enum CopyToSliceError<ReadError, SeekError>
     /// Failure of [`Payload::seek`](crate::Payload::seek).
     Seek(SeekError),
     /// The `self` argument's implementation of [`Payload`](crate::Payload) violated
-    /// required behavior.
-    BadImpl,
+    /// required behavior, in the given way.
+    BadImpl(BadImplKind),
 }
 
 impl<R, S> CopyToSliceError<R, S>
@@ -67,7 +70,128 @@ impl<R, S> Display for CopyToSliceError<R, S>
             CopyToSliceError::OutOfBounds { at } => fmt_oob(f, prefix, *at),
             CopyToSliceError::Read(_) => fmt_r(f, prefix),
             CopyToSliceError::Seek(_) => fmt_s(f, prefix),
-            CopyToSliceError::BadImpl => fmt_bi(f, prefix),
+            CopyToSliceError::BadImpl(kind) => fmt_bi(f, prefix, *kind),
+        }
+    }
+}
+
+
+/// Errors possibly returned by [`read_exact`](crate::payload::ExtraCore::read_exact).
+#[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[allow(clippy::exhaustive_enums)]
+pub enum ReadExactError<ReadError>
+{
+    /// The end of the `self` argument was reached before the `buf` argument was filled.
+    UnexpectedEof
+    {
+        /// How many bytes of `buf` were filled.
+        filled: usize,
+    },
+    /// Failure of [`Payload::read`](crate::Payload::read).
+    Read(ReadError),
+    /// The `self` argument's implementation of [`Payload`](crate::Payload) violated
+    /// required behavior, in the given way.
+    BadImpl(BadImplKind),
+}
+
+impl<R> Display for ReadExactError<R>
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        let prefix = "`payload::ExtraCore::read_exact` failed due to";
+        match self {
+            ReadExactError::UnexpectedEof { filled } =>
+                write!(f, "{prefix} unexpected end after {filled} bytes"),
+            ReadExactError::Read(_) => fmt_r(f, prefix),
+            ReadExactError::BadImpl(kind) => fmt_bi(f, prefix, *kind),
+        }
+    }
+}
+
+
+/// Errors possibly returned by [`read_buffers`](crate::payload::ExtraCore::read_buffers).
+#[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[allow(clippy::exhaustive_enums)]
+pub enum ReadBuffersError<ReadError>
+{
+    /// Failure of [`Payload::read`](crate::Payload::read).
+    Read(ReadError),
+    /// The `self` argument's implementation of [`Payload`](crate::Payload) violated
+    /// required behavior, in the given way.
+    BadImpl(BadImplKind),
+}
+
+impl<R> Display for ReadBuffersError<R>
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        let prefix = "`payload::ExtraCore::read_buffers` failed due to";
+        match self {
+            ReadBuffersError::Read(_) => fmt_r(f, prefix),
+            ReadBuffersError::BadImpl(kind) => fmt_bi(f, prefix, *kind),
+        }
+    }
+}
+
+
+/// How an implementation of [`Payload`](crate::Payload) violated required behavior, as
+/// detected by the functions that give a `BadImpl` error.
+#[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[allow(clippy::exhaustive_enums)]
+pub enum BadImplKind
+{
+    /// [`Payload::seek`](crate::Payload::seek) succeeded but gave a position other than the one
+    /// requested.
+    SeekMismatch
+    {
+        /// The position that was sought.
+        requested: u64,
+        /// The position that `seek` returned.
+        got:       u64,
+    },
+    /// [`Payload::read`](crate::Payload::read) claimed to fill more bytes than the buffer it was
+    /// given.
+    ReadOverflow
+    {
+        /// The length of the buffer.
+        given:   usize,
+        /// The amount that `read` returned.
+        claimed: usize,
+    },
+    /// [`Payload::read`](crate::Payload::read) reached the end before giving all the bytes
+    /// that [`Payload::len`](crate::Payload::len) claimed.
+    ShortRead
+    {
+        /// How many bytes should have been read.
+        expected: u64,
+        /// How many bytes were read.
+        got:      u64,
+    },
+}
+
+impl Display for BadImplKind
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        match self {
+            BadImplKind::SeekMismatch { requested, got } =>
+                write!(f, "`seek` to {requested} gave {got}"),
+            BadImplKind::ReadOverflow { given, claimed } =>
+                write!(f, "`read` claimed {claimed} bytes for a buffer of {given}"),
+            BadImplKind::ShortRead { expected, got } =>
+                write!(f, "`read` ended after {got} bytes instead of {expected}"),
         }
     }
 }
@@ -105,9 +229,90 @@ fn fmt_s(
 fn fmt_bi(
     f: &mut Formatter<'_>,
     prefix: &str,
+    kind: BadImplKind,
 ) -> fmt::Result
 {
-    write!(f, "{prefix} bad implementation of `Payload`")
+    write!(f, "{prefix} bad implementation of `Payload`: {kind}")
+}
+
+
+/// Error returned by [`Empty::seek`](crate::payload::Empty) when seeking to any position other
+/// than 0.
+#[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct EmptySeekError
+{
+    /// The position that was attempted.
+    pub pos: SeekFrom,
+}
+
+impl Display for EmptySeekError
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        write!(f, "seeking to a non-0 position is beyond the empty `Payload`")
+    }
+}
+
+
+/// Error returned by `seek` of some [`Payload`](crate::Payload)s when seeking to a position
+/// beyond the end or before the start.
+#[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct SeekOutOfBoundsError
+{
+    /// The position that was attempted.
+    pub pos: SeekFrom,
+}
+
+impl Display for SeekOutOfBoundsError
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        write!(f, "seeking to ")?;
+        match self.pos {
+            SeekFrom::Start(offset) => write!(f, "start + {offset}"),
+            SeekFrom::End(offset) => write!(f, "end - {offset}"),
+            SeekFrom::Current(offset) => write!(f, "current {offset:+}"),
+        }?;
+        write!(f, " is outside the bounds of the `Payload`")
+    }
+}
+
+
+/// Error returned by [`HeaderThen::seek`](crate::payload::HeaderThen).
+#[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[allow(clippy::exhaustive_enums)]
+pub enum HeaderThenSeekError<SeekError>
+{
+    /// Seeking to a position beyond the end or before the start of the body.
+    OutOfBounds(SeekOutOfBoundsError),
+    /// Failure of the inner [`Payload::seek`](crate::Payload::seek).
+    Inner(SeekError),
+}
+
+impl<S> Display for HeaderThenSeekError<S>
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        match self {
+            HeaderThenSeekError::OutOfBounds(oob) => write!(f, "{oob} body"),
+            HeaderThenSeekError::Inner(_) =>
+                fmt_s(f, "`payload::HeaderThen::seek` failed due to inner"),
+        }
+    }
 }
 
 
@@ -123,6 +328,8 @@ mod alloc
             fmt_oob,
             fmt_r,
             fmt_s,
+            oob_at,
+            BadImplKind,
             CopyToSliceError,
         },
         core::{
@@ -153,8 +360,8 @@ mod alloc
         /// Failure of [`Payload::seek`](crate::Payload::seek).
         Seek(SeekError),
         /// The `self` argument's implementation of [`Payload`](crate::Payload) violated
-        /// required behavior.
-        BadImpl,
+        /// required behavior, in the given way.
+        BadImpl(BadImplKind),
     }
 
     impl<R, S> ToBoxedSliceError<R, S>
@@ -182,7 +389,7 @@ mod alloc
                 CopyToSliceError::OutOfBounds { at } => Self::OutOfBounds { at },
                 CopyToSliceError::Read(read_error) => Self::Read(read_error),
                 CopyToSliceError::Seek(seek_error) => Self::Seek(seek_error),
-                CopyToSliceError::BadImpl => Self::BadImpl,
+                CopyToSliceError::BadImpl(kind) => Self::BadImpl(kind),
             }
         }
     }
@@ -202,7 +409,73 @@ mod alloc
                     write!(f, "{prefix} range-too-long by {by}"),
                 ToBoxedSliceError::Read(_) => fmt_r(f, prefix),
                 ToBoxedSliceError::Seek(_) => fmt_s(f, prefix),
-                ToBoxedSliceError::BadImpl => fmt_bi(f, prefix),
+                ToBoxedSliceError::BadImpl(kind) => fmt_bi(f, prefix, *kind),
+            }
+        }
+    }
+
+    /// Errors possibly returned by [`for_each_chunk`](crate::payload::Extra::for_each_chunk).
+    #[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+    #[allow(clippy::exhaustive_enums)]
+    pub enum ForEachChunkError<ReadError, SeekError>
+    {
+        /// The `range` argument is out-of-bounds of the length of the `self` argument.
+        OutOfBounds
+        {
+            /// The position that is out-of-bounds, or `None` if overflow occurred.
+            at: Option<NonZeroU64>,
+        },
+        /// Failure of [`Payload::read`](crate::Payload::read).
+        Read(ReadError),
+        /// Failure of [`Payload::seek`](crate::Payload::seek).
+        Seek(SeekError),
+        /// The `self` argument's implementation of [`Payload`](crate::Payload) violated
+        /// required behavior, in the given way.
+        BadImpl(BadImplKind),
+    }
+
+    impl<R, S> ForEachChunkError<R, S>
+    {
+        pub(crate) fn out_of_bounds_at(at: u64) -> Self
+        {
+            Self::OutOfBounds { at: Some(oob_at(at)) }
+        }
+
+        pub(crate) fn out_of_bounds_overflowed() -> Self
+        {
+            Self::OutOfBounds { at: None }
+        }
+    }
+
+    /// Convert all variants, for the same reason as for `ToBoxedSliceError`.
+    impl<R, S> From<CopyToSliceError<R, S>> for ForEachChunkError<R, S>
+    {
+        #[inline]
+        fn from(value: CopyToSliceError<R, S>) -> Self
+        {
+            match value {
+                CopyToSliceError::OutOfBounds { at } => Self::OutOfBounds { at },
+                CopyToSliceError::Read(read_error) => Self::Read(read_error),
+                CopyToSliceError::Seek(seek_error) => Self::Seek(seek_error),
+                CopyToSliceError::BadImpl(kind) => Self::BadImpl(kind),
+            }
+        }
+    }
+
+    impl<R, S> Display for ForEachChunkError<R, S>
+    {
+        #[inline]
+        fn fmt(
+            &self,
+            f: &mut core::fmt::Formatter<'_>,
+        ) -> core::fmt::Result
+        {
+            let prefix = "`payload::Extra::for_each_chunk` failed due to";
+            match self {
+                ForEachChunkError::OutOfBounds { at } => fmt_oob(f, prefix, *at),
+                ForEachChunkError::Read(_) => fmt_r(f, prefix),
+                ForEachChunkError::Seek(_) => fmt_s(f, prefix),
+                ForEachChunkError::BadImpl(kind) => fmt_bi(f, prefix, *kind),
             }
         }
     }
@@ -212,10 +485,19 @@ mod alloc
 #[cfg(any(feature = "std", feature = "anticipate", rust_lib_feature = "error_in_core"))]
 mod standard_error
 {
+    #[cfg(feature = "alloc")]
     use super::{
-        CopyToSliceError,
+        ForEachChunkError,
         ToBoxedSliceError,
     };
+    use super::{
+        CopyToSliceError,
+        EmptySeekError,
+        HeaderThenSeekError,
+        ReadBuffersError,
+        ReadExactError,
+        SeekOutOfBoundsError,
+    };
 
     cfg_if::cfg_if! { if #[cfg(any(feature = "anticipate", rust_lib_feature = "error_in_core"))]
     {
@@ -238,7 +520,50 @@ mod standard_error
             match self {
                 CopyToSliceError::Read(read_error) => Some(read_error),
                 CopyToSliceError::Seek(seek_error) => Some(seek_error),
-                CopyToSliceError::OutOfBounds { .. } | CopyToSliceError::BadImpl => None,
+                CopyToSliceError::OutOfBounds { .. } | CopyToSliceError::BadImpl(_) => None,
+            }
+        }
+    }
+
+    impl<R> Error for ReadExactError<R>
+    where R: Error + 'static
+    {
+        #[inline]
+        fn source(&self) -> Option<&(dyn Error + 'static)>
+        {
+            match self {
+                ReadExactError::Read(read_error) => Some(read_error),
+                ReadExactError::UnexpectedEof { .. } | ReadExactError::BadImpl(_) => None,
+            }
+        }
+    }
+
+    impl<R> Error for ReadBuffersError<R>
+    where R: Error + 'static
+    {
+        #[inline]
+        fn source(&self) -> Option<&(dyn Error + 'static)>
+        {
+            match self {
+                ReadBuffersError::Read(read_error) => Some(read_error),
+                ReadBuffersError::BadImpl(_) => None,
+            }
+        }
+    }
+
+    impl Error for EmptySeekError {}
+
+    impl Error for SeekOutOfBoundsError {}
+
+    impl<S> Error for HeaderThenSeekError<S>
+    where S: Error + 'static
+    {
+        #[inline]
+        fn source(&self) -> Option<&(dyn Error + 'static)>
+        {
+            match self {
+                HeaderThenSeekError::OutOfBounds(oob) => Some(oob),
+                HeaderThenSeekError::Inner(seek_error) => Some(seek_error),
             }
         }
     }
@@ -257,7 +582,24 @@ mod standard_error
                 ToBoxedSliceError::Seek(seek_error) => Some(seek_error),
                 ToBoxedSliceError::OutOfBounds { .. }
                 | ToBoxedSliceError::RangeTooLong { .. }
-                | ToBoxedSliceError::BadImpl => None,
+                | ToBoxedSliceError::BadImpl(_) => None,
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<R, S> Error for ForEachChunkError<R, S>
+    where
+        R: Error + 'static,
+        S: Error + 'static,
+    {
+        #[inline]
+        fn source(&self) -> Option<&(dyn Error + 'static)>
+        {
+            match self {
+                ForEachChunkError::Read(read_error) => Some(read_error),
+                ForEachChunkError::Seek(seek_error) => Some(seek_error),
+                ForEachChunkError::OutOfBounds { .. } | ForEachChunkError::BadImpl(_) => None,
             }
         }
     }
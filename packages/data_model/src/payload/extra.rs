@@ -2,10 +2,18 @@
 
 #![allow(async_fn_in_trait)] // TODO: Re-evaluate.
 
-use super::{
-    errors::CopyToSliceError,
-    Payload,
-    SeekFrom,
+use {
+    super::{
+        errors::{
+            BadImplKind,
+            CopyToSliceError,
+            ReadBuffersError,
+            ReadExactError,
+        },
+        Payload,
+        SeekFrom,
+    },
+    core::num::NonZeroUsize,
 };
 
 
@@ -35,6 +43,34 @@ pub trait ExtraCore: Payload
         self.seek(SeekFrom::Current(0)).await
     }
 
+    /// Like [`Payload::seek`] but, instead of erroring when `pos` is outside the bounds of
+    /// `self`, clamps it to them: beyond the end becomes the end, and before byte `0` becomes
+    /// `0`.  Returns the resulting position.
+    ///
+    /// This only ever gives `seek` a target within the bounds, and so it doesn't change the
+    /// requirement that `seek` itself errors when given one outside them.
+    ///
+    /// # Errors
+    /// If `self`'s implementation of [`Payload::seek`] errors, which could only be due to some
+    /// other problem than the bounds.
+    #[inline]
+    async fn seek_clamped(
+        &mut self,
+        pos: SeekFrom,
+    ) -> Result<u64, Self::SeekError>
+    {
+        let len = self.len().await;
+        let target = match pos {
+            SeekFrom::Start(offset) => offset.min(len),
+            SeekFrom::End(offset) => len.saturating_sub(offset),
+            SeekFrom::Current(offset) => {
+                let current = self.current_position().await?;
+                current.saturating_add_signed(offset).min(len)
+            },
+        };
+        self.seek(SeekFrom::Start(target)).await
+    }
+
     /// Copy a range of the bytes of a [`Payload`] into a slice.
     ///
     /// The range is `start .. (start + dest.len())`.  If `start` is `None`, the current position
@@ -75,19 +111,134 @@ pub trait ExtraCore: Payload
 
         let orig_pos = self.current_position().await.map_err(Error::Seek)?;
         let payload_len = self.len().await;
-        copy_to_slice_with(self, payload_len, orig_pos, start, dest, callback, restore).await
+        copy_to_slice_with(self, payload_len, orig_pos, start, dest, None, callback, restore)
+            .await
+    }
+
+    /// Like [`copy_to_slice`](Self::copy_to_slice) but each [`read`](Payload::read) is given
+    /// at most `chunk` many bytes of `dest` to fill, and so `callback` is invoked on chunks of
+    /// at most that size.
+    ///
+    /// This can be useful for `Payload` types that have overhead per `read` or that perform
+    /// worse with huge `read`s, or to bound the amount of bytes that `callback` processes at
+    /// once.
+    ///
+    /// # Errors
+    /// The same as [`copy_to_slice`](Self::copy_to_slice).
+    #[inline]
+    async fn copy_to_slice_chunked<C>(
+        &mut self,
+        start: Option<u64>,
+        dest: &mut [u8],
+        chunk: NonZeroUsize,
+        callback: Option<C>,
+        restore: bool,
+    ) -> Result<(), CopyToSliceError<Self::ReadError, Self::SeekError>>
+    where
+        C: FnMut(&mut [u8]),
+    {
+        use CopyToSliceError as Error;
+
+        let orig_pos = self.current_position().await.map_err(Error::Seek)?;
+        let payload_len = self.len().await;
+        let chunk = Some(chunk);
+        copy_to_slice_with(self, payload_len, orig_pos, start, dest, chunk, callback, restore)
+            .await
+    }
+
+    /// Fill all of `buf` from the current position of `self`, by looping
+    /// [`read`](Payload::read)ing, which advances the position by `buf.len()`.
+    ///
+    /// Unlike [`copy_to_slice`](Self::copy_to_slice), this doesn't [`seek`](Payload::seek) nor
+    /// check [`len`](Payload::len), and so it relies on a `read` that gives `0` to indicate the
+    /// end.
+    ///
+    /// # Errors
+    /// - If the end is reached before `buf` is filled.  The position is then at the end, and the
+    ///   filled part of `buf` is as `read`.
+    /// - If `read` errors.  The position is then after the bytes that were already read.
+    /// - If the implementation of `Payload` misbehaves in a detected way.
+    #[inline]
+    async fn read_exact(
+        &mut self,
+        buf: &mut [u8],
+    ) -> Result<(), ReadExactError<Self::ReadError>>
+    {
+        use ReadExactError as Error;
+
+        let buf_len = buf.len();
+        let mut rest = buf;
+        while !rest.is_empty() {
+            match self.read(rest).await.map_err(Error::Read)? {
+                0 => {
+                    let filled = buf_len.saturating_sub(rest.len());
+                    return Err(Error::UnexpectedEof { filled });
+                },
+                consumed => {
+                    let overflow =
+                        BadImplKind::ReadOverflow { given: rest.len(), claimed: consumed };
+                    rest = rest.get_mut(consumed ..).ok_or(Error::BadImpl(overflow))?;
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Fill each of `bufs` in order from the current position of `self`, by looping
+    /// [`read`](Payload::read)ing, until all are full or the end is reached.  Returns the total
+    /// amount of bytes read, which advanced the position by that.
+    ///
+    /// This is a scatter read into fragmented destinations, like
+    /// [`std::io::Read::read_vectored`](
+    /// https://doc.rust-lang.org/std/io/trait.Read.html#method.read_vectored), but each buffer
+    /// is completely filled before the next, unless the end is reached, and so a total less than
+    /// that of `bufs` means the end was reached.
+    ///
+    /// Like [`read_exact`](Self::read_exact), this doesn't [`seek`](Payload::seek) nor check
+    /// [`len`](Payload::len).
+    ///
+    /// # Errors
+    /// - If `read` errors.  The position is then after the bytes that were already read, and the
+    ///   filled parts of `bufs` are as `read`.
+    /// - If the implementation of `Payload` misbehaves in a detected way.
+    #[inline]
+    async fn read_buffers(
+        &mut self,
+        bufs: &mut [&mut [u8]],
+    ) -> Result<usize, ReadBuffersError<Self::ReadError>>
+    {
+        use ReadBuffersError as Error;
+
+        let mut total: usize = 0;
+        for buf in bufs {
+            let mut rest = &mut **buf;
+            while !rest.is_empty() {
+                match self.read(rest).await.map_err(Error::Read)? {
+                    0 => return Ok(total),
+                    consumed => {
+                        let overflow =
+                            BadImplKind::ReadOverflow { given: rest.len(), claimed: consumed };
+                        rest = rest.get_mut(consumed ..).ok_or(Error::BadImpl(overflow))?;
+                        total = total.saturating_add(consumed);
+                    },
+                }
+            }
+        }
+        Ok(total)
     }
 }
 
 
 /// Take a given `payload_len` & `orig_pos` and assume they're correct.  This enables avoiding
-/// computing them more than once.
+/// computing them more than once.  If `chunk` is `Some`, each `read` is limited to that size.
+#[allow(clippy::too_many_arguments)]
 async fn copy_to_slice_with<P, C>(
     payload: &mut P,
     payload_len: u64,
     orig_pos: u64,
     start: Option<u64>,
     dest: &mut [u8],
+    chunk: Option<NonZeroUsize>,
     mut callback: Option<C>,
     restore: bool,
 ) -> Result<(), CopyToSliceError<P::ReadError, P::SeekError>>
@@ -108,32 +259,50 @@ where
     if start != orig_pos {
         let seeked_pos = payload.seek(SeekFrom::Start(start)).await.map_err(Error::Seek)?;
         if seeked_pos != start {
-            return Err(Error::BadImpl);
+            return Err(Error::BadImpl(BadImplKind::SeekMismatch {
+                requested: start,
+                got:       seeked_pos,
+            }));
         }
     }
     let mut buf = dest;
     // Loop on this condition, to be more robust by not relying on the `Payload` impl to behave
     // correctly when `buf.len() == 0`.
     while !buf.is_empty() {
-        if let consumed @ 1 .. = payload.read(buf).await.map_err(Error::Read)? {
+        let limit = chunk.map_or(buf.len(), |chunk| chunk.get().min(buf.len()));
+        // (These `get_mut`s can't fail, because `consumed <= limit <= buf.len()`.)
+        let window = buf.get_mut(.. limit).unwrap_or_default();
+        if let consumed @ 1 .. = payload.read(window).await.map_err(Error::Read)? {
+            if consumed > limit {
+                return Err(Error::BadImpl(BadImplKind::ReadOverflow {
+                    given:   limit,
+                    claimed: consumed,
+                }));
+            }
             if let Some(callback) = &mut callback {
-                let filled = buf.get_mut(.. consumed).ok_or_else(|| Error::BadImpl)?;
-                callback(filled);
+                callback(buf.get_mut(.. consumed).unwrap_or_default());
             }
-            let remainder = buf.get_mut(consumed ..).ok_or_else(|| Error::BadImpl)?;
-            buf = remainder;
+            buf = buf.get_mut(consumed ..).unwrap_or_default();
         }
         else {
             break;
         }
     }
     if !buf.is_empty() {
-        return Err(Error::BadImpl); // It failed to give all of what it said was its length.
+        // It failed to give all of what it said was its length.
+        let unfilled = buf.len().try_into().unwrap_or(u64::MAX);
+        return Err(Error::BadImpl(BadImplKind::ShortRead {
+            expected: dest_len,
+            got:      dest_len.saturating_sub(unfilled),
+        }));
     }
     if restore {
         let restored_pos = payload.seek(SeekFrom::Start(orig_pos)).await.map_err(Error::Seek)?;
         if restored_pos != orig_pos {
-            return Err(Error::BadImpl);
+            return Err(Error::BadImpl(BadImplKind::SeekMismatch {
+                requested: orig_pos,
+                got:       restored_pos,
+            }));
         }
     }
     Ok(())
@@ -148,18 +317,27 @@ mod alloc
 {
     use {
         super::{
-            super::errors::ToBoxedSliceError,
+            super::errors::{
+                BadImplKind,
+                ForEachChunkError,
+                ToBoxedSliceError,
+            },
             copy_to_slice_with,
             ExtraCore,
             Payload,
+            SeekFrom,
         },
         alloc::{
             boxed::Box,
-            vec,
+            vec::Vec,
         },
-        core::ops::{
-            Bound,
-            RangeBounds,
+        core::{
+            hash::Hasher,
+            num::NonZeroUsize,
+            ops::{
+                Bound,
+                RangeBounds,
+            },
         },
     };
 
@@ -209,50 +387,268 @@ mod alloc
         where
             C: FnMut(&mut [u8]),
         {
-            use ToBoxedSliceError as Error;
+            to_boxed_slice_with(self, range, None, callback, restore).await
+        }
 
-            let orig_pos = self.current_position().await.map_err(Error::Seek)?;
-            let payload_len = self.len().await;
+        /// Like [`to_boxed_slice`](Self::to_boxed_slice) but limits each
+        /// [`read`](Payload::read) to `chunk` many bytes, the same as
+        /// [`copy_to_slice_chunked`](ExtraCore::copy_to_slice_chunked).
+        ///
+        /// # Errors
+        /// The same as [`to_boxed_slice`](Self::to_boxed_slice).
+        #[inline]
+        async fn to_boxed_slice_chunked<C>(
+            &mut self,
+            range: impl RangeBounds<u64>,
+            chunk: NonZeroUsize,
+            callback: Option<C>,
+            restore: bool,
+        ) -> Result<Box<[u8]>, ToBoxedSliceError<Self::ReadError, Self::SeekError>>
+        where
+            C: FnMut(&mut [u8]),
+        {
+            to_boxed_slice_with(self, range, Some(chunk), callback, restore).await
+        }
 
-            let checked_incr =
-                |x: u64| x.checked_add(1).ok_or_else(Error::out_of_bounds_overflowed);
-            let start = match range.start_bound() {
-                Bound::Included(x) => *x,
-                Bound::Excluded(x) => checked_incr(*x)?,
-                Bound::Unbounded => orig_pos,
-            };
-            let end = match range.end_bound() {
-                Bound::Included(x) => checked_incr(*x)?,
-                Bound::Excluded(x) => *x,
-                Bound::Unbounded => payload_len,
+        /// Invoke `f` on each consecutive chunk, of at most `chunk_size` many bytes, of a range
+        /// of the bytes of a [`Payload`], reusing a single buffer for all the chunks.
+        ///
+        /// This is like [`to_boxed_slice`](Self::to_boxed_slice) but without allocating for the
+        /// whole range, and `range` and `restore` have the same meanings and the same bounds
+        /// checking.  Each chunk is filled like by [`copy_to_slice`](ExtraCore::copy_to_slice)
+        /// before being given to `f`, and so only the last one can be shorter than `chunk_size`.
+        ///
+        /// # Errors
+        /// - If `range` is outside the bounds of `self`, in which case `f` is never invoked.
+        /// - If any of the called `Payload` methods error.
+        /// - If the implementation of `Payload` misbehaves in a detected way.
+        #[inline]
+        async fn for_each_chunk<F>(
+            &mut self,
+            range: impl RangeBounds<u64>,
+            chunk_size: NonZeroUsize,
+            f: F,
+            restore: bool,
+        ) -> Result<(), ForEachChunkError<Self::ReadError, Self::SeekError>>
+        where
+            F: FnMut(&[u8]),
+        {
+            for_each_chunk_with(self, range, chunk_size, f, restore).await
+        }
+
+        /// Write all the bytes of a [`Payload`], from its beginning to its end, to `hasher`, and
+        /// return how many were written.
+        ///
+        /// This is done by [`for_each_chunk`](Self::for_each_chunk) with a bounded chunk size,
+        /// and so without allocating for the whole `Payload`.  `restore` has the same meaning.
+        /// [`Hasher::finish`] isn't called, so that the caller can combine other data with the
+        /// same `hasher`.
+        ///
+        /// # Errors
+        /// The same as [`for_each_chunk`](Self::for_each_chunk).  What was already read remains
+        /// written to `hasher`.
+        #[inline]
+        async fn hash_with<H>(
+            &mut self,
+            hasher: &mut H,
+            restore: bool,
+        ) -> Result<u64, ForEachChunkError<Self::ReadError, Self::SeekError>>
+        where
+            H: Hasher + ?Sized,
+        {
+            let mut written = 0_u64;
+            let write = |chunk: &[u8]| {
+                hasher.write(chunk);
+                written = written.saturating_add(chunk.len().try_into().unwrap_or(u64::MAX));
             };
-            let range_len = end.saturating_sub(start); // If `start > end` then empty.
-            let alloc_len =
-                isize::try_from(range_len).and_then(usize::try_from).ok().ok_or_else(|| {
-                    debug_assert!(range_len > ISIZE_MAX_AS_U64, "failed convert implies greater");
-                    Error::range_too_long_by(range_len.saturating_sub(ISIZE_MAX_AS_U64))
-                })?;
-
-            let vec = vec![0; alloc_len]; // FUTURE: Use more-efficient uninitialized.
-            debug_assert_eq!(
-                vec.capacity(),
-                alloc_len,
-                "Want `into_boxed_slice` to not reallocate."
-            );
-            let mut boxed_slice = vec.into_boxed_slice();
+            for_each_chunk_with(self, 0 .., HASH_WITH_CHUNK_SIZE, write, restore).await?;
+            Ok(written)
+        }
+
+        /// Append all the bytes from the current position of `self` to the end onto `dest`, by
+        /// looping [`read`](Payload::read)ing until it gives `0`, and return how many were
+        /// appended.
+        ///
+        /// [`len`](Payload::len) is only used as a hint of how much to grow `dest` by.
+        ///
+        /// # Errors
+        /// If `read` errors.  What was already read remains appended onto `dest` and the
+        /// position is after it.
+        #[inline]
+        async fn read_to_end(
+            &mut self,
+            dest: &mut Vec<u8>,
+        ) -> Result<usize, Self::ReadError>
+        {
+            let orig_len = dest.len();
+            let hint = usize::try_from(self.len().await).unwrap_or(usize::MAX);
+            loop {
+                let filled = dest.len();
+                let appended = filled.saturating_sub(orig_len);
+                // At least 1, to detect the end when `hint` is already reached.
+                let grow = hint.saturating_sub(appended).clamp(1, READ_TO_END_MAX_GROW);
+                dest.resize(filled.saturating_add(grow), 0); // FUTURE: Use uninitialized.
+                let window = dest.get_mut(filled ..).unwrap_or_default();
+                match self.read(window).await {
+                    Ok(0) => {
+                        dest.truncate(filled);
+                        break Ok(appended);
+                    },
+                    // (A misbehaving `read` that claims more than it was given can't cause
+                    // garbage to be appended.)
+                    Ok(consumed) => dest.truncate(filled.saturating_add(consumed.min(grow))),
+                    Err(error) => {
+                        dest.truncate(filled);
+                        break Err(error);
+                    },
+                }
+            }
+        }
+    }
+
+    /// The most that [`Extra::read_to_end`] grows its `dest` by at once, so that a `len` hint
+    /// that's huge doesn't cause a huge allocation before knowing that there's that much.
+    const READ_TO_END_MAX_GROW: usize = 1 << 16;
+
+
+    /// The size of the chunks that [`Extra::hash_with`] reads and writes.
+    const HASH_WITH_CHUNK_SIZE: NonZeroUsize = NonZeroUsize::MIN.saturating_add((1 << 13) - 1);
+
+
+    /// The `start` and `end` positions of `range`, where an unbounded start is `orig_pos` and an
+    /// unbounded end is `payload_len`, or `None` if converting an inclusive end or an exclusive
+    /// start overflows.
+    fn resolve_range(
+        range: &impl RangeBounds<u64>,
+        orig_pos: u64,
+        payload_len: u64,
+    ) -> Option<(u64, u64)>
+    {
+        let start = match range.start_bound() {
+            Bound::Included(x) => *x,
+            Bound::Excluded(x) => x.checked_add(1)?,
+            Bound::Unbounded => orig_pos,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(x) => x.checked_add(1)?,
+            Bound::Excluded(x) => *x,
+            Bound::Unbounded => payload_len,
+        };
+        Some((start, end))
+    }
+
+
+    /// The implementation of both [`Extra::to_boxed_slice`] and
+    /// [`Extra::to_boxed_slice_chunked`].
+    async fn to_boxed_slice_with<P, C>(
+        payload: &mut P,
+        range: impl RangeBounds<u64>,
+        chunk: Option<NonZeroUsize>,
+        callback: Option<C>,
+        restore: bool,
+    ) -> Result<Box<[u8]>, ToBoxedSliceError<P::ReadError, P::SeekError>>
+    where
+        P: Payload + ?Sized,
+        C: FnMut(&mut [u8]),
+    {
+        use ToBoxedSliceError as Error;
+
+        let orig_pos = payload.current_position().await.map_err(Error::Seek)?;
+        let payload_len = payload.len().await;
+
+        let (start, end) = resolve_range(&range, orig_pos, payload_len)
+            .ok_or_else(Error::out_of_bounds_overflowed)?;
+        let range_len = end.saturating_sub(start); // If `start > end` then empty.
+        let alloc_len =
+            isize::try_from(range_len).and_then(usize::try_from).ok().ok_or_else(|| {
+                debug_assert!(range_len > ISIZE_MAX_AS_U64, "failed convert implies greater");
+                Error::range_too_long_by(range_len.saturating_sub(ISIZE_MAX_AS_U64))
+            })?;
+
+        let vec = alloc::vec![0; alloc_len]; // FUTURE: Use more-efficient uninitialized.
+        debug_assert_eq!(vec.capacity(), alloc_len, "Want `into_boxed_slice` to not reallocate.");
+        let mut boxed_slice = vec.into_boxed_slice();
+        copy_to_slice_with(
+            payload,
+            payload_len,
+            orig_pos,
+            Some(start),
+            &mut boxed_slice,
+            chunk,
+            callback,
+            restore,
+        )
+        .await?;
+        Ok(boxed_slice)
+    }
+
+
+    /// The implementation of [`Extra::for_each_chunk`].
+    async fn for_each_chunk_with<P, F>(
+        payload: &mut P,
+        range: impl RangeBounds<u64>,
+        chunk_size: NonZeroUsize,
+        mut f: F,
+        restore: bool,
+    ) -> Result<(), ForEachChunkError<P::ReadError, P::SeekError>>
+    where
+        P: Payload + ?Sized,
+        F: FnMut(&[u8]),
+    {
+        use ForEachChunkError as Error;
+
+        let orig_pos = payload.current_position().await.map_err(Error::Seek)?;
+        let payload_len = payload.len().await;
+
+        let (start, end) = resolve_range(&range, orig_pos, payload_len)
+            .ok_or_else(Error::out_of_bounds_overflowed)?;
+        let end = end.max(start); // If `start > end` then empty.
+        for x in [start, end] {
+            if x > payload_len {
+                return Err(Error::out_of_bounds_at(x));
+            }
+        }
+
+        let range_len = end.saturating_sub(start);
+        let limit = |len: u64| {
+            usize::try_from(len).map_or(chunk_size.get(), |len| len.min(chunk_size.get()))
+        };
+        let mut buf = alloc::vec![0; limit(range_len)]; // FUTURE: Use uninitialized.
+        let mut pos = orig_pos;
+        let mut next = start;
+        while next < end {
+            // Only the last chunk can be shorter, and so `buf` is only shortened for that.
+            buf.truncate(limit(end.saturating_sub(next)));
+            let chunk = buf.as_mut_slice();
             copy_to_slice_with(
-                self,
+                payload,
                 payload_len,
-                orig_pos,
-                Some(start),
-                &mut boxed_slice,
-                callback,
-                restore,
+                pos,
+                Some(next),
+                chunk,
+                None,
+                NO_CALLBACK,
+                false,
             )
             .await?;
-            Ok(boxed_slice)
+            f(chunk);
+            pos = next.saturating_add(chunk.len().try_into().unwrap_or(u64::MAX));
+            next = pos;
         }
+        if restore && pos != orig_pos {
+            let restored_pos =
+                payload.seek(SeekFrom::Start(orig_pos)).await.map_err(Error::Seek)?;
+            if restored_pos != orig_pos {
+                return Err(Error::BadImpl(BadImplKind::SeekMismatch {
+                    requested: orig_pos,
+                    got:       restored_pos,
+                }));
+            }
+        }
+        Ok(())
     }
+
+    const NO_CALLBACK: Option<fn(&mut [u8])> = None;
 }
 
 
@@ -260,8 +656,16 @@ mod alloc
 pub mod sync
 {
     use {
-        super::super::errors::CopyToSliceError,
+        super::super::{
+            errors::{
+                CopyToSliceError,
+                ReadBuffersError,
+                ReadExactError,
+            },
+            SeekFrom,
+        },
         crate::syncify::Syncify,
+        core::num::NonZeroUsize,
     };
 
 
@@ -279,6 +683,17 @@ pub mod sync
             block_on(super::ExtraCore::current_position(self), data)
         }
 
+        /// Like [`crate::payload::ExtraCore::seek_clamped`] but synchronous.  Might block.
+        #[inline]
+        fn seek_clamped(
+            &mut self,
+            pos: SeekFrom,
+        ) -> Result<u64, Self::SeekError>
+        {
+            let (block_on, data) = get_block_on_and_data!(self);
+            block_on(super::ExtraCore::seek_clamped(self, pos), data)
+        }
+
         /// Like [`crate::payload::ExtraCore::copy_to_slice`] but synchronous.  Might block.
         #[inline]
         fn copy_to_slice<C>(
@@ -294,6 +709,51 @@ pub mod sync
             let (block_on, data) = get_block_on_and_data!(self);
             block_on(super::ExtraCore::copy_to_slice(self, start, dest, callback, restore), data)
         }
+
+        /// Like [`crate::payload::ExtraCore::copy_to_slice_chunked`] but synchronous.  Might
+        /// block.
+        #[inline]
+        fn copy_to_slice_chunked<C>(
+            &mut self,
+            start: Option<u64>,
+            dest: &mut [u8],
+            chunk: NonZeroUsize,
+            callback: Option<C>,
+            restore: bool,
+        ) -> Result<(), CopyToSliceError<Self::ReadError, Self::SeekError>>
+        where
+            C: FnMut(&mut [u8]),
+        {
+            let (block_on, data) = get_block_on_and_data!(self);
+            block_on(
+                super::ExtraCore::copy_to_slice_chunked(
+                    self, start, dest, chunk, callback, restore,
+                ),
+                data,
+            )
+        }
+
+        /// Like [`crate::payload::ExtraCore::read_exact`] but synchronous.  Might block.
+        #[inline]
+        fn read_exact(
+            &mut self,
+            buf: &mut [u8],
+        ) -> Result<(), ReadExactError<Self::ReadError>>
+        {
+            let (block_on, data) = get_block_on_and_data!(self);
+            block_on(super::ExtraCore::read_exact(self, buf), data)
+        }
+
+        /// Like [`crate::payload::ExtraCore::read_buffers`] but synchronous.  Might block.
+        #[inline]
+        fn read_buffers(
+            &mut self,
+            bufs: &mut [&mut [u8]],
+        ) -> Result<usize, ReadBuffersError<Self::ReadError>>
+        {
+            let (block_on, data) = get_block_on_and_data!(self);
+            block_on(super::ExtraCore::read_buffers(self, bufs), data)
+        }
     }
 
 
@@ -304,9 +764,19 @@ pub mod sync
     mod alloc
     {
         use {
-            super::super::super::errors::ToBoxedSliceError,
-            alloc::boxed::Box,
-            core::ops::RangeBounds,
+            super::super::super::errors::{
+                ForEachChunkError,
+                ToBoxedSliceError,
+            },
+            alloc::{
+                boxed::Box,
+                vec::Vec,
+            },
+            core::{
+                hash::Hasher,
+                num::NonZeroUsize,
+                ops::RangeBounds,
+            },
         };
 
         /// Like [`crate::payload::Extra`] but all methods are synchronous (i.e. not `async`) and
@@ -333,6 +803,75 @@ pub mod sync
                     data,
                 )
             }
+
+            /// Like [`crate::payload::Extra::to_boxed_slice_chunked`] but synchronous.  Might
+            /// block.
+            #[inline]
+            #[allow(clippy::type_complexity)]
+            fn to_boxed_slice_chunked<C>(
+                &mut self,
+                range: impl RangeBounds<u64>,
+                chunk: NonZeroUsize,
+                callback: Option<C>,
+                restore: bool,
+            ) -> Result<Box<[u8]>, ToBoxedSliceError<Self::ReadError, Self::SeekError>>
+            where
+                C: FnMut(&mut [u8]),
+            {
+                let (block_on, data) = get_block_on_and_data!(self);
+                block_on(
+                    super::super::Extra::to_boxed_slice_chunked(
+                        self, range, chunk, callback, restore,
+                    ),
+                    data,
+                )
+            }
+
+            /// Like [`crate::payload::Extra::for_each_chunk`] but synchronous.  Might block.
+            #[inline]
+            #[allow(clippy::type_complexity)]
+            fn for_each_chunk<F>(
+                &mut self,
+                range: impl RangeBounds<u64>,
+                chunk_size: NonZeroUsize,
+                f: F,
+                restore: bool,
+            ) -> Result<(), ForEachChunkError<Self::ReadError, Self::SeekError>>
+            where
+                F: FnMut(&[u8]),
+            {
+                let (block_on, data) = get_block_on_and_data!(self);
+                block_on(
+                    super::super::Extra::for_each_chunk(self, range, chunk_size, f, restore),
+                    data,
+                )
+            }
+
+            /// Like [`crate::payload::Extra::hash_with`] but synchronous.  Might block.
+            #[inline]
+            #[allow(clippy::type_complexity)]
+            fn hash_with<H>(
+                &mut self,
+                hasher: &mut H,
+                restore: bool,
+            ) -> Result<u64, ForEachChunkError<Self::ReadError, Self::SeekError>>
+            where
+                H: Hasher + ?Sized,
+            {
+                let (block_on, data) = get_block_on_and_data!(self);
+                block_on(super::super::Extra::hash_with(self, hasher, restore), data)
+            }
+
+            /// Like [`crate::payload::Extra::read_to_end`] but synchronous.  Might block.
+            #[inline]
+            fn read_to_end(
+                &mut self,
+                dest: &mut Vec<u8>,
+            ) -> Result<usize, Self::ReadError>
+            {
+                let (block_on, data) = get_block_on_and_data!(self);
+                block_on(super::super::Extra::read_to_end(self, dest), data)
+            }
         }
     }
 }
@@ -0,0 +1,190 @@
+use {
+    crate::{
+        payload::{
+            self,
+            sync,
+            SeekOutOfBoundsError,
+        },
+        syncify::Syncify,
+        Payload,
+    },
+    core::{
+        convert::Infallible,
+        future::Future,
+    },
+    std::{
+        fs,
+        io::{
+            self,
+            Read as _,
+            Seek as _,
+        },
+    },
+};
+
+
+/// A [`Payload`] over an open file, for serving payloads stored on disk without first copying
+/// them into memory.
+///
+/// This is only available with our `"std"` package feature.
+///
+/// The methods may block, because they're the file's own blocking operations, which violates
+/// [`Payload::read`]'s guarantee of not blocking.  So this should only be used via the
+/// [`sync`] facade or with an executor that tolerates blocking (e.g. one that runs the futures
+/// on a thread dedicated to that).
+///
+/// The file should not be modified while this is in use, since a `Payload` is supposed to be an
+/// unchanging sequence of bytes.  Its length is gotten once, when this is made.
+#[derive(Debug)]
+pub struct File
+{
+    file: fs::File,
+    len:  u64,
+}
+
+impl File
+{
+    /// Make a new `Payload` whose contents are all of the given `file`, and whose current
+    /// position is the file's current position.
+    ///
+    /// # Errors
+    /// If getting the length of `file`, from its metadata, fails.
+    #[inline]
+    pub fn new(file: fs::File) -> io::Result<Self>
+    {
+        let len = file.metadata()?.len();
+        Ok(Self { file, len })
+    }
+
+    /// Return the file, which keeps the current position.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> fs::File
+    {
+        self.file
+    }
+}
+
+impl TryFrom<fs::File> for File
+{
+    type Error = io::Error;
+
+    #[inline]
+    fn try_from(file: fs::File) -> io::Result<Self>
+    {
+        Self::new(file)
+    }
+}
+
+
+/// This does not uphold the requirement that the methods won't block.  See [`File`].
+impl Payload for File
+{
+    type ReadError = io::Error;
+    type SeekError = io::Error;
+
+    #[inline]
+    async fn read(
+        &mut self,
+        buf: &mut [u8],
+    ) -> Result<usize, Self::ReadError>
+    {
+        <Self as sync::Payload<()>>::read(self, buf)
+    }
+
+    #[inline]
+    async fn seek(
+        &mut self,
+        pos: payload::SeekFrom,
+    ) -> Result<u64, Self::SeekError>
+    {
+        <Self as sync::Payload<()>>::seek(self, pos)
+    }
+
+    #[inline]
+    async fn len(&self) -> u64
+    {
+        <Self as sync::Payload<()>>::len(self)
+    }
+
+    #[inline]
+    async fn is_empty(&self) -> bool
+    {
+        <Self as sync::Payload<()>>::is_empty(self)
+    }
+}
+
+
+/// `File`s actually don't need an executor, to reuse their methods as synchronous, but
+/// implementing this is needed to implement `sync::Payload`.
+#[allow(clippy::unreachable)]
+impl Syncify<()> for File
+{
+    type ExecutorData = Infallible;
+
+    #[inline]
+    fn get_block_on_fn<'f, F>(&self) -> impl 'f + FnOnce(F, Self::ExecutorData) -> F::Output
+    where F: Future + 'f
+    {
+        |_, _| unreachable!()
+    }
+
+    #[inline]
+    fn get_executor_data(&self) -> Self::ExecutorData
+    {
+        unreachable!()
+    }
+}
+
+/// Provides its own implementations of the methods, to directly use the file's blocking
+/// operations without involving an executor.
+impl sync::Payload<()> for File
+{
+    #[inline]
+    fn read(
+        &mut self,
+        buf: &mut [u8],
+    ) -> Result<usize, Self::ReadError>
+    {
+        self.file.read(buf)
+    }
+
+    /// Seeking beyond the end, or before the start, is an error of kind
+    /// [`InvalidInput`](io::ErrorKind::InvalidInput) that has a [`SeekOutOfBoundsError`], and
+    /// the position is then unchanged.
+    #[inline]
+    fn seek(
+        &mut self,
+        pos: payload::SeekFrom,
+    ) -> Result<u64, Self::SeekError>
+    {
+        let len = self.len;
+        let new_pos = match pos {
+            payload::SeekFrom::Start(offset) => Some(offset),
+            payload::SeekFrom::End(offset) => len.checked_sub(offset),
+            payload::SeekFrom::Current(offset) =>
+                self.file.stream_position()?.checked_add_signed(offset),
+        }
+        .filter(|&new_pos| new_pos <= len);
+
+        if let Some(new_pos) = new_pos {
+            self.file.seek(io::SeekFrom::Start(new_pos))
+        }
+        else {
+            Err(io::Error::new(io::ErrorKind::InvalidInput, SeekOutOfBoundsError { pos }))
+        }
+    }
+
+    /// The length that the file had when this was made.
+    #[inline]
+    fn len(&self) -> u64
+    {
+        self.len
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool
+    {
+        <Self as sync::Payload<()>>::len(self) == 0
+    }
+}
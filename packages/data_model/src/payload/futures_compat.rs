@@ -0,0 +1,104 @@
+use {
+    super::compat::{
+        complete,
+        read_error,
+        seek_error,
+        seek_from,
+    },
+    crate::payload::Payload,
+    alloc::boxed::Box,
+    core::{
+        pin::Pin,
+        task::{
+            Context,
+            Poll,
+        },
+    },
+    futures_io::{
+        AsyncRead,
+        AsyncSeek,
+    },
+    std::{
+        error::Error,
+        io,
+    },
+};
+
+
+/// Adapts a [`Payload`] to be a [`futures_io::AsyncRead`] and [`futures_io::AsyncSeek`], so
+/// that it can be used with `futures::io::copy` and similar.
+///
+/// This is only available with our `"futures-io"` package feature.
+///
+/// This works just like `TokioCompat` (of our `"tokio"` package feature) does: each `poll_*`
+/// polls the inner future only once, within the single call, and errors become [`io::Error`]s
+/// of [`io::ErrorKind::Other`] from `read` and of [`io::ErrorKind::InvalidInput`] from `seek`
+/// (including when seeking beyond the end by [`io::SeekFrom::End`]).  So this too requires the
+/// inner `Payload`'s futures to be immediately ready, and it panics if one is pending.
+#[derive(Debug)]
+pub struct FuturesCompat<P>
+{
+    inner: P,
+}
+
+impl<P> FuturesCompat<P>
+{
+    /// Wrap the given `Payload`, keeping its current position.
+    #[must_use]
+    #[inline]
+    pub fn new(inner: P) -> Self
+    {
+        Self { inner }
+    }
+
+    /// Return the inner `Payload`.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> P
+    {
+        self.inner
+    }
+
+    /// Return a reference to the inner `Payload`.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> &P
+    {
+        &self.inner
+    }
+}
+
+
+impl<P> AsyncRead for FuturesCompat<P>
+where
+    P: Payload + Unpin,
+    P::ReadError: Into<Box<dyn Error + Send + Sync>>,
+{
+    #[inline]
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>>
+    {
+        Poll::Ready(complete(self.get_mut().inner.read(buf), cx).map_err(read_error))
+    }
+}
+
+
+impl<P> AsyncSeek for FuturesCompat<P>
+where
+    P: Payload + Unpin,
+    P::SeekError: Into<Box<dyn Error + Send + Sync>>,
+{
+    #[inline]
+    fn poll_seek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: io::SeekFrom,
+    ) -> Poll<io::Result<u64>>
+    {
+        let pos = seek_from(pos)?;
+        Poll::Ready(complete(self.get_mut().inner.seek(pos), cx).map_err(seek_error))
+    }
+}
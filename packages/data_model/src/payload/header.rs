@@ -0,0 +1,159 @@
+use super::{
+    CopyToSliceError,
+    ExtraCore as _,
+    HeaderThenSeekError,
+    Payload,
+    SeekFrom,
+    SeekOutOfBoundsError,
+};
+
+
+/// A type that can be parsed from a fixed-size header of `N` bytes.
+///
+/// This is what [`HeaderThen`] uses to parse the header of its inner `Payload`.
+pub trait FromBytes<const N: usize>
+{
+    /// Parse `Self` from the given `bytes`.
+    ///
+    /// This is infallible, because all `N`-byte headers must be representable.  Types that need
+    /// to reject some headers can be an `enum` or `Option` that represents those.
+    fn from_bytes(bytes: &[u8; N]) -> Self;
+}
+
+/// The bytes as-is.
+impl<const N: usize> FromBytes<N> for [u8; N]
+{
+    #[inline]
+    fn from_bytes(bytes: &[u8; N]) -> Self
+    {
+        *bytes
+    }
+}
+
+
+/// A [`Payload`] that begins with a fixed-size header, of `N` bytes, that is parsed as a typed
+/// `H`, and whose remainder (the body) is presented as a `Payload` itself.
+///
+/// As a `Payload`, `Self` is only the body: its positions are offset past the header, and its
+/// [`len`](Payload::len) is that of the inner `Payload` minus `N`.
+///
+/// The header is only read, by [`header`](Self::header), on first access, and then it's cached.
+#[derive(Debug)]
+pub struct HeaderThen<H, P, const N: usize>
+{
+    inner:  P,
+    header: Option<H>,
+    /// Position in the body, i.e. not including the header.
+    pos:    u64,
+}
+
+impl<H, P, const N: usize> HeaderThen<H, P, N>
+where
+    H: FromBytes<N>,
+    P: Payload,
+{
+    /// The length of the header, as the type of `Payload` positions.
+    #[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+    const HEADER_LEN: u64 = {
+        let as_u64 = N as u64;
+        // Note: This panic can only occur at const-eval time, not at runtime.
+        if as_u64 as usize == N { as_u64 } else { panic!("header too long") }
+    };
+
+    /// Make a new `Payload` of the body of `inner`, whose current position is the start of the
+    /// body.
+    ///
+    /// This `seek`s `inner` to just past the header, but doesn't read the header yet.
+    ///
+    /// # Errors
+    /// If `inner`'s [`Payload::seek`] errors, which it must if `inner` is shorter than `N`.
+    #[inline]
+    pub async fn new(mut inner: P) -> Result<Self, P::SeekError>
+    {
+        let _start: u64 = inner.seek(SeekFrom::Start(Self::HEADER_LEN)).await?;
+        Ok(Self { inner, header: None, pos: 0 })
+    }
+
+    /// Return the header, reading and parsing it if this is the first access.
+    ///
+    /// The current position of the body remains unchanged.
+    ///
+    /// # Errors
+    /// If reading the header fails, as described by
+    /// [`copy_to_slice`](crate::payload::ExtraCore::copy_to_slice).  The next call will try
+    /// again.
+    #[inline]
+    pub async fn header(&mut self) -> Result<&H, CopyToSliceError<P::ReadError, P::SeekError>>
+    {
+        let header = if let Some(header) = self.header.take() {
+            header
+        }
+        else {
+            let mut bytes = [0; N];
+            let callback = None::<fn(&mut [u8])>;
+            self.inner.copy_to_slice(Some(0), &mut bytes, callback, true).await?;
+            H::from_bytes(&bytes)
+        };
+        Ok(self.header.insert(header))
+    }
+
+    /// Return the inner `Payload`, dropping the cached header.  Its position is wherever the body
+    /// was, plus `N`.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> P
+    {
+        self.inner
+    }
+}
+
+/// This upholds the requirement that the methods won't block, as long as the inner `Payload`
+/// does.
+impl<H, P, const N: usize> Payload for HeaderThen<H, P, N>
+where
+    H: FromBytes<N>,
+    P: Payload,
+{
+    type ReadError = P::ReadError;
+    type SeekError = HeaderThenSeekError<P::SeekError>;
+
+    #[inline]
+    async fn read(
+        &mut self,
+        buf: &mut [u8],
+    ) -> Result<usize, Self::ReadError>
+    {
+        let amount = self.inner.read(buf).await?;
+        self.pos = self.pos.saturating_add(amount.try_into().unwrap_or(u64::MAX));
+        Ok(amount)
+    }
+
+    #[inline]
+    async fn seek(
+        &mut self,
+        pos: SeekFrom,
+    ) -> Result<u64, Self::SeekError>
+    {
+        let len = self.len().await;
+        let new_pos = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => len.checked_sub(offset),
+            SeekFrom::Current(offset) => self.pos.checked_add_signed(offset),
+        }
+        .filter(|&new_pos| new_pos <= len)
+        .ok_or(HeaderThenSeekError::OutOfBounds(SeekOutOfBoundsError { pos }))?;
+
+        // Can't overflow, because `new_pos <= len` which excludes the header.
+        let inner_pos = new_pos.saturating_add(Self::HEADER_LEN);
+        let inner_seek = self.inner.seek(SeekFrom::Start(inner_pos));
+        let _inner_pos: u64 = inner_seek.await.map_err(HeaderThenSeekError::Inner)?;
+        self.pos = new_pos;
+        Ok(new_pos)
+    }
+
+    #[inline]
+    async fn len(&self) -> u64
+    {
+        self.inner.len().await.saturating_sub(Self::HEADER_LEN)
+    }
+}
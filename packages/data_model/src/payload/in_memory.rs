@@ -0,0 +1,119 @@
+use {
+    super::{
+        Payload,
+        SeekFrom,
+        SeekOutOfBoundsError,
+    },
+    core::convert::Infallible,
+};
+
+
+/// A [`Payload`] over bytes that are already in memory, e.g. a `&[u8]`, a `Vec<u8>`, or a
+/// `bytes::Bytes`, without needing `std`'s `Cursor`.
+///
+/// The bytes themselves can't be the `Payload`, because they have nowhere to keep the seek
+/// position, and so this pairs them with one.  Unlike `Cursor`, seeking beyond the end is an
+/// error, as required of `Payload`s.
+#[derive(Clone, Debug)]
+pub struct InMemory<T>
+{
+    bytes: T,
+    pos:   usize,
+}
+
+impl<T: AsRef<[u8]>> InMemory<T>
+{
+    /// Make a new `Payload` whose contents are all of the given `bytes`, and whose current
+    /// position is its beginning.
+    #[must_use]
+    #[inline]
+    pub fn new(bytes: T) -> Self
+    {
+        Self { bytes, pos: 0 }
+    }
+
+    /// Return the bytes, dropping the seek position.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> T
+    {
+        self.bytes
+    }
+
+    fn len_as_u64(&self) -> u64
+    {
+        // `Payload`s are defined by Willow to be at most `u64::MAX` in size, so if `usize` is
+        // ever wider than 64-bit then it conforms to Willow to ignore the part that is greater.
+        self.bytes.as_ref().len().try_into().unwrap_or(u64::MAX)
+    }
+}
+
+impl<T: AsRef<[u8]>> From<T> for InMemory<T>
+{
+    #[inline]
+    fn from(bytes: T) -> Self
+    {
+        Self::new(bytes)
+    }
+}
+
+/// This upholds the requirement that the methods won't block, because the bytes are in memory.
+impl<T: AsRef<[u8]>> Payload for InMemory<T>
+{
+    type ReadError = Infallible;
+    type SeekError = SeekOutOfBoundsError;
+
+    #[inline]
+    async fn read(
+        &mut self,
+        buf: &mut [u8],
+    ) -> Result<usize, Self::ReadError>
+    {
+        let avail = self.bytes.as_ref().get(self.pos ..).unwrap_or_default();
+        let amount = avail.len().min(buf.len());
+        if let (Some(dest), Some(src)) = (buf.get_mut(.. amount), avail.get(.. amount)) {
+            dest.copy_from_slice(src);
+            self.pos = self.pos.saturating_add(amount);
+            Ok(amount)
+        }
+        else {
+            Ok(0) // Can't happen, because `amount` is within both.
+        }
+    }
+
+    #[inline]
+    async fn seek(
+        &mut self,
+        pos: SeekFrom,
+    ) -> Result<u64, Self::SeekError>
+    {
+        let len = self.len_as_u64();
+        let current = self.pos.try_into().unwrap_or(u64::MAX);
+        let new_pos = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => len.checked_sub(offset),
+            SeekFrom::Current(offset) => current.checked_add_signed(offset),
+        }
+        .filter(|&new_pos| new_pos <= len);
+
+        match new_pos.and_then(|new_pos| Some((new_pos, new_pos.try_into().ok()?))) {
+            Some((new_pos, new_pos_usize)) => {
+                self.pos = new_pos_usize;
+                Ok(new_pos)
+            },
+            None => Err(SeekOutOfBoundsError { pos }),
+        }
+    }
+
+    #[inline]
+    async fn len(&self) -> u64
+    {
+        self.len_as_u64()
+    }
+
+    #[inline]
+    async fn is_empty(&self) -> bool
+    {
+        self.bytes.as_ref().is_empty()
+    }
+}
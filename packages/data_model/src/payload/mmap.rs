@@ -0,0 +1,114 @@
+use {
+    super::{
+        Payload,
+        SeekFrom,
+        SeekOutOfBoundsError,
+    },
+    core::convert::Infallible,
+};
+
+
+/// A [`Payload`] over a memory-mapped file, for efficiently serving large read-only payloads
+/// without first copying them into memory.
+///
+/// This is only available with our `"mmap"` package feature.
+///
+/// The map is given already made, because making it is `unsafe` (see [`memmap2::Mmap::map`])
+/// and this crate forbids `unsafe` code.  That leaves the obligation to the caller: the mapped
+/// file must not be truncated (nor otherwise modified) while it's mapped, else accessing the map
+/// is undefined behavior.
+#[derive(Debug)]
+pub struct Mmap
+{
+    map: memmap2::Mmap,
+    pos: usize,
+}
+
+impl Mmap
+{
+    /// Make a new `Payload` whose contents are all of the given `map`, and whose current
+    /// position is its beginning.
+    #[must_use]
+    #[inline]
+    pub fn new(map: memmap2::Mmap) -> Self
+    {
+        Self { map, pos: 0 }
+    }
+
+    /// Return the map, dropping the seek position.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> memmap2::Mmap
+    {
+        self.map
+    }
+
+    fn len_as_u64(&self) -> u64
+    {
+        // A map can't be larger than `u64::MAX` on any platform that exists.
+        self.map.len().try_into().unwrap_or(u64::MAX)
+    }
+}
+
+/// This upholds the requirement that the methods won't block, because the map is accessed as
+/// memory.  (Page faults can still occur when reading, but those aren't waiting for data in the
+/// sense that `Payload` cares about.)
+impl Payload for Mmap
+{
+    type ReadError = Infallible;
+    type SeekError = SeekOutOfBoundsError;
+
+    #[inline]
+    async fn read(
+        &mut self,
+        buf: &mut [u8],
+    ) -> Result<usize, Self::ReadError>
+    {
+        let avail = self.map.get(self.pos ..).unwrap_or_default();
+        let amount = avail.len().min(buf.len());
+        if let (Some(dest), Some(src)) = (buf.get_mut(.. amount), avail.get(.. amount)) {
+            dest.copy_from_slice(src);
+            self.pos = self.pos.saturating_add(amount);
+            Ok(amount)
+        }
+        else {
+            Ok(0) // Can't happen, because `amount` is within both.
+        }
+    }
+
+    #[inline]
+    async fn seek(
+        &mut self,
+        pos: SeekFrom,
+    ) -> Result<u64, Self::SeekError>
+    {
+        let len = self.len_as_u64();
+        let current = self.pos.try_into().unwrap_or(u64::MAX);
+        let new_pos = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => len.checked_sub(offset),
+            SeekFrom::Current(offset) => current.checked_add_signed(offset),
+        }
+        .filter(|&new_pos| new_pos <= len);
+
+        match new_pos.and_then(|new_pos| Some((new_pos, new_pos.try_into().ok()?))) {
+            Some((new_pos, new_pos_usize)) => {
+                self.pos = new_pos_usize;
+                Ok(new_pos)
+            },
+            None => Err(SeekOutOfBoundsError { pos }),
+        }
+    }
+
+    #[inline]
+    async fn len(&self) -> u64
+    {
+        self.len_as_u64()
+    }
+
+    #[inline]
+    async fn is_empty(&self) -> bool
+    {
+        self.map.is_empty()
+    }
+}
@@ -0,0 +1,128 @@
+use {
+    super::compat::{
+        complete,
+        read_error,
+        seek_error,
+        seek_from,
+    },
+    crate::payload::{
+        self,
+        Payload,
+    },
+    alloc::boxed::Box,
+    core::{
+        pin::Pin,
+        task::{
+            Context,
+            Poll,
+        },
+    },
+    std::{
+        error::Error,
+        io,
+    },
+    tokio::io::{
+        AsyncRead,
+        AsyncSeek,
+        ReadBuf,
+    },
+};
+
+
+/// Adapts a [`Payload`] to be a [`tokio::io::AsyncRead`] and [`tokio::io::AsyncSeek`], for use
+/// with code that expects those.
+///
+/// This is only available with our `"tokio"` package feature.
+///
+/// Each `poll_*` of this polls the inner future only once, within the single call, which avoids
+/// needing to keep an in-progress future across calls.  So the inner `Payload`'s futures must be
+/// immediately ready, e.g. like those of [`InMemory`](payload::InMemory) and
+/// [`File`](payload::File), and it panics if one is pending.
+///
+/// Errors from the inner `Payload` become [`io::Error`]s: those from `read` with
+/// [`io::ErrorKind::Other`], and those from `seek` with [`io::ErrorKind::InvalidInput`], since
+/// the main reason that `seek` fails is seeking beyond the end or before the start (which
+/// [`std::io::Seek`] instead usually allows or reports as `InvalidInput`).  Seeking to a
+/// position beyond the end by [`io::SeekFrom::End`] is also `InvalidInput`.
+#[derive(Debug)]
+pub struct TokioCompat<P>
+{
+    inner:        P,
+    pending_seek: Option<payload::SeekFrom>,
+}
+
+impl<P> TokioCompat<P>
+{
+    /// Wrap the given `Payload`, keeping its current position.
+    #[must_use]
+    #[inline]
+    pub fn new(inner: P) -> Self
+    {
+        Self { inner, pending_seek: None }
+    }
+
+    /// Return the inner `Payload`.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> P
+    {
+        self.inner
+    }
+
+    /// Return a reference to the inner `Payload`.
+    #[must_use]
+    #[inline]
+    pub fn get_ref(&self) -> &P
+    {
+        &self.inner
+    }
+}
+
+impl<P> AsyncRead for TokioCompat<P>
+where
+    P: Payload + Unpin,
+    P::ReadError: Into<Box<dyn Error + Send + Sync>>,
+{
+    #[inline]
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>>
+    {
+        let this = self.get_mut();
+        let dest = buf.initialize_unfilled();
+        let amount = complete(this.inner.read(dest), cx).map_err(read_error)?;
+        buf.advance(amount);
+        Poll::Ready(Ok(()))
+    }
+}
+
+
+impl<P> AsyncSeek for TokioCompat<P>
+where
+    P: Payload + Unpin,
+    P::SeekError: Into<Box<dyn Error + Send + Sync>>,
+{
+    #[inline]
+    fn start_seek(
+        self: Pin<&mut Self>,
+        position: io::SeekFrom,
+    ) -> io::Result<()>
+    {
+        self.get_mut().pending_seek = Some(seek_from(position)?);
+        Ok(())
+    }
+
+    #[inline]
+    fn poll_complete(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<u64>>
+    {
+        let this = self.get_mut();
+        // When no seek was started, this must give the current position.
+        let pos = this.pending_seek.take().unwrap_or(payload::SeekFrom::Current(0));
+        Poll::Ready(complete(this.inner.seek(pos), cx).map_err(seek_error))
+    }
+}
@@ -0,0 +1,173 @@
+//! Aspects of range-based set reconciliation.
+//!
+//! Reconciling two [`Store`](crate::Store)s by ranges involves comparing a _fingerprint_ of each
+//! one's [`Entry`](crate::Entry)s in a [`ThreeDimRange`](crate::group::ThreeDimRange), without
+//! transferring the `Entry`s themselves, and so the fingerprint must be the same regardless of
+//! the order and grouping of the `Entry`s that it summarizes.  See
+//! [`Store::fingerprint_of_range`](crate::Store::fingerprint_of_range).
+
+use {
+    crate::{
+        ParamsEntry,
+        Path,
+    },
+    core::{
+        hash::{
+            Hash,
+            Hasher,
+        },
+        marker::PhantomData,
+    },
+};
+
+
+/// A monoid of fingerprints of sets of [`Entry`](crate::Entry)s.
+///
+/// Implementations must make [`Self::combine`] associative and commutative, with
+/// [`Self::neutral`] as its identity, so that the fingerprint of a set is the same however it's
+/// folded.
+pub trait Fingerprint<Params>
+where Params: crate::Params + ?Sized
+{
+    /// The type of fingerprints.
+    type Hash;
+
+    /// The fingerprint of the set of only `entry`.
+    fn lift(entry: &ParamsEntry<Params, impl Path>) -> Self::Hash;
+
+    /// The fingerprint of the union of the disjoint sets that `a` and `b` are the fingerprints
+    /// of.
+    fn combine(
+        a: &Self::Hash,
+        b: &Self::Hash,
+    ) -> Self::Hash;
+
+    /// The fingerprint of the empty set.
+    fn neutral() -> Self::Hash;
+}
+
+
+/// A [`Fingerprint`] that is the XOR of the digests of the [`Entry`](crate::Entry)s, as hashed
+/// by a `Hasher` of type `H`.
+///
+/// All of an `Entry`'s fields, except its `namespace_id` (which is the same for all the
+/// `Entry`s of a `Store`), are hashed, and so this distinguishes `Entry`s that differ in any
+/// way.  But note that XOR is weak as a fingerprint: e.g. any `Entry` included twice cancels
+/// out.  Both sides of a reconciliation must use the same type of `H` and the same
+/// [`Hash`] implementations, so `H` should be some stable and keyless (or fixed-key) algorithm.
+///
+/// So that peers on different platforms agree, every integer given to `H`, including those by
+/// the `Hash` implementations of the `subspace_id` and `payload_digest`, is given as its
+/// fixed-width big-endian bytes, with `usize`s (e.g. lengths) widened to `u64`s.  The `path` is
+/// given as its count of `Component`s and then each `Component` as its length and then its
+/// bytes.
+#[derive(Copy, Clone, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct XorDigests<H>(PhantomData<H>);
+
+impl<Params, H> Fingerprint<Params> for XorDigests<H>
+where
+    Params: crate::Params + ?Sized,
+    Params::SubspaceId: Hash,
+    Params::PayloadDigest: Hash,
+    H: Hasher + Default,
+{
+    type Hash = u64;
+
+    #[inline]
+    fn lift(entry: &ParamsEntry<Params, impl Path>) -> u64
+    {
+        let mut hasher = H::default();
+        let mut portable = BigEndian(&mut hasher);
+        entry.subspace_id.hash(&mut portable);
+        let components = entry.path.components();
+        portable.write_usize(components.len());
+        for component in components {
+            let bytes = component.bytes();
+            portable.write_usize(bytes.len());
+            portable.write(bytes);
+        }
+        portable.write_u64(entry.timestamp.μs_since_epoch);
+        entry.payload_digest.hash(&mut portable);
+        portable.write_u64(entry.payload_length);
+        hasher.finish()
+    }
+
+    #[inline]
+    fn combine(
+        a: &u64,
+        b: &u64,
+    ) -> u64
+    {
+        a ^ b
+    }
+
+    #[inline]
+    fn neutral() -> u64
+    {
+        0
+    }
+}
+
+
+/// Gives the integers written to it to the wrapped `Hasher` as their fixed-width big-endian
+/// bytes, instead of the native-endian bytes that the default methods of `Hasher` give, and
+/// widens `usize`s and `isize`s to 64 bits, so that what's hashed is the same on every platform.
+struct BigEndian<'h, H>(&'h mut H);
+
+/// Each of the `write_*` methods for integers.
+macro_rules! write_be {
+    ($($method:ident: $int:ty),* $(,)?) => { $(
+        #[inline]
+        fn $method(
+            &mut self,
+            i: $int,
+        )
+        {
+            self.0.write(&i.to_be_bytes());
+        }
+    )* };
+}
+
+#[allow(clippy::big_endian_bytes)] // Our layout is defined as big-endian.
+impl<H: Hasher> Hasher for BigEndian<'_, H>
+{
+    write_be! {
+        write_u8: u8, write_u16: u16, write_u32: u32, write_u64: u64, write_u128: u128,
+        write_i8: i8, write_i16: i16, write_i32: i32, write_i64: i64, write_i128: i128,
+    }
+
+    #[inline]
+    fn finish(&self) -> u64
+    {
+        self.0.finish()
+    }
+
+    #[inline]
+    fn write(
+        &mut self,
+        bytes: &[u8],
+    )
+    {
+        self.0.write(bytes);
+    }
+
+    #[inline]
+    fn write_usize(
+        &mut self,
+        i: usize,
+    )
+    {
+        // `usize` is never wider than 64 bits on the platforms that Rust supports.
+        self.write_u64(i.try_into().unwrap_or(u64::MAX));
+    }
+
+    #[inline]
+    fn write_isize(
+        &mut self,
+        i: isize,
+    )
+    {
+        self.write_i64(i.try_into().unwrap_or(i64::MAX));
+    }
+}
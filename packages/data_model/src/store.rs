@@ -1,13 +1,31 @@
 //! Aspects of `Store`s.
 
+#[cfg(feature = "alloc")]
+use alloc::{
+    boxed::Box,
+    collections::BinaryHeap,
+    vec::Vec,
+};
 use {
     crate::{
+        group::{
+            range::End,
+            AreaOfInterest,
+            Range,
+            ThreeDimRange,
+        },
+        path::Extra as _,
+        reconcile::Fingerprint,
         AuthorisedEntry,
+        EmptyPath,
         ParamsEntry,
         Path,
         Payload,
     },
-    core::borrow::Borrow,
+    core::{
+        borrow::Borrow,
+        cmp::Ordering,
+    },
 };
 
 
@@ -15,8 +33,6 @@ mod errors;
 pub use errors::*;
 
 
-// TODO: Make a separate impl of this that uses FS, e.g. sailce/packages/fs_store/ with tests.
-//
 /// A _store_ is a set of [`AuthorisedEntry`]s such that
 /// - all its [`Entry`](crate::Entry)s have the same [`namespace_id`](crate::Entry::namespace_id),
 ///   and
@@ -79,18 +95,420 @@ where
     {
         self.ext.iter(&self.namespace_id)
     }
+
+    /// The number of [`Entry`](crate::Entry)s in `self`.
+    ///
+    /// This counts only the current `Entry`s, i.e. not the prefix-pruned nor the overwritten,
+    /// and so it's always the same as the count of what's yielded by [`Self::iter`].
+    #[must_use]
+    #[inline]
+    pub fn count(&self) -> usize
+    {
+        self.ext.count(&self.namespace_id)
+    }
+
+    /// Whether `self` has no [`Entry`](crate::Entry)s, i.e. whether [`Self::count`] is `0`.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool
+    {
+        self.ext.is_empty(&self.namespace_id)
+    }
+
+    /// Like [`Self::iter`] but each item is a `Result`, so that a [`StoreExt`] that does
+    /// fallible I/O per [`Entry`](crate::Entry) can report a failure to read one, instead of
+    /// having to panic or to skip it.
+    ///
+    /// Whether further items are yielded after an `Err` depends on the `StoreExt`.
+    #[inline]
+    pub fn try_iter(
+        &self
+    ) -> impl Iterator<Item = Result<StoreAuthorisedEntry<Ext>, Ext::GetError>> + '_
+    {
+        self.ext.try_iter(&self.namespace_id)
+    }
+
+    /// Return an [`Iterator`] of the distinct [`SubspaceId`](crate::Params::SubspaceId)s of
+    /// `self`'s [`Entry`](crate::Entry)s, each only once, e.g. for enumerating them in a UI.
+    ///
+    /// Only the current `Entry`s are considered, i.e. not the prefix-pruned nor the overwritten,
+    /// and so a Subspace whose `Entry`s were all removed is not yielded.  The order is
+    /// unspecified.  The `SubspaceId`s are owned, because [`Self::iter`] yields owned `Entry`s.
+    #[inline]
+    pub fn subspaces(&self) -> impl Iterator<Item = Params::SubspaceId> + '_
+    {
+        self.ext.subspaces(&self.namespace_id)
+    }
+
+    /// Return an [`Iterator`] of the [`Path`]s of `self`'s [`Entry`](crate::Entry)s in the
+    /// Subspace of `subspace_id`, e.g. for a directory-like view of it.
+    ///
+    /// Each is yielded only once, because a `Store` has at most one `Entry` per location, and the
+    /// prefix-pruned and overwritten `Entry`s are not considered.  The order is unspecified.
+    #[inline]
+    pub fn paths_in_subspace<'s>(
+        &'s self,
+        subspace_id: &'s Params::SubspaceId,
+    ) -> impl Iterator<Item = Ext::IterPath> + 's
+    {
+        self.ext.paths_in_subspace(&self.namespace_id, subspace_id)
+    }
+
+    /// Return an [`Iterator`] of the `max_count` newest of `self`'s [`Entry`](crate::Entry)s,
+    /// newest first, as ordered by [`Entry::cmp_newer_than`](crate::Entry::cmp_newer_than).
+    /// The order of `Entry`s that are equally new is unspecified, and so is which of them are
+    /// yielded when not all fit within `max_count`.
+    ///
+    /// This is the _newest N_ that [`AreaOfInterest`]s are about, over the whole `Store`; for
+    /// within an [`Area`](crate::group::Area), [`Area::matching_entries`](
+    /// crate::group::Area::matching_entries) can be counted instead.
+    ///
+    /// This does a single pass over [`Self::iter`] while keeping only the newest so far in a
+    /// bounded binary heap, and so it takes `O(n log max_count)` time and `O(max_count)` memory,
+    /// for `n` `Entry`s, instead of sorting all of them.
+    ///
+    /// Only available with our `"alloc"` package feature.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn iter_newest(
+        &self,
+        max_count: usize,
+    ) -> impl Iterator<Item = StoreAuthorisedEntry<Ext>>
+    {
+        let mut newest = BinaryHeap::new();
+        for auth_entry in self.iter() {
+            if newest.len() < max_count {
+                newest.push(OldestFirst::<Ext>(auth_entry));
+                continue;
+            }
+            // Replacing the oldest, when this is newer, keeps the heap at `max_count`.
+            if let Some(mut oldest) = newest.peek_mut() {
+                if auth_entry.entry().is_newer_than(oldest.0.entry()) {
+                    *oldest = OldestFirst(auth_entry);
+                }
+            }
+        }
+        newest.into_sorted_vec().into_iter().map(|OldestFirst(auth_entry)| auth_entry)
+    }
+
+    /// Return an [`Iterator`] of the union of `self`'s and `other`'s current
+    /// [`Entry`](crate::Entry)s, as if they were [joined](async::Store::join), but without
+    /// mutating either.  This enables read-only federated queries over multiple `Store`s.
+    ///
+    /// For each location, only the newest of the two `Store`s' `Entry`s is yielded, and an
+    /// `Entry` in one that is prefix-pruned by a newer `Entry` in the other is not yielded.  When
+    /// both have the same `Entry`, only `self`'s is yielded.  If `other` is for a different
+    /// Namespace, only `self`'s `Entry`s are yielded.
+    ///
+    /// This doesn't allocate, but it rescans the other `Store` for each `Entry`, so it's only
+    /// efficient for small `Store`s or for cheap [`StoreExt::iter`]s.
+    #[inline]
+    pub fn iter_union<'s>(
+        &'s self,
+        other: &'s Self,
+    ) -> impl Iterator<Item = StoreAuthorisedEntry<Ext>> + 's
+    {
+        let same_namespace = self.namespace_id == other.namespace_id;
+        let ours = self
+            .iter()
+            .filter(move |ours| !same_namespace || !Self::is_overridden_by(ours, other, false));
+        let theirs = other
+            .iter()
+            .filter(move |theirs| same_namespace && !Self::is_overridden_by(theirs, self, true));
+        ours.chain(theirs)
+    }
+
+    /// Return an [`Iterator`] of `self`'s [`Entry`](crate::Entry)s, and their
+    /// [`AuthorisationToken`](crate::Params::AuthorisationToken)s, that are included in `range`,
+    /// i.e. in all three of its dimensions.  This is the counterpart of
+    /// [`Area::matching_entries`](crate::group::Area::matching_entries), for range-based set
+    /// reconciliation.
+    ///
+    /// The `subspaces` and `times` are checked by [`Range::includes`].  The `paths` are compared
+    /// by their [`Component`s](crate::path::Extra::cmp_components), so that `P` needn't be the
+    /// same type as the stored `Path`s, and, like every `Range`, they're half-open: the `start`
+    /// is included and a closed `end` is excluded.  E.g. `["a"] .. ["b"]` includes `["a"]` and
+    /// `["a", "z"]`, but not `["b"]` nor `["b", "a"]`.
+    ///
+    /// This filters all of [`Self::iter`], and so it's as efficient as that.
+    #[inline]
+    pub fn entries_in_range<'s, P: Path>(
+        &'s self,
+        range: &'s ThreeDimRange<Params::SubspaceId, P>,
+    ) -> impl Iterator<Item = StoreAuthorisedEntry<Ext>> + 's
+    where
+        Params::SubspaceId: Ord,
+    {
+        self.iter().filter(move |auth_entry| {
+            let entry = auth_entry.entry();
+            range.subspaces.includes(&entry.subspace_id)
+                && paths_include(&range.paths, &entry.path)
+                && range.times.includes(entry.timestamp)
+        })
+    }
+
+    /// The fingerprint, by `F`, of `self`'s [`Entry`](crate::Entry)s that are
+    /// [included](Self::entries_in_range) in `range`.  Two `Store`s whose fingerprints of the
+    /// same `range` differ have different `Entry`s in it, which range-based set reconciliation
+    /// uses to find what to transfer.
+    #[inline]
+    pub fn fingerprint_of_range<F, P>(
+        &self,
+        range: &ThreeDimRange<Params::SubspaceId, P>,
+    ) -> F::Hash
+    where
+        F: Fingerprint<Params>,
+        P: Path,
+        Params::SubspaceId: Ord,
+    {
+        self.entries_in_range(range).fold(F::neutral(), |fingerprint, auth_entry| {
+            F::combine(&fingerprint, &F::lift(auth_entry.entry()))
+        })
+    }
+
+    /// Whether joining `auth_entry` with `store` would remove it, because `store` has a newer
+    /// `Entry` at a prefix of (or at) its location, or, if `ties`, the same `Entry` at its
+    /// location.
+    fn is_overridden_by(
+        auth_entry: &StoreAuthorisedEntry<Ext>,
+        store: &Self,
+        ties: bool,
+    ) -> bool
+    {
+        let entry = auth_entry.entry();
+        store.iter().any(|other| {
+            let other = other.entry();
+            other.subspace_id == entry.subspace_id
+                && other.path.is_prefix_of(&entry.path)
+                && match other.cmp_newer_than(entry) {
+                    Ordering::Greater => true,
+                    Ordering::Equal => ties && other.path.eq_components(&entry.path),
+                    Ordering::Less => false,
+                }
+        })
+    }
+
+    /// Return the [`ChangeToken`] that represents the current state of `self`.
+    ///
+    /// This can later be given to [`Self::entries_since`] to find what changed after now.
+    ///
+    /// If the [`StoreExt`] doesn't support this, as [`Self::tracks_changes`] tells, the token
+    /// is always the same, and so it can't tell whether there were changes.
+    #[must_use]
+    #[inline]
+    pub fn change_token(&self) -> ChangeToken
+    {
+        self.ext.change_token(&self.namespace_id)
+    }
+
+    /// Whether the [`StoreExt`] supports [`Self::change_token`] and [`Self::entries_since`],
+    /// as [`StoreExt::CHANGE_TRACKING`].
+    #[must_use]
+    #[inline]
+    pub fn tracks_changes(&self) -> bool
+    {
+        Ext::CHANGE_TRACKING
+    }
+
+    /// Return an [`Iterator`] of the [`Entry`](crate::Entry)s of `self` that were mutated after
+    /// the state represented by `token`, along with the `ChangeToken` of the current state (to
+    /// give to a subsequent call).
+    ///
+    /// This enables pull-based (polling) change detection, e.g. for syncing, without needing to
+    /// rescan all of `self`, for [`StoreExt`]s that can't push events.  Only `Entry`s that are
+    /// still present are yielded, and so `Entry`s that were deleted (e.g. by prefix pruning)
+    /// after `token` are not.
+    ///
+    /// If the `StoreExt` doesn't support this, as [`Self::tracks_changes`] tells, all of
+    /// `self`'s `Entry`s are yielded regardless of `token`.
+    #[inline]
+    pub fn entries_since(
+        &self,
+        token: ChangeToken,
+    ) -> (impl Iterator<Item = StoreAuthorisedEntry<Ext>> + '_, ChangeToken)
+    {
+        self.ext.entries_since(&self.namespace_id, token)
+    }
+
+    /// Register `callback` to be called with each [`StoreEvent`] of `self`, i.e. each change to
+    /// its current [`Entry`](crate::Entry)s, e.g. for keeping a live UI up-to-date.  Multiple
+    /// callbacks can be registered, and each is called for every event.
+    ///
+    /// This is push-based change detection, which is executor-agnostic because the callbacks are
+    /// called synchronously, during the mutating operations (e.g. `put` and `join`), with each
+    /// event after the change of it was made.  For one `Entry` that was put, its
+    /// [`Put`](StoreEvent::Put) is given before the `Entry`s that it removed.  Deleting gives
+    /// [`Deleted`](StoreEvent::Deleted), and restoring to a checkpoint (including when a
+    /// [transaction](Self::transaction) fails) gives [`Reset`](StoreEvent::Reset), after which
+    /// callbacks should resync from the current `Entry`s, e.g. by [`Self::iter`].
+    ///
+    /// Returns whether the [`StoreExt`] supports this, as [`StoreExt::CHANGE_EVENTS`].  If it
+    /// doesn't, `callback` will never be called, and pull-based change detection by
+    /// [`Self::entries_since`] can be used instead.
+    ///
+    /// Only available with our `"alloc"` package feature.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn on_change(
+        &mut self,
+        callback: impl FnMut(StoreEvent<StoreAuthorisedEntry<Ext>>) + 'static,
+    ) -> bool
+    {
+        self.ext.on_change(&self.namespace_id, Box::new(callback));
+        Ext::CHANGE_EVENTS
+    }
+
+    /// The [`AreaOfInterest`] that includes all the [`Entry`](crate::Entry)s of `self`, i.e.
+    /// everything in its Namespace, e.g. for syncing everything.
+    ///
+    /// This is the same as [`AreaOfInterest::default`], which has no limits.  An
+    /// `AreaOfInterest` doesn't itself represent a Namespace, but its
+    /// [`includes`](AreaOfInterest::includes) only includes `Entry`s of the Namespace of the
+    /// `Store` given to it.  The `P` type can be any [`EmptyPath`], e.g. `Vec<Vec<u8>>`.
+    #[must_use]
+    #[inline]
+    pub fn full_area_of_interest<P>(&self) -> AreaOfInterest<Params::SubspaceId, P>
+    where P: EmptyPath
+    {
+        AreaOfInterest::default()
+    }
+}
+
+impl<Params, Ext> Store<Params::NamespaceId, Ext>
+where
+    Params: crate::Params + ?Sized,
+    Ext: CheckpointExt<Params = Params>,
+{
+    /// Save the current state of `self`, so that it can be [restored](Self::restore) later.
+    ///
+    /// Only available when the [`StoreExt`] also implements [`CheckpointExt`].
+    #[must_use]
+    #[inline]
+    pub fn checkpoint(&self) -> Ext::Snapshot
+    {
+        self.ext.checkpoint(&self.namespace_id)
+    }
+
+    /// Return `self` to the state that `snapshot` was made from by [`Self::checkpoint`], undoing
+    /// all mutations done since then.
+    ///
+    /// It is a logic error to give a `snapshot` that was made from a different `Store`.
+    ///
+    /// # Errors
+    /// If restoring fails, e.g. due to the backing storage of the [`CheckpointExt`].  What
+    /// state `self` is then in depends on the implementation, which should leave it unchanged.
+    #[inline]
+    pub fn restore(
+        &mut self,
+        snapshot: Ext::Snapshot,
+    ) -> Result<(), Ext::RestoreError>
+    {
+        self.ext.restore(&self.namespace_id, snapshot)
+    }
+
+    /// Run `f` as a transaction on `self`: if it returns `Err`, `self` is
+    /// [restored](Self::restore) to its state from before, so that none of the mutations that
+    /// `f` did remain; else they all remain.
+    ///
+    /// `f` is not `async`, and so it should use the [`sync::Store`] API for its operations.  If
+    /// `f` panics, `self` is not restored.
+    ///
+    /// # Errors
+    /// If `f` does, with its error, and also with the error of restoring if that failed too.
+    #[inline]
+    pub fn transaction<T, E>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, E>,
+    ) -> Result<T, TransactionError<E, Ext::RestoreError>>
+    {
+        let snapshot = self.checkpoint();
+        f(self).map_err(|error| match self.restore(snapshot) {
+            Ok(()) => TransactionError::Transaction(error),
+            Err(restore) => TransactionError::Restore { transaction: error, restore },
+        })
+    }
+}
+
+
+/// Orders by [`Entry::cmp_newer_than`](crate::Entry::cmp_newer_than) reversed, so that the
+/// greatest in a `BinaryHeap` is the oldest, and so that sorting puts the newest first.
+#[cfg(feature = "alloc")]
+struct OldestFirst<Ext: StoreExt>(StoreAuthorisedEntry<Ext>);
+
+#[cfg(feature = "alloc")]
+impl<Ext: StoreExt> Ord for OldestFirst<Ext>
+{
+    fn cmp(
+        &self,
+        other: &Self,
+    ) -> Ordering
+    {
+        other.0.entry().cmp_newer_than(self.0.entry())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Ext: StoreExt> PartialOrd for OldestFirst<Ext>
+{
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> Option<Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Ext: StoreExt> PartialEq for OldestFirst<Ext>
+{
+    fn eq(
+        &self,
+        other: &Self,
+    ) -> bool
+    {
+        self.cmp(other).is_eq()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Ext: StoreExt> Eq for OldestFirst<Ext> {}
+
+
+/// Like [`Range::includes`] but by comparing `Component`s, so the types of `Path` can differ.
+fn paths_include(
+    range: &Range<impl Path>,
+    path: &(impl Path + ?Sized),
+) -> bool
+{
+    range.start.cmp_components(path).is_le()
+        && match &range.end {
+            End::Closed(end) => path.cmp_components(end).is_lt(),
+            End::Open => true,
+        }
 }
 
 
 /// Aspects of `async`-API `Store`s.
 pub mod r#async
 {
+    #[cfg(feature = "alloc")]
+    use {
+        super::errors::GetRangeError,
+        crate::payload::Extra as _,
+        alloc::boxed::Box,
+        core::ops::RangeBounds,
+    };
     use {
         super::{
             errors::{
                 JoinError,
                 PutError,
             },
+            DeleteExt,
+            JoinReport,
+            StoreAuthorisedEntry,
             StoreExt,
         },
         crate::{
@@ -99,7 +517,10 @@ pub mod r#async
             Path,
             Payload,
         },
-        core::borrow::Borrow,
+        core::{
+            borrow::Borrow,
+            ptr,
+        },
     };
 
 
@@ -139,6 +560,64 @@ pub mod r#async
             path: &(impl Path + ?Sized),
         ) -> Result<Option<Ext::GetPayload>, Ext::GetError>;
 
+        /// Retrieve only the [`Entry`](crate::Entry) at a location, without its [`Payload`].
+        ///
+        /// Returns `None` the same as [`get`](Self::get) does, but, unlike `get`, this succeeds
+        /// even when the entry was [`put`](Self::put) without its payload yet.  This is useful
+        /// for deciding whether to request a payload before transferring it.
+        ///
+        /// # Errors
+        /// If retrieval fails for any reason.
+        async fn get_entry(
+            &self,
+            subspace_id: &Params::SubspaceId,
+            path: &(impl Path + ?Sized),
+        ) -> Result<Option<ParamsEntry<Params, Ext::IterPath>>, Ext::GetError>;
+
+        /// Whether there is a current [`Entry`](crate::Entry), i.e. not overwritten nor
+        /// prefix-pruned, at a location, without touching its [`Payload`].
+        ///
+        /// Unlike [`get`](Self::get), this doesn't conflate a missing payload with the absence of
+        /// the `Entry`, and, like [`get_entry`](Self::get_entry), this succeeds even when the
+        /// entry was [`put`](Self::put) without its payload yet.
+        ///
+        /// # Errors
+        /// If checking fails for any reason.
+        async fn contains(
+            &self,
+            subspace_id: &Params::SubspaceId,
+            path: &(impl Path + ?Sized),
+        ) -> Result<bool, Ext::GetError>;
+
+        /// Retrieve only a `range` of the bytes of the [`Payload`] of an [`Entry`](crate::Entry),
+        /// e.g. for range requests, into a newly-allocated boxed slice.  This avoids copying all
+        /// of a payload when only part of it is needed.
+        ///
+        /// Returns `None` if there is no such `Entry`, the same as [`get`](Self::get).
+        ///
+        /// `range` is interpreted like by [`payload::Extra::to_boxed_slice`](
+        /// crate::payload::Extra::to_boxed_slice), and, because the payload given by `get` starts
+        /// at position `0`, an `Unbounded` start is the beginning.
+        ///
+        /// # Errors
+        /// - If [`get`](Self::get) fails.
+        /// - If `range` is outside the bounds of the payload, or if copying it fails.
+        #[cfg(feature = "alloc")]
+        #[allow(clippy::type_complexity)]
+        async fn get_range(
+            &self,
+            subspace_id: &Params::SubspaceId,
+            path: &(impl Path + ?Sized),
+            range: impl RangeBounds<u64>,
+        ) -> Result<
+            Option<Box<[u8]>>,
+            GetRangeError<
+                Ext::GetError,
+                <Ext::GetPayload as Payload>::ReadError,
+                <Ext::GetPayload as Payload>::SeekError,
+            >,
+        >;
+
         /// Store an `Entry`, and its `AuthorisationToken`, in `self`, only if the `Entry` was
         /// already authorised by the `Params` of `Self`.
         ///
@@ -163,6 +642,72 @@ pub mod r#async
             payload: Option<P>,
         ) -> Result<(), PutError<Ext::PutError<P>>>;
 
+        /// Like [`put`](Self::put), but also return the `Entry` as it was stored, in the
+        /// representation of the [`StoreExt`] (e.g. with its owned [`IterPath`](
+        /// StoreExt::IterPath) type), the same as what's yielded by
+        /// [`iter`](super::Store::iter).  This avoids needing to look it up again after putting.
+        ///
+        /// The returned `Entry` is what was stored even if it's not the newest at its location
+        /// (in which case it won't be yielded by `iter`).  It's `None` when `auth_entry` wasn't
+        /// retained (e.g. when it's obsolete and the [`StoreExt`] doesn't keep history).
+        ///
+        /// # Errors
+        /// If putting fails for any reason.
+        #[allow(clippy::type_complexity)]
+        async fn put_returning<P: Payload>(
+            &mut self,
+            auth_entry: AuthorisedEntry<
+                Params,
+                impl Path,
+                impl Borrow<Params::AuthorisationToken>,
+            >,
+            payload: Option<P>,
+        ) -> Result<Option<StoreAuthorisedEntry<Ext>>, PutError<Ext::PutError<P>>>
+        where
+            Params::PayloadDigest: Clone;
+
+        /// Like [`put`](Self::put) with `Some(payload)`, but for a `payload` that might be too
+        /// large to hold in memory all at once.
+        ///
+        /// What's done differently depends on the [`StoreExt`]: implementations that can, read
+        /// `payload` in chunks and verify its digest as they write it to their backing storage,
+        /// so that a wrong digest is detected without `payload` ever being buffered entirely.
+        ///
+        /// # Errors
+        /// If putting fails for any reason, including if `payload` doesn't match the `Entry`'s
+        /// digest.
+        async fn put_streaming<P: Payload>(
+            &mut self,
+            auth_entry: AuthorisedEntry<
+                Params,
+                impl Path,
+                impl Borrow<Params::AuthorisationToken>,
+            >,
+            payload: P,
+        ) -> Result<(), PutError<Ext::PutError<P>>>;
+
+        /// Like [`put`](Self::put) with `Some(payload)`, but trust that the `payload_digest` and
+        /// `payload_length` of the `Entry` are correct for `payload`, instead of verifying that
+        /// by computing the digest.  This can avoid re-hashing large payloads, e.g. for bulk
+        /// imports or migrations from a source that already verified them.
+        ///
+        /// **Warning:** If the `Entry`'s digest or length are not correct for `payload`, `self`
+        /// will store a payload that doesn't match its `Entry`, which will then be served to
+        /// others as if it did.  Only use this for payloads from a trusted source that already
+        /// verified them.
+        ///
+        /// # Errors
+        /// If putting fails for any reason.
+        async fn put_trusted<P: Payload>(
+            &mut self,
+            auth_entry: AuthorisedEntry<
+                Params,
+                impl Path,
+                impl Borrow<Params::AuthorisationToken>,
+            >,
+            payload: P,
+        ) -> Result<(), PutError<Ext::PutError<P>>>;
+
         /// The _join_ of two [`Store`](super::Store)s that store [`Entry`](crate::Entry)s of the
         /// same `namespace_id` is the `Store` obtained as follows:
         /// - Start with the union of the two `Store`s.
@@ -186,6 +731,85 @@ pub mod r#async
             other: &Self,
         ) -> Result<(), JoinError<Ext::JoinError>>;
 
+        /// Make a fresh [`Store`](super::Store) that is the [join](Self::join) of all of
+        /// `stores`, i.e. the least `Store` that contains all of their `Entry`s, after prefix
+        /// pruning and keeping only the newest.  None of `stores` is mutated.
+        ///
+        /// This starts from a clone of the largest of `stores` and joins the others into that,
+        /// which is more efficient than joining each of them into a new empty `Store`.
+        ///
+        /// # Errors
+        /// If `stores` is empty, or if joining any of them fails for any reason, including if any
+        /// is for a different Namespace than `namespace_id`.
+        async fn join_all<'s, I>(
+            namespace_id: &Params::NamespaceId,
+            stores: I,
+        ) -> Result<Self, JoinError<Ext::JoinError>>
+        where
+            Self: Clone + 's,
+            I: IntoIterator<Item = &'s Self>,
+            I::IntoIter: Clone;
+
+        /// Join `entries`, each with its payload if available, into `self`, from any source
+        /// (e.g. a network peer, or a `Store` of a different [`StoreExt`]), instead of from
+        /// another `Store` of the same type like [`join`](Self::join) requires.
+        ///
+        /// The merge rules are the same as `join`'s: each is [`put`](Self::put), which keeps
+        /// only the newest at each location and prunes those at extensions of a newer one's
+        /// `Path`, and so the result is the same regardless of the order of `entries`.  Unlike
+        /// `join`, the payloads are verified, because they're not known to be from a `Store`.
+        ///
+        /// # Errors
+        /// If putting any of `entries` fails for any reason, with the failure of
+        /// [`StoreExt::put`] as [`JoinError::Join`], or if any is for a different Namespace, as
+        /// [`JoinError::DifferentNamespace`] like `join`.  The ones before it will have already
+        /// been joined.
+        async fn join_entries<P: Payload>(
+            &mut self,
+            entries: impl IntoIterator<
+                Item = (
+                    AuthorisedEntry<Params, impl Path, impl Borrow<Params::AuthorisationToken>>,
+                    Option<P>,
+                ),
+            >,
+        ) -> Result<(), JoinError<Ext::PutError<P>>>;
+
+        /// Like [`join`](Self::join), but also report what joining changed, by counting
+        /// `other`'s current `Entry`s by the outcome of merging each.
+        ///
+        /// An `Entry` of `other` that `self` already has is not counted.  The counts are made by
+        /// the [`StoreExt`] while it joins, and so this costs the same as `join`.
+        ///
+        /// # Errors
+        /// If joining fails for any reason.
+        async fn join_reporting(
+            &mut self,
+            other: &Self,
+        ) -> Result<JoinReport, JoinError<Ext::JoinError>>;
+
+        /// Remove the [`Entry`](crate::Entry) at the location of `subspace_id` and `path`, along
+        /// with its `Payload`.  Returns whether there was one to remove.
+        ///
+        /// This goes beyond the Willow data model, where `Entry`s only cease to be stored by
+        /// being overwritten or prefix-pruned by newer ones, and it removes only exactly what's
+        /// at the location (not what's prefixed by it).  Deleting does not resurrect anything:
+        /// the `Entry`s that the deleted one had prefix-pruned, and any older one that it had
+        /// overwritten at its location, remain removed.  An `Entry` that is already prefix-pruned
+        /// is not there to delete.  A deleted `Entry` can be put again, including by a later
+        /// [`join`](Self::join) with a `Store` that still has it.
+        ///
+        /// Only available when the [`StoreExt`] also implements [`DeleteExt`].
+        ///
+        /// # Errors
+        /// If deleting fails for any reason.
+        async fn delete(
+            &mut self,
+            subspace_id: &Params::SubspaceId,
+            path: &(impl Path + ?Sized),
+        ) -> Result<bool, Ext::DeleteError>
+        where
+            Ext: DeleteExt;
+
         /// Whether `entry` is among the `max_count` newest `Entry`s of
         /// `self`, and whether the sum of the `payload_length`s of `entry` and all
         /// [newer](crate::Entry::is_newer_than) `Entry`s in `self` is less than or equal to
@@ -198,6 +822,20 @@ pub mod r#async
             entry: impl Borrow<ParamsEntry<Params, P>>,
             max_size: Option<u64>,
         ) -> bool;
+
+        /// Return an [`Iterator`] of all the stored versions of the [`Entry`](crate::Entry) at
+        /// the location of `subspace_id` and `path`, newest first, each with its [`Payload`] if
+        /// that's available.
+        ///
+        /// This is for auditing, and so, for a [`StoreExt`] that keeps the history of
+        /// overwritten `Entry`s, the older versions are yielded even though they're no longer
+        /// part of `self` as far as the other methods are concerned.  For a `StoreExt` that
+        /// doesn't keep history, only the current `Entry` at that location, if any, is yielded.
+        async fn iter_history(
+            &self,
+            subspace_id: &Params::SubspaceId,
+            path: &(impl Path + ?Sized),
+        ) -> impl Iterator<Item = (StoreAuthorisedEntry<Ext>, Option<Ext::GetPayload>)>;
     }
 
 
@@ -226,29 +864,195 @@ pub mod r#async
         }
 
         #[inline]
-        async fn put<P: Payload>(
-            &mut self,
-            auth_entry: AuthorisedEntry<
-                Params,
-                impl Path,
-                impl Borrow<Params::AuthorisationToken>,
-            >,
-            payload: Option<P>,
-        ) -> Result<(), PutError<Ext::PutError<P>>>
+        async fn get_entry(
+            &self,
+            subspace_id: &Params::SubspaceId,
+            path: &(impl Path + ?Sized),
+        ) -> Result<Option<ParamsEntry<Params, Ext::IterPath>>, Ext::GetError>
         {
-            if self.namespace_id == auth_entry.entry().namespace_id {
-                self.ext.put(&self.namespace_id, auth_entry, payload).await.map_err(PutError::Put)
-            }
-            else {
-                Err(PutError::DifferentNamespace)
-            }
+            self.ext.get_entry(&self.namespace_id, subspace_id, path).await
         }
 
         #[inline]
-        async fn join(
-            &mut self,
+        async fn contains(
+            &self,
+            subspace_id: &Params::SubspaceId,
+            path: &(impl Path + ?Sized),
+        ) -> Result<bool, Ext::GetError>
+        {
+            self.ext.contains(&self.namespace_id, subspace_id, path).await
+        }
+
+        #[cfg(feature = "alloc")]
+        #[inline]
+        async fn get_range(
+            &self,
+            subspace_id: &Params::SubspaceId,
+            path: &(impl Path + ?Sized),
+            range: impl RangeBounds<u64>,
+        ) -> Result<
+            Option<Box<[u8]>>,
+            GetRangeError<
+                Ext::GetError,
+                <Ext::GetPayload as Payload>::ReadError,
+                <Ext::GetPayload as Payload>::SeekError,
+            >,
+        >
+        {
+            match self.get(subspace_id, path).await.map_err(GetRangeError::Get)? {
+                Some(mut payload) => payload
+                    .to_boxed_slice(range, None::<fn(&mut [u8])>, false)
+                    .await
+                    .map(Some)
+                    .map_err(GetRangeError::Range),
+                None => Ok(None),
+            }
+        }
+
+        #[inline]
+        async fn put<P: Payload>(
+            &mut self,
+            auth_entry: AuthorisedEntry<
+                Params,
+                impl Path,
+                impl Borrow<Params::AuthorisationToken>,
+            >,
+            payload: Option<P>,
+        ) -> Result<(), PutError<Ext::PutError<P>>>
+        {
+            if self.namespace_id == auth_entry.entry().namespace_id {
+                self.ext.put(&self.namespace_id, auth_entry, payload).await.map_err(PutError::Put)
+            }
+            else {
+                Err(PutError::DifferentNamespace)
+            }
+        }
+
+        #[inline]
+        async fn put_returning<P: Payload>(
+            &mut self,
+            auth_entry: AuthorisedEntry<
+                Params,
+                impl Path,
+                impl Borrow<Params::AuthorisationToken>,
+            >,
+            payload: Option<P>,
+        ) -> Result<Option<StoreAuthorisedEntry<Ext>>, PutError<Ext::PutError<P>>>
+        where
+            Params::PayloadDigest: Clone,
+        {
+            if self.namespace_id == auth_entry.entry().namespace_id {
+                self.ext
+                    .put_returning(&self.namespace_id, auth_entry, payload)
+                    .await
+                    .map_err(PutError::Put)
+            }
+            else {
+                Err(PutError::DifferentNamespace)
+            }
+        }
+
+        #[inline]
+        async fn put_streaming<P: Payload>(
+            &mut self,
+            auth_entry: AuthorisedEntry<
+                Params,
+                impl Path,
+                impl Borrow<Params::AuthorisationToken>,
+            >,
+            payload: P,
+        ) -> Result<(), PutError<Ext::PutError<P>>>
+        {
+            if self.namespace_id == auth_entry.entry().namespace_id {
+                self.ext
+                    .put_streaming(&self.namespace_id, auth_entry, payload)
+                    .await
+                    .map_err(PutError::Put)
+            }
+            else {
+                Err(PutError::DifferentNamespace)
+            }
+        }
+
+        #[inline]
+        async fn put_trusted<P: Payload>(
+            &mut self,
+            auth_entry: AuthorisedEntry<
+                Params,
+                impl Path,
+                impl Borrow<Params::AuthorisationToken>,
+            >,
+            payload: P,
+        ) -> Result<(), PutError<Ext::PutError<P>>>
+        {
+            if self.namespace_id == auth_entry.entry().namespace_id {
+                self.ext
+                    .put_trusted(&self.namespace_id, auth_entry, payload)
+                    .await
+                    .map_err(PutError::Put)
+            }
+            else {
+                Err(PutError::DifferentNamespace)
+            }
+        }
+
+        #[inline]
+        async fn join(
+            &mut self,
             other: &Self,
         ) -> Result<(), JoinError<Ext::JoinError>>
+        {
+            self.join_reporting(other).await.map(drop)
+        }
+
+        #[inline]
+        async fn join_all<'s, I>(
+            namespace_id: &Params::NamespaceId,
+            stores: I,
+        ) -> Result<Self, JoinError<Ext::JoinError>>
+        where
+            Self: Clone + 's,
+            I: IntoIterator<Item = &'s Self>,
+            I::IntoIter: Clone,
+        {
+            let stores = stores.into_iter();
+            let largest =
+                stores.clone().max_by_key(|store| store.count()).ok_or(JoinError::NoStores)?;
+            if largest.namespace_id != *namespace_id {
+                return Err(JoinError::DifferentNamespace);
+            }
+            let mut joined = largest.clone();
+            for other in stores.filter(|store| !ptr::eq(*store, largest)) {
+                joined.join(other).await?;
+            }
+            Ok(joined)
+        }
+
+        #[inline]
+        async fn join_entries<P: Payload>(
+            &mut self,
+            entries: impl IntoIterator<
+                Item = (
+                    AuthorisedEntry<Params, impl Path, impl Borrow<Params::AuthorisationToken>>,
+                    Option<P>,
+                ),
+            >,
+        ) -> Result<(), JoinError<Ext::PutError<P>>>
+        {
+            for (auth_entry, payload) in entries {
+                self.put(auth_entry, payload).await.map_err(|error| match error {
+                    PutError::DifferentNamespace => JoinError::DifferentNamespace,
+                    PutError::Put(put_error) => JoinError::Join(put_error),
+                })?;
+            }
+            Ok(())
+        }
+
+        #[inline]
+        async fn join_reporting(
+            &mut self,
+            other: &Self,
+        ) -> Result<JoinReport, JoinError<Ext::JoinError>>
         {
             if self.namespace_id == other.namespace_id {
                 self.ext
@@ -261,6 +1065,18 @@ pub mod r#async
             }
         }
 
+        #[inline]
+        async fn delete(
+            &mut self,
+            subspace_id: &Params::SubspaceId,
+            path: &(impl Path + ?Sized),
+        ) -> Result<bool, Ext::DeleteError>
+        where
+            Ext: DeleteExt,
+        {
+            self.ext.delete(&self.namespace_id, subspace_id, path).await
+        }
+
         #[inline]
         async fn newest_includes_within_total_size<P: Path>(
             &self,
@@ -280,6 +1096,16 @@ pub mod r#async
                     )
                     .await
         }
+
+        #[inline]
+        async fn iter_history(
+            &self,
+            subspace_id: &Params::SubspaceId,
+            path: &(impl Path + ?Sized),
+        ) -> impl Iterator<Item = (StoreAuthorisedEntry<Ext>, Option<Ext::GetPayload>)>
+        {
+            self.ext.iter_history(&self.namespace_id, subspace_id, path).await
+        }
     }
 }
 
@@ -315,6 +1141,40 @@ pub trait StoreExt: Sized
         path: &(impl Path + ?Sized),
     ) -> Result<Option<Self::GetPayload>, Self::GetError>;
 
+    /// See [`Store::get_entry`](async::Store::get_entry).
+    ///
+    /// The default implementation searches [`Self::iter`] for the location.  Implementations
+    /// that can look up a location directly should override this.
+    #[inline]
+    async fn get_entry(
+        &self,
+        namespace_id: &<Self::Params as crate::Params>::NamespaceId,
+        subspace_id: &<Self::Params as crate::Params>::SubspaceId,
+        path: &(impl Path + ?Sized),
+    ) -> Result<Option<ParamsEntry<Self::Params, Self::IterPath>>, Self::GetError>
+    {
+        Ok(self.iter(namespace_id).find_map(|auth_entry| {
+            let (entry, _) = auth_entry.into_parts();
+            (entry.subspace_id == *subspace_id && entry.path.eq_components(path)).then_some(entry)
+        }))
+    }
+
+    /// See [`Store::contains`](async::Store::contains).
+    ///
+    /// The default implementation is whether [`Self::get_entry`] finds one.  Implementations
+    /// that can check for a location more cheaply than retrieving its `Entry` should override
+    /// this.
+    #[inline]
+    async fn contains(
+        &self,
+        namespace_id: &<Self::Params as crate::Params>::NamespaceId,
+        subspace_id: &<Self::Params as crate::Params>::SubspaceId,
+        path: &(impl Path + ?Sized),
+    ) -> Result<bool, Self::GetError>
+    {
+        Ok(self.get_entry(namespace_id, subspace_id, path).await?.is_some())
+    }
+
     /// See [`Store::put`](async::Store::put).
     async fn put<P: Payload>(
         &mut self,
@@ -327,13 +1187,100 @@ pub trait StoreExt: Sized
         payload: Option<P>,
     ) -> Result<(), Self::PutError<P>>;
 
+    /// See [`Store::put_returning`](async::Store::put_returning).
+    ///
+    /// The default implementation does [`Self::put`] and then searches [`Self::iter_history`]
+    /// for the same version, which is why the `PayloadDigest` must be `Clone`, to keep it for
+    /// that after giving `auth_entry` to `put`.  Implementations that can make the returned
+    /// value from what they stored, without looking it up again, should override this.
+    #[inline]
+    async fn put_returning<P: Payload>(
+        &mut self,
+        namespace_id: &<Self::Params as crate::Params>::NamespaceId,
+        auth_entry: AuthorisedEntry<
+            Self::Params,
+            impl Path,
+            impl Borrow<<Self::Params as crate::Params>::AuthorisationToken>,
+        >,
+        payload: Option<P>,
+    ) -> Result<Option<StoreAuthorisedEntry<Self>>, Self::PutError<P>>
+    where
+        <Self::Params as crate::Params>::PayloadDigest: Clone,
+    {
+        self.put(namespace_id, auth_entry.as_ref(), payload).await?;
+        let given = auth_entry.entry();
+        Ok(self.iter_history(namespace_id, &given.subspace_id, &given.path).await.find_map(
+            |(version, _)| {
+                let stored = version.entry();
+                (stored.timestamp == given.timestamp
+                    && stored.payload_digest == given.payload_digest
+                    && stored.payload_length == given.payload_length)
+                    .then_some(version)
+            },
+        ))
+    }
+
+    /// See [`Store::put_streaming`](async::Store::put_streaming).
+    ///
+    /// The default implementation just calls [`Self::put`].  Implementations whose `put` buffers
+    /// the entire payload (e.g. to hash it before storing it) should override this to hash the
+    /// chunks while writing them, and to give their wrong-digest error when that's detected at
+    /// the end.
+    #[inline]
+    async fn put_streaming<P: Payload>(
+        &mut self,
+        namespace_id: &<Self::Params as crate::Params>::NamespaceId,
+        auth_entry: AuthorisedEntry<
+            Self::Params,
+            impl Path,
+            impl Borrow<<Self::Params as crate::Params>::AuthorisationToken>,
+        >,
+        payload: P,
+    ) -> Result<(), Self::PutError<P>>
+    {
+        self.put(namespace_id, auth_entry, Some(payload)).await
+    }
+
+    /// See [`Store::put_trusted`](async::Store::put_trusted).
+    ///
+    /// The default implementation doesn't take advantage of the trust and just calls
+    /// [`Self::put`], which verifies.  Implementations that can skip computing the digest should
+    /// override this.
+    #[inline]
+    async fn put_trusted<P: Payload>(
+        &mut self,
+        namespace_id: &<Self::Params as crate::Params>::NamespaceId,
+        auth_entry: AuthorisedEntry<
+            Self::Params,
+            impl Path,
+            impl Borrow<<Self::Params as crate::Params>::AuthorisationToken>,
+        >,
+        payload: P,
+    ) -> Result<(), Self::PutError<P>>
+    {
+        self.put(namespace_id, auth_entry, Some(payload)).await
+    }
+
     /// See [`Store::join`](async::Store::join).
+    ///
+    /// Only the newest `Entry` of each location of `other` needs to be merged into `self`,
+    /// because the rules of joining discard all the older ones anyway, and so implementations
+    /// should stream those from `other` without materializing its history of overwritten
+    /// versions, to merge in bounded memory and in time proportional to its amount of
+    /// locations.  Those that are prefix-pruned in `other` may be merged or skipped, because
+    /// the newer `Entry`s that prune them are also merged.  Thus, joining doesn't preserve
+    /// `other`'s overwritten versions in `self`, even for implementations that keep their own.
+    ///
+    /// Implementations must return the [`JoinReport`] of what joining changed, as described by
+    /// [`Store::join_reporting`](async::Store::join_reporting), by classifying each of
+    /// `other`'s current `Entry`s by the outcome of putting it, as they merge it.  Those that
+    /// are prefix-pruned in `other` must not be counted.
     async fn join(
         &mut self,
         namespace_id: &<Self::Params as crate::Params>::NamespaceId,
         other: &Self,
         other_namespace_id: &<Self::Params as crate::Params>::NamespaceId,
-    ) -> Result<(), Self::JoinError>;
+    ) -> Result<JoinReport, Self::JoinError>;
 
     /// See [`Store::newest_includes_within_total_size`](
     /// async::Store::newest_includes_within_total_size).
@@ -354,6 +1301,340 @@ pub trait StoreExt: Sized
         &self,
         namespace_id: &<Self::Params as crate::Params>::NamespaceId,
     ) -> impl Iterator<Item = StoreAuthorisedEntry<Self>>;
+
+    /// See [`Store::count`].
+    ///
+    /// The default implementation counts all the items of [`Self::iter`].  Implementations that
+    /// can know the count without iterating, e.g. by querying a database, should override this.
+    #[inline]
+    fn count(
+        &self,
+        namespace_id: &<Self::Params as crate::Params>::NamespaceId,
+    ) -> usize
+    {
+        self.iter(namespace_id).count()
+    }
+
+    /// See [`Store::is_empty`].
+    ///
+    /// The default implementation checks whether [`Self::iter`] yields anything, which stops at
+    /// the first item instead of counting all.
+    #[inline]
+    fn is_empty(
+        &self,
+        namespace_id: &<Self::Params as crate::Params>::NamespaceId,
+    ) -> bool
+    {
+        self.iter(namespace_id).next().is_none()
+    }
+
+    /// See [`Store::try_iter`].
+    ///
+    /// The default implementation yields all the items of [`Self::iter`] as `Ok`, which is
+    /// appropriate when iterating can't fail, e.g. for in-memory implementations.  Others should
+    /// override this to yield their failures.
+    #[inline]
+    fn try_iter(
+        &self,
+        namespace_id: &<Self::Params as crate::Params>::NamespaceId,
+    ) -> impl Iterator<Item = Result<StoreAuthorisedEntry<Self>, Self::GetError>>
+    {
+        self.iter(namespace_id).map(Ok)
+    }
+
+    /// See [`Store::subspaces`].
+    ///
+    /// The default implementation collects the distinct `SubspaceId`s from one pass of
+    /// [`Self::iter`], which takes `O(n * s)` time for `n` `Entry`s in `s` Subspaces, because
+    /// `SubspaceId`s can only be compared for equality.  Implementations that index their
+    /// `Entry`s by Subspace should override this.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn subspaces(
+        &self,
+        namespace_id: &<Self::Params as crate::Params>::NamespaceId,
+    ) -> impl Iterator<Item = <Self::Params as crate::Params>::SubspaceId>
+    {
+        let mut distinct = Vec::new();
+        for auth_entry in self.iter(namespace_id) {
+            let (entry, _) = auth_entry.into_parts();
+            if !distinct.contains(&entry.subspace_id) {
+                distinct.push(entry.subspace_id);
+            }
+        }
+        distinct.into_iter()
+    }
+
+    /// See [`Store::subspaces`].
+    ///
+    /// Without our `"alloc"` package feature, the default implementation filters
+    /// [`Self::iter`] to, for each Subspace, only its `Entry` with the least `Path`, which
+    /// rescans `Self::iter` for each `Entry`, taking `O(n^2)` time, but doesn't allocate.
+    /// Implementations that index their `Entry`s by Subspace should override this.
+    #[cfg(not(feature = "alloc"))]
+    #[inline]
+    fn subspaces(
+        &self,
+        namespace_id: &<Self::Params as crate::Params>::NamespaceId,
+    ) -> impl Iterator<Item = <Self::Params as crate::Params>::SubspaceId>
+    {
+        self.iter(namespace_id).filter_map(move |auth_entry| {
+            let (entry, _) = auth_entry.into_parts();
+            let is_least = !self.iter(namespace_id).any(|other| {
+                let other = other.entry();
+                other.subspace_id == entry.subspace_id
+                    && other.path.cmp_components(&entry.path).is_lt()
+            });
+            is_least.then_some(entry.subspace_id)
+        })
+    }
+
+    /// See [`Store::paths_in_subspace`].
+    ///
+    /// The default implementation filters [`Self::iter`] by the `subspace_id`.  Implementations
+    /// that index their `Entry`s by Subspace should override this.
+    #[inline]
+    fn paths_in_subspace(
+        &self,
+        namespace_id: &<Self::Params as crate::Params>::NamespaceId,
+        subspace_id: &<Self::Params as crate::Params>::SubspaceId,
+    ) -> impl Iterator<Item = Self::IterPath>
+    {
+        self.iter(namespace_id).filter_map(move |auth_entry| {
+            let (entry, _) = auth_entry.into_parts();
+            (entry.subspace_id == *subspace_id).then_some(entry.path)
+        })
+    }
+
+    /// See [`Store::iter_history`](async::Store::iter_history).
+    ///
+    /// Must yield the versions newest first, and each equivalent version only once.
+    ///
+    /// The default implementation yields only the current `Entry`, as searched for in
+    /// [`Self::iter`], with its `Payload` from [`Self::get`] (or `None` if that fails), which is
+    /// appropriate for implementations that don't keep history.  Those that do should override
+    /// this.
+    #[inline]
+    async fn iter_history(
+        &self,
+        namespace_id: &<Self::Params as crate::Params>::NamespaceId,
+        subspace_id: &<Self::Params as crate::Params>::SubspaceId,
+        path: &(impl Path + ?Sized),
+    ) -> impl Iterator<Item = (StoreAuthorisedEntry<Self>, Option<Self::GetPayload>)>
+    {
+        let current = self.iter(namespace_id).find(|auth_entry| {
+            let entry = auth_entry.entry();
+            entry.subspace_id == *subspace_id && entry.path.eq_components(path)
+        });
+        let payload = match current {
+            Some(_) => self.get(namespace_id, subspace_id, path).await.ok().flatten(),
+            None => None,
+        };
+        current.map(|auth_entry| (auth_entry, payload)).into_iter()
+    }
+
+    /// See [`Store::change_token`].
+    ///
+    /// Implementations must advance the returned token on each mutating operation (e.g. `put`,
+    /// `join`), so that it's monotonically increasing.
+    ///
+    /// The default implementation always returns the same token, because it can't know when
+    /// there's a change, which is only appropriate when [`Self::CHANGE_TRACKING`] is `false`.
+    /// Implementations that count their mutations should override this.
+    #[inline]
+    fn change_token(
+        &self,
+        _namespace_id: &<Self::Params as crate::Params>::NamespaceId,
+    ) -> ChangeToken
+    {
+        ChangeToken::default()
+    }
+
+    /// See [`Store::entries_since`].
+    ///
+    /// Not `async`, for the same reasons as [`Self::iter`].
+    ///
+    /// The default implementation yields all of [`Self::iter`], regardless of `token`, along with
+    /// [`Self::change_token`], because it can't know which changed.  Implementations that track
+    /// when each `Entry` was stored should override this.
+    #[inline]
+    fn entries_since(
+        &self,
+        namespace_id: &<Self::Params as crate::Params>::NamespaceId,
+        _token: ChangeToken,
+    ) -> (impl Iterator<Item = StoreAuthorisedEntry<Self>>, ChangeToken)
+    {
+        (self.iter(namespace_id), self.change_token(namespace_id))
+    }
+
+    /// Whether [`Self::change_token`] and [`Self::entries_since`] are supported, i.e. whether
+    /// the tokens tell when there are changes and which `Entry`s changed.
+    ///
+    /// The default is `false`.  Implementations that override `Self::change_token` and
+    /// `Self::entries_since` must make this `true`.
+    const CHANGE_TRACKING: bool = false;
+
+    /// Whether [`Self::on_change`] is supported, i.e. whether its callbacks are ever called.
+    ///
+    /// The default is `false`.  Implementations that override `Self::on_change` must make this
+    /// `true`.
+    const CHANGE_EVENTS: bool = false;
+
+    /// See [`Store::on_change`].
+    ///
+    /// Implementations that support this must call each registered `callback` with a
+    /// [`StoreEvent::Put`] for each `Entry` that becomes current, and with a
+    /// [`StoreEvent::Overwritten`] or [`StoreEvent::Pruned`] for each current `Entry` that a
+    /// `put` or `join` removes, as described by `Store::on_change`.  When also implementing
+    /// [`DeleteExt`], they must give [`StoreEvent::Deleted`] for each `Entry` that
+    /// [`DeleteExt::delete`] removes, and when also implementing [`CheckpointExt`], they must
+    /// give [`StoreEvent::Reset`] when [`CheckpointExt::restore`] succeeds.
+    ///
+    /// The default implementation drops `callback` without ever calling it, which is only
+    /// appropriate when [`Self::CHANGE_EVENTS`] is `false`.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn on_change(
+        &mut self,
+        _namespace_id: &<Self::Params as crate::Params>::NamespaceId,
+        _callback: Box<dyn FnMut(StoreEvent<StoreAuthorisedEntry<Self>>)>,
+    )
+    {
+    }
+}
+
+
+/// An optional extension of [`StoreExt`], for [`Store::checkpoint`], [`Store::restore`], and
+/// [`Store::transaction`], which are only available when this is also implemented.
+///
+/// In-memory implementations can simply use a clone of their state as the `Snapshot`.  Others
+/// may implement this with copy-on-write, e.g. by sharing the unchanged parts between `self`
+/// and its `Snapshot`s, or by keeping a log of the mutations to undo.
+pub trait CheckpointExt: StoreExt
+{
+    /// A saved state, made by [`checkpoint`](Self::checkpoint) and given to
+    /// [`restore`](Self::restore).
+    type Snapshot;
+    /// Error(s) possibly returned by [`restore`](Self::restore).
+    type RestoreError;
+
+    /// See [`Store::checkpoint`].
+    fn checkpoint(
+        &self,
+        namespace_id: &<Self::Params as crate::Params>::NamespaceId,
+    ) -> Self::Snapshot;
+
+    /// See [`Store::restore`].
+    ///
+    /// This is a mutating operation, so it must advance the [`ChangeToken`] like the others
+    /// (instead of reverting it to what it was at the `checkpoint`).
+    ///
+    /// # Errors
+    /// If restoring fails, in which case `self` should be left unchanged.
+    fn restore(
+        &mut self,
+        namespace_id: &<Self::Params as crate::Params>::NamespaceId,
+        snapshot: Self::Snapshot,
+    ) -> Result<(), Self::RestoreError>;
+}
+
+
+/// An optional extension of [`StoreExt`], for [`Store::delete`](async::Store::delete), which is
+/// only available when this is also implemented.
+///
+/// This is separate because deleting goes beyond the Willow data model, and so not all
+/// implementations support it.
+#[allow(async_fn_in_trait)] // TODO: Re-evaluate `async_fn_in_trait`.
+pub trait DeleteExt: StoreExt
+{
+    /// Error(s) possibly returned by [`delete`](Self::delete).
+    type DeleteError;
+
+    /// See [`Store::delete`](async::Store::delete).
+    ///
+    /// Implementations that keep the history of overwritten `Entry`s must not let an older
+    /// version become the current `Entry` at the location, and those that keep prefix-pruned
+    /// `Entry`s must not let the ones pruned by the deleted `Entry` become current again.
+    async fn delete(
+        &mut self,
+        namespace_id: &<Self::Params as crate::Params>::NamespaceId,
+        subspace_id: &<Self::Params as crate::Params>::SubspaceId,
+        path: &(impl Path + ?Sized),
+    ) -> Result<bool, Self::DeleteError>;
+}
+
+
+/// An opaque counter of the mutations of a [`Store`], for pull-based change detection.  See
+/// [`Store::change_token`] and [`Store::entries_since`].
+///
+/// Greater values represent later states of the same `Store`.  Values from different `Store`s
+/// are not comparable in any meaningful way.
+///
+/// Users should treat this as opaque.  Its constructor and accessor are for implementors of
+/// [`StoreExt`].
+#[derive(Default, Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+pub struct ChangeToken(u64);
+
+impl ChangeToken
+{
+    /// Make a `ChangeToken` from an implementation's internal count of mutations.
+    #[must_use]
+    #[inline]
+    pub fn new(count: u64) -> Self
+    {
+        Self(count)
+    }
+
+    /// The internal count of mutations that `self` was made from.
+    #[must_use]
+    #[inline]
+    pub fn get(self) -> u64
+    {
+        self.0
+    }
+}
+
+
+/// What [`join_reporting`](async::Store::join_reporting) changed, as counts of the other
+/// `Store`'s [`Entry`](crate::Entry)s by their outcome.
+#[derive(Default, Copy, Clone, Eq, Hash, PartialEq, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct JoinReport
+{
+    /// The amount that were added, because they were newer than, or at different locations
+    /// than, all of the `Store`'s.
+    pub inserted:   usize,
+    /// The amount of the `inserted` that removed any of the `Store`'s existing `Entry`s, by
+    /// overwriting or prefix-pruning them.
+    pub pruned:     usize,
+    /// The amount that were dropped, because the `Store` has a newer `Entry` at a prefix of (or
+    /// at) their locations.
+    pub superseded: usize,
+}
+
+
+/// A change to the current [`Entry`](crate::Entry)s of a [`Store`], as given to the callbacks
+/// registered by [`Store::on_change`].  Each has the `Entry`, with its
+/// [`AuthorisationToken`](crate::Params::AuthorisationToken), that changed, as a
+/// [`StoreAuthorisedEntry`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[allow(clippy::exhaustive_enums)]
+pub enum StoreEvent<AuthEntry>
+{
+    /// The `Entry` became current, by being put or joined.  This isn't given again for the same
+    /// `Entry` when its `Payload` is supplied later.
+    Put(AuthEntry),
+    /// The `Entry` was removed by prefix pruning, because a newer one was put at a strict prefix
+    /// of its location.
+    Pruned(AuthEntry),
+    /// The `Entry` was removed because a newer one was put at its location.
+    Overwritten(AuthEntry),
+    /// The `Entry` was removed by [`Store::delete`](async::Store::delete).
+    Deleted(AuthEntry),
+    /// All of the current `Entry`s might have changed, by [`Store::restore`], and so they should
+    /// be gotten again, e.g. by [`Store::iter`].  No other events are given for the changes
+    /// that this represents.
+    Reset,
 }
 
 
@@ -368,6 +1649,12 @@ pub type StoreAuthorisedEntry<Ext> = AuthorisedEntry<
 /// Aspects of synchronous-API `Store`s.
 pub mod sync
 {
+    #[cfg(feature = "alloc")]
+    use {
+        super::errors::GetRangeError,
+        alloc::boxed::Box,
+        core::ops::RangeBounds,
+    };
     use {
         super::{
             r#async,
@@ -375,6 +1662,9 @@ pub mod sync
                 JoinError,
                 PutError,
             },
+            DeleteExt,
+            JoinReport,
+            StoreAuthorisedEntry,
             StoreExt,
         },
         crate::{
@@ -410,6 +1700,52 @@ pub mod sync
             block_on(r#async::Store::get(self, subspace_id, path), data)
         }
 
+        /// Like [`async::Store::get_entry`] but synchronous.  Might block.
+        #[inline]
+        fn get_entry(
+            &self,
+            subspace_id: &Params::SubspaceId,
+            path: &(impl Path + ?Sized),
+        ) -> Result<Option<ParamsEntry<Params, Ext::IterPath>>, Ext::GetError>
+        {
+            let (block_on, data) = get_block_on_and_data!(self);
+            block_on(r#async::Store::get_entry(self, subspace_id, path), data)
+        }
+
+        /// Like [`async::Store::contains`] but synchronous.  Might block.
+        #[inline]
+        fn contains(
+            &self,
+            subspace_id: &Params::SubspaceId,
+            path: &(impl Path + ?Sized),
+        ) -> Result<bool, Ext::GetError>
+        {
+            let (block_on, data) = get_block_on_and_data!(self);
+            block_on(r#async::Store::contains(self, subspace_id, path), data)
+        }
+
+        /// Like [`async::Store::get_range`] but synchronous.  Might block.
+        #[cfg(feature = "alloc")]
+        #[inline]
+        #[allow(clippy::type_complexity)]
+        fn get_range(
+            &self,
+            subspace_id: &Params::SubspaceId,
+            path: &(impl Path + ?Sized),
+            range: impl RangeBounds<u64>,
+        ) -> Result<
+            Option<Box<[u8]>>,
+            GetRangeError<
+                Ext::GetError,
+                <Ext::GetPayload as Payload>::ReadError,
+                <Ext::GetPayload as Payload>::SeekError,
+            >,
+        >
+        {
+            let (block_on, data) = get_block_on_and_data!(self);
+            block_on(r#async::Store::get_range(self, subspace_id, path, range), data)
+        }
+
         /// Like [`async::Store::put`] but synchronous.  Might block.
         #[inline]
         fn put<P: Payload>(
@@ -426,6 +1762,57 @@ pub mod sync
             block_on(r#async::Store::put(self, auth_entry, payload), data)
         }
 
+        /// Like [`async::Store::put_returning`] but synchronous.  Might block.
+        #[inline]
+        #[allow(clippy::type_complexity)]
+        fn put_returning<P: Payload>(
+            &mut self,
+            auth_entry: AuthorisedEntry<
+                Params,
+                impl Path,
+                impl Borrow<Params::AuthorisationToken>,
+            >,
+            payload: Option<P>,
+        ) -> Result<Option<StoreAuthorisedEntry<Ext>>, PutError<Ext::PutError<P>>>
+        where
+            Params::PayloadDigest: Clone,
+        {
+            let (block_on, data) = get_block_on_and_data!(self);
+            block_on(r#async::Store::put_returning(self, auth_entry, payload), data)
+        }
+
+        /// Like [`async::Store::put_streaming`] but synchronous.  Might block.
+        #[inline]
+        fn put_streaming<P: Payload>(
+            &mut self,
+            auth_entry: AuthorisedEntry<
+                Params,
+                impl Path,
+                impl Borrow<Params::AuthorisationToken>,
+            >,
+            payload: P,
+        ) -> Result<(), PutError<Ext::PutError<P>>>
+        {
+            let (block_on, data) = get_block_on_and_data!(self);
+            block_on(r#async::Store::put_streaming(self, auth_entry, payload), data)
+        }
+
+        /// Like [`async::Store::put_trusted`] but synchronous.  Might block.
+        #[inline]
+        fn put_trusted<P: Payload>(
+            &mut self,
+            auth_entry: AuthorisedEntry<
+                Params,
+                impl Path,
+                impl Borrow<Params::AuthorisationToken>,
+            >,
+            payload: P,
+        ) -> Result<(), PutError<Ext::PutError<P>>>
+        {
+            let (block_on, data) = get_block_on_and_data!(self);
+            block_on(r#async::Store::put_trusted(self, auth_entry, payload), data)
+        }
+
         /// Like [`async::Store::join`] but synchronous.  Might block.
         #[inline]
         fn join(
@@ -437,6 +1824,64 @@ pub mod sync
             block_on(r#async::Store::join(self, other), data)
         }
 
+        /// Like [`async::Store::join_all`] but synchronous.  Might block.
+        #[inline]
+        fn join_all<'s, I>(
+            namespace_id: &Params::NamespaceId,
+            stores: I,
+        ) -> Result<Self, JoinError<Ext::JoinError>>
+        where
+            Self: Clone + 's,
+            I: IntoIterator<Item = &'s Self>,
+            I::IntoIter: Clone,
+        {
+            let stores = stores.into_iter();
+            let first = stores.clone().next().ok_or(JoinError::NoStores)?;
+            let (block_on, data) = get_block_on_and_data!(first);
+            block_on(<Self as r#async::Store<Params, Ext>>::join_all(namespace_id, stores), data)
+        }
+
+        /// Like [`async::Store::join_entries`] but synchronous.  Might block.
+        #[inline]
+        fn join_entries<P: Payload>(
+            &mut self,
+            entries: impl IntoIterator<
+                Item = (
+                    AuthorisedEntry<Params, impl Path, impl Borrow<Params::AuthorisationToken>>,
+                    Option<P>,
+                ),
+            >,
+        ) -> Result<(), JoinError<Ext::PutError<P>>>
+        {
+            let (block_on, data) = get_block_on_and_data!(self);
+            block_on(r#async::Store::join_entries(self, entries), data)
+        }
+
+        /// Like [`async::Store::join_reporting`] but synchronous.  Might block.
+        #[inline]
+        fn join_reporting(
+            &mut self,
+            other: &Self,
+        ) -> Result<JoinReport, JoinError<Ext::JoinError>>
+        {
+            let (block_on, data) = get_block_on_and_data!(self);
+            block_on(r#async::Store::join_reporting(self, other), data)
+        }
+
+        /// Like [`async::Store::delete`] but synchronous.  Might block.
+        #[inline]
+        fn delete(
+            &mut self,
+            subspace_id: &Params::SubspaceId,
+            path: &(impl Path + ?Sized),
+        ) -> Result<bool, Ext::DeleteError>
+        where
+            Ext: DeleteExt,
+        {
+            let (block_on, data) = get_block_on_and_data!(self);
+            block_on(r#async::Store::delete(self, subspace_id, path), data)
+        }
+
         /// Like [`async::Store::newest_includes_within_total_size`] but synchronous.  Might
         /// block.
         #[inline]
@@ -455,5 +1900,17 @@ pub mod sync
                 data,
             )
         }
+
+        /// Like [`async::Store::iter_history`] but synchronous.  Might block.
+        #[inline]
+        fn iter_history(
+            &self,
+            subspace_id: &Params::SubspaceId,
+            path: &(impl Path + ?Sized),
+        ) -> impl Iterator<Item = (StoreAuthorisedEntry<Ext>, Option<Ext::GetPayload>)>
+        {
+            let (block_on, data) = get_block_on_and_data!(self);
+            block_on(r#async::Store::iter_history(self, subspace_id, path), data)
+        }
     }
 }
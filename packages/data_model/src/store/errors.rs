@@ -32,15 +32,22 @@ impl<E> Display for PutError<E>
 }
 
 
-/// Errors possibly returned by [`Store::join`](crate::store::async::Store::join).
+/// Errors possibly returned by [`Store::join`](crate::store::async::Store::join),
+/// [`Store::join_all`](crate::store::async::Store::join_all), and
+/// [`Store::join_entries`](crate::store::async::Store::join_entries).
 #[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
 #[allow(clippy::exhaustive_enums)]
 pub enum JoinError<E>
 {
-    /// The `other` argument is not for the same Namespace.
+    /// The `other` argument, or an `Entry` of the `entries` argument, is not for the same
+    /// Namespace.
     DifferentNamespace,
-    /// Failure of [`StoreExt::join`](crate::StoreExt::join)
+    /// Failure of [`StoreExt::join`](crate::StoreExt::join), or of
+    /// [`StoreExt::put`](crate::StoreExt::put) for `join_entries`.
     Join(E),
+    /// The `stores` argument of `join_all` is empty, and so there is nothing to make the joined
+    /// `Store` from.
+    NoStores,
 }
 
 impl<E> Display for JoinError<E>
@@ -54,17 +61,98 @@ impl<E> Display for JoinError<E>
         write!(f, "`Store::join()` failed due to {}", match self {
             JoinError::DifferentNamespace => "different namespace",
             JoinError::Join(_) => "`StoreExt::join()`",
+            JoinError::NoStores => "no stores",
         })
     }
 }
 
 
+/// Errors possibly returned by [`Store::transaction`](crate::Store::transaction).
+#[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[allow(clippy::exhaustive_enums)]
+pub enum TransactionError<E, R>
+{
+    /// The transaction failed, and the `Store` was restored.
+    Transaction(E),
+    /// The transaction failed, and then restoring the `Store` also failed.
+    Restore
+    {
+        /// The failure of the transaction.
+        transaction: E,
+        /// Failure of [`CheckpointExt::restore`](crate::store::CheckpointExt::restore).
+        restore:     R,
+    },
+}
+
+impl<E, R> Display for TransactionError<E, R>
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        write!(f, "`Store::transaction()` failed due to {}", match self {
+            TransactionError::Transaction(_) => "the transaction",
+            TransactionError::Restore { .. } => "the transaction and `CheckpointExt::restore()`",
+        })
+    }
+}
+
+
+#[cfg(feature = "alloc")]
+pub use alloc::*;
+
+#[cfg(feature = "alloc")]
+mod alloc
+{
+    use {
+        crate::payload::ToBoxedSliceError,
+        core::fmt::{
+            self,
+            Display,
+            Formatter,
+        },
+    };
+
+    /// Errors possibly returned by [`Store::get_range`](crate::store::async::Store::get_range).
+    #[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+    #[allow(clippy::exhaustive_enums)]
+    pub enum GetRangeError<GetError, ReadError, SeekError>
+    {
+        /// Failure of [`StoreExt::get`](crate::StoreExt::get).
+        Get(GetError),
+        /// Failure of [`payload::Extra::to_boxed_slice`](crate::payload::Extra::to_boxed_slice),
+        /// including when the `range` argument is out-of-bounds of the payload.
+        Range(ToBoxedSliceError<ReadError, SeekError>),
+    }
+
+    impl<G, R, S> Display for GetRangeError<G, R, S>
+    {
+        #[inline]
+        fn fmt(
+            &self,
+            f: &mut Formatter<'_>,
+        ) -> fmt::Result
+        {
+            write!(f, "`Store::get_range()` failed due to {}", match self {
+                GetRangeError::Get(_) => "`StoreExt::get()`",
+                GetRangeError::Range(_) => "`payload::Extra::to_boxed_slice()`",
+            })
+        }
+    }
+}
+
+
 #[cfg(any(feature = "std", feature = "anticipate", rust_lib_feature = "error_in_core"))]
 mod standard_error
 {
+    #[cfg(feature = "alloc")]
+    use super::GetRangeError;
     use super::{
         JoinError,
         PutError,
+        TransactionError,
     };
 
     cfg_if::cfg_if! { if #[cfg(any(feature = "anticipate", rust_lib_feature = "error_in_core"))]
@@ -96,9 +184,41 @@ mod standard_error
         fn source(&self) -> Option<&(dyn Error + 'static)>
         {
             match self {
-                JoinError::DifferentNamespace => None,
+                JoinError::DifferentNamespace | JoinError::NoStores => None,
                 JoinError::Join(join_error) => Some(join_error),
             }
         }
     }
+
+    impl<E, R> Error for TransactionError<E, R>
+    where
+        E: Error + 'static,
+        R: Error + 'static,
+    {
+        #[inline]
+        fn source(&self) -> Option<&(dyn Error + 'static)>
+        {
+            match self {
+                TransactionError::Transaction(transaction_error) => Some(transaction_error),
+                TransactionError::Restore { restore, .. } => Some(restore),
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<G, R, S> Error for GetRangeError<G, R, S>
+    where
+        G: Error + 'static,
+        R: Error + 'static,
+        S: Error + 'static,
+    {
+        #[inline]
+        fn source(&self) -> Option<&(dyn Error + 'static)>
+        {
+            match self {
+                GetRangeError::Get(get_error) => Some(get_error),
+                GetRangeError::Range(range_error) => Some(range_error),
+            }
+        }
+    }
 }
@@ -2,7 +2,32 @@
 
 #![macro_use] // Make this module's macro visible to all following modules.
 
-use core::future::Future;
+#[cfg(feature = "std")]
+use {
+    alloc::{
+        sync::Arc,
+        task::Wake,
+    },
+    std::thread::{
+        self,
+        Thread,
+    },
+};
+#[cfg(all(feature = "anticipate", not(feature = "std")))]
+use core::{
+    hint,
+    task::Waker,
+};
+use {
+    core::{
+        future::Future,
+        pin::pin,
+        task::{
+            Context,
+            Poll,
+        },
+    },
+};
 
 
 /// Helps a sub-trait be an adaptor trait that has synchronous (i.e. not `async`) wrappers of the
@@ -19,7 +44,7 @@ use core::future::Future;
 /// Examples of executors which can be used with this include:
 /// [`futures::executor::block_on`](
 /// https://docs.rs/futures/latest/futures/executor/fn.block_on.html), [`pollster`](
-/// https://docs.rs/pollster/latest/pollster/), etc.
+/// https://docs.rs/pollster/latest/pollster/), our own [`block_on`] (via [`DefaultSync`]), etc.
 pub trait Syncify<Executor>
 where Executor: ?Sized
 {
@@ -51,3 +76,113 @@ macro_rules! get_block_on_and_data {
         ($self.get_block_on_fn(), $self.get_executor_data())
     };
 }
+
+
+/// Run `future` to completion on the current thread, without `async` runtime.
+///
+/// With our `"std"` package feature, the thread is parked while `future` is pending, until its
+/// [`Waker`](core::task::Waker) is woken, and so a pending future doesn't consume a CPU core
+/// while waiting.  But one that can only progress by being driven by something else on this
+/// same thread (e.g. a reactor of an `async` runtime) will never complete.
+///
+/// Without `"std"`, e.g. for bare-metal, this is only available with our `"anticipate"` package
+/// feature (because it needs `Waker::noop`, of Rust 1.85), and it doesn't allocate.  Futures
+/// that are ready on their first poll, as many of this crate's `async` methods are, complete
+/// immediately.  Otherwise, there's nothing to park on, and so this busy-waits: the `Waker` given
+/// to the future does nothing, and the future is polled again after each
+/// [`spin_loop`](core::hint::spin_loop), up to a bound.
+///
+/// # Panics
+/// Without `"std"`, if `future` is still pending after 2<sup>24</sup> polls, instead of spinning
+/// forever on a future that's waiting on something that this can't drive.
+#[cfg(feature = "std")]
+#[inline]
+pub fn block_on<F: Future>(future: F) -> F::Output
+{
+    std::thread_local! {
+        static WAKER: core::task::Waker = Arc::new(Unparker(thread::current())).into();
+    }
+
+    let mut future = pin!(future);
+    WAKER.with(|waker| {
+        let mut cx = Context::from_waker(waker);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                break output;
+            }
+            thread::park(); // Spurious unparks are fine: it's just polled again.
+        }
+    })
+}
+
+/// Wakes by unparking the thread that's blocked in [`block_on`].
+#[cfg(feature = "std")]
+struct Unparker(Thread);
+
+#[cfg(feature = "std")]
+impl Wake for Unparker
+{
+    #[inline]
+    fn wake(self: Arc<Self>)
+    {
+        self.0.unpark();
+    }
+
+    #[inline]
+    fn wake_by_ref(self: &Arc<Self>)
+    {
+        self.0.unpark();
+    }
+}
+
+/// See the `std` version.
+#[cfg(all(feature = "anticipate", not(feature = "std")))]
+#[allow(clippy::incompatible_msrv)] // `Waker::noop` is why this needs `"anticipate"`.
+#[inline]
+pub fn block_on<F: Future>(future: F) -> F::Output
+{
+    const MAX_POLLS: u32 = 1 << 24;
+
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    for _ in 0 .. MAX_POLLS {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        hint::spin_loop();
+    }
+    #[allow(clippy::panic)] // Documented.
+    {
+        panic!("future still pending after {MAX_POLLS} polls");
+    }
+}
+
+
+/// Represents using our [`block_on`] as the `Executor` for [`Syncify`].
+///
+/// `Syncify<DefaultSync>` is implemented for all types, so the `sync::` adaptor traits can be
+/// used without providing an executor, by just opting-in like:
+/// `impl payload::sync::Payload<DefaultSync> for MyPayload {}`.  (Those aren't implemented
+/// for all types automatically, because that would make calls ambiguous for types that also
+/// implement them for another executor.)  The caveats of `block_on` apply, and this is only
+/// available when it is.
+#[cfg(any(feature = "std", feature = "anticipate"))]
+#[derive(Copy, Clone, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct DefaultSync;
+
+#[cfg(any(feature = "std", feature = "anticipate"))]
+impl<T: ?Sized> Syncify<DefaultSync> for T
+{
+    type ExecutorData = ();
+
+    #[inline]
+    fn get_block_on_fn<'f, F>(&self) -> impl 'f + FnOnce(F, Self::ExecutorData) -> F::Output
+    where F: Future + 'f
+    {
+        |future, ()| block_on(future)
+    }
+
+    #[inline]
+    fn get_executor_data(&self) -> Self::ExecutorData {}
+}
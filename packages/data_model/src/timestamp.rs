@@ -0,0 +1,242 @@
+use core::{
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+    time::Duration,
+};
+#[cfg(feature = "std")]
+use std::time::SystemTime;
+
+
+/// A time in microseconds since the [Unix epoch](https://en.wikipedia.org/wiki/Unix_epoch).
+///
+/// With the `serde` package-feature, this is (de)serialized as just its `u64`.
+#[derive(Default, Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+#[allow(clippy::exhaustive_structs)]
+pub struct Timestamp
+{
+    /// The microseconds since the Unix epoch.
+    pub μs_since_epoch: u64,
+}
+
+impl From<u64> for Timestamp
+{
+    #[inline]
+    fn from(value: u64) -> Self
+    {
+        Self { μs_since_epoch: value }
+    }
+}
+
+impl Timestamp
+{
+    /// Make a `Timestamp` from milliseconds since the Unix epoch.
+    ///
+    /// Saturates at [`u64::MAX`] microseconds, if `millis` is greater than that can represent.
+    #[must_use]
+    #[inline]
+    pub fn from_millis(millis: u64) -> Self
+    {
+        millis.saturating_mul(1_000).into()
+    }
+
+    /// The milliseconds since the Unix epoch.  Truncates any sub-millisecond part.
+    #[must_use]
+    #[inline]
+    pub fn to_millis(self) -> u64
+    {
+        Self::μs_as_millis(self.μs_since_epoch)
+    }
+
+    /// Make a `Timestamp` from a [`Duration`] since the Unix epoch.  Truncates any
+    /// sub-microsecond part.
+    ///
+    /// # Errors
+    /// If `duration` is greater than [`u64::MAX`] microseconds.
+    #[inline]
+    pub fn from_duration_since_epoch(duration: Duration) -> Result<Self, TimestampOverflowError>
+    {
+        u64::try_from(duration.as_micros()).ok().map(Self::from).ok_or(TimestampOverflowError)
+    }
+
+    /// The [`Duration`] since the Unix epoch.  This is always exact, because `Duration` can
+    /// represent all `Timestamp`s.
+    #[must_use]
+    #[inline]
+    pub fn to_duration_since_epoch(self) -> Duration
+    {
+        Duration::from_micros(self.μs_since_epoch)
+    }
+
+    /// The current time of the system clock, as by [`SystemTime::now`].
+    ///
+    /// Saturates at the Unix epoch, if the clock is set before that, and at [`u64::MAX`]
+    /// microseconds (about the year 584,542 AD), if after that.
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    pub fn now() -> Self
+    {
+        Self::try_from(SystemTime::now()).unwrap_or_default()
+    }
+
+    /// `self` plus `micros` microseconds.  Saturates at [`u64::MAX`] microseconds.
+    #[must_use]
+    #[inline]
+    pub fn saturating_add_micros(
+        self,
+        micros: u64,
+    ) -> Self
+    {
+        self.μs_since_epoch.saturating_add(micros).into()
+    }
+
+    /// `self` minus `micros` microseconds, or `None` if that would be before the Unix epoch.
+    #[must_use]
+    #[inline]
+    pub fn checked_sub(
+        self,
+        micros: u64,
+    ) -> Option<Self>
+    {
+        self.μs_since_epoch.checked_sub(micros).map(Self::from)
+    }
+
+    /// Truncates, as intended.
+    #[allow(clippy::integer_division, clippy::integer_division_remainder_used)]
+    const fn μs_as_millis(μs: u64) -> u64
+    {
+        μs / 1_000
+    }
+}
+
+
+/// Convert from the system's representation of time.  Truncates any sub-microsecond part, and
+/// saturates at [`u64::MAX`] microseconds (about the year 584,542 AD).
+///
+/// # Errors
+/// If `time` is before the Unix epoch, which a `Timestamp` can't represent.
+#[cfg(feature = "std")]
+impl TryFrom<SystemTime> for Timestamp
+{
+    type Error = TimestampBeforeEpochError;
+
+    #[inline]
+    fn try_from(time: SystemTime) -> Result<Self, Self::Error>
+    {
+        let duration = time.duration_since(SystemTime::UNIX_EPOCH).ok();
+        let duration = duration.ok_or(TimestampBeforeEpochError)?;
+        Ok(u64::try_from(duration.as_micros()).unwrap_or(u64::MAX).into())
+    }
+}
+
+/// Convert to the system's representation of time.
+///
+/// # Errors
+/// If the system can't represent `timestamp`.  Some platforms' `SystemTime` can't represent as
+/// far after the Unix epoch as a `Timestamp` can.
+#[cfg(feature = "std")]
+impl TryFrom<Timestamp> for SystemTime
+{
+    type Error = SystemTimeOverflowError;
+
+    #[inline]
+    fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error>
+    {
+        SystemTime::UNIX_EPOCH
+            .checked_add(timestamp.to_duration_since_epoch())
+            .ok_or(SystemTimeOverflowError)
+    }
+}
+
+
+/// Error returned when a time is too far after the Unix epoch to be represented as a
+/// [`Timestamp`], i.e. when it's greater than [`u64::MAX`] microseconds (about 584,542 years).
+#[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct TimestampOverflowError;
+
+impl Display for TimestampOverflowError
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        write!(f, "time is greater than `u64::MAX` microseconds since the epoch")
+    }
+}
+
+
+/// Error returned when a time is before the Unix epoch, and so can't be represented as a
+/// [`Timestamp`].
+#[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct TimestampBeforeEpochError;
+
+impl Display for TimestampBeforeEpochError
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        write!(f, "time is before the epoch")
+    }
+}
+
+
+/// Error returned when a [`Timestamp`] is too far after the Unix epoch to be represented as a
+/// [`SystemTime`] of the platform.
+#[cfg(feature = "std")]
+#[derive(Copy, Clone, Eq, Ord, Hash, PartialEq, PartialOrd, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct SystemTimeOverflowError;
+
+#[cfg(feature = "std")]
+impl Display for SystemTimeOverflowError
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        write!(f, "time is too far after the epoch for the system")
+    }
+}
+
+
+#[cfg(any(feature = "std", feature = "anticipate", rust_lib_feature = "error_in_core"))]
+mod standard_error
+{
+    use super::{
+        TimestampBeforeEpochError,
+        TimestampOverflowError,
+    };
+
+    cfg_if::cfg_if! { if #[cfg(any(feature = "anticipate", rust_lib_feature = "error_in_core"))]
+    {
+        use core::error::Error;
+    }
+    else if #[cfg(feature = "std")]
+    {
+        use std::error::Error;
+    } }
+
+    impl Error for TimestampOverflowError {}
+
+    impl Error for TimestampBeforeEpochError {}
+
+    #[cfg(feature = "std")]
+    impl Error for super::SystemTimeOverflowError {}
+}
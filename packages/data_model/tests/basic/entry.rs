@@ -97,4 +97,33 @@ fn ordering()
 }
 
 
+#[test]
+fn with()
+{
+    assert_eq!(
+        E1.with_timestamp(Timestamp { μs_since_epoch: 11111 }),
+        e(1, 2, &["3"], 11111, [5; 8], 6)
+    );
+    assert_eq!(
+        E2.with_payload_meta([5; 8], 6),
+        e(42, 321, &["fooba", "rzab", "oof"], 11111, [5; 8], 6)
+    );
+    let moved = E1.with_path(vec![b"fooba".to_vec()]); // Different type is fine.
+    assert_eq!(moved.path, [b"fooba"]);
+    assert_eq!(moved.with_path(&["3"][..]), E1);
+    assert_eq!(
+        E0.with_path(E2.path)
+            .with_timestamp(E2.timestamp)
+            .with_payload_meta(E2.payload_digest, E2.payload_length),
+        Entry { namespace_id: 0, subspace_id: 0, ..E2 }
+    );
+}
+
+
 mod auth;
+
+#[cfg(feature = "alloc")]
+mod encoding;
+
+#[cfg(feature = "serde")]
+mod serialization;
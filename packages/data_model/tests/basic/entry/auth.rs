@@ -67,3 +67,64 @@ fn new()
     assert_eq!(auth_entry.auth_token(), &true);
     assert_eq!(auth_entry.entry(), &entry);
 }
+
+
+#[test]
+fn map_path()
+{
+    let entry = Entry {
+        namespace_id:   54321,
+        subspace_id:    "blah",
+        path:           ["foo", "bar"],
+        timestamp:      2.into(),
+        payload_digest: [2; 64],
+        payload_length: 2,
+    };
+    let auth_entry = AuthorisedEntry::<MockParams, _, _>::new(entry, true).unwrap();
+
+    let owned: AuthorisedEntry<MockParams, Vec<Vec<u8>>, _> = auth_entry.map_path();
+    assert_eq!(owned.entry(), &entry);
+    assert_eq!(owned.entry().path, [b"foo".to_vec(), b"bar".to_vec()]);
+    assert_eq!(owned.auth_token(), &true);
+
+    let boxed: AuthorisedEntry<MockParams, Vec<Box<[u8]>>, _> = owned.map_path();
+    assert_eq!(boxed.entry(), &entry);
+    assert_eq!(boxed.auth_token(), &true);
+}
+
+
+#[cfg(feature = "unchecked-construction")]
+#[test]
+fn new_unchecked()
+{
+    let entry = Entry {
+        namespace_id:   12345,
+        subspace_id:    "blah",
+        path:           ["foo"],
+        timestamp:      1.into(),
+        payload_digest: [1; 64],
+        payload_length: 1,
+    };
+
+    let auth_entry = AuthorisedEntry::<MockParams, _, _>::new_unchecked(entry, true);
+    assert_eq!(auth_entry.auth_token(), &true);
+    assert_eq!(auth_entry.entry(), &entry);
+    assert_eq!(Some(auth_entry), AuthorisedEntry::new(entry, true));
+}
+
+#[cfg(all(feature = "unchecked-construction", debug_assertions))]
+#[test]
+#[should_panic = "caller must ensure the `Entry` was already authorised"]
+fn new_unchecked_debug_checks()
+{
+    let entry = Entry {
+        namespace_id:   12345,
+        subspace_id:    "blah",
+        path:           ["foo"],
+        timestamp:      1.into(),
+        payload_digest: [1; 64],
+        payload_length: 1,
+    };
+
+    let _unauthorised = AuthorisedEntry::<MockParams, _, _>::new_unchecked(entry, false);
+}
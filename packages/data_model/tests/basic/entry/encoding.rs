@@ -0,0 +1,306 @@
+#![allow(clippy::big_endian_bytes)] // The encodings are defined as big-endian.
+
+use {
+    crate::nz_usize,
+    sailce_data_model::{
+        encoding::{
+            decode_entry,
+            decode_entry_relative,
+            encode_entry,
+            encode_entry_relative,
+            DecodeError,
+        },
+        path::{
+            self,
+            Component,
+            PathLimitError,
+        },
+        Entry,
+        ParamsEntry,
+        Path,
+        Payload,
+        Timestamp,
+    },
+    std::{
+        convert::Infallible,
+        num::NonZeroUsize,
+    },
+};
+
+
+type Decoded = ParamsEntry<Params, Vec<Component<Box<[u8]>>>>;
+
+
+/// Has `Params`-specific types with differing encodings, and small limits.
+struct Params;
+
+impl sailce_data_model::Params for Params
+{
+    type AuthorisationToken = ();
+    type HashPayloadError<P: Payload + ?Sized> = Infallible;
+    type NamespaceId = String;
+    type PayloadDigest = [u8; 4];
+    type SubspaceId = u16;
+
+    const MAX_COMPONENT_COUNT: NonZeroUsize = nz_usize(3);
+    const MAX_COMPONENT_LENGTH: NonZeroUsize = nz_usize(4);
+    const MAX_PATH_LENGTH: NonZeroUsize = nz_usize(10);
+
+    async fn hash_payload<P: Payload + ?Sized>(
+        _payload: &mut P
+    ) -> Result<Self::PayloadDigest, Self::HashPayloadError<P>>
+    {
+        Ok([0; 4])
+    }
+
+    fn is_authorised_write(
+        _entry: &ParamsEntry<Self, impl Path>,
+        _auth_token: &Self::AuthorisationToken,
+    ) -> bool
+    {
+        true
+    }
+}
+
+
+/// Length-prefixed, by 1 byte.
+fn encode_namespace(
+    namespace_id: &String,
+    dest: &mut Vec<u8>,
+)
+{
+    dest.push(namespace_id.len().try_into().unwrap());
+    dest.extend_from_slice(namespace_id.as_bytes());
+}
+
+fn decode_namespace(bytes: &mut &[u8]) -> Option<String>
+{
+    let (&len, rest) = bytes.split_first()?;
+    let name = rest.get(.. usize::from(len))?;
+    *bytes = rest.get(usize::from(len) ..)?;
+    String::from_utf8(name.to_vec()).ok()
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)] // As `encode_entry` requires.
+fn encode_subspace(
+    subspace_id: &u16,
+    dest: &mut Vec<u8>,
+)
+{
+    dest.extend_from_slice(&subspace_id.to_be_bytes());
+}
+
+fn decode_subspace(bytes: &mut &[u8]) -> Option<u16>
+{
+    let (id, rest) = (bytes.get(.. 2)?, bytes.get(2 ..)?);
+    *bytes = rest;
+    Some(u16::from_be_bytes(id.try_into().ok()?))
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)] // As `encode_entry` requires.
+fn encode_digest(
+    digest: &[u8; 4],
+    dest: &mut Vec<u8>,
+)
+{
+    dest.extend_from_slice(digest);
+}
+
+fn decode_digest(bytes: &mut &[u8]) -> Option<[u8; 4]>
+{
+    let (digest, rest) = (bytes.get(.. 4)?, bytes.get(4 ..)?);
+    *bytes = rest;
+    digest.try_into().ok()
+}
+
+
+fn encode(entry: &ParamsEntry<Params, impl Path>) -> Result<Vec<u8>, PathLimitError>
+{
+    encode_entry::<Params>(entry, encode_namespace, encode_subspace, encode_digest)
+}
+
+fn decode(bytes: &[u8]) -> Result<Decoded, DecodeError>
+{
+    decode_entry::<Params>(bytes, decode_namespace, decode_subspace, decode_digest)
+}
+
+fn encode_relative(
+    entry: &ParamsEntry<Params, impl Path>,
+    reference: &ParamsEntry<Params, impl Path>,
+) -> Result<Vec<u8>, PathLimitError>
+{
+    encode_entry_relative::<Params>(
+        entry,
+        reference,
+        encode_namespace,
+        encode_subspace,
+        encode_digest,
+    )
+}
+
+fn decode_relative(
+    bytes: &[u8],
+    reference: &ParamsEntry<Params, impl Path>,
+) -> Result<Decoded, DecodeError>
+{
+    decode_entry_relative::<Params>(
+        bytes,
+        reference,
+        decode_namespace,
+        decode_subspace,
+        decode_digest,
+    )
+}
+
+fn entry(path: &'static [&'static str]) -> ParamsEntry<Params, &'static [&'static str]>
+{
+    Entry {
+        namespace_id: "ns".into(),
+        subspace_id: 0x0102,
+        path,
+        timestamp: Timestamp { μs_since_epoch: 0x0A0B },
+        payload_digest: [9, 8, 7, 6],
+        payload_length: 5,
+    }
+}
+
+
+#[test]
+fn encode_layout()
+{
+    assert_eq!(
+        encode(&entry(&["ab", ""])),
+        Ok(vec![
+            2, b'n', b's', // namespace_id
+            1, 2, // subspace_id
+            2, 2, b'a', b'b', 0, // path
+            0, 0, 0, 0, 0, 0, 0x0A, 0x0B, // timestamp
+            9, 8, 7, 6, // payload_digest
+            0, 0, 0, 0, 0, 0, 0, 5, // payload_length
+        ])
+    );
+    assert_eq!(
+        encode(&entry(&["a", "b", "c", "d"])),
+        Err(PathLimitError::TooManyComponents { count: 4, max: 3 })
+    );
+}
+
+#[test]
+fn round_trips()
+{
+    let paths: [&'static [&'static str]; 5] =
+        [&[], &[""], &["a"], &["abcd", "ab", "cd"], &["abcd", "abcd", "ab"]];
+    for path in paths {
+        let original = entry(path);
+        let encoded = encode(&original).expect("within limits");
+        assert_eq!(decode(&encoded).unwrap(), original, "{path:?}");
+    }
+    let other = Entry {
+        namespace_id: String::new(),
+        subspace_id: u16::MAX,
+        timestamp: Timestamp { μs_since_epoch: u64::MAX },
+        payload_digest: [0; 4],
+        payload_length: 0,
+        ..entry(&["x"])
+    };
+    assert_eq!(decode(&encode(&other).unwrap()).unwrap(), other);
+}
+
+#[test]
+fn decode_errors()
+{
+    let encoded = encode(&entry(&["ab"])).unwrap();
+    let decode_len = |len: usize| decode(encoded.get(.. len).unwrap_or_default()).map(|_| ());
+    assert_eq!(decode_len(0), Err(DecodeError::InvalidNamespace));
+    assert_eq!(decode_len(4), Err(DecodeError::InvalidSubspace));
+    assert_eq!(decode_len(6), Err(DecodeError::Path(path::encoding::DecodeError::UnexpectedEnd)));
+    assert_eq!(decode_len(12), Err(DecodeError::UnexpectedEnd));
+    assert_eq!(decode_len(19), Err(DecodeError::InvalidPayloadDigest));
+    assert_eq!(decode_len(25), Err(DecodeError::UnexpectedEnd));
+
+    let mut trailing = encoded.clone();
+    trailing.push(0);
+    assert_eq!(decode(&trailing).map(|_| ()), Err(DecodeError::TrailingBytes));
+
+    let mut exceeds = encoded;
+    if let Some(count) = exceeds.get_mut(5) {
+        *count = 4;
+    }
+    assert!(matches!(
+        decode(&exceeds),
+        Err(DecodeError::Path(path::encoding::DecodeError::ExceedsLimits(_)))
+    ));
+}
+
+
+#[test]
+fn relative_layout()
+{
+    let reference = entry(&["ab", "c"]);
+    assert_eq!(encode_relative(&reference, &reference), Ok(vec![0xF0, 2, 0]));
+    let later = Entry { timestamp: Timestamp { μs_since_epoch: 0x0A0C }, ..entry(&["ab"]) };
+    assert_eq!(encode_relative(&later, &reference), Ok(vec![0xF1, 1, 0, 2]));
+    let earlier = Entry { timestamp: Timestamp { μs_since_epoch: 0x0A0A }, ..entry(&["ab"]) };
+    assert_eq!(encode_relative(&earlier, &reference), Ok(vec![0xF1, 1, 0, 1]));
+    let different = Entry {
+        namespace_id:   "x".into(),
+        subspace_id:    3,
+        path:           &["d"][..],
+        timestamp:      Timestamp { μs_since_epoch: 0x0A0B + 0x100 },
+        payload_digest: [1, 2, 3, 4],
+        payload_length: 6,
+    };
+    assert_eq!(
+        encode_relative(&different, &reference),
+        Ok(vec![
+            0x02, // header
+            1, b'x', // namespace_id
+            0, 3, // subspace_id
+            0, 1, 1, b'd', // path
+            0x02, 0x00, // timestamp delta
+            1, 2, 3, 4, // payload_digest
+            0, 0, 0, 0, 0, 0, 0, 6, // payload_length
+        ])
+    );
+}
+
+#[test]
+fn relative_round_trips()
+{
+    let at = |μs_since_epoch| Timestamp { μs_since_epoch };
+    let entries = [
+        entry(&[]),
+        entry(&["ab", "c"]),
+        Entry { namespace_id: "other".into(), ..entry(&["ab"]) },
+        Entry { subspace_id: 7, payload_length: 0, ..entry(&["ab", "cd", ""]) },
+        Entry { timestamp: at(0), payload_digest: [0; 4], ..entry(&["x"]) },
+        Entry { timestamp: at(u64::MAX), ..entry(&["abcd", "abcd", "ab"]) },
+        Entry { timestamp: at(1 << 63), payload_length: u64::MAX, ..entry(&["ab", "c"]) },
+    ];
+    for original in &entries {
+        for reference in &entries {
+            let encoded = encode_relative(original, reference).expect("within limits");
+            let decoded = decode_relative(&encoded, reference).expect("valid encoding");
+            assert_eq!(decoded, *original, "{original:?} relative to {reference:?}");
+        }
+    }
+}
+
+#[test]
+fn relative_decode_errors()
+{
+    let reference = entry(&["ab"]);
+    let decode = |bytes: &[u8]| decode_relative(bytes, &reference).map(|_| ());
+    assert_eq!(decode(&[]), Err(DecodeError::UnexpectedEnd));
+    assert_eq!(decode(&[0xF9, 1, 0]), Err(DecodeError::InvalidHeader(0xF9)));
+    assert_eq!(decode(&[0xF2, 1, 0, 0, 1]), Err(DecodeError::InvalidHeader(0xF2)));
+    assert_eq!(decode(&[0xF1, 1, 0]), Err(DecodeError::UnexpectedEnd));
+    assert_eq!(
+        decode(&[0xF0, 2, 0]),
+        Err(DecodeError::Path(path::encoding::DecodeError::ExceedsReference))
+    );
+    assert_eq!(decode(&[0x70, 9]), Err(DecodeError::InvalidNamespace));
+    assert_eq!(decode(&[0xE0, 1, 0, 0, 0, 0, 0, 0]), Err(DecodeError::UnexpectedEnd));
+    assert_eq!(decode(&[0xF0, 1, 0, 0]), Err(DecodeError::TrailingBytes));
+    assert_eq!(decode(&[0xF0, 1, 0]), Ok(()));
+}
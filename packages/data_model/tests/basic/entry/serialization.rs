@@ -0,0 +1,100 @@
+use {
+    sailce_data_model::{
+        path::Extra as _,
+        Entry,
+        Timestamp,
+    },
+    std::sync::Arc,
+};
+
+
+type Owned = Entry<u32, String, Vec<Box<[u8]>>, [u8; 4]>;
+type ArcPath = Arc<[Box<[u8]>]>;
+
+
+#[test]
+fn timestamp()
+{
+    let ts = Timestamp { μs_since_epoch: 1_234 };
+    assert_eq!(serde_json::to_string(&ts).unwrap(), "1234");
+    assert_eq!(serde_json::from_str::<Timestamp>("1234").unwrap(), ts);
+}
+
+#[test]
+fn entry()
+{
+    let entry = Entry {
+        namespace_id:   7_u32,
+        subspace_id:    "alfie",
+        path:           ["a", "bc"],
+        timestamp:      Timestamp { μs_since_epoch: 99 },
+        payload_digest: [1, 2, 3, 4],
+        payload_length: 5,
+    };
+
+    let json = serde_json::to_string(&entry).unwrap();
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"namespace_id":7,"subspace_id":"alfie","path":[[97],[98,99]],"timestamp":99,"#,
+            r#""payload_digest":[1,2,3,4],"payload_length":5}"#
+        )
+    );
+
+    let owned: Owned = serde_json::from_str(&json).unwrap();
+    assert_eq!(owned, entry);
+    assert!(owned.path.eq_components(&["a", "bc"]));
+}
+
+fn with_path<P>(path: P) -> Entry<(), (), P, ()>
+{
+    Entry {
+        namespace_id: (),
+        subspace_id: (),
+        path,
+        timestamp: Timestamp::default(),
+        payload_digest: (),
+        payload_length: 0,
+    }
+}
+
+/// The serialized form of the `path` doesn't depend on its type.
+#[test]
+fn path_independent_of_type()
+{
+    let with_strs = with_path(vec!["x", "", "yz"]);
+    let with_arcs = with_path(Arc::<[Arc<[u8]>]>::from_path(&with_strs.path));
+    let with_arrays = with_path([&b"x"[..], b"", b"yz"].map(<[u8]>::to_vec));
+
+    let json = serde_json::to_string(&with_strs).unwrap();
+    assert_eq!(serde_json::to_string(&with_arcs).unwrap(), json);
+    assert_eq!(serde_json::to_string(&with_arrays).unwrap(), json);
+
+    let owned: Entry<(), (), ArcPath, ()> = serde_json::from_str(&json).unwrap();
+    assert_eq!(owned, with_strs);
+
+    let empty = with_path(Vec::<&str>::new());
+    let owned_empty: Entry<(), (), Vec<Box<[u8]>>, ()> =
+        serde_json::from_str(&serde_json::to_string(&empty).unwrap()).unwrap();
+    assert!(owned_empty.path.is_empty());
+}
+
+
+#[test]
+fn deserialize_path()
+{
+    let with = |path: &str| {
+        let json = format!(
+            "{}{path}{}",
+            r#"{"namespace_id":0,"subspace_id":"","path":"#,
+            r#","timestamp":0,"payload_digest":[0,0,0,0],"payload_length":0}"#
+        );
+        serde_json::from_str::<Owned>(&json).map(|entry| entry.path)
+    };
+    assert!(with("[[97], []]").unwrap().eq_components(&["a", ""]));
+    // JSON strings are accepted as byte-strings, by `serde_json`.
+    assert!(with(r#"["a"]"#).unwrap().eq_components(&["a"]));
+    assert!(with("[[256]]").ok().is_none());
+    assert!(with("[97]").ok().is_none());
+    assert!(with(r#""a""#).ok().is_none());
+}
@@ -25,9 +25,13 @@ fn A<'p, const N: usize>(
 
 mod subspace
 {
-    use sailce_data_model::group::area::Subspace::{
-        Any,
-        Id,
+    use sailce_data_model::group::{
+        area::Subspace::{
+            self,
+            Any,
+            Id,
+        },
+        Range,
     };
 
     #[test]
@@ -40,6 +44,33 @@ mod subspace
         assert!(Id(0) < Id(1));
         assert!(Id(u128::MAX) < Any);
     }
+
+    #[test]
+    fn includes_id()
+    {
+        assert!(Id(1).includes_id(1));
+        assert!(!Id(1).includes_id(2));
+        assert!(Any::<u8>.includes_id(u8::MAX));
+        assert!(Any::<&str>.includes_id("anything"));
+    }
+
+    #[test]
+    fn into_range()
+    {
+        let ranges: [(Subspace<u8>, Range<u8>); 4] = [
+            (Id(0), (0 .. 1).into()),
+            (Id(7), (7 .. 8).into()),
+            (Id(u8::MAX), (u8::MAX ..).into()),
+            (Any, (..).into()),
+        ];
+        for (subspace, range) in ranges {
+            assert_eq!(Range::from(subspace), range);
+            for id in 0 ..= u8::MAX {
+                assert_eq!(subspace.includes_id(id), range.includes(id), "{subspace:?} {id}");
+            }
+        }
+        assert!(Range::from(Any::<i32>).is_full());
+    }
 }
 
 
@@ -202,4 +233,32 @@ fn intersection()
 }
 
 
+#[test]
+fn intersects()
+{
+    let areas = [
+        Area::empty(),
+        Area::full(),
+        Area::subspace(3),
+        A(Any, &["bb"], 22 .. 33),
+        A(Id(4), &["bb", "ccc"], 27 ..),
+        A(Id(4), &[], 1 ..),
+        A(Any, &["d"], 0 .. 50),
+        A(Id(4), &["bb"], 33 ..),
+    ];
+
+    for a in &areas {
+        for b in &areas {
+            assert_eq!(a.intersects(b), !a.intersection(b).is_empty(), "{a:?} {b:?}");
+            assert_eq!(a.intersects(b), b.intersects(a));
+        }
+    }
+    assert!(!A(Any, &["bb"], 22 .. 33).intersects(A(Id(4), &["bb"], 33 ..)));
+    assert!(!A(Any, &["d"], 0 .. 50).intersects(A(Any, &["bb"], 22 .. 33)));
+    assert!(A(Any, &["bb"], 22 .. 33).intersects(A(Id(4), &[], 1 ..)));
+}
+
+
 mod of_interest;
+
+mod three_dim;
@@ -213,3 +213,149 @@ fn intersection()
         assert_eq!(aoi2.intersection(aoi1), i);
     }
 }
+
+
+#[test]
+fn intersection_empty_does_not_clone()
+{
+    use {
+        sailce_data_model::{
+            path::Component,
+            Path,
+        },
+        std::cell::Cell,
+    };
+
+    thread_local! {
+        static CLONES: Cell<u32> = const { Cell::new(0) };
+    }
+
+    /// Counts how many times it's cloned.
+    #[derive(Default, Debug, Eq, PartialEq)]
+    struct CountingPath(&'static [&'static str]);
+
+    impl Clone for CountingPath
+    {
+        fn clone(&self) -> Self
+        {
+            CLONES.with(|clones| clones.set(clones.get().saturating_add(1)));
+            Self(self.0)
+        }
+    }
+
+    impl Path for CountingPath
+    {
+        fn components(&self) -> impl ExactSizeIterator<Item = Component<&[u8]>>
+        {
+            self.0.components()
+        }
+    }
+
+    fn aoi(
+        subspace: Subspace<u8>,
+        path: &'static [&'static str],
+        times: std::ops::Range<u64>,
+    ) -> AreaOfInterest<u8, CountingPath>
+    {
+        AreaOfInterest {
+            area:      Area { subspace, path: CountingPath(path), times: times.into() },
+            max_count: Max::Limit(10.try_into().unwrap()),
+            max_size:  Max::Limit(1_000.try_into().unwrap()),
+        }
+    }
+
+    let canonical_empty = AreaOfInterest {
+        area:      Area::empty(),
+        max_count: Max::Unlimited,
+        max_size:  Max::Unlimited,
+    };
+    let a = aoi(Subspace::Id(1), &["a"], 0 .. 10);
+    let disjoint = [
+        aoi(Subspace::Id(2), &["a"], 0 .. 10),
+        aoi(Subspace::Any, &["b"], 0 .. 10),
+        aoi(Subspace::Id(1), &["a", "b"], 10 .. 20),
+    ];
+    for other in &disjoint {
+        assert!(!a.area.intersects(&other.area));
+        assert_eq!(a.intersection(other), canonical_empty);
+        assert_eq!(other.intersection(&a), canonical_empty);
+        assert_eq!(a.area.intersection(&other.area), Area::empty());
+    }
+    assert_eq!(CLONES.with(Cell::get), 0);
+
+    // Only the nonempty intersection clones.
+    let overlapping = aoi(Subspace::Any, &["a", "b"], 5 .. 15);
+    assert!(a.intersection(overlapping).is_satisfiable());
+    assert_eq!(CLONES.with(Cell::get), 1);
+}
+
+
+#[test]
+fn satisfiable_and_normalize()
+{
+    fn limited<'p>(area: Area<u8, &'p [&'p [u8]]>) -> AreaOfInterest<u8, &'p [&'p [u8]]>
+    {
+        AreaOfInterest {
+            area,
+            max_count: Max::Limit(10.try_into().unwrap()),
+            max_size:  Max::Limit(1_000.try_into().unwrap()),
+        }
+    }
+
+    let canonical_empty = AreaOfInterest {
+        area:      Area::<u8, &[&[u8]]>::empty(),
+        max_count: Max::Unlimited,
+        max_size:  Max::Unlimited,
+    };
+
+    let path_a = [b"a".as_slice()];
+    let empty = limited(Area {
+        subspace: Subspace::Id(1),
+        path:     path_a.as_slice(),
+        times:    (5 .. 5).into(),
+    });
+    assert!(!empty.is_satisfiable());
+    assert_eq!(empty.normalize(), canonical_empty);
+    assert!(!canonical_empty.is_satisfiable());
+    assert_eq!(canonical_empty.normalize(), canonical_empty);
+
+    let full = limited(Area::full());
+    assert!(full.is_satisfiable());
+    assert_eq!(full.normalize(), full);
+    let default = AreaOfInterest::<u8, &[&[u8]]>::default();
+    assert_eq!(default.normalize(), default);
+
+    let subspace = limited(Area::subspace(7));
+    assert!(subspace.is_satisfiable());
+    assert_eq!(subspace.normalize(), subspace);
+    // Intersecting disjoint ones is unsatisfiable.
+    let disjoint = subspace.intersection(limited(Area::subspace(8)));
+    assert!(!disjoint.is_satisfiable());
+    assert_eq!(disjoint.normalize(), canonical_empty);
+}
+
+
+#[test]
+fn is_empty()
+{
+    fn aoi(times: std::ops::Range<u64>) -> AreaOfInterest<u8, &'static [&'static [u8]]>
+    {
+        AreaOfInterest {
+            area:      Area { subspace: Subspace::Id(1), path: &[b"a"], times: times.into() },
+            max_count: Max::Limit(1.try_into().unwrap()),
+            max_size:  Max::Unlimited,
+        }
+    }
+
+    assert!(!aoi(0 .. 10).is_empty());
+    assert!(aoi(3 .. 3).is_empty());
+    assert!(AreaOfInterest::<u8, &[&[u8]]>::default().intersection(aoi(3 .. 3)).is_empty());
+
+    // Same subspace and path, but adjacent times, so the intersection of the areas is empty.
+    let adjacent = aoi(0 .. 10).intersection(aoi(10 .. 20));
+    assert!(adjacent.is_empty());
+    assert!(adjacent.area.is_empty());
+    assert_eq!(adjacent.max_count, Max::Unlimited, "not the misleading limit of the operands");
+    assert_eq!(adjacent, aoi(5 .. 5).normalize());
+    assert!(!aoi(0 .. 11).intersection(aoi(10 .. 20)).is_empty());
+}
@@ -0,0 +1,195 @@
+use sailce_data_model::group::{
+    area::{
+        FromThreeDimRangeError,
+        Subspace::{
+            Any,
+            Id,
+        },
+    },
+    range::End,
+    Area,
+    Range,
+    ThreeDimRange,
+};
+#[cfg(feature = "alloc")]
+use sailce_data_model::Path as _;
+
+
+type P = Vec<Box<[u8]>>;
+
+fn path<const N: usize>(components: [&str; N]) -> P
+{
+    components.iter().map(|c| c.as_bytes().into()).collect()
+}
+
+fn three_dim(
+    subspaces: Range<u8>,
+    paths: Range<P>,
+) -> ThreeDimRange<u8, P>
+{
+    ThreeDimRange { subspaces, paths, times: (10 .. 20).into() }
+}
+
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_three_dim_range()
+{
+    let times = (10 .. 20).into();
+
+    let id = Area { subspace: Id(7_u8), path: path(["a", "bc"]), times };
+    let paths = (path(["a", "bc"]) .. path(["a", "bc\0"])).into();
+    assert_eq!(id.to_three_dim_range(), three_dim((7 .. 8).into(), paths));
+
+    let full = Area { subspace: Any, path: path([]), times };
+    assert_eq!(full.to_three_dim_range(), three_dim((0 ..).into(), (path([]) ..).into()));
+
+    let greatest = Area { subspace: Id(u8::MAX), path: path([""]), times };
+    let empty_component = (path([""]) .. path(["\0"])).into();
+    assert_eq!(greatest.to_three_dim_range(), three_dim((u8::MAX ..).into(), empty_component));
+}
+
+
+#[cfg(feature = "alloc")]
+#[test]
+fn round_trip()
+{
+    let areas = [
+        Area::full(),
+        Area::subspace(0),
+        Area::subspace(u8::MAX),
+        Area { subspace: Any, path: path(["x"]), times: (0 ..).into() },
+        Area { subspace: Id(3), path: path(["x", "", "yz"]), times: (5 .. 6).into() },
+        Area { subspace: Id(3), path: path(["\u{FF}"]), times: (5 .. 5).into() },
+    ];
+    for area in areas {
+        let range = area.to_three_dim_range();
+        assert_eq!(Area::try_from(&range), Ok(area.clone()), "{range:?}");
+        for entry_path in [path([]), path(["x"]), path(["x", ""]), path(["x\0"]), path(["y"])] {
+            assert_eq!(
+                area.path.is_prefix_of(&entry_path),
+                range.paths.includes(&entry_path),
+                "{area:?} {entry_path:?}"
+            );
+        }
+    }
+}
+
+
+#[test]
+fn try_from()
+{
+    let ok = |subspaces: Range<u8>, paths: Range<P>| Area::try_from(&three_dim(subspaces, paths));
+
+    assert_eq!(
+        ok((0 ..).into(), (path([]) ..).into()),
+        Ok(Area { subspace: Any, path: path([]), times: (10 .. 20).into() })
+    );
+    assert_eq!(
+        ok((42 .. 43).into(), (path(["a", "b"]) .. path(["a", "b\0"])).into()),
+        Ok(Area { subspace: Id(42), path: path(["a", "b"]), times: (10 .. 20).into() })
+    );
+    assert_eq!(
+        ok((u8::MAX ..).into(), (path([""]) .. path(["\0"])).into()),
+        Ok(Area { subspace: Id(u8::MAX), path: path([""]), times: (10 .. 20).into() })
+    );
+}
+
+
+#[test]
+fn try_from_errors()
+{
+    let err = |subspaces: Range<u8>, paths: Range<P>| {
+        Area::try_from(&three_dim(subspaces, paths)).err().map(
+            |FromThreeDimRangeError { subspaces_convertible, paths_convertible }| {
+                (subspaces_convertible, paths_convertible)
+            },
+        )
+    };
+    let good_paths = || (path(["a"]) .. path(["a\0"])).into();
+
+    assert_eq!(err((1 ..).into(), good_paths()), Some((false, true)));
+    assert_eq!(err((1 .. 3).into(), good_paths()), Some((false, true)));
+    assert_eq!(err((1 .. 1).into(), good_paths()), Some((false, true)));
+    assert_eq!(err((0 .. u8::MAX).into(), good_paths()), Some((false, true)));
+
+    assert_eq!(err((0 ..).into(), (path(["a"]) ..).into()), Some((true, false)));
+    assert_eq!(err((0 ..).into(), (path([]) .. path([""])).into()), Some((true, false)));
+    assert_eq!(err((0 ..).into(), (path(["a"]) .. path(["b"])).into()), Some((true, false)));
+    assert_eq!(err((0 ..).into(), (path(["a"]) .. path(["a\0\0"])).into()), Some((true, false)));
+    assert_eq!(err((0 ..).into(), (path(["a"]) .. path(["a\x01"])).into()), Some((true, false)));
+    assert_eq!(err((0 ..).into(), (path(["a"]) .. path(["a", ""])).into()), Some((true, false)));
+    let paths = (path(["a", "b"]) .. path(["c", "b\0"])).into();
+    assert_eq!(err((0 ..).into(), paths), Some((true, false)));
+    assert_eq!(err((0 ..).into(), (path(["a"]) .. path(["a"])).into()), Some((true, false)));
+
+    assert_eq!(err((5 .. 7).into(), (path(["a"]) .. path(["b"])).into()), Some((false, false)));
+
+    let error = FromThreeDimRangeError { subspaces_convertible: true, paths_convertible: false };
+    assert!(error.to_string().contains("paths convertible: false"));
+}
+
+
+#[test]
+fn open_end_ranges_convert()
+{
+    let range = ThreeDimRange {
+        subspaces: (0_u8 ..).into(),
+        paths:     (path([]) ..).into(),
+        times:     (0 ..).into(),
+    };
+    assert_eq!(range.subspaces.end, End::Open);
+    assert_eq!(Area::try_from(&range), Ok(Area::full()));
+}
+
+
+/// `ThreeDimRange::includes` and `ThreeDimRange::intersection` agree with those of `Area`.
+#[cfg(feature = "alloc")]
+#[test]
+fn parity_with_area()
+{
+    use sailce_data_model::Entry;
+
+    let areas = [
+        Area::full(),
+        Area::empty(),
+        Area::subspace(3),
+        Area { subspace: Any, path: path(["bb"]), times: (22 .. 33).into() },
+        Area { subspace: Id(4), path: path(["bb", "ccc"]), times: (27 ..).into() },
+        Area { subspace: Id(4), path: path([]), times: (1 ..).into() },
+        Area { subspace: Id(u8::MAX), path: path([""]), times: (0 .. 30).into() },
+    ];
+    let entries: Vec<Entry<(), u8, P, ()>> = [0, 3, 4, u8::MAX]
+        .into_iter()
+        .flat_map(|subspace_id| {
+            [path([]), path([""]), path(["bb"]), path(["bb", "ccc", "d"]), path(["bb\0"])]
+                .into_iter()
+                .flat_map(move |path| {
+                    [0, 22, 30, 40].map(|timestamp| Entry {
+                        namespace_id: (),
+                        subspace_id,
+                        path: path.clone(),
+                        timestamp: timestamp.into(),
+                        payload_digest: (),
+                        payload_length: 0,
+                    })
+                })
+        })
+        .collect();
+
+    for a in &areas {
+        let a_range = a.to_three_dim_range();
+        for b in &areas {
+            let intersection = a.intersection(b);
+            let range_intersection = a_range.intersection(b.to_three_dim_range());
+            for entry in &entries {
+                assert_eq!(a.includes::<Entry<_, _, _, _>>(entry), a_range.includes(entry));
+                assert_eq!(
+                    intersection.includes::<Entry<_, _, _, _>>(entry),
+                    range_intersection.includes(entry),
+                    "{a:?} {b:?} {entry:?}"
+                );
+            }
+        }
+    }
+}
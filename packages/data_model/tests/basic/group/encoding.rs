@@ -0,0 +1,215 @@
+#![allow(clippy::big_endian_bytes)] // Our layouts are defined as big-endian.
+
+use sailce_data_model::{
+    group::{
+        area::Subspace::{
+            Any,
+            Id,
+        },
+        encoding::{
+            decode_area,
+            decode_area_relative,
+            decode_timestamp_range,
+            encode_area,
+            encode_area_relative,
+            encode_timestamp_range,
+            DecodeError,
+            NotIncludedError,
+        },
+        range::End,
+        Area,
+        Range,
+    },
+    Timestamp,
+};
+
+
+fn ranges() -> [Range<Timestamp>; 6]
+{
+    [
+        (0 ..).into(),
+        (0 .. 0).into(),
+        (1 ..).into(),
+        (1 .. 2).into(),
+        (u64::MAX ..).into(),
+        (0 .. u64::MAX).into(),
+    ]
+}
+
+
+#[test]
+fn timestamp_range()
+{
+    assert_eq!(encode_timestamp_range(&(1 .. 258).into()), [
+        0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 2
+    ]);
+    assert_eq!(encode_timestamp_range(&(2 ..).into()), [
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0
+    ]);
+
+    let ranges = ranges();
+    for range in &ranges {
+        let encoded = encode_timestamp_range(range);
+        assert_eq!(encoded, encode_timestamp_range(range), "deterministic");
+        assert_eq!(decode_timestamp_range(&encoded), Ok(*range));
+    }
+    for (i, a) in ranges.iter().enumerate() {
+        for b in ranges.iter().skip(i + 1) {
+            assert_ne!(encode_timestamp_range(a), encode_timestamp_range(b), "distinct");
+        }
+    }
+
+    let mut bad_tag = encode_timestamp_range(&(0 ..).into());
+    bad_tag[8] = 2;
+    assert_eq!(decode_timestamp_range(&bad_tag), Err(DecodeError::InvalidTag(2)));
+    let mut non_canonical = encode_timestamp_range(&(0 ..).into());
+    non_canonical[16] = 1;
+    assert_eq!(decode_timestamp_range(&non_canonical), Err(DecodeError::NonCanonical));
+}
+
+
+#[test]
+fn area()
+{
+    fn decode(bytes: &[u8]) -> Result<Area<u16, Vec<&[u8]>>, DecodeError>
+    {
+        decode_area(bytes, |id_bytes| Some(u16::from_be_bytes(id_bytes.try_into().ok()?)))
+    }
+
+    let encode = |area: &Area<u16, Vec<&[u8]>>| {
+        encode_area(area, |id, dest| dest.extend_from_slice(&id.to_be_bytes()))
+    };
+
+    let areas: Vec<Area<u16, Vec<&[u8]>>> = [Any, Id(0), Id(1), Id(256)]
+        .into_iter()
+        .flat_map(|subspace| {
+            let paths: [Vec<&[u8]>; 5] =
+                [vec![], vec![b""], vec![b"", b""], vec![b"a"], vec![b"a", b"bc"]];
+            paths.into_iter().flat_map(move |path| {
+                ranges().map(|times| Area { subspace, path: path.clone(), times })
+            })
+        })
+        .collect();
+
+    for area in &areas {
+        let encoded = encode(area);
+        assert_eq!(encoded, encode(area), "deterministic");
+        assert_eq!(decode(&encoded).as_ref(), Ok(area));
+    }
+    for (i, a) in areas.iter().enumerate() {
+        for b in areas.iter().skip(i + 1) {
+            assert_ne!(encode(a), encode(b), "distinct");
+        }
+    }
+
+    let full = Area { subspace: Id(7), path: vec![&b"xy"[..]], times: (3 .. 4).into() };
+    assert_eq!(encode(&full), [
+        &[1][..],
+        &[0, 0, 0, 0, 0, 0, 0, 2, 0, 7],
+        &[0, 0, 0, 0, 0, 0, 0, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 2, b'x', b'y'],
+        &[0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 0, 4],
+    ]
+    .concat());
+    assert_eq!(full.times.end, End::Closed(4.into()));
+
+    let encoded = encode(&full);
+    let (_, truncated) = encoded.split_last().unwrap();
+    assert_eq!(decode(truncated), Err(DecodeError::UnexpectedEnd));
+    assert_eq!(decode(&[encoded.as_slice(), &[0]].concat()), Err(DecodeError::TrailingBytes));
+    let (_, after_tag) = encoded.split_first().unwrap();
+    assert_eq!(decode(&[&[2], after_tag].concat()), Err(DecodeError::InvalidTag(2)));
+    let (_, after_subspace) = encoded.split_at(11);
+    let bad_subspace = [&[1][..], &[0, 0, 0, 0, 0, 0, 0, 1, 7], after_subspace].concat();
+    assert_eq!(decode(&bad_subspace), Err(DecodeError::InvalidSubspace));
+}
+
+
+#[test]
+fn area_relative()
+{
+    type A<'l> = Area<u16, Vec<&'l [u8]>>;
+
+    fn decode<'l>(
+        bytes: &'l [u8],
+        outer: &'l A<'l>,
+    ) -> Result<A<'l>, DecodeError>
+    {
+        decode_area_relative(bytes, outer, |id_bytes| {
+            Some(u16::from_be_bytes(id_bytes.try_into().ok()?))
+        })
+    }
+
+    let encode_subspace =
+        |id: &u16, dest: &mut Vec<u8>| dest.extend_from_slice(&id.to_be_bytes());
+    let encode =
+        |inner: &A<'_>, outer: &A<'_>| encode_area_relative(inner, outer, encode_subspace);
+
+    let areas: Vec<A<'_>> = [Any, Id(1), Id(256)]
+        .into_iter()
+        .flat_map(|subspace| {
+            let paths: [Vec<&[u8]>; 4] = [vec![], vec![b"a"], vec![b"a", b"bc"], vec![b"b"]];
+            paths.into_iter().flat_map(move |path| {
+                [
+                    (0 ..).into(),
+                    (1 ..).into(),
+                    (1 .. 3).into(),
+                    (2 .. 3).into(),
+                    (0 .. u64::MAX).into(),
+                ]
+                .map(|times| Area { subspace, path: path.clone(), times })
+            })
+        })
+        .collect();
+
+    for outer in &areas {
+        let mut encoded_by_inner = Vec::new();
+        for inner in &areas {
+            if outer.includes::<A<'_>>(inner) {
+                let encoded = encode(inner, outer).unwrap();
+                assert!(encoded.len() <= encode_area(inner, encode_subspace).len());
+                assert_eq!(decode(&encoded, outer).as_ref(), Ok(inner));
+                encoded_by_inner.push(encoded);
+            }
+            else {
+                assert_eq!(encode(inner, outer), Err(NotIncludedError));
+            }
+        }
+        for (i, a) in encoded_by_inner.iter().enumerate() {
+            for b in encoded_by_inner.iter().skip(i.saturating_add(1)) {
+                assert_ne!(a, b, "distinct");
+            }
+        }
+    }
+
+    let outer = Area { subspace: Any, path: vec![&b"x"[..]], times: (10 .. 20).into() };
+    let inner =
+        Area { subspace: Id(7), path: vec![&b"x"[..], b"y"], times: (12 .. 15).into() };
+    let encoded = encode(&inner, &outer).unwrap();
+    assert_eq!(encoded, [
+        &[1][..],
+        &[0, 0, 0, 0, 0, 0, 0, 2, 0, 7],
+        &[0, 0, 0, 0, 0, 0, 0, 1],
+        &[0, 0, 0, 0, 0, 0, 0, 1, b'y'],
+        &[0, 0, 0, 0, 0, 0, 0, 2],
+        &[1, 0, 0, 0, 0, 0, 0, 0, 5],
+    ]
+    .concat());
+    let same = encode(&outer, &outer).unwrap();
+    assert_eq!(same, [&[0][..], &[0; 8], &[0; 8], &[1], &[0; 8]].concat());
+
+    let (_, truncated) = encoded.split_last().unwrap();
+    assert_eq!(decode(truncated, &outer), Err(DecodeError::UnexpectedEnd));
+    let trailing = [encoded.as_slice(), &[0]].concat();
+    assert_eq!(decode(&trailing, &outer), Err(DecodeError::TrailingBytes));
+    let id_outer = Area { subspace: Id(7), ..outer.clone() };
+    assert_eq!(decode(&encoded, &id_outer), Err(DecodeError::InvalidTag(1)));
+    let open_outer = Area { times: (10 ..).into(), ..outer.clone() };
+    let (before_end, after_start) = same.split_at(17);
+    let open_end = [before_end, &[0]].concat();
+    assert_eq!(decode(&open_end, &open_outer).map(|a| a.times), Ok(open_outer.times));
+    assert_eq!(decode(&open_end, &outer), Err(DecodeError::InvalidTag(0)));
+    let (before_start, _) = before_end.split_at(9);
+    let too_late = [before_start, &[0xFF; 8], after_start].concat();
+    assert_eq!(decode(&too_late, &outer), Err(DecodeError::OutOfBounds));
+}
@@ -1,6 +1,9 @@
 #![allow(unused_parens, clippy::reversed_empty_ranges, clippy::almost_complete_range)]
 
-use sailce_data_model::group::Range;
+use sailce_data_model::group::{
+    range::End,
+    Range,
+};
 
 
 mod end
@@ -21,6 +24,13 @@ mod end
         assert!(End::Closed(1) < End::Closed(u16::MAX));
         assert!(End::Closed(u128::MAX) < End::Open);
     }
+
+    #[test]
+    fn map()
+    {
+        assert_eq!(End::Closed(2).map(|v| v * 10), End::Closed(20));
+        assert_eq!(End::<i32>::Open.map(|v| v * 10), End::Open);
+    }
 }
 
 
@@ -122,6 +132,119 @@ fn intersection()
 }
 
 
+#[test]
+fn union()
+{
+    let r1 = Range { start: 1, end: End::Closed(4) };
+    let r2 = Range { start: -9, end: End::Open };
+
+    assert_eq!(r1.union(r1), Some(r1));
+    assert_eq!(r1.union(Range::from(2 .. 3)), Some(r1));
+    assert_eq!(r1.union(Range::from(0 .. 3)), Some((0 .. 4).into()));
+    assert_eq!(r1.union(Range::from(2 .. 5)), Some((1 .. 5).into()));
+    assert_eq!(r1.union(Range::from(2 ..)), Some((1 ..).into()));
+    assert_eq!(r1.union(r2), Some(r2));
+    assert_eq!(r2.union(r1), Some(r2));
+    // Adjacent.
+    assert_eq!(r1.union(Range::from(4 .. 7)), Some((1 .. 7).into()));
+    assert_eq!(Range::from(4 .. 7).union(r1), Some((1 .. 7).into()));
+    assert_eq!(r1.union(Range::from(4 ..)), Some((1 ..).into()));
+    assert_eq!(Range::from(-12 .. -9).union(r2), Some((-12 ..).into()));
+    assert_eq!(r2.union(Range::from(-12 .. -9)), Some((-12 ..).into()));
+    // A gap.
+    assert_eq!(r1.union(Range::from(5 .. 7)), None);
+    assert_eq!(Range::from(5 .. 7).union(r1), None);
+    assert_eq!(r1.union(Range::from(5 ..)), None);
+    assert_eq!(Range::from(-12 .. -10).union(r2), None);
+    assert_eq!(r2.union(Range::from(-12 .. -10)), None);
+
+    let empty1 = Range { start: 1, end: End::Closed(1) };
+    let empty2 = Range { start: -9, end: End::Closed(-11) };
+
+    assert_eq!(r1.union(empty1), Some(r1));
+    assert_eq!(r1.union(empty2), Some(r1));
+    assert_eq!(empty2.union(r1), Some(r1));
+    assert_eq!(r2.union(Range::from(20 .. 20)), Some(r2));
+    assert_eq!(Range::from(-20 .. -20).union(r2), Some(r2));
+    assert!(empty1.union(empty2).is_some_and(|r| r.is_empty()));
+}
+
+#[test]
+fn is_full()
+{
+    use sailce_data_model::Timestamp;
+
+    assert!(Range::<u8>::default().is_full());
+    assert!(Range::from(0_u8 ..).is_full());
+    assert!(!Range::from(1_u8 ..).is_full());
+    assert!(!Range::from(0 .. u8::MAX).is_full());
+    assert!(Range::from(i64::MIN ..).is_full());
+    assert!(Range::<Timestamp>::from(0 ..).is_full());
+    assert!(!Range::<Timestamp>::from(0 .. u64::MAX).is_full());
+    assert!(!Range::<Timestamp>::empty().is_full());
+    // The union of adjacent ranges can be full.
+    let halves = Range::from(0_u8 .. 128).union(Range::from(128 ..));
+    assert!(halves.is_some_and(|r| r.is_full()));
+}
+
+#[test]
+fn from_std_ranges()
+{
+    use sailce_data_model::Timestamp;
+
+    fn closed<T>(
+        start: T,
+        end: T,
+    ) -> Range<T>
+    {
+        Range { start, end: End::Closed(end) }
+    }
+    fn open<T>(start: T) -> Range<T>
+    {
+        Range { start, end: End::Open }
+    }
+
+    assert_eq!(Range::from(3 ..= 5), closed(3, 6));
+    assert_eq!(Range::from(5 ..= 5), closed(5, 6));
+    assert!(Range::from(5 ..= 4).is_empty());
+    // The greatest has no successor, so the range is open, which includes the same.
+    assert_eq!(Range::from(3 ..= u8::MAX), open(3));
+    assert!(Range::from(3 ..= u8::MAX).includes(u8::MAX));
+    assert_eq!(Range::from(.. 5_u8), closed(0, 5));
+    assert_eq!(Range::from(.. i8::MIN), closed(i8::MIN, i8::MIN));
+    assert_eq!(Range::<i16>::from(..), open(i16::MIN));
+    assert!(Range::<u8>::from(..).is_full());
+
+    let ts = Timestamp::from;
+    assert_eq!(Range::<Timestamp>::from(3 ..= 5), closed(ts(3), ts(6)));
+    assert_eq!(Range::<Timestamp>::from(3 ..= u64::MAX), open(ts(3)));
+    assert_eq!(Range::<Timestamp>::from(.. 5), closed(ts(0), ts(5)));
+    assert_eq!(Range::<Timestamp>::from(..), Range::from(0 ..));
+    assert_eq!(Range::from(ts(1) ..= ts(2)), Range::<Timestamp>::from(1 .. 3));
+}
+
+#[test]
+fn map()
+{
+    use sailce_data_model::Timestamp;
+
+    assert_eq!(Range::<u64>::from(1 .. 3).map(Timestamp::from), Range::<Timestamp>::from(1 .. 3));
+    assert_eq!(Range::<u64>::from(1 ..).map(Timestamp::from), Range::<Timestamp>::from(1 ..));
+    assert_eq!(Range::from(1_u8 .. 3).map(i16::from).map(|v| -v), Range {
+        start: -1,
+        end:   End::Closed(-3),
+    });
+    // Strictly monotonic, so the same values are included.
+    let chars = Range::from('a' .. 'c').map(String::from);
+    assert!(chars.includes("b".to_owned()));
+    assert!(!chars.includes("c".to_owned()));
+    // Not monotonic, so the result is empty although `self` isn't.
+    assert!(Range::from(1 .. 3).map(|v: i32| -v).is_empty());
+}
+
+
 mod three_dim;
 
 mod least;
+
+mod successor;
@@ -28,6 +28,40 @@ fn three_dim()
 }
 
 
+#[test]
+fn values()
+{
+    assert_eq!(u8::least(), 0);
+    assert_eq!(u128::least(), 0);
+    assert_eq!(i64::least(), i64::MIN);
+    assert_eq!(char::least(), '\0');
+    assert_eq!(<&str>::least(), "");
+    assert_eq!(<&[u8]>::least(), b"");
+    assert_eq!(sailce_data_model::Timestamp::least(), 0.into());
+    assert!(Range::<&[u8]>::least().is_empty());
+}
+
+
+#[cfg(feature = "alloc")]
+#[test]
+fn owned()
+{
+    use std::{
+        rc::Rc,
+        sync::Arc,
+    };
+
+    assert_eq!(Vec::<u8>::least(), Vec::<u8>::new());
+    assert_eq!(Box::<[u8]>::least(), Box::from([]));
+    assert_eq!(Rc::<[u8]>::least(), Rc::from([]));
+    assert_eq!(Arc::<str>::least(), Arc::from(""));
+    assert_eq!(Box::<str>::least(), Box::from(""));
+    assert_eq!(Rc::<str>::least(), Rc::from(""));
+    assert!(Range::<Arc<str>>::least() < (Arc::from("") .. Arc::from("\0")).into());
+    assert!(Range::<Vec<u8>>::least() < (vec![] .. vec![0]).into());
+}
+
+
 #[cfg(feature = "alloc")]
 #[test]
 fn string()
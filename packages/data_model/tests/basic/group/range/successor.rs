@@ -0,0 +1,40 @@
+use sailce_data_model::{
+    group::range::Successor as _,
+    Timestamp,
+};
+
+
+#[test]
+fn integers()
+{
+    assert_eq!(0_u8.successor(), Some(1));
+    assert_eq!(u8::MAX.successor(), None);
+    assert_eq!((-1_i64).successor(), Some(0));
+    assert_eq!(i128::MAX.successor(), None);
+    assert_eq!(Timestamp::from(41).successor(), Some(Timestamp::from(42)));
+    assert_eq!(Timestamp::from(u64::MAX).successor(), None);
+}
+
+
+#[test]
+fn array()
+{
+    assert_eq!([0_u8, 0].successor(), Some([0, 1]));
+    assert_eq!([0_u8, u8::MAX].successor(), Some([1, 0]));
+    assert_eq!([3_u8, u8::MAX, u8::MAX].successor(), Some([4, 0, 0]));
+    assert_eq!([u8::MAX, u8::MAX].successor(), None);
+    assert_eq!([i8::MAX, i8::MAX].successor(), None);
+    assert_eq!([i8::MIN, i8::MAX].successor(), Some([i8::MIN + 1, i8::MIN]));
+    assert_eq!(<[u32; 0]>::successor(&[]), None);
+}
+
+
+#[cfg(feature = "alloc")]
+#[test]
+fn strings()
+{
+    assert_eq!(String::new().successor(), Some("\0".to_owned()));
+    assert_eq!("a".to_owned().successor(), Some("a\0".to_owned()));
+    assert_eq!(vec![1_u8, 2].successor(), Some(vec![1, 2, 0]));
+    assert!(vec![1_u8].successor().is_some_and(|s| vec![1_u8] < s && s < vec![1_u8, 0, 0]));
+}
@@ -0,0 +1,99 @@
+use {
+    sailce_data_model::{
+        group::{
+            area::{
+                of_interest::Max,
+                Subspace,
+            },
+            range::End,
+            Area,
+            AreaOfInterest,
+            Range,
+        },
+        path::Extra as _,
+        Timestamp,
+    },
+    std::num::NonZeroU64,
+};
+
+
+type OwnedArea = Area<String, Vec<Box<[u8]>>>;
+
+
+#[test]
+fn range()
+{
+    let closed: Range<Timestamp> = (1 .. 2).into();
+    let json = serde_json::to_string(&closed).unwrap();
+    assert_eq!(json, r#"{"start":1,"end":{"Closed":2}}"#);
+    assert_eq!(serde_json::from_str::<Range<Timestamp>>(&json).unwrap(), closed);
+
+    let open: Range<Timestamp> = (3 ..).into();
+    let open_json = serde_json::to_string(&open).unwrap();
+    assert_eq!(open_json, r#"{"start":3,"end":"Open"}"#);
+    assert_eq!(serde_json::from_str::<Range<Timestamp>>(&open_json).unwrap(), open);
+
+    assert_eq!(serde_json::to_string(&End::Closed('z')).unwrap(), r#"{"Closed":"z"}"#);
+}
+
+#[test]
+fn max()
+{
+    let limit = Max::Limit(NonZeroU64::new(5).unwrap());
+    assert_eq!(serde_json::to_string(&limit).unwrap(), r#"{"Limit":5}"#);
+    assert_eq!(serde_json::from_str::<Max>(r#"{"Limit":5}"#).unwrap(), limit);
+    assert_eq!(serde_json::to_string(&Max::Unlimited).unwrap(), r#""Unlimited""#);
+    assert_eq!(serde_json::from_str::<Max>(r#""Unlimited""#).unwrap(), Max::Unlimited);
+    // Zero isn't a valid limit.
+    assert!(serde_json::from_str::<Max>(r#"{"Limit":0}"#).ok().is_none());
+}
+
+#[test]
+fn area()
+{
+    let with_strs: Area<&str, &[&str]> =
+        Area { subspace: Subspace::Id("alfie"), path: &["a", "b"], times: (0 .. 10).into() };
+    let with_vecs: Area<&str, Vec<Vec<u8>>> = Area {
+        subspace: Subspace::Id("alfie"),
+        path:     vec![b"a".to_vec(), b"b".to_vec()],
+        times:    (0 .. 10).into(),
+    };
+
+    let json = serde_json::to_string(&with_strs).unwrap();
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"subspace":{"Id":"alfie"},"path":[[97],[98]],"#,
+            r#""times":{"start":0,"end":{"Closed":10}}}"#
+        )
+    );
+    assert_eq!(serde_json::to_string(&with_vecs).unwrap(), json);
+
+    let owned: OwnedArea = serde_json::from_str(&json).unwrap();
+    assert_eq!(owned.subspace, Subspace::Id("alfie".to_owned()));
+    assert!(owned.path.eq_components(&with_strs.path));
+    assert_eq!(owned.times, with_strs.times);
+
+    let full: Area<&str, [&str; 0]> = Area::full();
+    let full_json = serde_json::to_string(&full).unwrap();
+    assert_eq!(full_json, r#"{"subspace":"Any","path":[],"times":{"start":0,"end":"Open"}}"#);
+    let owned_full: OwnedArea = serde_json::from_str(&full_json).unwrap();
+    assert_eq!(owned_full.subspace, Subspace::Any);
+    assert!(owned_full.path.is_empty());
+}
+
+#[test]
+fn area_of_interest()
+{
+    let aoi = AreaOfInterest {
+        area:      Area { subspace: Subspace::<&str>::Any, path: ["x"], times: (5 ..).into() },
+        max_count: Max::Limit(NonZeroU64::new(1).unwrap()),
+        max_size:  Max::Unlimited,
+    };
+    let json = serde_json::to_string(&aoi).unwrap();
+    let owned: AreaOfInterest<String, Vec<Box<[u8]>>> = serde_json::from_str(&json).unwrap();
+    assert!(owned.area.path.eq_components(&aoi.area.path));
+    assert_eq!(owned.area.subspace, Subspace::Any);
+    assert_eq!(owned.area.times, aoi.area.times);
+    assert_eq!((owned.max_count, owned.max_size), (aoi.max_count, aoi.max_size));
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "alloc")]
+
+use {
+    crate::payload::InMem,
+    sailce_data_model::{
+        payload::SeekFrom,
+        Entry,
+        Params as _,
+        Payload as _,
+        Timestamp,
+    },
+    std::num::NonZeroUsize,
+};
+
+
+/// The digest is all the bytes, to check that they're all given in order.
+struct Params;
+
+sailce_data_model::impl_params! {
+    Params {
+        NamespaceId = u8,
+        SubspaceId = char,
+        PayloadDigest = Vec<u8>,
+        AuthorisationToken = [char; 2],
+        MAX_COMPONENT_LENGTH = 10,
+        MAX_COMPONENT_COUNT = 2 * 3,
+        MAX_PATH_LENGTH = usize::MAX,
+        hash_payload = {
+            init: Vec::new(),
+            update: |all, bytes| all.extend_from_slice(bytes),
+            finish: |all| all,
+        },
+        is_authorised_write = |entry, auth_token| {
+            entry.namespace_id == 1 && auth_token.contains(&entry.subspace_id)
+        },
+    }
+}
+
+
+#[test]
+fn limits()
+{
+    assert_eq!(Params::MAX_COMPONENT_LENGTH, NonZeroUsize::new(10).unwrap());
+    assert_eq!(Params::MAX_COMPONENT_COUNT, NonZeroUsize::new(6).unwrap());
+    assert_eq!(Params::MAX_PATH_LENGTH, NonZeroUsize::MAX);
+    // Greater than the other limits could total, which is allowed.
+    assert_eq!(Params::check_limits(), Ok(()));
+}
+
+#[test]
+fn hash_payload()
+{
+    let hash = |payload: &mut InMem| pollster::block_on(Params::hash_payload(payload)).unwrap();
+
+    let bytes = (0 ..= u8::MAX).cycle().take(10_000).collect::<Vec<_>>();
+    let mut payload = InMem::new(&bytes).unwrap();
+    assert_eq!(hash(&mut payload), bytes);
+    // Regardless of the current position.
+    let _: u64 = pollster::block_on(payload.seek(SeekFrom::Start(1234))).unwrap();
+    assert_eq!(hash(&mut payload), bytes);
+
+    assert_eq!(hash(&mut InMem::new([]).unwrap()), Vec::<u8>::new());
+    assert_eq!(pollster::block_on(Params::empty_payload_digest()), Ok(vec![]));
+}
+
+#[test]
+fn is_authorised_write()
+{
+    let entry = Entry {
+        namespace_id:   1,
+        subspace_id:    'a',
+        path:           ["x"],
+        timestamp:      Timestamp::from(0),
+        payload_digest: vec![],
+        payload_length: 0,
+    };
+    assert!(Params::is_authorised_write(&entry, &['a', 'b']));
+    assert!(!Params::is_authorised_write(&entry, &['b', 'c']));
+    assert!(!Params::is_authorised_write(&Entry { namespace_id: 2, ..entry }, &['a', 'b']));
+}
@@ -14,14 +14,21 @@ mod group
 {
     mod range;
     mod area;
+    mod encoding;
+    #[cfg(feature = "serde")]
+    mod serialization;
 }
 
+mod impl_params;
+
 mod path;
 
 mod payload;
 
 mod store;
 
+mod timestamp;
+
 mod async_help;
 
 /// Until [`Option::unwrap`] as `const` becomes stabilized (if ever).
@@ -124,7 +124,14 @@ fn types()
 fn from_path()
 {
     use {
-        sailce_data_model::path::Extra as _,
+        sailce_data_model::{
+            path::{
+                Extra as _,
+                StrComponent,
+                Strish,
+            },
+            Path as _,
+        },
         std::sync::Arc,
     };
 
@@ -134,13 +141,870 @@ fn from_path()
     let p2: Arc<[Arc<[u8]>]> = Arc::from_path(&["a", "b"][..]);
     assert!(p2.eq_components(&["a", "b"]));
 
-    // TODO: try_from_path
+    let tried1: Result<Vec<[u8; 1]>, _> = Vec::try_from_path(&["a", "b"]);
+    assert_eq!(tried1.ok(), Some(vec![*b"a", *b"b"]));
+    let tried2: Result<Arc<[[u8; 1]]>, _> = Arc::try_from_path(&["a", "bb", "c"]);
+    assert!(tried2.ok().is_none());
+
+    // The first error short-circuits, so none after it are converted.
+    {
+        use std::sync::atomic::{
+            AtomicUsize,
+            Ordering::Relaxed,
+        };
+
+        static CONVERTED: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Debug)]
+        struct NonEmpty(Vec<u8>);
+
+        impl AsRef<[u8]> for NonEmpty
+        {
+            fn as_ref(&self) -> &[u8]
+            {
+                &self.0
+            }
+        }
+
+        impl TryFrom<&[u8]> for NonEmpty
+        {
+            type Error = &'static str;
+
+            fn try_from(bytes: &[u8]) -> Result<Self, Self::Error>
+            {
+                let _previous = CONVERTED.fetch_add(1, Relaxed);
+                if bytes.is_empty() { Err("empty") } else { Ok(Self(bytes.to_vec())) }
+            }
+        }
+
+        let tried3: Result<Vec<NonEmpty>, _> = Vec::try_from_path(&["a", "", "b", ""]);
+        assert_eq!(tried3.unwrap_err(), "empty");
+        assert_eq!(CONVERTED.swap(0, Relaxed), 2);
+        let tried4: Result<Vec<NonEmpty>, _> = Vec::try_from_path(&["a", "b"]);
+        assert!(tried4.unwrap().eq_components(&["a", "b"]));
+        assert_eq!(CONVERTED.load(Relaxed), 2);
+    }
+
+    let bytes: [&[u8]; 2] = [b"a", b"bb"];
+    let p3: Vec<Strish<String>> = Vec::try_from_path(&bytes).unwrap();
+    assert_eq!(p3.iter().map(|s| s.0.as_str()).collect::<Vec<_>>(), ["a", "bb"]);
+    assert!(p3.eq_components(&bytes));
+
+    let p4: Vec<StrComponent<&str>> = Vec::try_from_path(&bytes).unwrap();
+    assert_eq!(p4.iter().map(StrComponent::str).collect::<Vec<_>>(), ["a", "bb"]);
+    assert!(p4.eq_components(&p3));
+
+    let invalid: [&[u8]; 3] = [b"a", b"b\xFF", b"\xFE"];
+    let e1 = Vec::<Strish<String>>::try_from_path(&invalid).unwrap_err();
+    assert_eq!((e1.valid_up_to(), e1.error_len()), (1, Some(1)));
+    let e2 = Vec::<StrComponent<&str>>::try_from_path(&invalid).unwrap_err();
+    assert_eq!(e2, e1);
+
+    assert_eq!(Strish::<&str>::try_from(&b"ok"[..]).map(|s| s.0), Ok("ok"));
+    assert_eq!(Strish::<&str>::try_from(&b"\xC0"[..]).unwrap_err().valid_up_to(), 0);
+    assert_eq!(StrComponent::try_from(&b"ok"[..]).map(|s| s.str().len()), Ok(2));
+
+    // Each component is validated separately, as it's reached.
+    let mut validated = invalid.str_components();
+    assert!(validated.next().is_some_and(|r| r.is_ok_and(|s| s.str() == "a")));
+    assert!(validated.next().is_some_and(|r| r.is_err()));
+    assert!(validated.next().is_some_and(|r| r.is_err()));
+    assert!(validated.next().is_none());
+}
+
+
+#[test]
+fn ancestors()
+{
+    use sailce_data_model::{
+        path::Extra as _,
+        Path as _,
+    };
+
+    let path = ["a", "b", "c"];
+    let expected: [&[&str]; 4] = [&[], &["a"], &["a", "b"], &["a", "b", "c"]];
+    assert_eq!(path.ancestors().count(), expected.len());
+    assert!(path.ancestors().zip(expected).all(|(ancestor, exp)| ancestor.eq_components(exp)));
+    assert!(path.ancestors().all(|ancestor| ancestor.is_prefix_of(&path)));
+
+    let empty: [&str; 0] = [];
+    assert_eq!(empty.ancestors().count(), 1);
+    assert!(empty.ancestors().all(|ancestor| ancestor.eq_components(&empty)));
+}
+
+#[test]
+fn prefixes()
+{
+    use sailce_data_model::{
+        path::Extra as _,
+        Path as _,
+    };
+
+    let path = ["a", "b", "c"];
+    let expected: [&[&str]; 4] = [&[], &["a"], &["a", "b"], &["a", "b", "c"]];
+    assert_eq!(path.prefixes().len(), expected.len());
+    assert!(path.prefixes().zip(expected).all(|(prefix, exp)| prefix.eq_components(exp)));
+    assert!(path.prefixes().all(|prefix| prefix.is_prefix_of(&path)));
+    assert!(path.prefixes().enumerate().all(|(i, prefix)| prefix.len() == i));
+    assert!(path.prefixes().zip(path.ancestors()).all(|(p, a)| p.eq_components(&a)));
+    // The strict prefixes.
+    assert_eq!(path.prefixes().take(path.len()).last().map(|p| p.len()), Some(2));
+
+    let empty: [&str; 0] = [];
+    assert_eq!(empty.prefixes().len(), 1);
+    assert!(empty.prefixes().all(|prefix| prefix.eq_components(&empty)));
+}
+
+#[test]
+fn len_and_is_empty()
+{
+    use {
+        sailce_data_model::path::{
+            Extra,
+            Prefix,
+        },
+        std::{
+            borrow::Cow,
+            collections::VecDeque,
+        },
+    };
+
+    // Called via the trait, to avoid any inherent methods of the same names.
+    fn check(
+        path: &(impl Extra + ?Sized),
+        expected: usize,
+    )
+    {
+        assert_eq!(Extra::len(path), expected);
+        assert_eq!(Extra::is_empty(path), expected == 0);
+        assert_eq!(Extra::len(path), path.components().count());
+    }
+
+    check(&["a", "b", "c"], 3);
+    check(&[""], 1);
+    check(&[""; 0], 0);
+    check(&[&b"x"[..], b"y"][..], 2);
+    check(&vec![vec![1_u8], vec![], vec![2, 3]], 3);
+    check(&Vec::<Vec<u8>>::new(), 0);
+    check(&Cow::<[&str]>::Borrowed(&["a"]), 1);
+    check(&Cow::<[&str]>::Owned(vec![]), 0);
+    check(&VecDeque::from(["a", "b"]), 2);
+    check(&Prefix::new(&["a", "b", "c"], 2), 2);
+    check(&Prefix::new(&["a", "b", "c"], 0), 0);
+}
+
+#[test]
+fn last_component_and_parent()
+{
+    use sailce_data_model::{
+        path::{
+            Component,
+            Extra as _,
+        },
+        EmptyPath as _,
+        Path as _,
+    };
+
+    let path = ["a", "b", "c"];
+    assert_eq!(path.last_component(), Some(Component::new(&b"c"[..])));
+    assert!(path.parent().unwrap().eq_components(&["a", "b"]));
+    assert!(path.parent().unwrap().is_prefix_of(&path));
+    assert!(path.parent().unwrap().parent().unwrap().parent().unwrap().is_empty());
+    assert!(path.parent().unwrap().parent().unwrap().parent().unwrap().parent().is_none());
+    assert_eq!(path.parent().unwrap().last_component(), Some(Component::new(&b"b"[..])));
+
+    assert_eq!([""].last_component(), Some(Component::new(&b""[..])));
+    assert!([""].parent().is_some_and(|parent| parent.is_empty()));
+
+    let empty = Vec::<Vec<u8>>::empty();
+    assert_eq!(empty.last_component(), None);
+    assert!(empty.parent().is_none());
+}
+
+#[test]
+fn components_rev()
+{
+    use sailce_data_model::{
+        path::Extra as _,
+        Path as _,
+    };
+
+    let path = ["a", "b", "c"];
+    let mut rev = path.components_rev();
+    assert_eq!(rev.len(), 3);
+    assert!(rev.next().is_some_and(|c| c.bytes() == b"c"));
+    assert_eq!(rev.len(), 2);
+    assert!(rev.next().is_some_and(|c| c.bytes() == b"b"));
+    assert!(rev.next().is_some_and(|c| c.bytes() == b"a"));
+    assert_eq!(rev.len(), 0);
+    assert!(rev.next().is_none());
+    assert!(path.components_rev().eq(path.components().collect::<Vec<_>>().into_iter().rev()));
+
+    let empty: [&str; 0] = [];
+    assert_eq!(empty.components_rev().len(), 0);
+    assert!(empty.components_rev().next().is_none());
+}
+
+#[test]
+fn concat()
+{
+    use sailce_data_model::{
+        path::Extra as _,
+        Path as _,
+    };
+
+    let base = ["a", "b"];
+    let suffix = ["c"];
+    let empty: [&str; 0] = [];
+
+    let both = base.concat(&suffix);
+    assert_eq!(both.len(), 3);
+    assert!(both.eq_components(&["a", "b", "c"]));
+    let mut components = both.components();
+    assert_eq!(components.len(), 3);
+    assert!(components.next().is_some_and(|c| c.bytes() == b"a"));
+    assert_eq!(components.len(), 2);
+    assert!(components.nth(1).is_some_and(|c| c.bytes() == b"c"));
+    assert_eq!(components.len(), 0);
+    assert!(components.next().is_none());
+    assert!(base.is_prefix_of(&both));
+    assert!(!suffix.is_prefix_of(&both));
+
+    assert!(base.concat(&empty).eq_components(&base));
+    assert!(empty.concat(&base).eq_components(&base));
+    assert_eq!(empty.concat(&empty).len(), 0);
+    assert!(empty.concat(&empty).is_empty());
+    // Can be nested.
+    assert!(both.concat(&both).eq_components(&["a", "b", "c", "a", "b", "c"]));
+    assert_eq!(base.concat(&both.concat(&suffix)).len(), 6);
+
+    #[cfg(feature = "alloc")]
+    {
+        let owned: Vec<Vec<u8>> = base.concat_into(&suffix);
+        assert_eq!(owned, [b"a".to_vec(), b"b".to_vec(), b"c".to_vec()]);
+        let owned_empty: Vec<Vec<u8>> = empty.concat_into(&empty);
+        assert!(owned_empty.is_empty());
+        let from_concat: Vec<Vec<u8>> = Vec::from_path(&both);
+        assert_eq!(from_concat, owned);
+    }
+}
+
+#[test]
+fn longest_common_prefix()
+{
+    use sailce_data_model::path::longest_common_prefix_len;
+
+    let empty: [&[&str]; 0] = [];
+    assert_eq!(longest_common_prefix_len(empty), 0);
+    assert_eq!(longest_common_prefix_len([&["a", "b"][..]]), 2);
+    assert_eq!(longest_common_prefix_len([&["a", "b"][..], &["a", "b"]]), 2);
+    assert_eq!(longest_common_prefix_len([&["a", "b"][..], &["a", "c"]]), 1);
+    let three: [&[&str]; 3] = [&["a", "b", "c"], &["a", "b"], &["a", "b", "d"]];
+    assert_eq!(longest_common_prefix_len(three), 2);
+    assert_eq!(longest_common_prefix_len([&["a", "b"][..], &["a", "b", "c"], &["b"]]), 0);
+    assert_eq!(longest_common_prefix_len([&["a"][..], &[]]), 0);
+    // By bytes, not by prefixes of bytes.
+    assert_eq!(longest_common_prefix_len([&["ab"][..], &["a"]]), 0);
+    assert_eq!(longest_common_prefix_len([&["", "x"][..], &["", "y"]]), 1);
+
+    #[cfg(feature = "alloc")]
+    {
+        use sailce_data_model::path::{
+            longest_common_prefix,
+            Extra as _,
+        };
+
+        let empty_paths: Vec<Vec<&str>> = vec![];
+        assert!(longest_common_prefix(&empty_paths).is_empty());
+        let paths = [vec!["x", "y", "z"], vec!["x", "y"], vec!["x", "y", "w"]];
+        assert!(longest_common_prefix(&paths).eq_components(&["x", "y"]));
+        assert!(longest_common_prefix(&paths[.. 1]).eq_components(&paths[0]));
+        assert!(longest_common_prefix(&[vec!["a"], vec!["b"]]).is_empty());
+    }
+}
+
+#[test]
+fn component_trim()
+{
+    use sailce_data_model::path::Component;
+
+    let component = Component { inner: &b"@ns:name.txt"[..] };
+    assert_eq!(component.trim_prefix(b"@ns:").bytes(), b"name.txt");
+    assert_eq!(component.trim_suffix(b".txt").bytes(), b"@ns:name");
+    assert_eq!(component.trim_prefix(b"@ns:").trim_suffix(b".txt").bytes(), b"name");
+    // Absent, so unchanged.
+    assert_eq!(component.trim_prefix(b"ns:").bytes(), b"@ns:name.txt");
+    assert_eq!(component.trim_suffix(b".tx").bytes(), b"@ns:name.txt");
+    assert_eq!(component.trim_prefix(b"@ns:name.txt!").bytes(), b"@ns:name.txt");
+    // No-op.
+    assert_eq!(component.trim_prefix(b"").bytes(), b"@ns:name.txt");
+    assert_eq!(component.trim_suffix(b"").bytes(), b"@ns:name.txt");
+    // All of it.
+    assert!(component.trim_prefix(b"@ns:name.txt").bytes().is_empty());
+    assert!(component.trim_suffix(b"@ns:name.txt").bytes().is_empty());
+    // Owned bytes, borrowed from.
+    let owned = Component { inner: b"xyx".to_vec() };
+    assert_eq!(owned.trim_prefix(b"x"), Component { inner: &b"yx"[..] });
+    assert_eq!(owned.trim_suffix(b"x"), Component { inner: &b"xy"[..] });
+}
+
+#[test]
+fn eq_ignoring_trailing_empty()
+{
+    use sailce_data_model::path::Extra as _;
+
+    assert!(["a"].eq_ignoring_trailing_empty(&["a", ""]));
+    assert!(!["a"].eq_components(&["a", ""]));
+    assert!(["a", "", ""].eq_ignoring_trailing_empty(&["a"]));
+    assert!(["a", ""].eq_ignoring_trailing_empty(&["a", "", ""]));
+    assert!([""; 0].eq_ignoring_trailing_empty(&["", ""]));
+    assert!(["", "a"].eq_ignoring_trailing_empty(&["", "a", ""]));
+    assert!(!["", "a"].eq_ignoring_trailing_empty(&["a"]));
+    assert!(!["a", "", "b"].eq_ignoring_trailing_empty(&["a", "b"]));
+    assert!(!["a"].eq_ignoring_trailing_empty(&["b", ""]));
+}
+
+
+#[test]
+fn eq_and_cmp()
+{
+    use {
+        sailce_data_model::path::Extra,
+        std::cmp::Ordering,
+    };
+
+    let strs: &[&str] = &["a", "b"];
+    let vecs: Vec<Vec<u8>> = vec![b"a".to_vec(), b"b".to_vec()];
+    let arrays = [*b"a", *b"c"];
+    let empty: [&str; 0] = [];
+
+    assert!(Extra::eq(strs, &vecs));
+    assert!(Extra::eq(&vecs, strs));
+    assert!(!Extra::eq(strs, &arrays));
+    assert!(!Extra::eq(strs, &["a"]));
+    assert!(!Extra::eq(strs, &["ab"]));
+    assert!(Extra::eq(&empty, &Vec::<Vec<u8>>::new()));
+    // Unlike `eq_ignoring_trailing_empty`.
+    assert!(!Extra::eq(strs, &["a", "b", ""]));
 
-    // TODO: w/ path::Strish helper wrapper type that can TryFrom<&[u8]>
+    assert_eq!(Extra::cmp(strs, &vecs), Ordering::Equal);
+    assert_eq!(Extra::cmp(strs, &arrays), Ordering::Less);
+    assert_eq!(Extra::cmp(&arrays, &vecs), Ordering::Greater);
+    // A prefix is less than what it prefixes.
+    assert_eq!(Extra::cmp(&["a"], &vecs), Ordering::Less);
+    assert_eq!(Extra::cmp(&empty, strs), Ordering::Less);
+    assert_eq!(Extra::cmp(&vecs, &["a", "b", ""]), Ordering::Less);
+    // By the bytes of components, not of the concatenation.
+    assert_eq!(Extra::cmp(&["ab"], strs), Ordering::Greater);
+
+    // Same as the lower-level names.
+    for (x, y) in [(strs, strs), (strs, &["a"][..]), (&["b"][..], strs)] {
+        assert_eq!(Extra::eq(x, y), x.eq_components(y));
+        assert_eq!(Extra::cmp(x, y), x.cmp_components(y));
+    }
 }
 
 
-// TODO: path::Extra::eq and path::Extra::cmp
+mod limits
+{
+    use {
+        crate::nz_usize,
+        sailce_data_model::{
+            path::{
+                component_is_valid,
+                max_component_len,
+                Extra as _,
+                PathLimitError,
+            },
+            Params,
+            ParamsEntry,
+            Path,
+            Payload,
+        },
+        std::{
+            convert::Infallible,
+            num::NonZeroUsize,
+        },
+    };
+
+
+    /// Has small limits, to be easy to test against.
+    pub(crate) struct SmallParams;
+
+    impl Params for SmallParams
+    {
+        type AuthorisationToken = ();
+        type HashPayloadError<P: Payload + ?Sized> = Infallible;
+        type NamespaceId = ();
+        type PayloadDigest = ();
+        type SubspaceId = ();
+
+        const MAX_COMPONENT_COUNT: NonZeroUsize = nz_usize(3);
+        const MAX_COMPONENT_LENGTH: NonZeroUsize = nz_usize(4);
+        const MAX_PATH_LENGTH: NonZeroUsize = nz_usize(10);
+
+        async fn hash_payload<P: Payload + ?Sized>(
+            _payload: &mut P
+        ) -> Result<Self::PayloadDigest, Self::HashPayloadError<P>>
+        {
+            Ok(())
+        }
+
+        fn is_authorised_write(
+            _entry: &ParamsEntry<Self, impl Path>,
+            _auth_token: &Self::AuthorisationToken,
+        ) -> bool
+        {
+            true
+        }
+    }
+
+
+    /// Shorthand, with the limits of `SmallParams`.
+    pub(crate) fn too_many(count: usize) -> PathLimitError
+    {
+        PathLimitError::TooManyComponents { count, max: 3 }
+    }
+
+    /// Shorthand, with the limits of `SmallParams`.
+    pub(crate) fn too_long(
+        index: usize,
+        length: usize,
+    ) -> PathLimitError
+    {
+        PathLimitError::ComponentTooLong { index, length, max: 4 }
+    }
+
+    /// Shorthand, with the limits of `SmallParams`.
+    pub(crate) fn total_too_long(length: usize) -> PathLimitError
+    {
+        PathLimitError::TotalTooLong { length, max: 10 }
+    }
+
+
+    #[test]
+    fn component()
+    {
+        assert_eq!(max_component_len::<SmallParams>(), 4);
+
+        assert_eq!(component_is_valid::<SmallParams>(b""), Ok(()));
+        assert_eq!(component_is_valid::<SmallParams>(b"abcd"), Ok(()));
+        assert_eq!(
+            component_is_valid::<SmallParams>(b"abcde"),
+            Err(too_long(0, 5))
+        );
+        assert_eq!(
+            component_is_valid::<SmallParams>(b"abcdefghijk"),
+            Err(too_long(0, 11))
+        );
+    }
+
+
+    #[test]
+    fn component_new()
+    {
+        use sailce_data_model::path::Component;
+
+        let component = Component::new(&b"abc"[..]);
+        assert_eq!(component, Component { inner: &b"abc"[..] });
+        assert_eq!(component.len(), 3);
+        assert!(!component.is_empty());
+        assert!(Component::new(Vec::new()).is_empty());
+        // Not checked.
+        assert_eq!(Component::new(&b"abcde"[..]).len(), 5);
+
+        let try_new = Component::try_new::<SmallParams>;
+        assert_eq!(try_new(&b"abcd"[..]), Ok(Component::new(&b"abcd"[..])));
+        assert_eq!(try_new(&b""[..]), Ok(Component::new(&b""[..])));
+        assert_eq!(try_new(&b"abcde"[..]), Err(too_long(0, 5)));
+    }
+
+
+    #[test]
+    fn validate()
+    {
+        fn validate(path: &[&str]) -> Result<(), PathLimitError>
+        {
+            path.validate::<SmallParams>()
+        }
+
+        assert_eq!(validate(&[]), Ok(()));
+        assert_eq!(validate(&["abcd", "ef", "ghij"]), Ok(()));
+        assert_eq!(validate(&["a", "abcde"]), Err(too_long(1, 5)));
+        assert_eq!(validate(&["a", "b", "c", "d"]), Err(too_many(4)));
+        assert_eq!(validate(&["abcd", "abcd", "abc"]), Err(total_too_long(11)));
+        // The amount takes precedence, and otherwise the first `Component` at which any limit is
+        // exceeded, with its own length before the total.
+        assert_eq!(validate(&["abcde", "a", "b", "c"]), Err(too_many(4)));
+        assert_eq!(validate(&["abcd", "abcde", "abcd"]), Err(too_long(1, 5)));
+        assert_eq!(validate(&["abcd", "abcd", "abcde"]), Err(too_long(2, 5)));
+    }
+
+
+    #[test]
+    fn from_path_limited()
+    {
+        fn convert<'l>(path: &'l [&str]) -> Result<Vec<&'l [u8]>, PathLimitError>
+        {
+            Vec::from_path_limited::<SmallParams, _, _>(path)
+        }
+
+        assert_eq!(convert(&["a", "b", "c"]), Ok(vec![&b"a"[..], b"b", b"c"]));
+        assert_eq!(convert(&["a", "b", "c", "d"]), Err(too_many(4)));
+        assert_eq!(convert(&["a", "abcde"]), Err(too_long(1, 5)));
+        assert_eq!(convert(&["abcd", "abcd", "abc"]), Err(total_too_long(11)));
+    }
+
+
+    #[test]
+    fn check_limits()
+    {
+        assert_eq!(SmallParams::check_limits(), Ok(()));
+    }
+
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn check_limits_inconsistent()
+    {
+        use sailce_data_model::path::ParamsLimitError;
+
+        struct Inconsistent;
+
+        sailce_data_model::impl_params! {
+            Inconsistent {
+                NamespaceId = (),
+                SubspaceId = (),
+                PayloadDigest = (),
+                AuthorisationToken = (),
+                MAX_COMPONENT_LENGTH = 11,
+                MAX_COMPONENT_COUNT = 3,
+                MAX_PATH_LENGTH = 10,
+                hash_payload = { init: (), update: |_s, _b| {}, finish: |_s| () },
+                is_authorised_write = |_entry, _auth_token| true,
+            }
+        }
+
+        assert_eq!(
+            Inconsistent::check_limits(),
+            Err(ParamsLimitError::ComponentLongerThanPath { component: 11, path: 10 })
+        );
+    }
+}
+
+
+#[cfg(feature = "alloc")]
+mod encoding
+{
+    use {
+        super::limits::{
+            too_long,
+            too_many,
+            total_too_long,
+            SmallParams,
+        },
+        crate::store::Params as StoreParams,
+        sailce_data_model::path::{
+            encoding::{
+                count_width,
+                decode_path,
+                decode_path_relative,
+                encode_path,
+                encode_path_relative,
+                length_width,
+                DecodeError,
+            },
+            Extra as _,
+        },
+    };
+
+
+    fn round_trip(path: &[&str])
+    {
+        let encoded = encode_path::<SmallParams>(&path).expect("within limits");
+        let decoded = decode_path::<SmallParams>(&encoded).expect("valid encoding");
+        assert!(decoded.eq_components(&path), "{path:?}");
+    }
+
+    #[test]
+    fn widths()
+    {
+        assert_eq!(count_width::<SmallParams>(), 1);
+        assert_eq!(length_width::<SmallParams>(), 1);
+        // 128 and 512.
+        assert_eq!(count_width::<StoreParams>(), 1);
+        assert_eq!(length_width::<StoreParams>(), 2);
+    }
+
+    #[test]
+    fn encode()
+    {
+        let empty: [&str; 0] = [];
+        assert_eq!(encode_path::<SmallParams>(&empty), Ok(vec![0]));
+        assert_eq!(encode_path::<SmallParams>(&["ab", ""]), Ok(vec![2, 2, b'a', b'b', 0]));
+        assert_eq!(
+            encode_path::<SmallParams>(&["a", "b", "c", "d"]),
+            Err(too_many(4))
+        );
+        assert_eq!(encode_path::<SmallParams>(&["abcde"]), Err(too_long(0, 5)));
+        assert_eq!(
+            encode_path::<SmallParams>(&["abcd", "abcd", "abc"]),
+            Err(total_too_long(11))
+        );
+        assert_eq!(encode_path::<StoreParams>(&["a", ""]), Ok(vec![2, 0, 1, b'a', 0, 0]));
+    }
+
+    #[test]
+    fn round_trips()
+    {
+        round_trip(&[]);
+        round_trip(&[""]);
+        round_trip(&["", ""]);
+        round_trip(&["a"]);
+        round_trip(&["abcd", "ab", "cd"]);
+        round_trip(&["abcd", "abcd", "ab"]);
+    }
+
+    #[test]
+    fn decode_errors()
+    {
+        assert_eq!(decode_path::<SmallParams>(&[]), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(decode_path::<SmallParams>(&[1]), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(decode_path::<SmallParams>(&[1, 2, b'a']), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(decode_path::<SmallParams>(&[0, 0]), Err(DecodeError::TrailingBytes));
+        assert_eq!(
+            decode_path::<SmallParams>(&[1, 1, b'a', b'b']),
+            Err(DecodeError::TrailingBytes)
+        );
+        assert_eq!(
+            decode_path::<SmallParams>(&[4, 0, 0, 0, 0]),
+            Err(DecodeError::ExceedsLimits(too_many(4)))
+        );
+        assert_eq!(
+            decode_path::<SmallParams>(&[1, 5, b'a', b'b', b'c', b'd', b'e']),
+            Err(DecodeError::ExceedsLimits(too_long(0, 5)))
+        );
+        assert_eq!(
+            decode_path::<SmallParams>(b"\x03\x04aaaa\x04bbbb\x03"),
+            Err(DecodeError::ExceedsLimits(total_too_long(11)))
+        );
+        // A huge length is rejected without needing that many bytes.
+        assert_eq!(
+            decode_path::<SmallParams>(&[1, 255]),
+            Err(DecodeError::ExceedsLimits(too_long(0, 255)))
+        );
+    }
+
+    #[test]
+    fn relative()
+    {
+        let paths: [&[&str]; 8] = [
+            &[],
+            &[""],
+            &["a"],
+            &["a", "b"],
+            &["a", "c"],
+            &["a", "b", "cd"],
+            &["b", "b", "cd"],
+            &["abcd", "abcd", "ab"],
+        ];
+        for path in paths {
+            for reference in paths {
+                let encoded = encode_path_relative::<SmallParams>(&path, &reference);
+                let encoded = encoded.expect("within limits");
+                let decoded = decode_path_relative::<SmallParams>(&encoded, &reference);
+                let decoded = decoded.expect("valid encoding");
+                assert!(decoded.eq_components(&path), "{path:?} relative to {reference:?}");
+            }
+        }
+
+        assert_eq!(encode_path_relative::<SmallParams>(&["a", "b"], &["a", "b"]), Ok(vec![2, 0]));
+        assert_eq!(encode_path_relative::<SmallParams>(&["a", "b"], &["a"]), Ok(vec![
+            1, 1, 1, b'b'
+        ]));
+        assert_eq!(encode_path_relative::<SmallParams>(&["a"], &["a", "b"]), Ok(vec![1, 0]));
+        assert_eq!(encode_path_relative::<SmallParams>(&["a"], &[""]), Ok(vec![0, 1, 1, b'a']));
+        assert_eq!(
+            encode_path_relative::<SmallParams>(&["a", "b", "c", "d"], &["a", "b", "c", "d"]),
+            Err(too_many(4))
+        );
+    }
+
+    #[test]
+    fn relative_decode_errors()
+    {
+        let reference = ["a", "b"];
+        let decode = |bytes: &[u8]| decode_path_relative::<SmallParams>(bytes, &reference);
+        assert_eq!(decode(&[]), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(decode(&[1]), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(decode(&[1, 0, 0]), Err(DecodeError::TrailingBytes));
+        assert_eq!(decode(&[3, 0]), Err(DecodeError::ExceedsReference));
+        // The whole, not only the rest, must be within the limits.
+        assert_eq!(
+            decode(&[2, 2, 0, 0]),
+            Err(DecodeError::ExceedsLimits(too_many(4)))
+        );
+        assert_eq!(
+            decode_path_relative::<SmallParams>(b"\x02\x01\x03abc", &["abcd", "abcd"]),
+            Err(DecodeError::ExceedsLimits(total_too_long(11)))
+        );
+    }
+}
+
+
+#[cfg(feature = "alloc")]
+mod successor
+{
+    use {
+        super::limits::SmallParams,
+        sailce_data_model::path::Extra as _,
+    };
+
+
+    fn successor(path: &[&[u8]]) -> Option<Vec<Vec<u8>>>
+    {
+        let successor = path.successor::<SmallParams, Vec<Vec<u8>>, _>();
+        if let Some(s) = &successor {
+            assert!(s.cmp_components(&path).is_gt(), "{path:?}");
+        }
+        successor
+    }
+
+
+    #[test]
+    fn appends_empty()
+    {
+        assert_eq!(successor(&[]), Some(vec![vec![]]));
+        assert_eq!(successor(&[b""]), Some(vec![vec![], vec![]]));
+        assert_eq!(
+            successor(&[b"a", b"bcde"]),
+            Some(vec![b"a".to_vec(), b"bcde".to_vec(), vec![]])
+        );
+    }
+
+    #[test]
+    fn at_max_component_count()
+    {
+        assert_eq!(
+            successor(&[b"a", b"b", b"c"]),
+            Some(vec![b"a".to_vec(), b"b".to_vec(), b"c\0".to_vec()])
+        );
+        assert_eq!(successor(&[b"", b"", b""]), Some(vec![vec![], vec![], vec![0]]));
+        // At the max component length.
+        assert_eq!(
+            successor(&[b"a", b"b", b"cdef"]),
+            Some(vec![b"a".to_vec(), b"b".to_vec(), b"cdeg".to_vec()])
+        );
+        // Carries past the maxed bytes, by dropping them.
+        assert_eq!(
+            successor(&[b"a", b"b", b"cd\xFF\xFF"]),
+            Some(vec![b"a".to_vec(), b"b".to_vec(), b"ce".to_vec()])
+        );
+        // Overflows into the previous component.
+        assert_eq!(
+            successor(&[b"a", b"b", b"\xFF\xFF\xFF\xFF"]),
+            Some(vec![b"a".to_vec(), b"b\0".to_vec()])
+        );
+        assert_eq!(
+            successor(&[b"a", b"\xFF\xFF\xFF\xFF", b"\xFF\xFF\xFF\xFF"]),
+            Some(vec![b"a\0".to_vec()])
+        );
+    }
+
+    #[test]
+    fn at_max_path_length()
+    {
+        assert_eq!(
+            successor(&[b"abcd", b"abcd", b"ab"]),
+            Some(vec![b"abcd".to_vec(), b"abcd".to_vec(), b"ac".to_vec()])
+        );
+        // The last can't grow nor be incremented, and the previous is at the max component
+        // length.
+        assert_eq!(
+            successor(&[b"abcd", b"abcd", b"\xFF\xFF"]),
+            Some(vec![b"abcd".to_vec(), b"abce".to_vec()])
+        );
+        assert_eq!(
+            successor(&[b"abc", b"abcd", b"\xFF\xFF\xFF"]),
+            Some(vec![b"abc".to_vec(), b"abce".to_vec()])
+        );
+        // Below the max path length, so the last can grow.
+        assert_eq!(
+            successor(&[b"abc", b"abc", b"\xFF\xFF\xFF"]),
+            Some(vec![b"abc".to_vec(), b"abc".to_vec(), b"\xFF\xFF\xFF\0".to_vec()])
+        );
+    }
+
+    #[test]
+    fn none()
+    {
+        let max = b"\xFF\xFF\xFF\xFF";
+        assert_eq!(successor(&[max, max, b"\xFF\xFF"]), None);
+    }
+}
+
+
+#[cfg(feature = "alloc")]
+mod buf
+{
+    use {
+        super::limits::{
+            too_long,
+            too_many,
+            total_too_long,
+            SmallParams,
+        },
+        sailce_data_model::path::{
+            Extra as _,
+            PathBuf,
+        },
+    };
+
+
+    #[test]
+    fn push_and_pop()
+    {
+        let mut path = PathBuf::<SmallParams>::new();
+        assert!(path.is_empty());
+        assert_eq!(path.push(*b"ab"), Ok(()));
+        assert_eq!(path.push(&b""[..]), Ok(()));
+        assert_eq!(path.push(vec![b'c']), Ok(()));
+        assert!(path.eq_components(&["ab", "", "c"]));
+        assert_eq!(path.as_path().len(), 3);
+        assert_eq!(path.total_len(), 3);
+
+        assert_eq!(path.pop().as_deref(), Some(&b"c"[..]));
+        assert_eq!(path.total_len(), 2);
+        assert_eq!(path.pop().as_deref(), Some(&b""[..]));
+        assert_eq!(path.pop().as_deref(), Some(&b"ab"[..]));
+        assert_eq!(path.pop(), None);
+        assert_eq!(path.total_len(), 0);
+        assert!(path.eq_components(&PathBuf::<SmallParams>::default()));
+    }
+
+    #[test]
+    fn limits()
+    {
+        let mut path = PathBuf::<SmallParams>::new();
+        assert_eq!(path.push(*b"abcde"), Err(too_long(0, 5)));
+        assert_eq!(path.push(*b"abcd"), Ok(()));
+        assert_eq!(path.push(*b"abcd"), Ok(()));
+        // The running total is checked at the exact offending push.
+        assert_eq!(path.push(*b"abc"), Err(total_too_long(11)));
+        assert_eq!(path.total_len(), 8);
+        assert_eq!(path.push(*b"ab"), Ok(()));
+        assert_eq!(path.total_len(), 10);
+        assert_eq!(path.push(*b""), Err(too_many(4)));
+        assert_eq!(path.push(*b"a"), Err(too_many(4)));
+        // Unchanged by the errors.
+        assert!(path.clone().into_vec().eq_components(&["abcd", "abcd", "ab"]));
+
+        // Popping makes room again.
+        assert!(path.pop().is_some());
+        assert_eq!(path.push(*b"a"), Ok(()));
+        assert!(path.eq_components(&["abcd", "abcd", "a"]));
+    }
+}
 
 
 mod empty
@@ -10,7 +10,11 @@ use {
             CopyToSliceError,
             SeekFrom,
         },
-        syncify::Syncify,
+        syncify::{
+            block_on,
+            DefaultSync,
+            Syncify,
+        },
     },
     std::{
         convert::Infallible,
@@ -81,6 +85,19 @@ pub(crate) enum TooFar
     BeforeStart(u64),
 }
 
+impl std::fmt::Display for TooFar
+{
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result
+    {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl std::error::Error for TooFar {}
+
 #[allow(clippy::indexing_slicing, clippy::arithmetic_side_effects)]
 impl sailce_data_model::Payload for InMem
 {
@@ -516,6 +533,289 @@ fn to_boxed_slice()
 }
 
 
+#[test]
+fn copy_to_slice_chunked()
+{
+    use {
+        extra::sync::ExtraCore as _,
+        std::num::NonZeroUsize,
+    };
+
+    let bytes = b"abcdefghijklmnopq";
+    let mut payload = InMem::new(bytes).unwrap();
+    let mut unchunked = [0; 17];
+    assert_eq!(payload.copy_to_slice(Some(0), &mut unchunked, NONE_CALLBACK, true), Ok(()));
+
+    for chunk in [1, 2, 5, 16, 17, 100] {
+        let chunk = NonZeroUsize::new(chunk).unwrap();
+        let mut sizes = vec![];
+        let mut chunked = [0; 17];
+        assert_eq!(
+            payload.copy_to_slice_chunked(
+                Some(0),
+                &mut chunked,
+                chunk,
+                Some(|filled: &mut [u8]| sizes.push(filled.len())),
+                true
+            ),
+            Ok(())
+        );
+        assert_eq!(chunked, unchunked);
+        assert_eq!(&chunked, bytes);
+        assert!(sizes.iter().all(|&size| 1 <= size && size <= chunk.get()), "{sizes:?}");
+        assert_eq!(sizes.iter().sum::<usize>(), bytes.len());
+        assert_eq!(sizes.len(), bytes.len().div_ceil(chunk.get()));
+        assert_eq!(payload.current_position(), Ok(0));
+    }
+
+    // Errors the same.
+    let chunk = NonZeroUsize::new(4).unwrap();
+    assert_eq!(
+        payload.copy_to_slice_chunked(Some(10), &mut [0; 8], chunk, UNREACHABLE_CALLBACK, true),
+        Err(CopyToSliceError::OutOfBounds { at: Some(NonZeroU64::new(18).unwrap()) })
+    );
+}
+
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_boxed_slice_chunked()
+{
+    use {
+        extra::sync::Extra as _,
+        std::num::NonZeroUsize,
+    };
+
+    let mut payload = InMem::new(b"0123456789").unwrap();
+    let chunk = NonZeroUsize::new(3).unwrap();
+    let mut sizes = vec![];
+    assert_eq!(
+        payload.to_boxed_slice_chunked(
+            1 ..= 8,
+            chunk,
+            Some(|filled: &mut [u8]| sizes.push(filled.len())),
+            false
+        ),
+        Ok((*b"12345678").into())
+    );
+    assert_eq!(sizes, [3, 3, 2]);
+    assert_eq!(
+        payload.to_boxed_slice_chunked(0 .., chunk, NONE_CALLBACK, false),
+        payload.to_boxed_slice(0 .., NONE_CALLBACK, false)
+    );
+}
+
+
+#[cfg(feature = "alloc")]
+#[test]
+fn for_each_chunk()
+{
+    use {
+        extra::sync::{
+            Extra as _,
+            ExtraCore as _,
+        },
+        sailce_data_model::payload::ForEachChunkError,
+        std::num::NonZeroUsize,
+    };
+
+    let mut payload = InMem::new(b"0123456789").unwrap();
+    let chunk_size = NonZeroUsize::new(3).unwrap();
+    let mut chunks = vec![];
+    let mut collect = |chunk: &[u8]| chunks.push(chunk.to_vec());
+
+    assert_eq!(payload.for_each_chunk(1 ..= 8, chunk_size, &mut collect, false), Ok(()));
+    assert_eq!(payload.current_position(), Ok(9));
+    assert_eq!(payload.for_each_chunk(.., chunk_size, &mut collect, true), Ok(()));
+    assert_eq!(payload.current_position(), Ok(9));
+    assert_eq!(payload.for_each_chunk(4 .. 4, chunk_size, &mut collect, false), Ok(()));
+    assert_eq!(payload.for_each_chunk(0 .., NonZeroUsize::MIN, &mut collect, true), Ok(()));
+    assert_eq!(payload.current_position(), Ok(9));
+    assert_eq!(chunks, [
+        &b"123"[..],
+        b"456",
+        b"78",
+        b"9",
+        b"0",
+        b"1",
+        b"2",
+        b"3",
+        b"4",
+        b"5",
+        b"6",
+        b"7",
+        b"8",
+        b"9"
+    ]);
+
+    // Bounds are checked before anything is given to the function.
+    let mut unreachable = |_: &[u8]| unreachable_callback(&mut []);
+    for (range, at) in [(0 .. 11, 11), (11 .. 12, 11), (5 .. 20, 20)] {
+        assert_eq!(
+            payload.for_each_chunk(range, chunk_size, &mut unreachable, true),
+            Err(ForEachChunkError::OutOfBounds { at: NonZeroU64::new(at) })
+        );
+    }
+    assert_eq!(
+        payload.for_each_chunk(.. u64::MAX, chunk_size, &mut unreachable, true),
+        Err(ForEachChunkError::OutOfBounds { at: NonZeroU64::new(u64::MAX) })
+    );
+    assert_eq!(
+        payload.for_each_chunk(..= u64::MAX, chunk_size, &mut unreachable, true),
+        Err(ForEachChunkError::OutOfBounds { at: None })
+    );
+    assert_eq!(payload.current_position(), Ok(9));
+}
+
+
+#[cfg(feature = "alloc")]
+#[test]
+fn hash_with()
+{
+    use {
+        extra::sync::{
+            Extra as _,
+            ExtraCore as _,
+        },
+        std::{
+            collections::hash_map::DefaultHasher,
+            hash::Hasher as _,
+        },
+        sync::Payload as _,
+    };
+
+    let hash = |bytes: &[u8]| {
+        let mut hasher = DefaultHasher::new();
+        hasher.write(bytes);
+        hasher.finish()
+    };
+    // Larger than a chunk, to exercise multiple.
+    let bytes = (0 ..= u8::MAX).cycle().take(20_000).collect::<Vec<_>>();
+    let mut payload = InMem::new(&bytes).unwrap();
+    assert_eq!(payload.seek(SeekFrom::Start(5)), Ok(5));
+
+    let mut restored = DefaultHasher::new();
+    assert_eq!(payload.hash_with(&mut restored, true), Ok(20_000));
+    assert_eq!(restored.finish(), hash(&bytes));
+    assert_eq!(payload.current_position(), Ok(5));
+    let mut unrestored = DefaultHasher::new();
+    assert_eq!(payload.hash_with(&mut unrestored, false), Ok(20_000));
+    assert_eq!(unrestored.finish(), hash(&bytes));
+    assert_eq!(payload.current_position(), Ok(20_000));
+
+    let mut empty = InMem::new([]).unwrap();
+    let mut of_empty = DefaultHasher::new();
+    assert_eq!(empty.hash_with(&mut of_empty, true), Ok(0));
+    assert_eq!(of_empty.finish(), hash(&[]));
+}
+
+
+#[test]
+fn seek_clamped()
+{
+    use {
+        extra::sync::ExtraCore as _,
+        sync::Payload as _,
+    };
+
+    let mut payload = InMem::new(b"0123456789").unwrap();
+    assert_eq!(payload.seek_clamped(SeekFrom::Start(3)), Ok(3));
+    assert_eq!(payload.seek_clamped(SeekFrom::Start(11)), Ok(10));
+    assert_eq!(payload.seek_clamped(SeekFrom::Start(u64::MAX)), Ok(10));
+    assert_eq!(payload.seek_clamped(SeekFrom::End(0)), Ok(10));
+    assert_eq!(payload.seek_clamped(SeekFrom::End(4)), Ok(6));
+    assert_eq!(payload.seek_clamped(SeekFrom::End(11)), Ok(0));
+    assert_eq!(payload.seek_clamped(SeekFrom::Current(5)), Ok(5));
+    assert_eq!(payload.seek_clamped(SeekFrom::Current(-2)), Ok(3));
+    assert_eq!(payload.seek_clamped(SeekFrom::Current(-4)), Ok(0));
+    assert_eq!(payload.seek_clamped(SeekFrom::Current(i64::MAX)), Ok(10));
+    assert_eq!(payload.seek_clamped(SeekFrom::Current(i64::MIN)), Ok(0));
+    assert_eq!(payload.pos, 0);
+    // The strict one still errors.
+    assert_eq!(payload.seek(SeekFrom::Start(11)), Err(TooFar::AfterEnd(1)));
+
+    let mut empty = InMem::new(b"").unwrap();
+    assert_eq!(empty.seek_clamped(SeekFrom::Start(1)), Ok(0));
+    assert_eq!(empty.seek_clamped(SeekFrom::Current(-1)), Ok(0));
+}
+
+
+#[test]
+fn read_exact()
+{
+    use {
+        extra::sync::ExtraCore as _,
+        sailce_data_model::payload::ReadExactError,
+        sync::Payload as _,
+    };
+
+    let mut payload = InMem::new(b"0123456789").unwrap();
+    let buf = &mut [0_u8; 4];
+    assert_eq!(payload.read_exact(&mut buf[.. 0]), Ok(()));
+    assert_eq!(payload.read_exact(buf), Ok(()));
+    assert_eq!(buf, b"0123");
+    assert_eq!(payload.read_exact(buf), Ok(()));
+    assert_eq!(buf, b"4567");
+    assert_eq!(payload.read_exact(buf), Err(ReadExactError::UnexpectedEof { filled: 2 }));
+    assert_eq!(buf, b"8967");
+    assert_eq!(payload.pos, 10);
+    assert_eq!(payload.read_exact(buf), Err(ReadExactError::UnexpectedEof { filled: 0 }));
+    assert_eq!(payload.seek(SeekFrom::End(1)), Ok(9));
+    assert_eq!(payload.read_exact(&mut buf[.. 1]), Ok(()));
+    assert_eq!(buf, b"9967");
+}
+
+
+#[test]
+fn read_buffers()
+{
+    use {
+        extra::sync::ExtraCore as _,
+        sync::Payload as _,
+    };
+
+    let mut payload = InMem::new(b"0123456789").unwrap();
+    let (mut a, mut b, mut c) = ([0_u8; 3], [0_u8; 0], [0_u8; 4]);
+    assert_eq!(payload.read_buffers(&mut [&mut a, &mut b, &mut c]), Ok(7));
+    assert_eq!((&a, &c), (b"012", b"3456"));
+    assert_eq!(payload.pos, 7);
+    // Reaches the end, before filling all.
+    assert_eq!(payload.read_buffers(&mut [&mut a, &mut c]), Ok(3));
+    assert_eq!((&a, &c), (b"789", b"3456"));
+    assert_eq!(payload.read_buffers(&mut [&mut a]), Ok(0));
+    assert_eq!(payload.read_buffers(&mut []), Ok(0));
+    assert_eq!(payload.seek(SeekFrom::Start(8)), Ok(8));
+    assert_eq!(payload.read_buffers(&mut [&mut a, &mut c]), Ok(2));
+    assert_eq!(a, *b"899");
+}
+
+
+#[cfg(feature = "alloc")]
+#[test]
+fn read_to_end()
+{
+    use {
+        extra::sync::Extra as _,
+        sync::Payload as _,
+    };
+
+    let mut payload = InMem::new(b"0123456789").unwrap();
+    let mut dest = b"ab".to_vec();
+    assert_eq!(payload.seek(SeekFrom::Start(3)), Ok(3));
+    assert_eq!(payload.read_to_end(&mut dest), Ok(7));
+    assert_eq!(dest, b"ab3456789");
+    assert_eq!(payload.read_to_end(&mut dest), Ok(0));
+    assert_eq!(dest, b"ab3456789");
+
+    // Larger than how much `dest` is grown by at once.
+    let bytes: Vec<u8> = (0 ..= u8::MAX).cycle().take(200_000).collect();
+    let mut large = InMem::new(&bytes).unwrap();
+    let mut all = vec![];
+    assert_eq!(large.read_to_end(&mut all), Ok(200_000));
+    assert_eq!(all, bytes);
+}
+
+
 #[cfg(feature = "std")]
 #[test]
 #[allow(unstable_name_collisions)]
@@ -552,3 +852,526 @@ fn cursor()
 
     assert_eq!(buf, b"foo zab\0\0\0\0\0\0\0\0\0");
 }
+
+
+#[test]
+fn in_memory()
+{
+    use sailce_data_model::{
+        payload::{
+            ExtraCore as _,
+            InMemory,
+            SeekOutOfBoundsError,
+        },
+        Payload as _,
+    };
+
+    let buf = &mut [0_u8; 16];
+
+    let mut p0 = InMemory::new(&[][..]);
+    assert_eq!(pollster::block_on(p0.read(&mut [])), Ok(0));
+    assert_eq!(pollster::block_on(p0.len()), 0);
+    assert!(pollster::block_on(p0.is_empty()));
+    assert_eq!(pollster::block_on(p0.seek(SeekFrom::End(0))), Ok(0));
+    assert_eq!(
+        pollster::block_on(p0.seek(SeekFrom::Start(1))),
+        Err(SeekOutOfBoundsError { pos: SeekFrom::Start(1) })
+    );
+
+    let mut p1 = InMemory::from(&b"foo bar zab"[..]);
+    assert_eq!(pollster::block_on(p1.len()), 11);
+    assert!(!pollster::block_on(p1.is_empty()));
+    assert_eq!(pollster::block_on(p1.read(&mut buf[.. 4])), Ok(4));
+    assert_eq!(&buf[.. 4], b"foo ");
+    assert_eq!(pollster::block_on(p1.current_position()), Ok(4));
+    assert_eq!(pollster::block_on(p1.seek(SeekFrom::End(3))), Ok(8));
+    assert_eq!(pollster::block_on(p1.read(&mut buf[4 ..])), Ok(3));
+    assert_eq!(pollster::block_on(p1.read(buf)), Ok(0));
+    assert_eq!(pollster::block_on(p1.seek(SeekFrom::Current(-7))), Ok(4));
+    assert_eq!(pollster::block_on(p1.read(&mut buf[7 .. 10])), Ok(3));
+    assert_eq!(buf, b"foo zabbar\0\0\0\0\0\0");
+
+    // Unlike `Cursor`, seeking beyond the end is an error, and it leaves the position unchanged.
+    for pos in [SeekFrom::Start(12), SeekFrom::End(12), SeekFrom::Current(-8)] {
+        assert_eq!(pollster::block_on(p1.seek(pos)), Err(SeekOutOfBoundsError { pos }));
+    }
+    assert_eq!(pollster::block_on(p1.current_position()), Ok(7));
+    assert_eq!(p1.into_inner(), b"foo bar zab");
+
+    // Owned bytes too.
+    let mut p2 = InMemory::new(b"foo bar zab".to_vec());
+    assert_eq!(pollster::block_on(p2.seek(SeekFrom::Start(11))), Ok(11));
+    assert_eq!(pollster::block_on(p2.read(buf)), Ok(0));
+    assert_eq!(p2.into_inner(), b"foo bar zab");
+}
+
+
+/// Its positions are absolute and reading doesn't consume, as the helpers require.
+#[cfg(feature = "alloc")]
+#[test]
+fn in_memory_extra()
+{
+    use sailce_data_model::payload::{
+        Extra as _,
+        InMemory,
+    };
+
+    let mut p = InMemory::new(&b"foo bar zab"[..]);
+    assert_eq!(
+        pollster::block_on(p.to_boxed_slice(4 .. 7, NONE_CALLBACK, false)),
+        Ok(b"bar"[..].into())
+    );
+    for _ in 0 .. 2 {
+        assert_eq!(
+            pollster::block_on(p.to_boxed_slice(0 .., NONE_CALLBACK, true)),
+            Ok(b"foo bar zab"[..].into())
+        );
+    }
+}
+
+
+#[cfg(feature = "std")]
+#[test]
+#[allow(unstable_name_collisions)]
+fn file()
+{
+    use {
+        sailce_data_model::payload::{
+            self,
+            sync::Payload as _,
+            ExtraCore as _,
+            SeekFrom,
+        },
+        std::{
+            fs,
+            io::{
+                self,
+                Write as _,
+            },
+        },
+    };
+
+    let path = std::env::temp_dir().join(format!("sailce-payload-file-{}", std::process::id()));
+    fs::File::create(&path).unwrap().write_all(b"foo bar zab").unwrap();
+    let mut p = payload::File::new(fs::File::open(&path).unwrap()).unwrap();
+    let buf = &mut [0_u8; 16];
+
+    assert_eq!(p.len(), 11);
+    assert!(!p.is_empty());
+    assert_eq!(p.read(&mut buf[.. 4]).ok(), Some(4));
+    assert_eq!(&buf[.. 4], b"foo ");
+    assert_eq!(p.seek(SeekFrom::Current(0)).ok(), Some(4));
+    assert_eq!(p.seek(SeekFrom::End(3)).ok(), Some(8));
+    assert_eq!(p.read(&mut buf[4 ..]).ok(), Some(3));
+    assert_eq!(p.read(buf).ok(), Some(0));
+    assert_eq!(p.seek(SeekFrom::Current(-7)).ok(), Some(4));
+    assert_eq!(p.read(&mut buf[7 .. 10]).ok(), Some(3));
+    assert_eq!(buf, b"foo zabbar\0\0\0\0\0\0");
+    // Seeking out of bounds is an error, which leaves the position unchanged.
+    let out_of_bounds =
+        [SeekFrom::Start(12), SeekFrom::End(12), SeekFrom::Current(5), SeekFrom::Current(-8)];
+    for pos in out_of_bounds {
+        let error = p.seek(pos).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(p.seek(SeekFrom::Current(0)).ok(), Some(7));
+    }
+    assert_eq!(p.seek(SeekFrom::Start(11)).ok(), Some(11));
+    assert_eq!(p.seek(SeekFrom::End(11)).ok(), Some(0));
+    assert_eq!(p.seek(SeekFrom::Current(7)).ok(), Some(7));
+
+    // The async methods are the same, and don't actually need an executor.
+    assert_eq!(pollster::block_on(sailce_data_model::Payload::len(&p)), 11);
+    assert_eq!(pollster::block_on(p.current_position()).ok(), Some(7));
+    assert_eq!(
+        pollster::block_on(sailce_data_model::Payload::seek(&mut p, SeekFrom::Start(0))).ok(),
+        Some(0)
+    );
+    assert_eq!(
+        pollster::block_on(sailce_data_model::Payload::read(&mut p, &mut buf[.. 3])).ok(),
+        Some(3)
+    );
+    assert_eq!(&buf[.. 3], b"foo");
+
+    drop(p.into_inner());
+    fs::remove_file(&path).unwrap();
+}
+
+
+#[cfg(feature = "mmap")]
+#[test]
+fn mmap()
+{
+    use sailce_data_model::{
+        payload::{
+            ExtraCore as _,
+            Mmap,
+            SeekOutOfBoundsError,
+        },
+        Payload as _,
+    };
+
+    // Mapping an actual file is `unsafe`, which our workspace forbids even in tests, so this uses
+    // an anonymous map, which is safe to make and is otherwise the same for `payload::Mmap`.
+    let mut map = memmap2::MmapMut::map_anon(11).unwrap();
+    map.copy_from_slice(b"foo bar zab");
+    let mut p = Mmap::new(map.make_read_only().unwrap());
+    let buf = &mut [0_u8; 16];
+
+    assert_eq!(pollster::block_on(p.len()), 11);
+    assert!(!pollster::block_on(p.is_empty()));
+    assert_eq!(pollster::block_on(p.read(&mut buf[.. 4])), Ok(4));
+    assert_eq!(&buf[.. 4], b"foo ");
+    assert_eq!(pollster::block_on(p.current_position()), Ok(4));
+    assert_eq!(pollster::block_on(p.seek(SeekFrom::End(3))), Ok(8));
+    assert_eq!(pollster::block_on(p.read(&mut buf[4 ..])), Ok(3));
+    assert_eq!(pollster::block_on(p.read(buf)), Ok(0));
+    assert_eq!(pollster::block_on(p.seek(SeekFrom::Current(-7))), Ok(4));
+    assert_eq!(pollster::block_on(p.read(&mut buf[7 .. 10])), Ok(3));
+    assert_eq!(buf, b"foo zabbar\0\0\0\0\0\0");
+
+    for pos in [SeekFrom::Start(12), SeekFrom::End(12), SeekFrom::Current(-8)] {
+        assert_eq!(pollster::block_on(p.seek(pos)), Err(SeekOutOfBoundsError { pos }));
+    }
+    assert_eq!(pollster::block_on(p.seek(SeekFrom::Start(11))), Ok(11));
+    assert_eq!(pollster::block_on(p.current_position()), Ok(11));
+    assert_eq!(&*p.into_inner(), b"foo bar zab");
+}
+
+
+#[cfg(feature = "tokio")]
+#[test]
+fn tokio_compat()
+{
+    use {
+        sailce_data_model::payload::{
+            ExtraCore as _,
+            InMemory,
+            TokioCompat,
+        },
+        tokio::io::{
+            AsyncReadExt as _,
+            AsyncSeekExt as _,
+        },
+    };
+
+    let mut p = TokioCompat::new(InMemory::new("foo bar zab"));
+    let mut all = Vec::new();
+
+    assert_eq!(pollster::block_on(p.read_to_end(&mut all)).ok(), Some(11));
+    assert_eq!(all, b"foo bar zab");
+    assert_eq!(pollster::block_on(p.stream_position()).ok(), Some(11));
+    assert_eq!(pollster::block_on(p.seek(io::SeekFrom::Start(4))).ok(), Some(4));
+    let mut buf = [0_u8; 3];
+    assert_eq!(pollster::block_on(p.read(&mut buf)).ok(), Some(3));
+    assert_eq!(&buf, b"bar");
+    assert_eq!(pollster::block_on(p.seek(io::SeekFrom::End(-3))).ok(), Some(8));
+    assert_eq!(pollster::block_on(p.stream_position()).ok(), Some(8));
+
+    // Seeking beyond the end is an error, unlike with `std::io::Seek` of files.
+    for pos in [io::SeekFrom::Start(12), io::SeekFrom::End(1), io::SeekFrom::Current(4)] {
+        let error = pollster::block_on(p.seek(pos)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+    assert_eq!(pollster::block_on(p.into_inner().current_position()), Ok(8));
+}
+
+
+/// `InMem`'s futures are pending a few times before completing, which the adapters can't keep.
+#[cfg(feature = "tokio")]
+#[test]
+#[should_panic = "`Payload` future must be immediately ready for this adapter"]
+fn tokio_compat_pending()
+{
+    use {
+        sailce_data_model::payload::TokioCompat,
+        tokio::io::AsyncReadExt as _,
+    };
+
+    let mut p = TokioCompat::new(InMem::new("foo").unwrap());
+    assert_eq!(pollster::block_on(p.read(&mut [0; 3])).ok(), Some(3));
+}
+
+
+#[cfg(feature = "futures-io")]
+#[test]
+fn futures_compat()
+{
+    use {
+        futures_util::io::{
+            copy,
+            AsyncReadExt as _,
+            AsyncSeekExt as _,
+        },
+        sailce_data_model::payload::{
+            ExtraCore as _,
+            FuturesCompat,
+            InMemory,
+        },
+    };
+
+    let mut p = FuturesCompat::new(InMemory::new("foo bar zab"));
+    let mut all = Vec::new();
+
+    assert_eq!(pollster::block_on(copy(&mut p, &mut all)).ok(), Some(11));
+    assert_eq!(all, b"foo bar zab");
+    assert_eq!(pollster::block_on(p.seek(io::SeekFrom::Current(-7))).ok(), Some(4));
+    let mut buf = [0_u8; 3];
+    assert_eq!(pollster::block_on(p.read(&mut buf)).ok(), Some(3));
+    assert_eq!(&buf, b"bar");
+    assert_eq!(pollster::block_on(p.stream_position()).ok(), Some(7));
+
+    for pos in [io::SeekFrom::Start(12), io::SeekFrom::End(1), io::SeekFrom::Current(-8)] {
+        let error = pollster::block_on(p.seek(pos)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+    assert_eq!(pollster::block_on(p.into_inner().current_position()), Ok(7));
+}
+
+
+#[test]
+fn header_then()
+{
+    use sailce_data_model::{
+        payload::{
+            ExtraCore as _,
+            FromBytes,
+            HeaderThen,
+            HeaderThenSeekError,
+            SeekOutOfBoundsError,
+        },
+        Payload as _,
+    };
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct ContentType
+    {
+        kind:    [u8; 3],
+        version: u8,
+    }
+
+    impl FromBytes<4> for ContentType
+    {
+        fn from_bytes(&[k0, k1, k2, version]: &[u8; 4]) -> Self
+        {
+            Self { kind: [k0, k1, k2], version }
+        }
+    }
+
+    let inner = InMem::new("txt\x07foo bar zab").unwrap();
+    let mut p = pollster::block_on(HeaderThen::<ContentType, _, 4>::new(inner)).unwrap();
+    let buf = &mut [0_u8; 16];
+
+    assert_eq!(pollster::block_on(p.len()), 11);
+    assert_eq!(pollster::block_on(p.current_position()), Ok(0));
+    assert_eq!(pollster::block_on(p.read(&mut buf[.. 4])), Ok(4));
+    assert_eq!(&buf[.. 4], b"foo ");
+    // Reading the header doesn't change the position of the body.
+    let expected = ContentType { kind: *b"txt", version: 7 };
+    assert_eq!(pollster::block_on(p.header()), Ok(&expected));
+    assert_eq!(pollster::block_on(p.current_position()), Ok(4));
+    assert_eq!(pollster::block_on(p.read(&mut buf[4 .. 7])), Ok(3));
+    assert_eq!(&buf[.. 7], b"foo bar");
+
+    // Seeking is within the body.
+    assert_eq!(pollster::block_on(p.seek(SeekFrom::End(3))), Ok(8));
+    assert_eq!(pollster::block_on(p.read(&mut buf[7 ..])), Ok(3));
+    assert_eq!(pollster::block_on(p.read(buf)), Ok(0));
+    assert_eq!(pollster::block_on(p.seek(SeekFrom::Current(-7))), Ok(4));
+    assert_eq!(pollster::block_on(p.read(&mut buf[10 .. 13])), Ok(3));
+    assert_eq!(pollster::block_on(p.seek(SeekFrom::Start(0))), Ok(0));
+    assert_eq!(pollster::block_on(p.read(&mut buf[13 ..])), Ok(3));
+    assert_eq!(buf, b"foo barzabbarfoo");
+    for pos in [SeekFrom::Start(12), SeekFrom::End(12), SeekFrom::Current(-4)] {
+        assert_eq!(
+            pollster::block_on(p.seek(pos)),
+            Err(HeaderThenSeekError::OutOfBounds(SeekOutOfBoundsError { pos }))
+        );
+    }
+    // Cached.
+    assert_eq!(pollster::block_on(p.header()), Ok(&expected));
+    assert_eq!(pollster::block_on(p.current_position()), Ok(3));
+    let mut returned = p.into_inner();
+    assert_eq!(pollster::block_on(returned.current_position()), Ok(7));
+}
+
+#[test]
+fn header_then_edges()
+{
+    use sailce_data_model::{
+        payload::HeaderThen,
+        Payload as _,
+    };
+
+    let buf = &mut [0_u8; 4];
+
+    // Only a header, and an empty body.
+    let mut only = pollster::block_on(HeaderThen::<[u8; 2], _, 2>::new(InMem::new("ab").unwrap()))
+        .unwrap();
+    assert!(pollster::block_on(only.is_empty()));
+    assert_eq!(pollster::block_on(only.header()), Ok(b"ab"));
+    assert_eq!(pollster::block_on(only.read(buf)), Ok(0));
+
+    // Too short for the header.
+    let short = pollster::block_on(HeaderThen::<[u8; 4], _, 4>::new(InMem::new("abc").unwrap()));
+    assert_eq!(short.err(), Some(TooFar::AfterEnd(1)));
+}
+
+
+/// Uses only [`DefaultSync`], to exercise that without the ambiguity that `InMem` would have.
+struct DefaultSynced(InMem);
+
+impl sailce_data_model::Payload for DefaultSynced
+{
+    type ReadError = Infallible;
+    type SeekError = TooFar;
+
+    async fn read(
+        &mut self,
+        buf: &mut [u8],
+    ) -> Result<usize, Self::ReadError>
+    {
+        sailce_data_model::Payload::read(&mut self.0, buf).await
+    }
+
+    async fn seek(
+        &mut self,
+        pos: SeekFrom,
+    ) -> Result<u64, Self::SeekError>
+    {
+        sailce_data_model::Payload::seek(&mut self.0, pos).await
+    }
+
+    async fn len(&self) -> u64
+    {
+        sailce_data_model::Payload::len(&self.0).await
+    }
+}
+
+impl sync::Payload<DefaultSync> for DefaultSynced {}
+
+impl extra::sync::ExtraCore<DefaultSync> for DefaultSynced {}
+
+
+#[test]
+fn default_sync()
+{
+    use {
+        extra::sync::ExtraCore as _,
+        sync::Payload as _,
+    };
+
+    assert_eq!(block_on(async { 42 }), 42);
+    // Pending a few times, which is busy-waited through.
+    block_on(not_yet_ready(3));
+
+    let mut payload = DefaultSynced(InMem::new(b"0123456789").unwrap());
+    let buf = &mut [0_u8; 4];
+    assert_eq!(payload.read(buf), Ok(4));
+    assert_eq!(buf, b"0123");
+    assert_eq!(payload.seek(SeekFrom::End(2)), Ok(8));
+    assert_eq!(payload.read_exact(&mut buf[.. 2]), Ok(()));
+    assert_eq!(buf, b"8923");
+    assert_eq!(payload.len(), 10);
+    assert_eq!(payload.current_position(), Ok(10));
+}
+
+
+/// Violates the required behavior of `Payload` in the given way, to exercise detecting that.
+struct Misbehaving
+{
+    inner: InMem,
+    lie:   Lie,
+}
+
+#[derive(Clone, Copy)]
+enum Lie
+{
+    /// `seek` gives one more than the actual position.
+    Seek,
+    /// `read` claims one more byte than it was given a buffer for.
+    Read,
+    /// `len` claims one more byte than there is.
+    Len,
+}
+
+impl sailce_data_model::Payload for Misbehaving
+{
+    type ReadError = Infallible;
+    type SeekError = TooFar;
+
+    async fn read(
+        &mut self,
+        buf: &mut [u8],
+    ) -> Result<usize, Self::ReadError>
+    {
+        let amount = sailce_data_model::Payload::read(&mut self.inner, buf).await?;
+        Ok(if let Lie::Read = self.lie { amount.saturating_add(1) } else { amount })
+    }
+
+    async fn seek(
+        &mut self,
+        pos: SeekFrom,
+    ) -> Result<u64, Self::SeekError>
+    {
+        let new_pos = sailce_data_model::Payload::seek(&mut self.inner, pos).await?;
+        Ok(if let Lie::Seek = self.lie { new_pos.saturating_add(1) } else { new_pos })
+    }
+
+    async fn len(&self) -> u64
+    {
+        let len = sailce_data_model::Payload::len(&self.inner).await;
+        len.saturating_add(if let Lie::Len = self.lie { 1 } else { 0 })
+    }
+}
+
+
+#[test]
+fn bad_impl()
+{
+    use sailce_data_model::payload::{
+        BadImplKind,
+        CopyToSliceError,
+        ExtraCore as _,
+        ReadBuffersError,
+        ReadExactError,
+    };
+
+    let misbehaving = |lie| Misbehaving { inner: InMem::new(b"0123456789").unwrap(), lie };
+    let buf = &mut [0_u8; 4];
+
+    let mut seek_lying = misbehaving(Lie::Seek);
+    assert_eq!(
+        pollster::block_on(seek_lying.copy_to_slice(Some(3), buf, NONE_CALLBACK, false)),
+        Err(CopyToSliceError::BadImpl(BadImplKind::SeekMismatch { requested: 3, got: 4 }))
+    );
+    let mut read_lying = misbehaving(Lie::Read);
+    assert_eq!(
+        pollster::block_on(read_lying.copy_to_slice(Some(0), buf, NONE_CALLBACK, false)),
+        Err(CopyToSliceError::BadImpl(BadImplKind::ReadOverflow { given: 4, claimed: 5 }))
+    );
+    assert_eq!(
+        pollster::block_on(misbehaving(Lie::Read).read_exact(buf)),
+        Err(ReadExactError::BadImpl(BadImplKind::ReadOverflow { given: 4, claimed: 5 }))
+    );
+    let (mut a, mut b) = ([0_u8; 2], [0_u8; 3]);
+    assert_eq!(
+        pollster::block_on(misbehaving(Lie::Read).read_buffers(&mut [&mut a, &mut b])),
+        Err(ReadBuffersError::BadImpl(BadImplKind::ReadOverflow { given: 2, claimed: 3 }))
+    );
+    let mut len_lying = misbehaving(Lie::Len);
+    let short_buf = &mut buf[.. 3];
+    assert_eq!(
+        pollster::block_on(len_lying.copy_to_slice(Some(8), short_buf, NONE_CALLBACK, false)),
+        Err(CopyToSliceError::BadImpl(BadImplKind::ShortRead { expected: 3, got: 2 }))
+    );
+
+    let error = CopyToSliceError::<Infallible, TooFar>::BadImpl(BadImplKind::ShortRead {
+        expected: 3,
+        got:      2,
+    });
+    assert_eq!(
+        error.to_string(),
+        "`payload::ExtraCore::copy_to_slice` failed due to bad implementation of `Payload`: \
+         `read` ended after 2 bytes instead of 3"
+    );
+}
@@ -9,7 +9,9 @@ use {
         path::Extra as _,
         store::{
             sync,
+            JoinError,
             PutError,
+            TransactionError,
         },
         syncify::Syncify,
         AuthorisedEntry,
@@ -17,12 +19,13 @@ use {
         Entry,
         Params as _,
         Store,
+        StoreAuthorisedEntry,
         Timestamp,
     },
     std::{
         future::Future,
+        num::NonZeroU64,
         sync::Arc,
-        time::SystemTime,
     },
 };
 
@@ -41,52 +44,1639 @@ mod stored_entry;
 mod in_mem;
 pub(crate) use in_mem::InMem;
 
+mod unreadable;
+
+#[cfg(feature = "proptests")]
+mod newest_index;
+
 
 impl Syncify<Pollster> for Store<<Params as sailce_data_model::Params>::NamespaceId, InMem>
 {
-    type ExecutorData = ();
+    type ExecutorData = ();
+
+    fn get_block_on_fn<'f, F>(&self) -> impl 'f + FnOnce(F, Self::ExecutorData) -> F::Output
+    where F: Future + 'f
+    {
+        fn adapt<F: Future>(
+            fut: F,
+            (): (),
+        ) -> F::Output
+        {
+            Pollster::block_on(fut, 123_u32)
+        }
+
+        adapt
+    }
+
+    fn get_executor_data(&self) -> Self::ExecutorData {}
+}
+
+impl sync::Store<Pollster, Params, InMem>
+    for Store<<Params as sailce_data_model::Params>::NamespaceId, InMem>
+{
+}
+
+
+pub(crate) fn payload_and_digest(bytes: impl AsRef<[u8]>) -> (InMemPayload, u64)
+{
+    let mut payload = InMemPayload::new(bytes).expect("size fits");
+    let digest = pollster::block_on(Params::hash_payload(&mut payload)).expect("should work");
+    (payload, digest)
+}
+
+
+#[test]
+fn empty_payload_digest()
+{
+    let (_, digest) = payload_and_digest([]);
+    assert_eq!(pollster::block_on(Params::empty_payload_digest()), Ok(digest));
+}
+
+
+#[test]
+fn compute_digest()
+{
+    use sailce_data_model::{
+        payload::SeekFrom,
+        Payload as _,
+    };
+
+    let (mut payload, digest) = payload_and_digest("foo bar baz");
+    // Regardless of the current position.
+    assert_eq!(pollster::block_on(payload.seek(SeekFrom::Start(4))), Ok(4));
+    assert_eq!(pollster::block_on(Params::compute_digest(&mut payload)), Ok((digest, 11)));
+    let (mut empty, empty_digest) = payload_and_digest([]);
+    assert_eq!(pollster::block_on(Params::compute_digest(&mut empty)), Ok((empty_digest, 0)));
+}
+
+
+#[test]
+fn entries_since()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let mut store = Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let (empty_payload, empty_payload_digest) = payload_and_digest([]);
+    let timestamp = Timestamp::now();
+    let auth_entry = |path: &[&str], μs_after: u64| {
+        AuthorisedEntry::new(
+            Entry {
+                namespace_id:   Arc::clone(&ns1),
+                subspace_id:    user1.clone(),
+                path:           Path::from_path(path),
+                timestamp:      (timestamp.μs_since_epoch + μs_after).into(),
+                payload_digest: empty_payload_digest,
+                payload_length: 0,
+            },
+            Permission {
+                user:       user1.clone(),
+                namespaces: [Arc::clone(&ns1)].into(),
+                subspaces:  [].into(),
+                paths:      [Path::empty()].into(),
+                times:      [(0 ..).into()].into(),
+            },
+        )
+        .expect("auth should succeed")
+    };
+    let paths_since = |of: &Store<NamespaceName, InMem>, token| {
+        let (entries, current) = of.entries_since(token);
+        let mut paths = entries.map(|ae| ae.into_parts().0.path).collect::<Vec<_>>();
+        paths.sort();
+        (paths, current)
+    };
+
+    assert!(store.tracks_changes());
+    let token0 = store.change_token();
+    assert_eq!(paths_since(&store, token0), (vec![], token0));
+
+    assert_eq!(store.put(auth_entry(&["a"], 0), Some(empty_payload.clone())), Ok(()));
+    assert_eq!(store.put(auth_entry(&["b"], 0), Some(empty_payload.clone())), Ok(()));
+    let token1 = store.change_token();
+    assert!(token1 > token0);
+    assert_eq!(paths_since(&store, token0), (
+        vec![Path::from_path(&["a"]), Path::from_path(&["b"])],
+        token1
+    ));
+
+    assert_eq!(store.put(auth_entry(&["c"], 0), Some(empty_payload.clone())), Ok(()));
+    assert_eq!(store.put(auth_entry(&["a"], 1), Some(empty_payload.clone())), Ok(()));
+    let token2 = store.change_token();
+    assert!(token2 > token1);
+    // Only the new ones, including the overwritten.
+    assert_eq!(paths_since(&store, token1), (
+        vec![Path::from_path(&["a"]), Path::from_path(&["c"])],
+        token2
+    ));
+    assert_eq!(paths_since(&store, token2), (vec![], token2));
+
+    // Supplying the payload of a previously-put entry is a change to it.
+    let ae = auth_entry(&["d"], 0);
+    assert_eq!(store.put(ae.clone(), None::<InMemPayload>), Ok(()));
+    let token3 = store.change_token();
+    assert_eq!(store.put(ae, Some(empty_payload)), Ok(()));
+    assert_eq!(paths_since(&store, token3).0, vec![Path::from_path(&["d"])]);
+}
+
+
+#[test]
+fn entries_since_default()
+{
+    use {
+        sailce_data_model::StoreExt as _,
+        unreadable::Unreadable,
+    };
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let mut inner = InMem::new_block_on_pollster(&ns1);
+    for path in ["a", "c"] {
+        let (payload, digest) = payload_and_digest(path);
+        let auth_entry = self_authorised_entry(&ns1, &user1, path, digest, payload.len_as_u64());
+        assert_eq!(pollster::block_on(inner.put(&ns1, auth_entry, Some(payload))), Ok(()));
+    }
+    // Its `StoreExt` doesn't override `change_token` nor `entries_since`.
+    let store = Store::new(&ns1, Unreadable { inner, unreadable: Path::from_path(&["b"]) });
+
+    // Unsupported, which is told, and so the token is always the same.
+    assert!(!store.tracks_changes());
+    let token = store.change_token();
+    assert_eq!(store.change_token(), token);
+    // Always all of them.
+    let (entries, current) = store.entries_since(token);
+    let mut paths = entries.map(|ae| ae.into_parts().0.path).collect::<Vec<_>>();
+    paths.sort();
+    assert_eq!(paths, [Path::from_path(&["a"]), Path::from_path(&["c"])]);
+    assert_eq!(current, token);
+}
+
+/// Put an entry, at `path` in the subspace of `user`, whose payload is the concatenation of
+/// `path`.
+fn put_with_own_payload(
+    store: &mut Store<NamespaceName, InMem>,
+    user: &User,
+    path: &[&str],
+    timestamp: Timestamp,
+)
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let namespace_id = store.namespace_id();
+    let (payload, payload_digest) = payload_and_digest(path.concat());
+    let auth_entry = AuthorisedEntry::new(
+        Entry {
+            namespace_id: Arc::clone(namespace_id),
+            subspace_id: user.clone(),
+            path: Path::from_path(path),
+            timestamp,
+            payload_digest,
+            payload_length: payload.len_as_u64(),
+        },
+        Permission {
+            user:       user.clone(),
+            namespaces: [Arc::clone(namespace_id)].into(),
+            subspaces:  [].into(),
+            paths:      [Path::empty()].into(),
+            times:      [(0 ..).into()].into(),
+        },
+    )
+    .expect("auth should succeed");
+    assert_eq!(store.put(auth_entry, Some(payload)), Ok(()));
+}
+
+/// The sorted paths of `entries` with the `μs` of their `timestamp` after `since`.
+fn paths_and_ages(
+    entries: impl Iterator<Item = StoreAuthorisedEntry<InMem>>,
+    since: Timestamp,
+) -> Vec<(Path, u64)>
+{
+    let mut paths = entries
+        .map(|auth_entry| {
+            let entry = auth_entry.into_parts().0;
+            (entry.path, entry.timestamp.μs_since_epoch.saturating_sub(since.μs_since_epoch))
+        })
+        .collect::<Vec<_>>();
+    paths.sort();
+    paths
+}
+
+
+#[test]
+fn iter_union()
+{
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let user2 = User::new("dos");
+    let new_store = || Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| (timestamp.μs_since_epoch + μs_after).into();
+
+    let mut store_a = new_store();
+    put_with_own_payload(&mut store_a, &user1, &["a"], at(0)); // Unique.
+    put_with_own_payload(&mut store_a, &user1, &["x"], at(0));
+    put_with_own_payload(&mut store_a, &user1, &["p", "deep"], at(0));
+    put_with_own_payload(&mut store_a, &user1, &["q"], at(2));
+    put_with_own_payload(&mut store_a, &user1, &["same"], at(0));
+    let mut store_b = new_store();
+    put_with_own_payload(&mut store_b, &user1, &["b"], at(0)); // Unique.
+    put_with_own_payload(&mut store_b, &user1, &["x"], at(1)); // Overlaps and is newer.
+    put_with_own_payload(&mut store_b, &user1, &["p"], at(1)); // Prunes `store_a`'s deep one.
+    put_with_own_payload(&mut store_b, &user1, &["q", "deep"], at(0)); // Pruned by `store_a`'s.
+    put_with_own_payload(&mut store_b, &user1, &["same"], at(0)); // Same as `store_a`'s.
+    put_with_own_payload(&mut store_b, &user2, &["p", "deep"], at(0)); // Other subspace.
+
+    let expected = vec![
+        (Path::from_path(&["a"]), 0),
+        (Path::from_path(&["b"]), 0),
+        (Path::from_path(&["p"]), 1),
+        (Path::from_path(&["p", "deep"]), 0), // Of `user2`.
+        (Path::from_path(&["q"]), 2),
+        (Path::from_path(&["same"]), 0),
+        (Path::from_path(&["x"]), 1),
+    ];
+    assert_eq!(paths_and_ages(store_a.iter_union(&store_b), timestamp), expected);
+    assert_eq!(paths_and_ages(store_b.iter_union(&store_a), timestamp), expected);
+    // The same as actually joining them.
+    {
+        use sailce_data_model::store::sync::Store as _;
+
+        let joined = Store::join_all(&ns1, [&store_a, &store_b]).expect("should succeed");
+        assert_eq!(paths_and_ages(joined.iter(), timestamp), expected);
+    }
+    // Neither was mutated.
+    assert_eq!(store_a.iter().count(), 5);
+    assert_eq!(store_b.iter().count(), 6);
+
+    // A store for a different Namespace contributes nothing.
+    let ns2 = "namespace-2".into();
+    let mut other_ns = Store::new(&ns2, InMem::new_block_on_pollster(&ns2));
+    put_with_own_payload(&mut other_ns, &user1, &["a"], at(9));
+    assert_eq!(store_a.iter_union(&other_ns).count(), 5);
+}
+
+
+#[test]
+fn subspaces_and_paths_in_subspace()
+{
+    fn sorted<T: Ord>(items: impl Iterator<Item = T>) -> Vec<T>
+    {
+        let mut items = items.collect::<Vec<_>>();
+        items.sort();
+        items
+    }
+
+    let ns = "namespace".into();
+    let user1 = User::new("uno");
+    let user2 = User::new("dos");
+    let user3 = User::new("tres");
+    let mut store = Store::new(&ns, InMem::new_block_on_pollster(&ns));
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| (timestamp.μs_since_epoch + μs_after).into();
+
+    assert_eq!(store.subspaces().count(), 0);
+    assert_eq!(store.paths_in_subspace(&user1).count(), 0);
+
+    put_with_own_payload(&mut store, &user1, &["b"], at(0));
+    put_with_own_payload(&mut store, &user1, &["a", "z"], at(0));
+    put_with_own_payload(&mut store, &user1, &["a", "y"], at(0));
+    put_with_own_payload(&mut store, &user2, &["a"], at(0));
+    put_with_own_payload(&mut store, &user3, &["c", "d"], at(0));
+    put_with_own_payload(&mut store, &user1, &["b"], at(1)); // Overwrites.
+
+    assert_eq!(sorted(store.subspaces()), [user2.clone(), user3.clone(), user1.clone()]);
+    assert_eq!(sorted(store.paths_in_subspace(&user1)), [
+        Path::from_path(&["a", "y"]),
+        Path::from_path(&["a", "z"]),
+        Path::from_path(&["b"]),
+    ]);
+    assert_eq!(store.paths_in_subspace(&user2).collect::<Vec<_>>(), [Path::from_path(&["a"])]);
+    assert_eq!(store.paths_in_subspace(&User::new("nobody")).count(), 0);
+
+    // Prefix-pruned ones are not yielded.
+    put_with_own_payload(&mut store, &user1, &["a"], at(2));
+    assert_eq!(sorted(store.paths_in_subspace(&user1)), [
+        Path::from_path(&["a"]),
+        Path::from_path(&["b"]),
+    ]);
+    // Nor is a Subspace whose `Entry`s were all pruned.
+    put_with_own_payload(&mut store, &user3, &["c"], at(3));
+    put_with_own_payload(&mut store, &user3, &[], at(4));
+    assert_eq!(store.paths_in_subspace(&user3).collect::<Vec<_>>(), [Path::empty()]);
+    assert_eq!(store.subspaces().count(), 3);
+}
+
+
+#[test]
+fn iter_newest()
+{
+    let ns = "namespace".into();
+    let user1 = User::new("uno");
+    let user2 = User::new("dos");
+    let mut store = Store::new(&ns, InMem::new_block_on_pollster(&ns));
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| (timestamp.μs_since_epoch + μs_after).into();
+    let newest_paths = |of: &Store<NamespaceName, InMem>, max_count| {
+        of.iter_newest(max_count).map(|ae| ae.into_parts().0.path).collect::<Vec<_>>()
+    };
+
+    assert_eq!(newest_paths(&store, 3), []);
+
+    put_with_own_payload(&mut store, &user1, &["c"], at(2));
+    put_with_own_payload(&mut store, &user1, &["a"], at(0));
+    put_with_own_payload(&mut store, &user2, &["d"], at(3));
+    put_with_own_payload(&mut store, &user2, &["b"], at(1));
+
+    assert_eq!(newest_paths(&store, 2), [Path::from_path(&["d"]), Path::from_path(&["c"])]);
+    assert_eq!(newest_paths(&store, 10), [
+        Path::from_path(&["d"]),
+        Path::from_path(&["c"]),
+        Path::from_path(&["b"]),
+        Path::from_path(&["a"]),
+    ]);
+    assert_eq!(newest_paths(&store, 0), []);
+
+    // Only the current `Entry`s.
+    put_with_own_payload(&mut store, &user1, &["c"], at(0)); // Older, so not stored.
+    put_with_own_payload(&mut store, &user2, &[], at(4)); // Prunes user2's.
+    assert_eq!(newest_paths(&store, 3), [
+        Path::empty(),
+        Path::from_path(&["c"]),
+        Path::from_path(&["a"]),
+    ]);
+}
+
+
+#[test]
+fn on_change()
+{
+    use {
+        sailce_data_model::store::StoreEvent,
+        std::{
+            cell::RefCell,
+            rc::Rc,
+        },
+    };
+
+    let ns = "namespace".into();
+    let user = User::new("uno");
+    let mut store = Store::new(&ns, InMem::new_block_on_pollster(&ns));
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| (timestamp.μs_since_epoch + μs_after).into();
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let take_events = || events.borrow_mut().drain(..).collect::<Vec<_>>();
+
+    let registered = Rc::clone(&events);
+    let supported = store.on_change(move |event| {
+        let kind_and_path = match event {
+            StoreEvent::Put(ae) => ("put", ae.into_parts().0.path),
+            StoreEvent::Pruned(ae) => ("pruned", ae.into_parts().0.path),
+            StoreEvent::Overwritten(ae) => ("overwritten", ae.into_parts().0.path),
+            StoreEvent::Deleted(ae) => ("deleted", ae.into_parts().0.path),
+            StoreEvent::Reset => ("reset", Path::empty()),
+        };
+        registered.borrow_mut().push(kind_and_path);
+    });
+    assert!(supported);
+
+    put_with_own_payload(&mut store, &user, &["a", "b"], at(1));
+    put_with_own_payload(&mut store, &user, &["a", "c"], at(2));
+    assert_eq!(take_events(), [
+        ("put", Path::from_path(&["a", "b"])),
+        ("put", Path::from_path(&["a", "c"])),
+    ]);
+
+    put_with_own_payload(&mut store, &user, &["a", "b"], at(3));
+    assert_eq!(take_events(), [
+        ("put", Path::from_path(&["a", "b"])),
+        ("overwritten", Path::from_path(&["a", "b"])),
+    ]);
+
+    // Older, so not current, and nothing changed.
+    put_with_own_payload(&mut store, &user, &["a", "c"], at(0));
+    assert_eq!(take_events(), []);
+    // Older than those at its extensions, which doesn't prevent it being current.
+    put_with_own_payload(&mut store, &user, &["a"], at(0));
+    assert_eq!(take_events(), [("put", Path::from_path(&["a"]))]);
+
+    put_with_own_payload(&mut store, &user, &["a"], at(4));
+    assert_eq!(take_events(), [
+        ("put", Path::from_path(&["a"])),
+        ("overwritten", Path::from_path(&["a"])),
+        ("pruned", Path::from_path(&["a", "b"])),
+        ("pruned", Path::from_path(&["a", "c"])),
+    ]);
+
+    let checkpoint = store.checkpoint();
+    assert_eq!(sync::Store::delete(&mut store, &user, &["a"]), Ok(true));
+    assert_eq!(take_events(), [("deleted", Path::from_path(&["a"]))]);
+    assert_eq!(sync::Store::delete(&mut store, &user, &["a"]), Ok(false));
+    assert_eq!(take_events(), []);
+    assert_eq!(store.restore(checkpoint), Ok(()));
+    assert_eq!(take_events(), [("reset", Path::empty())]);
+}
+
+
+#[test]
+fn area_matching_entries()
+{
+    use sailce_data_model::group::{
+        area::Subspace,
+        Area,
+    };
+
+    let ns = "namespace".into();
+    let user1 = User::new("uno");
+    let user2 = User::new("dos");
+    let mut store = Store::new(&ns, InMem::new_block_on_pollster(&ns));
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| (timestamp.μs_since_epoch + μs_after).into();
+
+    put_with_own_payload(&mut store, &user1, &["a"], at(0));
+    put_with_own_payload(&mut store, &user1, &["a", "b"], at(1));
+    put_with_own_payload(&mut store, &user1, &["ab"], at(2));
+    put_with_own_payload(&mut store, &user1, &["c"], at(3));
+    put_with_own_payload(&mut store, &user2, &["a", "z"], at(4));
+
+    let matching =
+        |area: &Area<User, Path>| paths_and_ages(area.matching_entries(&store), timestamp);
+
+    let all = Area::full();
+    assert_eq!(matching(&all), paths_and_ages(store.iter(), timestamp));
+
+    let only_user1 = Area::subspace(user1.clone());
+    assert_eq!(matching(&only_user1).len(), 4);
+
+    let prefixed =
+        Area { subspace: Subspace::Any, path: Path::from_path(&["a"]), times: (0 ..).into() };
+    assert_eq!(matching(&prefixed), vec![
+        (Path::from_path(&["a"]), 0),
+        (Path::from_path(&["a", "b"]), 1),
+        (Path::from_path(&["a", "z"]), 4),
+    ]);
+
+    let within_times = Area { times: (at(1) .. at(4)).into(), ..only_user1.clone() };
+    assert_eq!(matching(&within_times), vec![
+        (Path::from_path(&["a", "b"]), 1),
+        (Path::from_path(&["ab"]), 2),
+        (Path::from_path(&["c"]), 3),
+    ]);
+
+    let user2_prefixed = Area { subspace: Subspace::Id(user2), ..prefixed };
+    assert_eq!(matching(&user2_prefixed), vec![(Path::from_path(&["a", "z"]), 4)]);
+
+    assert_eq!(matching(&Area::empty()), vec![]);
+}
+
+
+#[test]
+fn entries_in_range()
+{
+    use sailce_data_model::group::ThreeDimRange;
+
+    let ns = "namespace".into();
+    let user1 = User::new("uno");
+    let user2 = User::new("dos");
+    let user3 = User::new("tres");
+    let mut store = Store::new(&ns, InMem::new_block_on_pollster(&ns));
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| (timestamp.μs_since_epoch + μs_after).into();
+
+    put_with_own_payload(&mut store, &user1, &["a"], at(0));
+    put_with_own_payload(&mut store, &user1, &["a", "z"], at(1));
+    put_with_own_payload(&mut store, &user1, &["b"], at(2));
+    put_with_own_payload(&mut store, &user1, &["b", "a"], at(3));
+    put_with_own_payload(&mut store, &user2, &["a"], at(4));
+    put_with_own_payload(&mut store, &user3, &["c"], at(5));
+
+    let in_range = |range: &ThreeDimRange<User, Path>| {
+        paths_and_ages(store.entries_in_range(range), timestamp)
+    };
+    let all = ThreeDimRange {
+        subspaces: (User::new("") ..).into(),
+        paths:     (Path::empty() ..).into(),
+        times:     (0 ..).into(),
+    };
+    assert_eq!(in_range(&all), paths_and_ages(store.iter(), timestamp));
+
+    // Half-open paths, whose start is included but whose end, and all it prefixes, are not.
+    let paths = ThreeDimRange {
+        paths: (Path::from_path(&["a"]) .. Path::from_path(&["b"])).into(),
+        ..all.clone()
+    };
+    assert_eq!(in_range(&paths), vec![
+        (Path::from_path(&["a"]), 0),
+        (Path::from_path(&["a"]), 4),
+        (Path::from_path(&["a", "z"]), 1),
+    ]);
+
+    // Half-open subspaces, which are ordered by name: "dos" < "tres" < "uno".
+    let subspaces = ThreeDimRange { subspaces: (user2 .. user1.clone()).into(), ..all.clone() };
+    assert_eq!(in_range(&subspaces), vec![
+        (Path::from_path(&["a"]), 4),
+        (Path::from_path(&["c"]), 5),
+    ]);
+
+    // Half-open times, combined with the other dimensions.
+    let times = ThreeDimRange {
+        subspaces: (user1 ..).into(),
+        paths:     (Path::from_path(&["a", "z"]) ..).into(),
+        times:     (at(1) .. at(3)).into(),
+    };
+    assert_eq!(in_range(&times), vec![
+        (Path::from_path(&["a", "z"]), 1),
+        (Path::from_path(&["b"]), 2),
+    ]);
+
+    assert_eq!(in_range(&ThreeDimRange { times: (at(6) ..).into(), ..all }), vec![]);
+}
+
+
+#[test]
+fn fingerprint_of_range()
+{
+    use {
+        sailce_data_model::{
+            group::ThreeDimRange,
+            reconcile::{
+                Fingerprint,
+                XorDigests,
+            },
+        },
+        std::collections::hash_map::DefaultHasher,
+    };
+
+    type Fp = XorDigests<DefaultHasher>;
+    let combine = <Fp as Fingerprint<Params>>::combine;
+    let neutral = <Fp as Fingerprint<Params>>::neutral;
+
+    let ns: NamespaceName = "namespace".into();
+    let users = [User::new("uno"), User::new("dos"), User::new("tres")];
+    let timestamp = Timestamp::now();
+
+    // Pseudo-random, but reproducible, sets of entries.
+    let mut state = 0x_2545_f491_4f6c_dd1d_u64;
+    let mut random = |bound: u64| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state.rem_euclid(bound)
+    };
+    let mut random_entry = || {
+        let mut pick = |n| usize::try_from(random(n)).unwrap();
+        let user = users.get(pick(3)).unwrap();
+        let full_path = [*["a", "b", "c"].get(pick(3)).unwrap(), "x"];
+        let path = full_path.get(.. pick(2) + 1).unwrap();
+        let (payload, payload_digest) = payload_and_digest(path.concat());
+        Entry {
+            namespace_id: Arc::clone(&ns),
+            subspace_id: user.clone(),
+            path: Path::from_path(path),
+            timestamp: (timestamp.μs_since_epoch + random(1000)).into(),
+            payload_digest,
+            payload_length: payload.len_as_u64(),
+        }
+    };
+
+    for _ in 0 .. 20 {
+        let hashes: [u64; 8] =
+            std::array::from_fn(|_| <Fp as Fingerprint<Params>>::lift(&random_entry()));
+        let [a, b, c, ..] = &hashes;
+        assert_eq!(combine(a, b), combine(b, a));
+        assert_eq!(combine(&combine(a, b), c), combine(a, &combine(b, c)));
+        assert_eq!(combine(a, &neutral()), *a);
+        // Folding in any order, or grouping, gives the same.
+        let forward = hashes.iter().fold(neutral(), |acc, h| combine(&acc, h));
+        let backward = hashes.iter().rev().fold(neutral(), |acc, h| combine(&acc, h));
+        let (left, right) = hashes.split_at(3);
+        let grouped = combine(
+            &left.iter().fold(neutral(), |acc, h| combine(&acc, h)),
+            &right.iter().fold(neutral(), |acc, h| combine(&acc, h)),
+        );
+        assert_eq!(forward, backward);
+        assert_eq!(forward, grouped);
+    }
+
+    // Stores with the same entries have the same fingerprints, however they were put.
+    let new_store = || Store::new(&ns, InMem::new_block_on_pollster(&ns));
+    let at = |μs_after: u64| (timestamp.μs_since_epoch + μs_after).into();
+    let puts: [(&User, &[&str], u64); 4] = [
+        (&users[0], &["a"], 0),
+        (&users[0], &["b", "x"], 1),
+        (&users[1], &["a"], 2),
+        (&users[2], &["c"], 3),
+    ];
+    let mut store_a = new_store();
+    for &(user, path, μs_after) in &puts {
+        put_with_own_payload(&mut store_a, user, path, at(μs_after));
+    }
+    let mut store_b = new_store();
+    for &(user, path, μs_after) in puts.iter().rev() {
+        put_with_own_payload(&mut store_b, user, path, at(μs_after));
+    }
+    let all = ThreeDimRange {
+        subspaces: (User::new("") ..).into(),
+        paths:     (Path::empty() ..).into(),
+        times:     (0 ..).into(),
+    };
+    let some = ThreeDimRange { times: (at(1) .. at(3)).into(), ..all.clone() };
+    let fingerprint = |store: &Store<NamespaceName, InMem>, range: &ThreeDimRange<User, Path>| {
+        store.fingerprint_of_range::<Fp, Path>(range)
+    };
+    assert_eq!(fingerprint(&store_a, &all), fingerprint(&store_b, &all));
+    assert_eq!(fingerprint(&store_a, &some), fingerprint(&store_b, &some));
+    assert_ne!(fingerprint(&store_a, &all), fingerprint(&store_a, &some));
+    assert_eq!(
+        fingerprint(&store_a, &ThreeDimRange { times: (at(4) ..).into(), ..all.clone() }),
+        neutral()
+    );
+    // A differing entry makes the fingerprints differ.
+    put_with_own_payload(&mut store_b, &users[1], &["b"], at(4));
+    assert_ne!(fingerprint(&store_a, &all), fingerprint(&store_b, &all));
+    assert_eq!(fingerprint(&store_a, &some), fingerprint(&store_b, &some));
+}
+
+
+#[test]
+#[allow(clippy::big_endian_bytes)]
+fn fingerprint_lift_is_portable()
+{
+    use {
+        sailce_data_model::reconcile::{
+            Fingerprint,
+            XorDigests,
+        },
+        std::hash::Hasher,
+    };
+
+    /// FNV-1a, to have a `finish` of exactly the bytes that were written.
+    struct Fnv(u64);
+
+    impl Default for Fnv
+    {
+        fn default() -> Self
+        {
+            Self(0xcbf2_9ce4_8422_2325)
+        }
+    }
+
+    impl Hasher for Fnv
+    {
+        fn finish(&self) -> u64
+        {
+            self.0
+        }
+
+        fn write(
+            &mut self,
+            bytes: &[u8],
+        )
+        {
+            for &byte in bytes {
+                self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+    }
+
+    let entry = Entry {
+        namespace_id:   NamespaceName::from("namespace"),
+        subspace_id:    User { name: "uno".into(), id: 0x0102 },
+        path:           Path::from_path(&["ab", ""]),
+        timestamp:      0x0304.into(),
+        payload_digest: 0x0506,
+        payload_length: 0x0708,
+    };
+    // Integers are big-endian, and lengths are 64 bits, regardless of the platform.
+    let expected: &[&[u8]] = &[
+        b"uno\xff", // As by `str`'s `Hash`.
+        &0x0102_u128.to_be_bytes(),
+        &2_u64.to_be_bytes(),
+        &2_u64.to_be_bytes(),
+        b"ab",
+        &0_u64.to_be_bytes(),
+        &0x0304_u64.to_be_bytes(),
+        &0x0506_u64.to_be_bytes(),
+        &0x0708_u64.to_be_bytes(),
+    ];
+    let mut fnv = Fnv::default();
+    fnv.write(&expected.concat());
+    assert_eq!(<XorDigests<Fnv> as Fingerprint<Params>>::lift(&entry), fnv.finish());
+}
+
+
+#[test]
+fn join_all()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let new_store = || Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let timestamp = Timestamp::now();
+    let put = |store: &mut Store<NamespaceName, InMem>, path: &[&str], μs_after: u64| {
+        put_with_own_payload(store, &user1, path, (timestamp.μs_since_epoch + μs_after).into());
+    };
+    let contents = |store: &Store<NamespaceName, InMem>| paths_and_ages(store.iter(), timestamp);
+
+    let mut replica_a = new_store();
+    put(&mut replica_a, &["x"], 0);
+    put(&mut replica_a, &["y", "1"], 0);
+    let mut replica_b = new_store();
+    put(&mut replica_b, &["x"], 1); // Conflicts with `replica_a`'s and is newer.
+    put(&mut replica_b, &["z"], 0);
+    let mut replica_c = new_store();
+    put(&mut replica_c, &["y"], 2); // Prunes `replica_a`'s `["y", "1"]`.
+    put(&mut replica_c, &["w"], 0);
+    put(&mut replica_c, &["x"], 0); // Same as `replica_a`'s.
+
+    let mut pairwise = new_store();
+    for replica in [&replica_a, &replica_b, &replica_c] {
+        assert_eq!(pairwise.join(replica), Ok(()));
+    }
+    let expected = vec![
+        (Path::from_path(&["w"]), 0),
+        (Path::from_path(&["x"]), 1),
+        (Path::from_path(&["y"]), 2),
+        (Path::from_path(&["z"]), 0),
+    ];
+    assert_eq!(contents(&pairwise), expected);
+
+    let all = Store::join_all(&ns1, [&replica_a, &replica_b, &replica_c]).expect("should join");
+    assert_eq!(contents(&all), expected);
+    // The order doesn't matter.
+    let reordered = Store::join_all(&ns1, [&replica_c, &replica_a, &replica_b]).expect("ok");
+    assert_eq!(contents(&reordered), expected);
+    // None of the inputs was mutated.
+    assert_eq!(replica_a.count(), 2);
+    assert_eq!(replica_b.count(), 2);
+    assert_eq!(replica_c.count(), 3);
+    // The same one given more than once is fine.
+    let repeated = Store::join_all(&ns1, [&replica_c, &replica_c]).expect("should join");
+    assert_eq!(contents(&repeated), contents(&replica_c));
+    // There must be some to make it from.
+    assert!(matches!(Store::join_all(&ns1, []), Err(JoinError::NoStores)));
+
+    // A store for a different Namespace is rejected.
+    let ns2 = "namespace-2".into();
+    let other_ns = Store::new(&ns2, InMem::new_block_on_pollster(&ns2));
+    assert!(matches!(
+        Store::join_all(&ns1, [&replica_a, &other_ns]),
+        Err(JoinError::DifferentNamespace)
+    ));
+    assert!(matches!(Store::join_all(&ns1, [&other_ns]), Err(JoinError::DifferentNamespace)));
+}
+
+
+#[test]
+fn join_reporting()
+{
+    use sailce_data_model::store::{
+        sync::Store as _,
+        JoinReport,
+    };
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let new_store = || Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let timestamp = Timestamp::now();
+    let put = |store: &mut Store<NamespaceName, InMem>, path: &[&str], μs_after: u64| {
+        put_with_own_payload(store, &user1, path, (timestamp.μs_since_epoch + μs_after).into());
+    };
+
+    let mut ours = new_store();
+    put(&mut ours, &["a"], 1);
+    put(&mut ours, &["b", "1"], 0);
+    put(&mut ours, &["b", "2"], 0);
+    put(&mut ours, &["c"], 0);
+    put(&mut ours, &["d"], 1);
+    let mut theirs = new_store();
+    put(&mut theirs, &["a"], 0); // Older than ours.
+    put(&mut theirs, &["b"], 1); // Prunes both of ours under it.
+    put(&mut theirs, &["c"], 1); // Overwrites ours.
+    put(&mut theirs, &["d"], 1); // Same as ours.
+    put(&mut theirs, &["e"], 0); // New.
+
+    let report = ours.join_reporting(&theirs);
+    assert_eq!(report, Ok(JoinReport { inserted: 3, pruned: 2, superseded: 1 }));
+    assert_eq!(paths_and_ages(ours.iter(), timestamp), vec![
+        (Path::from_path(&["a"]), 1),
+        (Path::from_path(&["b"]), 1),
+        (Path::from_path(&["c"]), 1),
+        (Path::from_path(&["d"]), 1),
+        (Path::from_path(&["e"]), 0),
+    ]);
+    // Joining again inserts nothing, and theirs that's older is still superseded.
+    assert_eq!(
+        ours.join_reporting(&theirs),
+        Ok(JoinReport { inserted: 0, pruned: 0, superseded: 1 })
+    );
+
+    // A store for a different Namespace is rejected.
+    let ns2 = "namespace-2".into();
+    let other_ns = Store::new(&ns2, InMem::new_block_on_pollster(&ns2));
+    assert_eq!(ours.join_reporting(&other_ns), Err(JoinError::DifferentNamespace));
+}
+
+
+#[test]
+fn join_only_newest()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let new_store = || Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| (timestamp.μs_since_epoch + μs_after).into();
+
+    let mut theirs = new_store();
+    put_with_own_payload(&mut theirs, &user1, &["x", "y"], at(0));
+    put_with_own_payload(&mut theirs, &user1, &["x"], at(1));
+    put_with_own_payload(&mut theirs, &user1, &["x"], at(2)); // Overwrites the older.
+    assert_eq!(theirs.iter_history(&user1, &["x"]).count(), 2);
+
+    let mut ours = new_store();
+    assert_eq!(ours.join(&theirs), Ok(()));
+    assert_eq!(paths_and_ages(ours.iter(), timestamp), paths_and_ages(theirs.iter(), timestamp));
+    // Their overwritten version isn't needed, and so isn't joined.
+    assert_eq!(ours.iter_history(&user1, &["x"]).count(), 1);
+}
+
+
+#[test]
+fn join_entries()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let new_store = || Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let timestamp = Timestamp::now();
+    let put = |store: &mut Store<NamespaceName, InMem>, path: &[&str], μs_after: u64| {
+        put_with_own_payload(store, &user1, path, (timestamp.μs_since_epoch + μs_after).into());
+    };
+    let with_payloads = |store: &Store<NamespaceName, InMem>| {
+        store
+            .iter()
+            .map(|auth_entry| {
+                let entry = auth_entry.entry();
+                let payload = store.get(&entry.subspace_id, &entry.path).unwrap();
+                (auth_entry, payload)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut ours = new_store();
+    put(&mut ours, &["a"], 1);
+    put(&mut ours, &["b", "1"], 0);
+    put(&mut ours, &["c"], 0);
+    let mut theirs = new_store();
+    put(&mut theirs, &["a"], 0); // Older than ours.
+    put(&mut theirs, &["b"], 1); // Prunes ours under it.
+    put(&mut theirs, &["c"], 1); // Overwrites ours.
+    put(&mut theirs, &["d"], 0); // New.
+
+    // The same as `join`, regardless of the order.
+    let mut joined = new_store();
+    joined.join(&ours).unwrap();
+    joined.join(&theirs).unwrap();
+    let mut entries = with_payloads(&theirs);
+    entries.reverse();
+    assert_eq!(ours.join_entries(entries), Ok(()));
+    assert_eq!(paths_and_ages(ours.iter(), timestamp), paths_and_ages(joined.iter(), timestamp));
+    assert_eq!(paths_and_ages(ours.iter(), timestamp), vec![
+        (Path::from_path(&["a"]), 1),
+        (Path::from_path(&["b"]), 1),
+        (Path::from_path(&["c"]), 1),
+        (Path::from_path(&["d"]), 0),
+    ]);
+    // With their payloads.
+    assert!(ours.get(&user1, &["d"]).unwrap().is_some());
+
+    // Entries for a different Namespace are rejected.
+    let ns2 = "namespace-2".into();
+    let mut other_ns = Store::new(&ns2, InMem::new_block_on_pollster(&ns2));
+    put_with_own_payload(&mut other_ns, &user1, &["e"], timestamp);
+    assert_eq!(ours.join_entries(with_payloads(&other_ns)), Err(JoinError::DifferentNamespace));
+
+    // Failures of putting are given as the same type of error.
+    let (payload, payload_digest) = payload_and_digest("wrong");
+    let auth_entry = self_authorised_entry(&ns1, &user1, "f", payload_digest ^ 1, 5);
+    assert_eq!(
+        ours.join_entries([(auth_entry, Some(payload))]),
+        Err(JoinError::Join(in_mem::PutError::WrongDigest {
+            given:    payload_digest ^ 1,
+            computed: payload_digest,
+        }))
+    );
+}
+
+
+#[test]
+fn full_area_of_interest()
+{
+    use sailce_data_model::group::{
+        area::of_interest::Max,
+        Area,
+        AreaOfInterest,
+    };
 
-    fn get_block_on_fn<'f, F>(&self) -> impl 'f + FnOnce(F, Self::ExecutorData) -> F::Output
-    where F: Future + 'f
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let user2 = User::new("dos");
+    let mut store = Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let timestamp = Timestamp::now();
+    put_with_own_payload(&mut store, &user1, &["a", "b"], timestamp);
+    put_with_own_payload(&mut store, &user2, &[], 0.into());
+
+    let aoi: AreaOfInterest<User, Vec<Vec<u8>>> = store.full_area_of_interest();
+    assert_eq!(aoi.area, Area::full());
+    assert_eq!((aoi.max_count, aoi.max_size), (Max::Unlimited, Max::Unlimited));
+    assert_eq!(store.iter().count(), 2);
+    for auth_entry in store.iter() {
+        assert!(pollster::block_on(aoi.includes(auth_entry.entry(), &store)));
+    }
+
+    // Doesn't include those of a different Namespace.
+    let ns2 = "namespace-2".into();
+    let mut other_ns = Store::new(&ns2, InMem::new_block_on_pollster(&ns2));
+    put_with_own_payload(&mut other_ns, &user1, &["a", "b"], timestamp);
+    let other_entry = other_ns.iter().next().expect("was put");
+    assert!(!pollster::block_on(aoi.includes(other_entry.entry(), &store)));
+    assert!(pollster::block_on(aoi.includes(other_entry.entry(), &other_ns)));
+}
+
+
+#[test]
+fn try_iter()
+{
+    use {
+        sailce_data_model::{
+            store::sync::Store as _,
+            StoreExt as _,
+        },
+        unreadable::{
+            GetError,
+            Unreadable,
+        },
+    };
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let mut store = Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let timestamp = Timestamp::now();
+    put_with_own_payload(&mut store, &user1, &["a"], timestamp);
+    put_with_own_payload(&mut store, &user1, &["b"], timestamp);
+    put_with_own_payload(&mut store, &user1, &["c"], timestamp);
+
+    // The in-memory one never fails.
+    let all = store.try_iter().collect::<Result<Vec<_>, _>>().expect("all `Ok`");
+    assert_eq!(all, store.iter().collect::<Vec<_>>());
+    assert_eq!(all.len(), 3);
+
+    // One that fails on a specific entry reports it, without preventing the others.
+    let mut inner = InMem::new_block_on_pollster(&ns1);
+    for auth_entry in store.iter() {
+        let payload = store.get(&auth_entry.entry().subspace_id, &auth_entry.entry().path);
+        let put = inner.put(&ns1, auth_entry, payload.expect("no error"));
+        assert_eq!(pollster::block_on(put), Ok(()));
+    }
+    let unreadable = Store::new(&ns1, Unreadable { inner, unreadable: Path::from_path(&["b"]) });
+    assert_eq!(unreadable.iter().count(), 3);
+    let results = unreadable.try_iter().collect::<Vec<_>>();
+    assert_eq!(results.len(), 3);
+    let (oks, errs): (Vec<_>, Vec<_>) = results.into_iter().partition(Result::is_ok);
+    let ok_paths = oks.into_iter().map(|r| r.unwrap().into_parts().0.path).collect::<Vec<_>>();
+    assert_eq!(ok_paths, [Path::from_path(&["a"]), Path::from_path(&["c"])]);
+    assert_eq!(
+        errs.into_iter().map(Result::unwrap_err).collect::<Vec<_>>(),
+        [GetError::Unreadable(Path::from_path(&["b"]))]
+    );
+}
+
+
+#[test]
+fn transaction()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let mut store = Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| (timestamp.μs_since_epoch + μs_after).into();
+    put_with_own_payload(&mut store, &user1, &["a", "1"], at(0));
+    put_with_own_payload(&mut store, &user1, &["b"], at(0));
+    let before = paths_and_ages(store.iter(), timestamp);
+    let token = store.change_token();
+
+    // Fails after partially putting, which are all undone.
+    let failed = store.transaction(|s| {
+        put_with_own_payload(s, &user1, &["c"], at(1)); // New.
+        put_with_own_payload(s, &user1, &["b"], at(1)); // Overwrites.
+        put_with_own_payload(s, &user1, &["a"], at(1)); // Prunes `["a", "1"]`.
+        assert_eq!(s.iter().count(), 3);
+        Err::<(), _>("failed")
+    });
+    assert_eq!(failed, Err(TransactionError::Transaction("failed")));
+    assert_eq!(paths_and_ages(store.iter(), timestamp), before);
+    assert!(store.get(&user1, &["c"]).expect("no error").is_none());
+    assert!(store.get(&user1, &["a", "1"]).expect("no error").is_some());
+    // Restoring is itself a mutation.
+    assert!(store.change_token() > token);
+
+    // Succeeds, which keeps them.
+    let succeeded = store.transaction(|s| {
+        put_with_own_payload(s, &user1, &["c"], at(1));
+        Ok::<_, ()>(42)
+    });
+    assert_eq!(succeeded, Ok(42));
+    let expected = vec![
+        (Path::from_path(&["a", "1"]), 0),
+        (Path::from_path(&["b"]), 0),
+        (Path::from_path(&["c"]), 1),
+    ];
+    assert_eq!(paths_and_ages(store.iter(), timestamp), expected);
+
+    // Directly.
+    let snapshot = store.checkpoint();
+    put_with_own_payload(&mut store, &user1, &[], at(2)); // Prunes all.
+    assert_eq!(store.iter().count(), 1);
+    store.restore(snapshot).unwrap();
+    assert_eq!(paths_and_ages(store.iter(), timestamp), expected);
+}
+
+
+#[test]
+fn get_prefix_pruning()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    /// Whether `get` finds the entry at `path`, which must be `None` exactly when pruned.
+    fn found(
+        store: &Store<NamespaceName, InMem>,
+        user: &User,
+        path: &[&str],
+    ) -> bool
     {
-        fn adapt<F: Future>(
-            fut: F,
-            (): (),
-        ) -> F::Output
-        {
-            Pollster::block_on(fut, 123_u32)
+        let got = store.get(user, path).expect("no error");
+        if let Some(payload) = &got {
+            assert_eq!(*payload, InMemPayload::new(path.concat()).expect("size fits"));
         }
+        got.is_some()
+    }
 
-        adapt
+    fn iterated(
+        store: &Store<NamespaceName, InMem>,
+        user: &User,
+        path: &[&str],
+    ) -> bool
+    {
+        store.iter().any(|auth_entry| {
+            let entry = auth_entry.entry();
+            entry.subspace_id == *user && entry.path.eq_components(path)
+        })
     }
 
-    fn get_executor_data(&self) -> Self::ExecutorData {}
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let user2 = User::new("dos");
+    let mut store = Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| (timestamp.μs_since_epoch + μs_after).into();
+    let deep: [&[&str]; 3] = [&["a"], &["a", "b"], &["a", "b", "c"]];
+    let check = |in_store: &Store<NamespaceName, InMem>, expected: [bool; 3]| {
+        for (path, is_present) in deep.into_iter().zip(expected) {
+            assert_eq!(found(in_store, &user1, path), is_present, "{path:?}");
+            assert_eq!(iterated(in_store, &user1, path), is_present, "{path:?}");
+        }
+    };
+
+    put_with_own_payload(&mut store, &user1, &["a", "b", "c"], at(5));
+    put_with_own_payload(&mut store, &user1, &["a"], at(3)); // Older, so doesn't prune.
+    check(&store, [true, false, true]);
+    put_with_own_payload(&mut store, &user1, &["a", "b"], at(4)); // Older, so doesn't prune.
+    check(&store, [true, true, true]);
+
+    // Newer entries at non-prefixes, i.e. at siblings or in another subspace, don't prune.
+    put_with_own_payload(&mut store, &user1, &["a", "bb"], at(9));
+    put_with_own_payload(&mut store, &user1, &["a", "b", "cc"], at(9));
+    put_with_own_payload(&mut store, &user2, &["a"], at(9));
+    check(&store, [true, true, true]);
+    assert!(found(&store, &user1, &["a", "bb"]));
+
+    // A newer prefix at the middle depth prunes only what's below it.
+    put_with_own_payload(&mut store, &user1, &["a", "b"], at(6));
+    check(&store, [true, true, false]);
+    assert!(found(&store, &user1, &["a", "b", "cc"])); // Still newer than that prefix.
+    assert!(found(&store, &user1, &["a", "bb"]));
+
+    // A newer prefix at the top depth prunes everything below it.
+    put_with_own_payload(&mut store, &user1, &["a"], at(10));
+    check(&store, [true, false, false]);
+    assert!(!found(&store, &user1, &["a", "bb"]));
+    assert!(!found(&store, &user1, &["a", "b", "cc"]));
+    assert!(found(&store, &user2, &["a"]));
 }
 
-impl sync::Store<Pollster, Params, InMem>
-    for Store<<Params as sailce_data_model::Params>::NamespaceId, InMem>
+
+#[test]
+fn get_range()
+{
+    use sailce_data_model::{
+        payload::ToBoxedSliceError,
+        store::{
+            sync::Store as _,
+            GetRangeError,
+        },
+    };
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let mut store = Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let (payload, payload_digest) = payload_and_digest("foo bar baz");
+    let auth_entry = AuthorisedEntry::new(
+        Entry {
+            namespace_id: Arc::clone(&ns1),
+            subspace_id: user1.clone(),
+            path: ["a"],
+            timestamp: Timestamp::now(),
+            payload_digest,
+            payload_length: payload.len_as_u64(),
+        },
+        Permission {
+            user:       user1.clone(),
+            namespaces: [Arc::clone(&ns1)].into(),
+            subspaces:  [].into(),
+            paths:      [Path::empty()].into(),
+            times:      [(0 ..).into()].into(),
+        },
+    )
+    .expect("auth should succeed");
+    assert_eq!(store.put(auth_entry, Some(payload)), Ok(()));
+
+    assert_eq!(store.get_range(&user1, &["a"], 4 .. 7), Ok(Some(b"bar"[..].into())));
+    assert_eq!(store.get_range(&user1, &["a"], 8 ..), Ok(Some(b"baz"[..].into())));
+    assert_eq!(store.get_range(&user1, &["a"], .. 3), Ok(Some(b"foo"[..].into())));
+    assert_eq!(store.get_range(&user1, &["a"], 5 .. 5), Ok(Some([][..].into())));
+    assert_eq!(
+        store.get_range(&user1, &["a"], 8 .. 12),
+        Err(GetRangeError::Range(ToBoxedSliceError::OutOfBounds { at: NonZeroU64::new(12) }))
+    );
+    assert_eq!(store.get_range(&user1, &["nope"], 0 .. 1), Ok(None));
+}
+
+
+#[test]
+fn get_entry()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let mut store = Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let (payload, digest) = payload_and_digest("contents");
+    let older = self_authorised_entry(&ns1, &user1, "a", digest, payload.len_as_u64());
+    std::thread::sleep(std::time::Duration::from_millis(1)); // Ensure later timestamps.
+    let newer = self_authorised_entry(&ns1, &user1, "a", digest, payload.len_as_u64());
+
+    assert_eq!(store.get_entry(&user1, &["a"]).map(|got| got.is_some()), Ok(false));
+
+    // Succeeds even though the payload hasn't been supplied, unlike `get`.
+    assert_eq!(store.put(older.clone(), None::<InMemPayload>), Ok(()));
+    let _missing_payload = store.get(&user1, &["a"]).unwrap_err();
+    let found = store.get_entry(&user1, &["a"]).unwrap().unwrap();
+    assert!(found.path.eq_components(&["a"]));
+    assert_eq!(found.timestamp, older.entry().timestamp);
+    assert_eq!((found.payload_digest, found.payload_length), (digest, payload.len_as_u64()));
+
+    // The newest.
+    assert_eq!(store.put(newer.clone(), Some(payload)), Ok(()));
+    let found_newer = store.get_entry(&user1, &["a"]).unwrap().unwrap();
+    assert_eq!(found_newer.timestamp, newer.entry().timestamp);
+
+    // Not at other locations, nor when pruned.
+    assert_eq!(store.get_entry(&user1, &["b"]).map(|got| got.is_some()), Ok(false));
+    let other_user = User::new("dos");
+    assert_eq!(store.get_entry(&other_user, &["a"]).map(|got| got.is_some()), Ok(false));
+    put_with_own_payload(&mut store, &user1, &["a", "b"], Timestamp::now());
+    assert_eq!(store.get_entry(&user1, &["a", "b"]).map(|got| got.is_some()), Ok(true));
+    std::thread::sleep(std::time::Duration::from_millis(1));
+    put_with_own_payload(&mut store, &user1, &[], Timestamp::now());
+    assert_eq!(store.get_entry(&user1, &["a", "b"]).map(|got| got.is_some()), Ok(false));
+}
+
+
+#[test]
+fn contains()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let mut store = Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let (payload, digest) = payload_and_digest("contents");
+    let entry = self_authorised_entry(&ns1, &user1, "a", digest, payload.len_as_u64());
+
+    assert_eq!(store.contains(&user1, &["a"]), Ok(false));
+    // Even without its payload yet, unlike `get`.
+    assert_eq!(store.put(entry, None::<InMemPayload>), Ok(()));
+    let _missing_payload = store.get(&user1, &["a"]).unwrap_err();
+    assert_eq!(store.contains(&user1, &["a"]), Ok(true));
+    // Not at other locations.
+    assert_eq!(store.contains(&user1, &["b"]), Ok(false));
+    assert_eq!(store.contains(&user1, &["a", "b"]), Ok(false));
+    assert_eq!(store.contains(&User::new("dos"), &["a"]), Ok(false));
+    // Nor when pruned.
+    std::thread::sleep(std::time::Duration::from_millis(1)); // Ensure later timestamps.
+    put_with_own_payload(&mut store, &user1, &[], Timestamp::now());
+    assert_eq!(store.contains(&user1, &["a"]), Ok(false));
+    assert_eq!(store.contains(&user1, &Path::empty()), Ok(true));
+}
+
+
+/// An entry at `path`, with the given digest and length, that is authorised by its own
+/// `subspace_id`.
+fn self_authorised_entry(
+    namespace_id: &NamespaceName,
+    user: &User,
+    path: &'static str,
+    payload_digest: u64,
+    payload_length: u64,
+) -> AuthorisedEntry<Params, [&'static str; 1], Permission>
+{
+    AuthorisedEntry::new(
+        Entry {
+            namespace_id: Arc::clone(namespace_id),
+            subspace_id: user.clone(),
+            path: [path],
+            timestamp: Timestamp::now(),
+            payload_digest,
+            payload_length,
+        },
+        Permission {
+            user:       user.clone(),
+            namespaces: [Arc::clone(namespace_id)].into(),
+            subspaces:  [].into(),
+            paths:      [Path::empty()].into(),
+            times:      [(0 ..).into()].into(),
+        },
+    )
+    .expect("auth should succeed")
+}
+
+
+#[test]
+fn put_returning()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    /// What `iter` yields for the `["a"]` location of `store`.
+    fn iterated_a(store: &Store<NamespaceName, InMem>) -> Option<StoreAuthorisedEntry<InMem>>
+    {
+        store.iter().find(|auth_entry| auth_entry.entry().path.eq_components(&["a"]))
+    }
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let mut store = Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let (payload, payload_digest) = payload_and_digest("returned");
+    let len = payload.len_as_u64();
+
+    let older = self_authorised_entry(&ns1, &user1, "a", payload_digest, len);
+    std::thread::sleep(std::time::Duration::from_millis(1)); // Ensure a later timestamp.
+    let newer = self_authorised_entry(&ns1, &user1, "a", payload_digest, len);
+    assert!(newer.entry().is_newer_than(older.entry()));
+    let given = newer.entry().clone();
+    let returned = store
+        .put_returning(newer, Some(payload.clone()))
+        .expect("should succeed")
+        .expect("should be retained");
+    let stored = returned.entry();
+    assert_eq!(stored.namespace_id, given.namespace_id);
+    assert_eq!(stored.subspace_id, given.subspace_id);
+    assert!(stored.path.eq_components(&given.path));
+    assert_eq!(stored.timestamp, given.timestamp);
+    assert_eq!(stored.payload_digest, given.payload_digest);
+    assert_eq!(stored.payload_length, given.payload_length);
+    // The same as what's retrieved afterwards.
+    assert_eq!(iterated_a(&store).as_ref(), Some(&returned));
+
+    // An obsolete one is still returned as stored, but isn't what's retrieved.
+    let given_older = older.entry().clone();
+    let returned_older = store
+        .put_returning(older, Some(payload))
+        .expect("should succeed")
+        .expect("should be retained in its history");
+    assert_eq!(returned_older.entry().timestamp, given_older.timestamp);
+    assert_eq!(iterated_a(&store).as_ref(), Some(&returned));
+
+    // Entries with different namespace are still rejected.
+    let ns2 = "namespace-2".into();
+    let (other_payload, other_digest) = payload_and_digest("other");
+    let entry_c = self_authorised_entry(&ns2, &user1, "c", other_digest, 5);
+    assert_eq!(
+        store.put_returning(entry_c, Some(other_payload)),
+        Err(PutError::DifferentNamespace)
+    );
+}
+
+
+#[test]
+fn put_returning_default()
+{
+    use {
+        sailce_data_model::store::r#async::Store as _,
+        unreadable::Unreadable,
+    };
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let inner = InMem::new_block_on_pollster(&ns1);
+    // Its `StoreExt` doesn't override `put_returning`, nor `iter_history`.
+    let mut store = Store::new(&ns1, Unreadable { inner, unreadable: Path::from_path(&["b"]) });
+    let (payload, payload_digest) = payload_and_digest("returned");
+    let len = payload.len_as_u64();
+
+    let older = self_authorised_entry(&ns1, &user1, "a", payload_digest, len);
+    std::thread::sleep(std::time::Duration::from_millis(1)); // Ensure a later timestamp.
+    let newer = self_authorised_entry(&ns1, &user1, "a", payload_digest, len);
+    let given = newer.entry().clone();
+    let returned = pollster::block_on(store.put_returning(newer, Some(payload.clone())))
+        .expect("should succeed")
+        .expect("should find it");
+    assert_eq!(returned.entry().timestamp, given.timestamp);
+    assert_eq!(returned.entry().payload_digest, given.payload_digest);
+    assert!(returned.entry().path.eq_components(&given.path));
+
+    // An obsolete one isn't found, because only the current is in the default `iter_history`.
+    assert_eq!(pollster::block_on(store.put_returning(older, Some(payload))), Ok(None));
+}
+
+
+#[test]
+fn iter_history()
 {
+    use sailce_data_model::{
+        payload::Extra as _,
+        store::sync::Store as _,
+    };
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let mut store = Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let versions = ["first", "second", "third"].map(|bytes| {
+        let (payload, digest) = payload_and_digest(bytes);
+        let auth_entry = self_authorised_entry(&ns1, &user1, "a", digest, payload.len_as_u64());
+        std::thread::sleep(std::time::Duration::from_millis(1)); // Ensure later timestamps.
+        (auth_entry, payload)
+    });
+    let [(first, first_payload), (second, second_payload), (third, third_payload)] = versions;
+    let expected = [("third", &third), ("second", &second), ("first", &first)]
+        .map(|(bytes, auth_entry)| (auth_entry.entry().timestamp, bytes.as_bytes().to_vec()));
+
+    // Out of order, and with a redundant put of the same entry that supplies its payload later.
+    assert_eq!(store.put(second.clone(), None::<InMemPayload>), Ok(()));
+    assert_eq!(store.put(third, Some(third_payload)), Ok(()));
+    assert_eq!(store.put(first, Some(first_payload)), Ok(()));
+    assert_eq!(store.put(second, Some(second_payload)), Ok(()));
+
+    let history = store
+        .iter_history(&user1, &["a"])
+        .map(|(auth_entry, payload)| {
+            let entry = auth_entry.into_parts().0;
+            assert!(entry.path.eq_components(&["a"]));
+            let mut payload = payload.expect("all were given their payload");
+            let len = payload.len_as_u64();
+            let no_callback = None::<fn(&mut [u8])>;
+            let bytes = pollster::block_on(payload.to_boxed_slice(0 .. len, no_callback, false))
+                .expect("should succeed");
+            (entry.timestamp, bytes.into_vec())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(history, expected);
+
+    // Only the newest is what's current.
+    assert_eq!(store.iter().count(), 1);
+    // Other locations have no history.
+    assert_eq!(store.iter_history(&user1, &["b"]).count(), 0);
+    assert_eq!(store.iter_history(&User::new("dos"), &["a"]).count(), 0);
 }
 
 
-pub(crate) fn current_timestamp() -> Timestamp
+#[test]
+fn iter_history_default()
 {
-    let duration = SystemTime::UNIX_EPOCH
-        .elapsed()
-        .expect("time drift usually won't occur during these tests");
-    Timestamp {
-        μs_since_epoch: duration
-            .as_micros()
-            .try_into()
-            .expect("584.5K years until this will fail"),
+    use {
+        sailce_data_model::{
+            store::r#async::Store as _,
+            StoreExt as _,
+        },
+        unreadable::Unreadable,
+    };
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let mut inner = InMem::new_block_on_pollster(&ns1);
+    for bytes in ["older", "newer"] {
+        let (payload, digest) = payload_and_digest(bytes);
+        let auth_entry = self_authorised_entry(&ns1, &user1, "a", digest, payload.len_as_u64());
+        std::thread::sleep(std::time::Duration::from_millis(1)); // Ensure later timestamps.
+        assert_eq!(pollster::block_on(inner.put(&ns1, auth_entry, Some(payload))), Ok(()));
     }
+    let (_, newer_digest) = payload_and_digest("newer");
+    // Its `StoreExt` doesn't override `iter_history`, so only the current is given, even though
+    // the inner keeps the older.
+    let store = Store::new(&ns1, Unreadable { inner, unreadable: Path::from_path(&["b"]) });
+    let history = pollster::block_on(store.iter_history(&user1, &["a"])).collect::<Vec<_>>();
+    let [(auth_entry, payload)] = <[_; 1]>::try_from(history).expect("only one");
+    assert_eq!(auth_entry.entry().payload_digest, newer_digest);
+    assert!(payload.is_some());
+    assert_eq!(pollster::block_on(store.iter_history(&user1, &["b"])).count(), 0);
 }
 
-pub(crate) fn payload_and_digest(bytes: impl AsRef<[u8]>) -> (InMemPayload, u64)
+
+#[test]
+fn delete()
 {
-    let mut payload = InMemPayload::new(bytes).expect("size fits");
-    let digest = pollster::block_on(Params::hash_payload(&mut payload)).expect("should work");
-    (payload, digest)
+    use sailce_data_model::store::sync::Store as _;
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let user2 = User::new("dos");
+    let mut store = Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| (timestamp.μs_since_epoch + μs_after).into();
+
+    put_with_own_payload(&mut store, &user1, &["a"], at(0));
+    put_with_own_payload(&mut store, &user1, &["x"], at(0));
+    put_with_own_payload(&mut store, &user1, &["x"], at(1)); // Overwrites the older.
+    put_with_own_payload(&mut store, &user1, &["p", "deep"], at(0));
+    put_with_own_payload(&mut store, &user1, &["p"], at(1)); // Prunes the deep one.
+    put_with_own_payload(&mut store, &user1, &["q"], at(0));
+    put_with_own_payload(&mut store, &user1, &["q", "newer"], at(1)); // Not pruned.
+    put_with_own_payload(&mut store, &user2, &["a"], at(0));
+
+    // Deleting what isn't there.
+    let token = store.change_token();
+    assert_eq!(store.delete(&user1, &["nothing"]), Ok(false));
+    assert_eq!(store.delete(&user1, &["p", "deep"]), Ok(false)); // Already pruned.
+    assert_eq!(store.change_token(), token);
+
+    assert_eq!(store.delete(&user1, &["a"]), Ok(true));
+    assert!(store.change_token() > token);
+    assert_eq!(store.get(&user1, &["a"]), Ok(None));
+    assert_eq!(store.delete(&user1, &["a"]), Ok(false));
+    assert!(store.get(&user2, &["a"]).expect("no error").is_some()); // Other subspace.
+
+    // The older version isn't resurrected.
+    assert_eq!(store.delete(&user1, &["x"]), Ok(true));
+    assert_eq!(store.get(&user1, &["x"]), Ok(None));
+    assert_eq!(store.iter_history(&user1, &["x"]).count(), 0);
+
+    // What it pruned isn't resurrected.
+    assert_eq!(store.delete(&user1, &["p"]), Ok(true));
+    assert_eq!(store.get(&user1, &["p", "deep"]), Ok(None));
+
+    // Only exactly the location is deleted, and what's newer under it remains.
+    assert_eq!(store.delete(&user1, &["q"]), Ok(true));
+    assert_eq!(paths_and_ages(store.iter(), timestamp), vec![
+        (Path::from_path(&["a"]), 0), // Of `user2`.
+        (Path::from_path(&["q", "newer"]), 1),
+    ]);
+
+    // Can be put again.
+    put_with_own_payload(&mut store, &user1, &["x"], at(0));
+    assert!(store.get(&user1, &["x"]).expect("no error").is_some());
+    assert_eq!(store.iter().count(), 3);
+}
+
+
+#[test]
+fn count_and_is_empty()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let mut store = Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| (timestamp.μs_since_epoch + μs_after).into();
+
+    assert_eq!(store.count(), 0);
+    assert!(store.is_empty());
+
+    put_with_own_payload(&mut store, &user1, &["a"], at(0));
+    assert_eq!(store.count(), 1);
+    assert!(!store.is_empty());
+
+    put_with_own_payload(&mut store, &user1, &["a"], at(1)); // Overwrites.
+    put_with_own_payload(&mut store, &user1, &["b", "1"], at(0));
+    put_with_own_payload(&mut store, &user1, &["b", "2"], at(0));
+    assert_eq!(store.count(), 3);
+    put_with_own_payload(&mut store, &user1, &["b"], at(1)); // Prunes both under it.
+    assert_eq!(store.count(), 2);
+    assert_eq!(store.count(), store.iter().count());
+
+    assert_eq!(store.delete(&user1, &["a"]), Ok(true));
+    assert_eq!(store.delete(&user1, &["b"]), Ok(true));
+    assert_eq!(store.count(), 0);
+    assert!(store.is_empty());
+}
+
+
+#[test]
+fn put_trusted()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let mut store = Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let (payload, payload_digest) = payload_and_digest("trusted");
+    let len = payload.len_as_u64();
+    let hashed_before = in_mem::hash_calls();
+
+    let entry_a = self_authorised_entry(&ns1, &user1, "a", payload_digest, len);
+    assert_eq!(store.put_trusted(entry_a, payload.clone()), Ok(()));
+    assert_eq!(in_mem::hash_calls(), hashed_before);
+    let expected = InMemPayload::new("trusted").expect("size fits");
+    assert_eq!(store.get(&user1, &["a"]), Ok(Some(expected)));
+
+    // It really is trusted, and so a wrong digest is not detected.
+    let entry_b = self_authorised_entry(&ns1, &user1, "b", payload_digest ^ 1, len);
+    assert_eq!(store.put_trusted(entry_b, payload), Ok(()));
+    assert_eq!(in_mem::hash_calls(), hashed_before);
+    assert!(store.get(&user1, &["b"]).expect("should succeed").is_some());
+
+    // Entries with different namespace are still rejected.
+    let ns2 = "namespace-2".into();
+    let (other_payload, other_digest) = payload_and_digest("other");
+    let entry_c = self_authorised_entry(&ns2, &user1, "c", other_digest, 5);
+    assert_eq!(store.put_trusted(entry_c, other_payload), Err(PutError::DifferentNamespace));
+}
+
+
+#[test]
+fn put_streaming()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let mut store = Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let (payload, payload_digest) = payload_and_digest("streamed");
+    let len = payload.len_as_u64();
+
+    let entry_a = self_authorised_entry(&ns1, &user1, "a", payload_digest, len);
+    assert_eq!(store.put_streaming(entry_a, payload.clone()), Ok(()));
+    let expected = InMemPayload::new("streamed").expect("size fits");
+    assert_eq!(store.get(&user1, &["a"]), Ok(Some(expected)));
+
+    // Unlike with `put_trusted`, the digest is verified.
+    let entry_b = self_authorised_entry(&ns1, &user1, "b", payload_digest ^ 1, len);
+    assert_eq!(
+        store.put_streaming(entry_b, payload),
+        Err(PutError::Put(in_mem::PutError::WrongDigest {
+            given:    payload_digest ^ 1,
+            computed: payload_digest,
+        }))
+    );
+    assert_eq!(store.get(&user1, &["b"]), Ok(None));
+
+    let ns2 = "namespace-2".into();
+    let (other_payload, other_digest) = payload_and_digest("other");
+    let entry_c = self_authorised_entry(&ns2, &user1, "c", other_digest, 5);
+    assert_eq!(store.put_streaming(entry_c, other_payload), Err(PutError::DifferentNamespace));
+}
+
+
+#[test]
+fn put_verifies()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let ns1 = "namespace-1".into();
+    let user1 = User::new("uno");
+    let mut store = Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
+    let (payload, payload_digest) = payload_and_digest("verified");
+    let len = payload.len_as_u64();
+    let hashed_before = in_mem::hash_calls();
+
+    let entry_a = self_authorised_entry(&ns1, &user1, "a", payload_digest, len);
+    assert_eq!(store.put(entry_a, Some(payload.clone())), Ok(()));
+    assert_eq!(in_mem::hash_calls(), hashed_before.saturating_add(1));
+
+    let entry_b = self_authorised_entry(&ns1, &user1, "b", payload_digest ^ 1, len);
+    assert_eq!(
+        store.put(entry_b, Some(payload)),
+        Err(PutError::Put(in_mem::PutError::WrongDigest {
+            given:    payload_digest ^ 1,
+            computed: payload_digest,
+        }))
+    );
+    assert_eq!(in_mem::hash_calls(), hashed_before.saturating_add(2));
+    assert_eq!(store.get(&user1, &["b"]), Ok(None));
 }
 
 
@@ -128,7 +1718,7 @@ fn most_methods__sync_uses_async()
             namespace_id:   Arc::clone(&ns1),
             subspace_id:    user1.clone(),
             path:           &["some", "where"][..],
-            timestamp:      current_timestamp(),
+            timestamp:      Timestamp::now(),
             payload_digest: empty_payload_digest,
             payload_length: 0,
         },
@@ -142,25 +1732,17 @@ fn most_methods__sync_uses_async()
     )
     .expect("auth should succeed");
     let ae2 = {
-        let (mut entry, auth_token) = ae1.clone().into_parts();
-        entry = Entry {
-            timestamp: (entry.timestamp.μs_since_epoch + 1).into(),
-            payload_digest: another_payload_digest,
-            payload_length: another_payload.len(),
-            ..entry
-        };
+        let (entry, auth_token) = ae1.clone().into_parts();
+        let entry = entry
+            .with_timestamp((ae1.entry().timestamp.μs_since_epoch + 1).into())
+            .with_payload_meta(another_payload_digest, another_payload.len());
         AuthorisedEntry::new(entry, auth_token).expect("auth should succeed")
     };
     let ae3 = {
-        let (e1, auth_token) = ae1.clone().into_parts();
-        let entry = Entry {
-            timestamp:      (e1.timestamp.μs_since_epoch + 2).into(),
-            path:           vec![b"some"], // Different type is fine.
-            namespace_id:   e1.namespace_id,
-            subspace_id:    e1.subspace_id,
-            payload_digest: e1.payload_digest,
-            payload_length: e1.payload_length,
-        };
+        let (entry, auth_token) = ae1.clone().into_parts();
+        let entry = entry
+            .with_timestamp((ae1.entry().timestamp.μs_since_epoch + 2).into())
+            .with_path(vec![b"some"]); // Different type is fine.
         AuthorisedEntry::new(entry, auth_token).expect("auth should succeed")
     };
 
@@ -238,22 +1820,17 @@ fn most_methods__sync_uses_async()
 
     let mut store2 = Store::new(&ns1, InMem::new_block_on_pollster(&ns1));
     let ae4 = {
-        let (mut entry, auth_token) = ae2.clone().into_parts();
-        entry = Entry {
-            // timestamp is less-than ae3's that prefix-pruned.
-            path: &["some", "where", "under", "a", "cloud"][..],
-            ..entry
-        };
+        let (entry, auth_token) = ae2.clone().into_parts();
+        // timestamp is less-than ae3's that prefix-pruned.
+        let entry = entry.with_path(&["some", "where", "under", "a", "cloud"][..]);
         AuthorisedEntry::new(entry, auth_token).expect("auth should succeed")
     };
     let ae5 = {
-        let (mut entry, auth_token) = ae2.clone().into_parts();
-        entry = Entry {
+        let (entry, auth_token) = ae2.clone().into_parts();
+        let entry = entry
             // timestamp is greater-than ae3's that prefix-pruned.
-            timestamp: (ae3.entry().timestamp.μs_since_epoch + 1).into(),
-            path: &["some", "where", "over", "a", "rainbow"][..],
-            ..entry
-        };
+            .with_timestamp((ae3.entry().timestamp.μs_since_epoch + 1).into())
+            .with_path(&["some", "where", "over", "a", "rainbow"][..]);
         AuthorisedEntry::new(entry, auth_token).expect("auth should succeed")
     };
     // Populate `store2` for following `join` tests.
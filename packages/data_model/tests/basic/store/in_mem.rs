@@ -28,20 +28,28 @@ use {
         AuthorisedEntry,
         Params as _,
         ParamsEntry,
-        Path as _,
         Payload,
+        store::{
+            ChangeToken,
+            CheckpointExt,
+            DeleteExt,
+            JoinReport,
+            StoreEvent,
+        },
         StoreAuthorisedEntry,
         StoreExt,
+        Timestamp,
     },
     std::{
         borrow::Borrow,
+        cell::Cell,
         collections::{
             hash_map::DefaultHasher,
             BTreeMap,
+            BTreeSet,
             BinaryHeap,
-            HashMap,
-            HashSet,
         },
+        convert::Infallible,
         hash::Hasher,
         ops::ControlFlow,
         sync::Arc,
@@ -52,6 +60,14 @@ use {
 pub(crate) type StoredEntryHistory = BinaryHeap<StoredEntry>;
 pub(crate) type StoredSubspace = BTreeMap<Path, StoredEntryHistory>;
 
+/// Ordered by newness first, the same as `Entry::cmp_newer_than`, and then by location, so that
+/// iterating a `NewestIndex` in reverse is from newest to oldest.
+type NewestIndexKey = (Timestamp, u64, u64, User, Path);
+/// Has the key of the newest entry of each location.  Some of these might be prefix-pruned.
+type NewestIndex = BTreeSet<NewestIndexKey>;
+
+type ChangeCallback = Box<dyn FnMut(StoreEvent<StoreAuthorisedEntry<InMem>>)>;
+
 
 /// The minimal amount of data needed to store `Entry`s and preserve old overwritten ones.  Even
 /// though the `Store` API doesn't allow access to old overwritten ones, this type preserves them
@@ -59,7 +75,29 @@ pub(crate) type StoredSubspace = BTreeMap<Path, StoredEntryHistory>;
 pub(crate) struct InMem
 {
     subspaces:    BTreeMap<User, StoredSubspace>,
+    /// Maintained by every mutation of `subspaces`, so that finding the newest entries doesn't
+    /// need to scan and sort all of them.
+    newest_index: NewestIndex,
     namespace_id: NamespaceName, // Not really needed. Just to check against for testing.
+    /// Count of mutations, for `StoreExt::change_token`.
+    change_count: u64,
+    /// Registered by `StoreExt::on_change`.
+    callbacks:    Vec<ChangeCallback>,
+}
+
+/// The clone is a separate store, and so the callbacks registered for `self` aren't for it.
+impl Clone for InMem
+{
+    fn clone(&self) -> Self
+    {
+        Self {
+            subspaces:    self.subspaces.clone(),
+            newest_index: self.newest_index.clone(),
+            namespace_id: Arc::clone(&self.namespace_id),
+            change_count: self.change_count,
+            callbacks:    Vec::new(),
+        }
+    }
 }
 
 impl InMem
@@ -67,7 +105,13 @@ impl InMem
     pub(crate) async fn new(namespace_id: &NamespaceName) -> Self
     {
         not_yet_ready(5).await; // Just to have an async suspend point in here.
-        Self { subspaces: BTreeMap::new(), namespace_id: Arc::clone(namespace_id) }
+        Self {
+            subspaces:    BTreeMap::new(),
+            newest_index: NewestIndex::new(),
+            namespace_id: Arc::clone(namespace_id),
+            change_count: 0,
+            callbacks:    Vec::new(),
+        }
     }
 
     pub(crate) fn new_block_on_pollster(namespace_id: &NamespaceName) -> Self
@@ -97,87 +141,155 @@ impl InMem
             stored_entry_history.peek().map(|newest| (user, path, newest)).into_iter()
         })
     }
-}
 
-/// This implementation is only for exercising the API, and this uses simple approaches instead of
-/// trying to be more efficient.
-impl StoreExt for InMem
-{
-    type GetError = GetError;
-    type GetPayload = InMemPayload;
-    type IterAuthToken = Arc<Permission>;
-    type IterPath = Path;
-    type JoinError = JoinError;
-    type Params = Params;
-    type PutError<P> = PutError<CopyPayloadError<P>> where P: Payload + ?Sized;
+    fn newest_index_key(
+        user: &User,
+        path: &Path,
+        stored_entry: &StoredEntry,
+    ) -> NewestIndexKey
+    {
+        let StoredEntry { timestamp, payload_digest, payload_length, .. } = *stored_entry;
+        (timestamp, payload_digest, payload_length, user.clone(), Arc::clone(path))
+    }
 
-    async fn get(
+    /// Replace the key of a location's previous newest entry with its current newest, if that
+    /// changed.
+    fn update_newest_index(
+        index: &mut NewestIndex,
+        old_newest: Option<NewestIndexKey>,
+        new_newest: Option<NewestIndexKey>,
+    )
+    {
+        if new_newest != old_newest {
+            if let Some(old_newest) = old_newest {
+                let removed = index.remove(&old_newest);
+                debug_assert!(removed);
+            }
+            if let Some(new_newest) = new_newest {
+                let added = index.insert(new_newest);
+                debug_assert!(added);
+            }
+        }
+    }
+
+    fn rebuild_newest_index(&mut self)
+    {
+        let index = self
+            .iter_stored_entries()
+            .map(|(user, path, newest)| Self::newest_index_key(user, path, newest))
+            .collect();
+        self.newest_index = index;
+    }
+
+    /// Whether `stored_entry`, at `path` in the subspace of `user`, is prefix-pruned by a newer
+    /// entry at a strict prefix of `path`.  Only looks-up the prefixes, instead of scanning.
+    fn is_pruned(
         &self,
         namespace_id: &NamespaceName,
-        subspace_id: &User,
-        path: &(impl sailce_data_model::Path + ?Sized),
-    ) -> Result<Option<Self::GetPayload>, Self::GetError>
+        user: &User,
+        path: &Path,
+        stored_entry: &StoredEntry,
+    ) -> bool
     {
-        debug_assert_eq!(*namespace_id, self.namespace_id);
-        not_yet_ready(4).await; // Just to have an async suspend point in here.
+        let Some(subspace) = self.subspaces.get(user)
+        else {
+            return false;
+        };
+        let entry = stored_entry.to_entry(namespace_id, user, path);
+        path.prefixes().take(path.len()).any(|prefix| {
+            let prefix = Path::from_path(&prefix);
+            subspace.get(&prefix).and_then(BinaryHeap::peek).is_some_and(|prefixing_entry| {
+                prefixing_entry.to_entry(namespace_id, user, &prefix).is_newer_than(&entry)
+            })
+        })
+    }
 
-        if let Some((found_entry, subspace)) =
-            self.subspaces.get(subspace_id).and_then(|subspace| {
-                let path = Path::from_path(path);
-                subspace.get(&path).and_then(|entry_history| {
-                    entry_history.peek().map(|newest| (newest, subspace))
-                })
+    /// The current entries, i.e. not prefix-pruned, that are at `path` or at extensions of it in
+    /// the subspace of `user`.  These are all that a `put` at `path` could change.
+    fn current_at_or_under(
+        &self,
+        namespace_id: &NamespaceName,
+        user: &User,
+        path: &Path,
+    ) -> Vec<StoreAuthorisedEntry<Self>>
+    {
+        self.subspaces
+            .get(user)
+            .into_iter()
+            .flat_map(|subspace| subspace.range(Arc::clone(path) ..))
+            .take_while(|(p, _)| p.starts_with(path))
+            .filter_map(|(p, history)| {
+                let newest = history.peek()?;
+                (!self.is_pruned(namespace_id, user, p, newest))
+                    .then(|| newest.to_auth_entry(namespace_id, user, p))
             })
-        {
-            // All the `StoredEntry`s in the same Subspace whose `Path` is a prefix of and not
-            // equal to the requested `path`, because these are what might have pruned `path`.
-            let mut prefixes = subspace.iter().filter_map(|(other_path, entry_history)| {
-                entry_history.peek().and_then(|newest| {
-                    (other_path.is_prefix_of(path) && !other_path.eq_components(path))
-                        .then_some(newest)
-                })
-            });
-            not_yet_ready(3).await; // Just to have an async suspend point in here.
+            .collect()
+    }
 
-            // If a prefixing entry is newer than the found entry under its prefix, then prefix
-            // pruning has deleted everything under the prefix.
-            let is_pruned = prefixes.any(|prefixing_entry| prefixing_entry > found_entry);
-            if is_pruned {
-                Ok(None)
-            }
-            else if let Some(payload) = &found_entry.payload {
-                let payload_clone = payload.clone();
-                // We always create our internal backing InMemPayloads in a valid state with their
-                // current position at their beginning, so we know we uphold the requirement that
-                // the returned payload is like this when we `clone` here.
-                debug_assert!(payload_clone.check_invariants());
-                debug_assert_eq!(payload_clone.pos_as_u64(), 0);
-                not_yet_ready(2).await; // Just to have an async suspend point in here.
-                Ok(Some(payload_clone))
-            }
-            else {
-                Err(GetError::FoundEntryMissingPayload(found_entry.to_entry(
-                    namespace_id,
-                    subspace_id,
-                    &Path::from_path(path),
-                )))
+    /// Give each of the `events` to each of the callbacks.
+    fn emit(
+        &mut self,
+        events: &[StoreEvent<StoreAuthorisedEntry<Self>>],
+    )
+    {
+        for callback in &mut self.callbacks {
+            for event in events {
+                callback(event.clone());
             }
         }
-        else {
-            Ok(None)
-        }
     }
 
-    async fn put<P>(
+    /// The entries that aren't prefix-pruned, from newest to oldest, lazily via the index.
+    fn newest_first<'s>(
+        &'s self,
+        namespace_id: &'s NamespaceName,
+    ) -> impl Iterator<Item = ParamsEntry<Params, Path>> + 's
+    {
+        self.newest_index.iter().rev().filter_map(move |(.., user, path)| {
+            let newest = self.subspaces.get(user)?.get(path)?.peek()?;
+            (!self.is_pruned(namespace_id, user, path, newest))
+                .then(|| newest.to_entry(namespace_id, user, path))
+        })
+    }
+
+    /// The original implementation of `StoreExt::newest_includes_within_total_size`, that scans
+    /// and sorts all entries, kept to check the index-based one against.
+    #[cfg(feature = "proptests")]
+    pub(crate) fn newest_includes_within_total_size_naive(
+        &self,
+        namespace_id: &NamespaceName,
+        max_count: Option<u64>,
+        entry: &ParamsEntry<Params, impl sailce_data_model::Path>,
+        max_size: Option<u64>,
+    ) -> bool
+    {
+        let mut newest = self
+            .iter(namespace_id)
+            .map(|auth_entry| auth_entry.into_parts().0)
+            .collect::<Vec<_>>();
+        // Ties of newness are ordered by location, the same as the index, so that the results
+        // are the same.
+        newest.sort_unstable_by(|a, b| {
+            b.cmp_newer_than(a)
+                .then_with(|| (&b.subspace_id, &b.path).cmp(&(&a.subspace_id, &a.path)))
+        });
+        newest_includes_within(newest.into_iter(), entry, max_count, max_size)
+    }
+
+    /// The implementation of `put`, `put_returning`, and `put_trusted`.  If `verify`, the digest
+    /// of `payload` is computed and checked against the entry's, else it's trusted.  Returns the
+    /// entry as it was stored.
+    async fn put_with<P>(
         &mut self,
         namespace_id: &NamespaceName,
         auth_entry: AuthorisedEntry<
-            Self::Params,
+            Params,
             impl sailce_data_model::Path,
             impl Borrow<Permission>,
         >,
         payload: Option<P>,
-    ) -> Result<(), Self::PutError<P>>
+        verify: bool,
+    ) -> Result<StoreAuthorisedEntry<Self>, PutError<CopyPayloadError<P>>>
     where
         P: Payload,
     {
@@ -187,26 +299,40 @@ impl StoreExt for InMem
         debug_assert_eq!(entry.namespace_id, *namespace_id); // `Store::put` must ensure this.
         debug_assert!(Params::is_authorised_write(entry, auth_entry.auth_token()));
 
-        let subspace = self.subspaces.entry(entry.subspace_id.clone()).or_default();
+        let subspace_id = entry.subspace_id.clone();
         let path =
             Path::from_path_limited::<Params, _, _>(&entry.path).map_err(PutError::PathLimit)?;
-        let entry_history = subspace.entry(path).or_default();
+        // Only when needed for the events, because this scans.
+        let before = (!self.callbacks.is_empty())
+            .then(|| self.current_at_or_under(namespace_id, &subspace_id, &path));
+        let subspace = self.subspaces.entry(subspace_id.clone()).or_default();
+        let entry_history = subspace.entry(Arc::clone(&path)).or_default();
+        let old_newest =
+            entry_history.peek().map(|e| Self::newest_index_key(&subspace_id, &path, e));
         not_yet_ready(2).await; // Just to have an async suspend point here.
 
-        let mut stored_entry = StoredEntry::from_auth_entry(auth_entry);
+        self.change_count = self.change_count.saturating_add(1);
+        let mut stored_entry = StoredEntry::from_auth_entry(auth_entry, self.change_count);
         debug_assert!(stored_entry.payload.is_none());
 
         if let Some(payload) = payload {
-            let (copied_payload, payload_digest) =
-                InMemPayload::copy(payload).await.map_err(PutError::Copy)?;
-            if payload_digest == stored_entry.payload_digest {
-                stored_entry.payload = Some(copied_payload);
+            if verify {
+                let (copied_payload, payload_digest) =
+                    InMemPayload::copy(payload).await.map_err(PutError::Copy)?;
+                if payload_digest == stored_entry.payload_digest {
+                    stored_entry.payload = Some(copied_payload);
+                }
+                else {
+                    return Err(PutError::WrongDigest {
+                        given:    stored_entry.payload_digest,
+                        computed: payload_digest,
+                    });
+                }
             }
             else {
-                return Err(PutError::WrongDigest {
-                    given:    stored_entry.payload_digest,
-                    computed: payload_digest,
-                });
+                let copied_payload =
+                    InMemPayload::copy_unhashed(payload).await.map_err(PutError::Copy)?;
+                stored_entry.payload = Some(copied_payload);
             }
         }
 
@@ -240,6 +366,9 @@ impl StoreExt for InMem
                     // ownership without copying.  If it's `None`, this doesn't change
                     // `existing.payload` because that was already `None`.
                     existing.payload = stored_entry.payload.clone();
+                    // Either way, it's a mutation of the same `Entry`, which might be the one
+                    // `peek`ed.
+                    existing.change_seq = stored_entry.change_seq;
                 }
             }
             else {
@@ -255,8 +384,151 @@ impl StoreExt for InMem
         // exact `Entry`, and it's unspecified which will be `peek`ed first but that doesn't
         // matter because they're equivalent (modulo any possible differences in their
         // `auth_token`s).
+        let stored = stored_entry.to_auth_entry(namespace_id, &subspace_id, &path);
         entry_history.push(stored_entry);
-        Ok(())
+
+        let new_newest =
+            entry_history.peek().map(|e| Self::newest_index_key(&subspace_id, &path, e));
+        Self::update_newest_index(&mut self.newest_index, old_newest, new_newest);
+
+        if let Some(before) = before {
+            let after = self.current_at_or_under(namespace_id, &subspace_id, &path);
+            let put = (has_entry(&after, &stored) && !has_entry(&before, &stored))
+                .then(|| StoreEvent::Put(stored.clone()));
+            let removed = before.iter().filter(|e| !has_entry(&after, e)).map(|e| {
+                if e.entry().path == path {
+                    StoreEvent::Overwritten(e.clone())
+                }
+                else {
+                    StoreEvent::Pruned(e.clone())
+                }
+            });
+            let events = put.into_iter().chain(removed).collect::<Vec<_>>();
+            self.emit(&events);
+        }
+        Ok(stored)
+    }
+}
+
+
+/// Whether `entries` has the same `Entry` as `auth_entry`.
+fn has_entry(
+    entries: &[StoreAuthorisedEntry<InMem>],
+    auth_entry: &StoreAuthorisedEntry<InMem>,
+) -> bool
+{
+    entries.iter().any(|e| e.entry() == auth_entry.entry())
+}
+
+
+/// This implementation is only for exercising the API, and this uses simple approaches instead of
+/// trying to be more efficient.
+impl StoreExt for InMem
+{
+    type GetError = GetError;
+    type GetPayload = InMemPayload;
+    type IterAuthToken = Arc<Permission>;
+    type IterPath = Path;
+    type JoinError = JoinError;
+    type Params = Params;
+    type PutError<P> = PutError<CopyPayloadError<P>> where P: Payload + ?Sized;
+
+    const CHANGE_EVENTS: bool = true;
+    const CHANGE_TRACKING: bool = true;
+
+    async fn get(
+        &self,
+        namespace_id: &NamespaceName,
+        subspace_id: &User,
+        path: &(impl sailce_data_model::Path + ?Sized),
+    ) -> Result<Option<Self::GetPayload>, Self::GetError>
+    {
+        debug_assert_eq!(*namespace_id, self.namespace_id);
+        not_yet_ready(4).await; // Just to have an async suspend point in here.
+
+        let own_path = Path::from_path(path);
+        if let Some(found_entry) = self
+            .subspaces
+            .get(subspace_id)
+            .and_then(|subspace| subspace.get(&own_path))
+            .and_then(BinaryHeap::peek)
+        {
+            not_yet_ready(3).await; // Just to have an async suspend point in here.
+
+            // If a prefixing entry is newer than the found entry under its prefix, then prefix
+            // pruning has deleted everything under the prefix.
+            if self.is_pruned(namespace_id, subspace_id, &own_path, found_entry) {
+                Ok(None)
+            }
+            else if let Some(payload) = &found_entry.payload {
+                let payload_clone = payload.clone();
+                // We always create our internal backing InMemPayloads in a valid state with their
+                // current position at their beginning, so we know we uphold the requirement that
+                // the returned payload is like this when we `clone` here.
+                debug_assert!(payload_clone.check_invariants());
+                debug_assert_eq!(payload_clone.pos_as_u64(), 0);
+                not_yet_ready(2).await; // Just to have an async suspend point in here.
+                Ok(Some(payload_clone))
+            }
+            else {
+                Err(GetError::FoundEntryMissingPayload(found_entry.to_entry(
+                    namespace_id,
+                    subspace_id,
+                    &Path::from_path(path),
+                )))
+            }
+        }
+        else {
+            Ok(None)
+        }
+    }
+
+    async fn put<P>(
+        &mut self,
+        namespace_id: &NamespaceName,
+        auth_entry: AuthorisedEntry<
+            Self::Params,
+            impl sailce_data_model::Path,
+            impl Borrow<Permission>,
+        >,
+        payload: Option<P>,
+    ) -> Result<(), Self::PutError<P>>
+    where
+        P: Payload,
+    {
+        self.put_with(namespace_id, auth_entry, payload, true).await.map(drop)
+    }
+
+    async fn put_returning<P>(
+        &mut self,
+        namespace_id: &NamespaceName,
+        auth_entry: AuthorisedEntry<
+            Self::Params,
+            impl sailce_data_model::Path,
+            impl Borrow<Permission>,
+        >,
+        payload: Option<P>,
+    ) -> Result<Option<StoreAuthorisedEntry<Self>>, Self::PutError<P>>
+    where
+        P: Payload,
+    {
+        self.put_with(namespace_id, auth_entry, payload, true).await.map(Some)
+    }
+
+    async fn put_trusted<P>(
+        &mut self,
+        namespace_id: &NamespaceName,
+        auth_entry: AuthorisedEntry<
+            Self::Params,
+            impl sailce_data_model::Path,
+            impl Borrow<Permission>,
+        >,
+        payload: P,
+    ) -> Result<(), Self::PutError<P>>
+    where
+        P: Payload,
+    {
+        self.put_with(namespace_id, auth_entry, Some(payload), false).await.map(drop)
     }
 
     async fn join(
@@ -264,24 +536,45 @@ impl StoreExt for InMem
         namespace_id: &NamespaceName,
         other: &Self,
         other_namespace_id: &NamespaceName,
-    ) -> Result<(), Self::JoinError>
+    ) -> Result<JoinReport, Self::JoinError>
     {
         debug_assert_eq!(*namespace_id, self.namespace_id);
         debug_assert_eq!(*other_namespace_id, other.namespace_id);
 
         if other_namespace_id == namespace_id {
-            for (user, path, history) in other.iter_histories() {
-                for stored_entry in history {
-                    self.put(
+            let mut report = JoinReport::default();
+            // Only the newest of each location, as `StoreExt::join` allows, and only the current
+            // ones, as the `report` must count.
+            for (user, path, stored_entry) in other
+                .iter_stored_entries()
+                .filter(|&(user, path, entry)| !other.is_pruned(namespace_id, user, path, entry))
+            {
+                let before = self.current_at_or_under(namespace_id, user, path);
+                // `other`'s payloads were already verified when they were put into it.
+                let stored = self
+                    .put_with(
                         namespace_id,
                         stored_entry.to_auth_entry(namespace_id, user, path),
                         stored_entry.payload.clone(),
+                        false,
                     )
                     .await
                     .map_err(JoinError::Put)?;
+                let after = self.current_at_or_under(namespace_id, user, path);
+                if has_entry(&before, &stored) {
+                    // `self` already had it.
+                }
+                else if has_entry(&after, &stored) {
+                    report.inserted = report.inserted.saturating_add(1);
+                    if before.iter().any(|e| !has_entry(&after, e)) {
+                        report.pruned = report.pruned.saturating_add(1);
+                    }
+                }
+                else {
+                    report.superseded = report.superseded.saturating_add(1);
                 }
             }
-            Ok(())
+            Ok(report)
         }
         else {
             Err(JoinError::WrongNamespace(Arc::clone(other_namespace_id)))
@@ -302,93 +595,249 @@ impl StoreExt for InMem
         let entry = entry.borrow();
         debug_assert_eq!(entry.namespace_id, *namespace_id); // The `Store` method ensures this.
 
-        // (This inefficient approach is just for testing.)
-        let mut entries = self.iter(namespace_id).map(|auth_entry| auth_entry.into_parts().0);
+        newest_includes_within(self.newest_first(namespace_id), entry, max_count, max_size)
+    }
 
-        if max_count.is_none() && max_size.is_none() {
-            // We don't use `Self::get` because that might error if missing payload but we want to
-            // find such entry.
-            return entries.any(|e| *entry == e);
-        }
+    fn iter(
+        &self,
+        namespace_id: &NamespaceName,
+    ) -> impl Iterator<Item = StoreAuthorisedEntry<Self>>
+    {
+        debug_assert_eq!(*namespace_id, self.namespace_id);
 
-        let mut newest = entries.collect::<Vec<_>>();
-        newest.sort_unstable_by(|a, b| b.cmp_newer_than(a));
-        if let Some(max_count) = max_count {
-            if let Ok(max_count) = max_count.try_into() {
-                newest.truncate(max_count);
-            }
-        }
+        self.iter_stored_entries()
+            // Must filter-out those that have been prefix-pruned.
+            .filter(|&(user, path, stored_entry)| {
+                !self.is_pruned(namespace_id, user, path, stored_entry)
+            })
+            .map(|(user, path, stored_entry)| {
+                stored_entry.to_auth_entry(namespace_id, user, path)
+            })
+    }
 
-        if let Some(max_size) = max_size {
-            if let ControlFlow::Break(Some(total_size)) =
-                newest.iter().try_fold(Some(entry.payload_length), |total_size, e| {
-                    if entry == e {
-                        // Found it among the newest. Return success with the total.
-                        ControlFlow::Break(total_size)
-                    }
-                    else if let Some(total_size) =
-                        total_size.and_then(|x: u64| x.checked_add(e.payload_length))
-                    {
-                        // Keep searching and summing.
-                        ControlFlow::Continue(Some(total_size))
-                    }
-                    else {
-                        // Summing overflowed. Return failure.
-                        ControlFlow::Break(None)
-                    }
+    async fn iter_history(
+        &self,
+        namespace_id: &NamespaceName,
+        subspace_id: &User,
+        path: &(impl sailce_data_model::Path + ?Sized),
+    ) -> impl Iterator<Item = (StoreAuthorisedEntry<Self>, Option<Self::GetPayload>)>
+    {
+        debug_assert_eq!(*namespace_id, self.namespace_id);
+
+        let path = Path::from_path(path);
+        let mut history = self
+            .subspaces
+            .get(subspace_id)
+            .and_then(|subspace| subspace.get(&path))
+            .map(|entry_history| entry_history.iter().collect::<Vec<_>>())
+            .unwrap_or_default();
+        // Newest first.  Of the redundant elements that represent the same `Entry`, keep one that
+        // has the payload, if any does.
+        history.sort_unstable_by(|a, b| {
+            b.cmp(a).then_with(|| b.payload.is_some().cmp(&a.payload.is_some()))
+        });
+        history.dedup_by(|later, earlier| later == earlier);
+        history
+            .into_iter()
+            .map(|stored_entry| {
+                let auth_entry = stored_entry.to_auth_entry(namespace_id, subspace_id, &path);
+                (auth_entry, stored_entry.payload.clone())
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn change_token(
+        &self,
+        namespace_id: &NamespaceName,
+    ) -> ChangeToken
+    {
+        debug_assert_eq!(*namespace_id, self.namespace_id);
+        ChangeToken::new(self.change_count)
+    }
+
+    fn entries_since(
+        &self,
+        namespace_id: &NamespaceName,
+        token: ChangeToken,
+    ) -> (impl Iterator<Item = StoreAuthorisedEntry<Self>>, ChangeToken)
+    {
+        // Reuse `iter` for its prefix-pruning filtering, then look-up each's history to see
+        // whether any equivalent element was stored after `token`.  (This inefficient approach
+        // is just for testing.)
+        let changed = self.iter(namespace_id).filter(move |auth_entry| {
+            let entry = auth_entry.entry();
+            self.subspaces
+                .get(&entry.subspace_id)
+                .and_then(|subspace| subspace.get(&entry.path))
+                .and_then(|history| {
+                    let newest = history.peek()?;
+                    Some(history.iter().any(|stored_entry| {
+                        stored_entry == newest && stored_entry.change_seq > token.get()
+                    }))
                 })
-            {
-                // Found `entry` amoung the `max_count` newest.  Is it within `max_size` total
-                // size of newer?
-                total_size <= max_size
-            }
+                .unwrap_or(false)
+        });
+        (changed, self.change_token(namespace_id))
+    }
+
+    fn on_change(
+        &mut self,
+        namespace_id: &NamespaceName,
+        callback: ChangeCallback,
+    )
+    {
+        debug_assert_eq!(*namespace_id, self.namespace_id);
+        self.callbacks.push(callback);
+    }
+}
+
+impl DeleteExt for InMem
+{
+    type DeleteError = Infallible;
+
+    async fn delete(
+        &mut self,
+        namespace_id: &NamespaceName,
+        subspace_id: &User,
+        path: &(impl sailce_data_model::Path + ?Sized),
+    ) -> Result<bool, Self::DeleteError>
+    {
+        debug_assert_eq!(*namespace_id, self.namespace_id);
+        not_yet_ready(2).await; // Just to have an async suspend point in here.
+
+        let path = Path::from_path(path);
+        let Some(deleted) = self
+            .subspaces
+            .get(subspace_id)
+            .and_then(|subspace| subspace.get(&path))
+            .and_then(BinaryHeap::peek)
+            .filter(|newest| !self.is_pruned(namespace_id, subspace_id, &path, newest))
+            .map(|newest| newest.to_auth_entry(namespace_id, subspace_id, &path))
+        else {
+            return Ok(false); // Nothing there, or it's already prefix-pruned.
+        };
+        let Some(subspace) = self.subspaces.get_mut(subspace_id)
+        else {
+            return Ok(false);
+        };
+
+        // Remove all of the location's history, so that an older version doesn't become the
+        // newest, and remove everything that it prefix-pruned, so that none is resurrected.
+        let mut removed = Vec::new();
+        subspace.retain(|other_path, history| {
+            let Some(newest) = history.peek()
             else {
-                // Either: Not found; or, summing overflowed and `max_size <= u64::MAX <
-                // total_size`.
-                false
+                return true;
+            };
+            let is_pruned_by_deleted = || {
+                deleted
+                    .entry()
+                    .is_newer_than(&newest.to_entry(namespace_id, subspace_id, other_path))
+            };
+            let remove = *other_path == path
+                || (sailce_data_model::Path::is_prefix_of(&path, other_path)
+                    && is_pruned_by_deleted());
+            if remove {
+                removed.push(Self::newest_index_key(subspace_id, other_path, newest));
             }
+            !remove
+        });
+        for key in removed {
+            Self::update_newest_index(&mut self.newest_index, Some(key), None);
         }
-        else {
-            debug_assert!(max_count.is_some());
-            newest.iter().any(|e| entry == e)
-        }
+        self.change_count = self.change_count.saturating_add(1);
+        self.emit(&[StoreEvent::Deleted(deleted)]);
+        Ok(true)
     }
+}
 
-    fn iter(
+impl CheckpointExt for InMem
+{
+    type RestoreError = Infallible;
+    type Snapshot = BTreeMap<User, StoredSubspace>;
+
+    fn checkpoint(
         &self,
         namespace_id: &NamespaceName,
-    ) -> impl Iterator<Item = StoreAuthorisedEntry<Self>>
+    ) -> Self::Snapshot
     {
         debug_assert_eq!(*namespace_id, self.namespace_id);
+        self.subspaces.clone()
+    }
 
-        self.iter_stored_entries()
-            // Must filter-out those that have been prefix-pruned.  (This inefficient approach is
-            // just for testing.)
-            .scan(
-                HashMap::<&User, HashSet<(&Path, &StoredEntry)>>::new(),
-                |seen, item @ (user, path, stored_entry)| {
-                    let seen_sub = seen.entry(user).or_default();
-                    let mut prefixes = seen_sub.iter().filter_map(
-                        |&(seen_path, seen_entry): &(&Path, &StoredEntry)| {
-                            (seen_path.is_prefix_of(path)
-                                && !seen_path.eq_components(path))
-                            .then_some(seen_entry)
-                        },
-                    );
-                    // This relies on our `iter_stored_entries` yielding them in lexicographic
-                    // order of their paths (because they're held in a `BTreeMap`) which
-                    // guarantees that prefixes were seen before everything they prefix.
-                    let is_pruned =
-                        prefixes.any(|prefixing_entry| prefixing_entry > stored_entry);
-                    let added = seen_sub.insert((path, stored_entry));
-                    debug_assert!(added);
-                    Some((is_pruned, item))
-                },
-            )
-            .filter_map(|(is_pruned, item)| (!is_pruned).then_some(item))
-            .map(|(user, path, stored_entry)| {
-                stored_entry.to_auth_entry(namespace_id, user, path)
+    fn restore(
+        &mut self,
+        namespace_id: &NamespaceName,
+        snapshot: Self::Snapshot,
+    ) -> Result<(), Self::RestoreError>
+    {
+        debug_assert_eq!(*namespace_id, self.namespace_id);
+        self.subspaces = snapshot;
+        self.rebuild_newest_index();
+        self.change_count = self.change_count.saturating_add(1);
+        self.emit(&[StoreEvent::Reset]);
+        Ok(())
+    }
+}
+
+
+/// Whether `entry` is among the `max_count` first of `newest`, and whether its total size with
+/// those before it is within `max_size`.  `newest` must be ordered from newest to oldest.
+fn newest_includes_within(
+    mut newest: impl Iterator<Item = ParamsEntry<Params, Path>>,
+    entry: &ParamsEntry<Params, impl sailce_data_model::Path>,
+    max_count: Option<u64>,
+    max_size: Option<u64>,
+) -> bool
+{
+    if let Some(max_count) = max_count {
+        let max_count = max_count.try_into().unwrap_or(usize::MAX);
+        newest_includes_within_size(newest.by_ref().take(max_count), entry, max_size)
+    }
+    else {
+        newest_includes_within_size(newest, entry, max_size)
+    }
+}
+
+fn newest_includes_within_size(
+    mut newest: impl Iterator<Item = ParamsEntry<Params, Path>>,
+    entry: &ParamsEntry<Params, impl sailce_data_model::Path>,
+    max_size: Option<u64>,
+) -> bool
+{
+    if let Some(max_size) = max_size {
+        if let ControlFlow::Break(Some(total_size)) =
+            newest.try_fold(Some(entry.payload_length), |total_size, e| {
+                if *entry == e {
+                    // Found it among the newest. Return success with the total.
+                    ControlFlow::Break(total_size)
+                }
+                else if let Some(total_size) =
+                    total_size.and_then(|x: u64| x.checked_add(e.payload_length))
+                {
+                    // Keep searching and summing.
+                    ControlFlow::Continue(Some(total_size))
+                }
+                else {
+                    // Summing overflowed. Return failure.
+                    ControlFlow::Break(None)
+                }
             })
+        {
+            // Found `entry` amoung the `max_count` newest.  Is it within `max_size` total size of
+            // newer?
+            total_size <= max_size
+        }
+        else {
+            // Either: Not found; or, summing overflowed and `max_size <= u64::MAX < total_size`.
+            false
+        }
+    }
+    else {
+        // We don't use `Self::get` because that might error if missing payload but we want to
+        // find such entry.
+        newest.any(|e| *entry == e)
     }
 }
 
@@ -425,6 +874,7 @@ impl InMemPayload
     pub(crate) async fn copy<P>(mut other: P) -> Result<(Self, u64), CopyPayloadError<P>>
     where P: Payload
     {
+        HASH_CALLS.with(|count| count.set(count.get().saturating_add(1)));
         // Compute its hash while we copy it.
         let mut hasher = DefaultHasher::default();
         let bytes = other
@@ -438,6 +888,31 @@ impl InMemPayload
 
         Ok((Self::new(bytes).map_err(CopyPayloadError::New)?, hasher.finish()))
     }
+
+    /// Like [`Self::copy`] but without computing the hash.
+    pub(crate) async fn copy_unhashed<P>(mut other: P) -> Result<Self, CopyPayloadError<P>>
+    where P: Payload
+    {
+        let bytes = other
+            .to_boxed_slice(0 .., None::<fn(&mut [u8])>, true)
+            .await
+            .map_err(CopyPayloadError::ToBoxedSlice)?;
+
+        Self::new(bytes).map_err(CopyPayloadError::New)
+    }
+}
+
+
+thread_local! {
+    /// Count of how many times [`InMemPayload::copy`] has computed a hash, in the current
+    /// thread, so tests can check whether hashing happened.
+    static HASH_CALLS: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Return how many times a payload's hash has been computed by [`InMem`], in the current thread.
+pub(crate) fn hash_calls() -> u64
+{
+    HASH_CALLS.with(Cell::get)
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -0,0 +1,221 @@
+//! Checks that the index-based `InMem::newest_includes_within_total_size` is the same as the
+//! original naive one that scans and sorts all the entries.
+
+use {
+    super::{
+        in_mem::InMem,
+        params::{
+            NamespaceName,
+            Params,
+            Path,
+            Permission,
+            User,
+        },
+    },
+    crate::payload::InMem as InMemPayload,
+    proptest::prelude::*,
+    sailce_data_model::{
+        path::Extra as _,
+        AuthorisedEntry,
+        EmptyPath as _,
+        Entry,
+        ParamsEntry,
+        StoreExt as _,
+    },
+    std::{
+        sync::Arc,
+        time::Instant,
+    },
+};
+
+
+/// What's generated to be `put`.  Small values, so that same locations, prefixes, and ties of
+/// newness are common.
+#[derive(Clone, Debug)]
+struct Put
+{
+    user:           usize,
+    path:           Vec<&'static str>,
+    timestamp:      u64,
+    payload_digest: u64,
+    payload_length: u64,
+}
+
+#[derive(Clone, Debug)]
+enum Op
+{
+    Put(Put),
+    /// `join` into the store another store that has these.
+    Join(Vec<Put>),
+}
+
+
+fn put() -> impl Strategy<Value = Put>
+{
+    (
+        0 .. 2_usize,
+        proptest::collection::vec(prop_oneof![Just("a"), Just("b")], 0 ..= 2),
+        0 .. 6_u64,
+        0 .. 3_u64,
+        0 .. 4_u64,
+    )
+        .prop_map(|(user, path, timestamp, payload_digest, payload_length)| Put {
+            user,
+            path,
+            timestamp,
+            payload_digest,
+            payload_length,
+        })
+}
+
+fn op() -> impl Strategy<Value = Op>
+{
+    prop_oneof![
+        4 => put().prop_map(Op::Put),
+        1 => proptest::collection::vec(put(), 0 .. 4).prop_map(Op::Join),
+    ]
+}
+
+
+fn entry(
+    namespace_id: &NamespaceName,
+    users: &[User],
+    put: &Put,
+) -> ParamsEntry<Params, Path>
+{
+    let user = users.get(put.user).expect("generated in range");
+    Entry {
+        namespace_id:   Arc::clone(namespace_id),
+        subspace_id:    user.clone(),
+        path:           Path::from_path(&put.path),
+        timestamp:      put.timestamp.into(),
+        payload_digest: put.payload_digest,
+        payload_length: put.payload_length,
+    }
+}
+
+/// Put without a payload, so that the digests don't need to be real.
+fn put_into(
+    store: &mut InMem,
+    namespace_id: &NamespaceName,
+    entry: ParamsEntry<Params, Path>,
+)
+{
+    let user = entry.subspace_id.clone();
+    let auth_entry = AuthorisedEntry::new(entry, Permission {
+        user,
+        namespaces: [Arc::clone(namespace_id)].into(),
+        subspaces: [].into(),
+        paths: [Path::empty()].into(),
+        times: [(0 ..).into()].into(),
+    })
+    .expect("auth should succeed");
+    pollster::block_on(store.put(namespace_id, auth_entry, None::<InMemPayload>))
+        .expect("should succeed");
+}
+
+fn apply(
+    store: &mut InMem,
+    namespace_id: &NamespaceName,
+    users: &[User],
+    op: &Op,
+)
+{
+    match op {
+        Op::Put(put) => put_into(store, namespace_id, entry(namespace_id, users, put)),
+        Op::Join(puts) => {
+            let mut other = InMem::new_block_on_pollster(namespace_id);
+            for put in puts {
+                put_into(&mut other, namespace_id, entry(namespace_id, users, put));
+            }
+            let _report = pollster::block_on(store.join(namespace_id, &other, namespace_id))
+                .expect("should succeed");
+        },
+    }
+}
+
+fn indexed(
+    store: &InMem,
+    namespace_id: &NamespaceName,
+    max_count: Option<u64>,
+    entry: &ParamsEntry<Params, Path>,
+    max_size: Option<u64>,
+) -> bool
+{
+    pollster::block_on(store.newest_includes_within_total_size(
+        namespace_id,
+        max_count,
+        entry,
+        max_size,
+    ))
+}
+
+
+proptest! {
+    #[test]
+    fn same_as_naive(
+        ops in proptest::collection::vec(op(), 0 .. 24),
+        absent in proptest::collection::vec(put(), 0 .. 4),
+        max_count in proptest::option::of(0 .. 6_u64),
+        max_size in proptest::option::of(0 .. 12_u64),
+    )
+    {
+        let ns = "namespace".into();
+        let users = [User::new("uno"), User::new("dos")];
+        let mut store = InMem::new_block_on_pollster(&ns);
+        for op in &ops {
+            apply(&mut store, &ns, &users, op);
+        }
+
+        // Both the entries that are stored, and ones that might not be.
+        let mut entries =
+            store.iter(&ns).map(|auth_entry| auth_entry.into_parts().0).collect::<Vec<_>>();
+        entries.extend(absent.iter().map(|put| entry(&ns, &users, put)));
+
+        for e in &entries {
+            prop_assert_eq!(
+                indexed(&store, &ns, max_count, e, max_size),
+                store.newest_includes_within_total_size_naive(&ns, max_count, e, max_size)
+            );
+        }
+    }
+}
+
+
+/// Not a real benchmark, but shows how the index scales compared to the naive approach.  Run with
+/// `cargo test --features proptests -- --ignored --nocapture newest_index`.
+#[test]
+#[ignore = "only for manually comparing timing"]
+#[allow(clippy::print_stdout, clippy::use_debug)]
+fn compare_timing()
+{
+    let ns = "namespace".into();
+    let users = [User::new("uno"), User::new("dos")];
+    for count in [1_000, 10_000, 100_000_u64] {
+        let mut store = InMem::new_block_on_pollster(&ns);
+        for (i, user) in (0 .. count).zip(users.iter().cycle()) {
+            let digits = i.to_string();
+            put_into(&mut store, &ns, Entry {
+                namespace_id:   Arc::clone(&ns),
+                subspace_id:    user.clone(),
+                path:           Path::from_path(&[digits.as_str()]),
+                timestamp:      i.into(),
+                payload_digest: i,
+                payload_length: 1,
+            });
+        }
+        let newest = store
+            .iter(&ns)
+            .map(|auth_entry| auth_entry.into_parts().0)
+            .max_by(Entry::cmp_newer_than)
+            .expect("non-empty");
+
+        let indexed_start = Instant::now();
+        assert!(indexed(&store, &ns, Some(1), &newest, Some(1)));
+        let indexed_time = indexed_start.elapsed();
+        let naive_start = Instant::now();
+        assert!(store.newest_includes_within_total_size_naive(&ns, Some(1), &newest, Some(1)));
+        let naive_time = naive_start.elapsed();
+        println!("{count} entries: indexed {indexed_time:?}, naive {naive_time:?}");
+    }
+}
@@ -113,7 +113,7 @@ impl sailce_data_model::Params for Params
             // Must only give it a slice length that is within its bounds.
             let ask_len = BUF_SIZE.min(remaining.try_into().unwrap_or(BUF_SIZE));
             let buf = if let Some(buf) = buf.get_mut(.. ask_len.into()) { buf } else { &mut buf };
-            debug_assert_eq!(buf.len(), ask_len.into());
+            debug_assert_eq!(buf.len(), usize::from(ask_len));
             debug_assert!(u64::from(ask_len) <= remaining);
 
             payload
@@ -28,6 +28,7 @@ use {
 
 
 /// The minimal amount of data needed to store an `Entry`.
+#[derive(Clone)]
 pub(crate) struct StoredEntry
 {
     pub(crate) timestamp:      Timestamp,
@@ -35,15 +36,17 @@ pub(crate) struct StoredEntry
     pub(crate) payload_length: u64,
     pub(crate) payload:        Option<InMemPayload>,
     pub(crate) auth_token:     Arc<Permission>,
+    /// The count of mutations of the store when this was stored, for `StoreExt::entries_since`.
+    pub(crate) change_seq:     u64,
 }
 
 impl Ord for StoredEntry
 {
     /// Compare by the same ordering as `Entry::is_newer_than`, so that `BinaryHeap<Self>` has the
     /// newest as the greatest.  `to_dummy_entry` always uses the same dummy values for the other
-    /// fields of `Entry`, and so those don't affect the comparison.  The `payload` & `auth_token`
-    /// fields of `Self` are ignored, because they're not relevant to comparison of `Entry`s, and
-    /// we want `Self` to be a representation of `Entry`.
+    /// fields of `Entry`, and so those don't affect the comparison.  The `payload`, `auth_token`,
+    /// & `change_seq` fields of `Self` are ignored, because they're not relevant to comparison of
+    /// `Entry`s, and we want `Self` to be a representation of `Entry`.
     fn cmp(
         &self,
         other: &Self,
@@ -95,7 +98,8 @@ impl StoredEntry
             Params,
             impl sailce_data_model::Path,
             impl Borrow<Permission>,
-        >
+        >,
+        change_seq: u64,
     ) -> Self
     {
         let (entry, auth_token) = auth_entry.into_parts();
@@ -106,6 +110,7 @@ impl StoredEntry
             payload_length: entry.payload_length,
             payload:        None,
             auth_token:     Arc::new(auth_token.borrow().clone()),
+            change_seq,
         }
     }
 
@@ -154,17 +159,23 @@ impl StoredEntry
         );
         debug_assert!(self.auth_token.paths.iter().any(|p| p.is_prefix_of(path)));
 
-        AuthorisedEntry::new(
-            self.to_entry(namespace_id, subspace_id, path),
-            Arc::clone(&self.auth_token),
-        )
-        .expect("the `Entry` was already authorized by the `auth_token`")
+        let entry = self.to_entry(namespace_id, subspace_id, path);
+        let auth_token = Arc::clone(&self.auth_token);
 
-        // Note: A different implementation that forbids potential panics should instead store the
-        // `AuthorisedEntry`s as taken in by `StoreExt::put`, to avoid needing to do something
-        // like our `expect` above, so that it can instead simply return a clone of the
-        // `AuthorisedEntry` it stored.  TODO: But how to deserialize to that type which has
-        // private fields?  TODO: Maybe the `sailce_data_model` crate will need a package-feature
-        // to have a `Deserialize` trait impl'ed for that type?
+        cfg_if::cfg_if! { if #[cfg(feature = "unchecked-construction")] {
+            // The `Entry` was already authorized by the `auth_token` when it was `put`, so
+            // there's no need to check again.  (Debug builds still do.)
+            AuthorisedEntry::new_unchecked(entry, auth_token)
+        } else {
+            AuthorisedEntry::new(entry, auth_token)
+                .expect("the `Entry` was already authorized by the `auth_token`")
+        } }
+
+        // Note: A different implementation that forbids potential panics, without the
+        // "unchecked-construction" package-feature, should instead store the `AuthorisedEntry`s
+        // as taken in by `StoreExt::put`, to avoid needing to do something like our `expect`
+        // above, so that it can instead simply return a clone of the `AuthorisedEntry` it stored.
+        // TODO: But how to deserialize to that type which has private fields?  (Deserializing
+        // the parts and then using `AuthorisedEntry::new_unchecked` is one way.)
     }
 }
@@ -0,0 +1,135 @@
+use {
+    super::{
+        in_mem::{
+            self,
+            InMem,
+        },
+        params::{
+            NamespaceName,
+            Params,
+            Path,
+            Permission,
+            User,
+        },
+    },
+    crate::payload::InMem as InMemPayload,
+    sailce_data_model::{
+        path::Extra as _,
+        store::JoinReport,
+        AuthorisedEntry,
+        ParamsEntry,
+        Payload,
+        StoreAuthorisedEntry,
+        StoreExt,
+    },
+    std::{
+        borrow::Borrow,
+        sync::Arc,
+    },
+};
+
+
+/// Like an [`InMem`], which it wraps, but whose `try_iter` fails to read the entries at the path
+/// `unreadable`, like how a persistent backend's I/O might fail for some entry.
+pub(crate) struct Unreadable
+{
+    pub(crate) inner:      InMem,
+    pub(crate) unreadable: Path,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) enum GetError
+{
+    Get(in_mem::GetError),
+    Unreadable(Path),
+}
+
+
+/// Delegates everything to `self.inner`, except `try_iter`, and except `put_returning`,
+/// `iter_history`, `change_token`, and `entries_since`, to exercise their defaults.  Doesn't
+/// implement `CheckpointExt` nor `DeleteExt`, because those are optional.
+impl StoreExt for Unreadable
+{
+    type GetError = GetError;
+    type GetPayload = InMemPayload;
+    type IterAuthToken = Arc<Permission>;
+    type IterPath = Path;
+    type JoinError = <InMem as StoreExt>::JoinError;
+    type Params = Params;
+    type PutError<P> = <InMem as StoreExt>::PutError<P> where P: Payload + ?Sized;
+
+    async fn get(
+        &self,
+        namespace_id: &NamespaceName,
+        subspace_id: &User,
+        path: &(impl sailce_data_model::Path + ?Sized),
+    ) -> Result<Option<Self::GetPayload>, Self::GetError>
+    {
+        self.inner.get(namespace_id, subspace_id, path).await.map_err(GetError::Get)
+    }
+
+    async fn put<P>(
+        &mut self,
+        namespace_id: &NamespaceName,
+        auth_entry: AuthorisedEntry<
+            Self::Params,
+            impl sailce_data_model::Path,
+            impl Borrow<Permission>,
+        >,
+        payload: Option<P>,
+    ) -> Result<(), Self::PutError<P>>
+    where
+        P: Payload,
+    {
+        self.inner.put(namespace_id, auth_entry, payload).await
+    }
+
+    async fn join(
+        &mut self,
+        namespace_id: &NamespaceName,
+        other: &Self,
+        other_namespace_id: &NamespaceName,
+    ) -> Result<JoinReport, Self::JoinError>
+    {
+        self.inner.join(namespace_id, &other.inner, other_namespace_id).await
+    }
+
+    async fn newest_includes_within_total_size<P>(
+        &self,
+        namespace_id: &NamespaceName,
+        max_count: Option<u64>,
+        entry: impl Borrow<ParamsEntry<Self::Params, P>>,
+        max_size: Option<u64>,
+    ) -> bool
+    where
+        P: sailce_data_model::Path,
+    {
+        self.inner
+            .newest_includes_within_total_size(namespace_id, max_count, entry, max_size)
+            .await
+    }
+
+    fn iter(
+        &self,
+        namespace_id: &NamespaceName,
+    ) -> impl Iterator<Item = StoreAuthorisedEntry<Self>>
+    {
+        self.inner.iter(namespace_id)
+    }
+
+    fn try_iter(
+        &self,
+        namespace_id: &NamespaceName,
+    ) -> impl Iterator<Item = Result<StoreAuthorisedEntry<Self>, Self::GetError>>
+    {
+        self.iter(namespace_id).map(|auth_entry| {
+            let path = &auth_entry.entry().path;
+            if path.eq_components(&self.unreadable) {
+                Err(GetError::Unreadable(Arc::clone(path)))
+            }
+            else {
+                Ok(auth_entry)
+            }
+        })
+    }
+}
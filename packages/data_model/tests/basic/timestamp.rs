@@ -0,0 +1,107 @@
+use {
+    sailce_data_model::{
+        Timestamp,
+        TimestampOverflowError,
+    },
+    std::time::Duration,
+};
+
+
+#[test]
+fn millis()
+{
+    assert_eq!(Timestamp::from_millis(0), Timestamp { μs_since_epoch: 0 });
+    assert_eq!(Timestamp::from_millis(1), Timestamp { μs_since_epoch: 1_000 });
+    assert_eq!(Timestamp::from_millis(1_234).to_millis(), 1_234);
+    // Sub-millisecond part is truncated.
+    assert_eq!(Timestamp { μs_since_epoch: 1_999 }.to_millis(), 1);
+    assert_eq!(Timestamp { μs_since_epoch: 999 }.to_millis(), 0);
+    assert_eq!(Timestamp::from_millis(Timestamp::from(5_678).to_millis()), 5_000.into());
+    // Saturates.
+    assert_eq!(Timestamp::from_millis(u64::MAX), u64::MAX.into());
+    let max_millis = 18_446_744_073_709_551; // `u64::MAX / 1_000`
+    assert_eq!(Timestamp::from_millis(max_millis).to_millis(), max_millis);
+    assert_eq!(Timestamp::from_millis(max_millis + 1), u64::MAX.into());
+    assert_eq!(Timestamp::from(u64::MAX).to_millis(), max_millis);
+}
+
+
+#[test]
+fn duration()
+{
+    assert_eq!(Timestamp::from_duration_since_epoch(Duration::ZERO), Ok(0.into()));
+    assert_eq!(Timestamp::from_duration_since_epoch(Duration::from_nanos(1_999)), Ok(1.into()));
+    assert_eq!(
+        Timestamp::from_duration_since_epoch(Duration::from_secs(3)),
+        Ok(3_000_000.into())
+    );
+    assert_eq!(Timestamp::from(42).to_duration_since_epoch(), Duration::from_micros(42));
+
+    let max = Duration::from_micros(u64::MAX);
+    assert_eq!(Timestamp::from_duration_since_epoch(max), Ok(u64::MAX.into()));
+    assert_eq!(Timestamp::from(u64::MAX).to_duration_since_epoch(), max);
+    assert_eq!(
+        Timestamp::from_duration_since_epoch(max + Duration::from_micros(1)),
+        Err(TimestampOverflowError)
+    );
+    assert_eq!(Timestamp::from_duration_since_epoch(Duration::MAX), Err(TimestampOverflowError));
+}
+
+
+#[test]
+fn arithmetic()
+{
+    let ts = Timestamp::from(1_000);
+    assert_eq!(ts.saturating_add_micros(234), 1_234.into());
+    assert_eq!(ts.saturating_add_micros(u64::MAX), u64::MAX.into());
+    assert_eq!(ts.checked_sub(1), Some(999.into()));
+    assert_eq!(ts.checked_sub(1_000), Some(0.into()));
+    assert_eq!(ts.checked_sub(1_001), None);
+}
+
+
+#[cfg(feature = "std")]
+#[test]
+fn system_time()
+{
+    use {
+        sailce_data_model::{
+            SystemTimeOverflowError,
+            TimestampBeforeEpochError,
+        },
+        std::time::SystemTime,
+    };
+
+    let epoch = SystemTime::UNIX_EPOCH;
+    assert_eq!(Timestamp::try_from(epoch), Ok(0.into()));
+    assert_eq!(Timestamp::try_from(epoch + Duration::from_nanos(1_999)), Ok(1.into()));
+    assert_eq!(
+        Timestamp::try_from(epoch - Duration::from_micros(1)),
+        Err(TimestampBeforeEpochError)
+    );
+    assert_eq!(SystemTime::try_from(Timestamp::from(0)), Ok(epoch));
+    assert_eq!(
+        SystemTime::try_from(Timestamp::from(42)),
+        Ok(epoch + Duration::from_micros(42))
+    );
+
+    // Errors, where the platform can't represent such a time.
+    if epoch.checked_add(Duration::from_micros(u64::MAX)).is_none()
+    {
+        assert_eq!(SystemTime::try_from(Timestamp::from(u64::MAX)), Err(SystemTimeOverflowError));
+    }
+
+    // Saturates, where the platform can represent such a time.
+    if let Some(far) = epoch.checked_add(Duration::from_micros(u64::MAX) + Duration::from_secs(1))
+    {
+        assert_eq!(Timestamp::try_from(far), Ok(u64::MAX.into()));
+    }
+
+    let ts = Timestamp::from(1_700_000_000_123_456);
+    assert_eq!(SystemTime::try_from(ts).map(Timestamp::try_from), Ok(Ok(ts)));
+
+    let before = Timestamp::try_from(SystemTime::now()).unwrap();
+    let now = Timestamp::now();
+    assert!(before <= now);
+    assert!(now <= Timestamp::try_from(SystemTime::now()).unwrap());
+}
@@ -0,0 +1,142 @@
+use {
+    core::fmt::{
+        self,
+        Debug,
+        Display,
+        Formatter,
+    },
+    sailce_data_model::path::PathLimitError,
+    std::{
+        error::Error,
+        io,
+    },
+};
+
+
+/// Errors possibly returned by [`StoreExt::get`](sailce_data_model::StoreExt::get) and
+/// [`StoreExt::try_iter`](sailce_data_model::StoreExt::try_iter) of
+/// [`FsStore`](crate::FsStore).
+#[derive(Debug)]
+#[allow(clippy::exhaustive_enums)]
+pub enum GetError
+{
+    /// Failure of the filesystem, or a file that isn't as `FsStore` would've written it.
+    Io(io::Error),
+    /// The `Entry` is stored but its `Payload` was never supplied.
+    FoundEntryMissingPayload,
+}
+
+impl Display for GetError
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        match self {
+            GetError::Io(_) => write!(f, "filesystem failure"),
+            GetError::FoundEntryMissingPayload => write!(f, "found entry missing payload"),
+        }
+    }
+}
+
+impl Error for GetError
+{
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)>
+    {
+        match self {
+            GetError::Io(error) => Some(error),
+            GetError::FoundEntryMissingPayload => None,
+        }
+    }
+}
+
+impl From<io::Error> for GetError
+{
+    #[inline]
+    fn from(error: io::Error) -> Self
+    {
+        Self::Io(error)
+    }
+}
+
+
+/// Errors possibly returned by [`StoreExt::put`](sailce_data_model::StoreExt::put) (et al) of
+/// [`FsStore`](crate::FsStore).
+///
+/// Nothing of the `Entry` is stored when any of these is returned.  (The `Payload` might have
+/// already been stored, if its digest was correct, but that's unobservable.)
+#[derive(Debug)]
+#[allow(clippy::exhaustive_enums)]
+pub enum PutError<PayloadDigest, HashError, ReadError, SeekError>
+{
+    /// The `Entry`'s `Path` exceeds the limits of the `Params`.
+    PathLimit(PathLimitError),
+    /// Failure of the filesystem, or a file that isn't as `FsStore` would've written it.
+    Io(io::Error),
+    /// Failure of [`Params::hash_payload`](sailce_data_model::Params::hash_payload).
+    Hash(HashError),
+    /// Failure of [`Payload::read`](sailce_data_model::Payload::read) of the given `Payload`.
+    Read(ReadError),
+    /// Failure of [`Payload::seek`](sailce_data_model::Payload::seek) of the given `Payload`.
+    Seek(SeekError),
+    /// The given `Payload`'s digest is not the `Entry`'s `payload_digest`.
+    WrongDigest
+    {
+        /// The `Entry`'s `payload_digest`.
+        given:    PayloadDigest,
+        /// The digest of the given `Payload`.
+        computed: PayloadDigest,
+    },
+}
+
+impl<D, H, R, S> Display for PutError<D, H, R, S>
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        write!(f, "`FsStore` put failed due to {}", match self {
+            PutError::PathLimit(_) => "path limit",
+            PutError::Io(_) => "filesystem failure",
+            PutError::Hash(_) => "hashing payload",
+            PutError::Read(_) => "reading payload",
+            PutError::Seek(_) => "seeking payload",
+            PutError::WrongDigest { .. } => "wrong digest",
+        })
+    }
+}
+
+impl<D, H, R, S> Error for PutError<D, H, R, S>
+where
+    D: Debug,
+    H: Debug,
+    R: Debug,
+    S: Debug,
+{
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)>
+    {
+        match self {
+            PutError::PathLimit(error) => Some(error),
+            PutError::Io(error) => Some(error),
+            PutError::Hash(_)
+            | PutError::Read(_)
+            | PutError::Seek(_)
+            | PutError::WrongDigest { .. } => None,
+        }
+    }
+}
+
+impl<D, H, R, S> From<io::Error> for PutError<D, H, R, S>
+{
+    #[inline]
+    fn from(error: io::Error) -> Self
+    {
+        Self::Io(error)
+    }
+}
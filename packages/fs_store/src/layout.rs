@@ -0,0 +1,212 @@
+//! How a Namespace is laid out as files and directories, under the directory given to
+//! [`FsStore::open`](crate::FsStore::open):
+//!
+//! ```text
+//! <namespace>/
+//!     change_count
+//!     payloads/
+//!         <digest>
+//!     entries/
+//!         <subspace>/
+//!             c<component>/.../history.json
+//! ```
+//!
+//! Where `<namespace>`, `<subspace>`, and `<digest>` are the IDs serialized as JSON and then
+//! hex-encoded, and each `c<component>` is a `Component`'s bytes hex-encoded with a `c` prefix
+//! (so that the empty `Component` is still a valid name).  Hex-encoding ensures that every name
+//! is valid on every platform and that none collide on case-insensitive filesystems, at the cost
+//! of doubling the lengths, which limits `Component`s to 127 bytes on filesystems that limit
+//! names to 255 bytes.  Each `history.json` is the history of the location of the directory
+//! that contains it.
+//!
+//! While restoring a `Snapshot`, there are also `entries-restoring/`, which becomes the new
+//! `entries/`, and `entries-replaced/`, which is the old one until it's deleted.
+
+use {
+    alloc::{
+        format,
+        string::String,
+        vec::Vec,
+    },
+    core::fmt::Write as _,
+    serde::{
+        de::DeserializeOwned,
+        Serialize,
+    },
+    std::{
+        io,
+        path::{
+            Path,
+            PathBuf,
+        },
+    },
+};
+
+
+pub(crate) const CHANGE_COUNT_FILE: &str = "change_count";
+pub(crate) const ENTRIES_DIR: &str = "entries";
+pub(crate) const HISTORY_FILE: &str = "history.json";
+pub(crate) const PAYLOADS_DIR: &str = "payloads";
+const RESTORING_ENTRIES_DIR: &str = "entries-restoring";
+const REPLACED_ENTRIES_DIR: &str = "entries-replaced";
+const COMPONENT_PREFIX: char = 'c';
+const TEMP_PREFIX: &str = "tmp-";
+
+
+/// The paths of the files and directories of a single Namespace.
+#[derive(Clone, Debug)]
+pub(crate) struct Layout
+{
+    namespace_dir: PathBuf,
+}
+
+impl Layout
+{
+    pub(crate) fn new(
+        base_dir: &Path,
+        namespace_id: &impl Serialize,
+    ) -> io::Result<Self>
+    {
+        Ok(Self { namespace_dir: base_dir.join(id_name(namespace_id)?) })
+    }
+
+    pub(crate) fn namespace_dir(&self) -> &Path
+    {
+        &self.namespace_dir
+    }
+
+    pub(crate) fn change_count_file(&self) -> PathBuf
+    {
+        self.namespace_dir.join(CHANGE_COUNT_FILE)
+    }
+
+    pub(crate) fn entries_dir(&self) -> PathBuf
+    {
+        self.namespace_dir.join(ENTRIES_DIR)
+    }
+
+    /// Where a restored `entries/` is written before it's renamed into place.
+    pub(crate) fn restoring_entries_dir(&self) -> PathBuf
+    {
+        self.namespace_dir.join(RESTORING_ENTRIES_DIR)
+    }
+
+    /// Where the `entries/` that is being replaced by a restored one is renamed to, before it's
+    /// deleted.
+    pub(crate) fn replaced_entries_dir(&self) -> PathBuf
+    {
+        self.namespace_dir.join(REPLACED_ENTRIES_DIR)
+    }
+
+    pub(crate) fn payloads_dir(&self) -> PathBuf
+    {
+        self.namespace_dir.join(PAYLOADS_DIR)
+    }
+
+    pub(crate) fn subspace_dir(
+        &self,
+        subspace_id: &impl Serialize,
+    ) -> io::Result<PathBuf>
+    {
+        Ok(self.entries_dir().join(id_name(subspace_id)?))
+    }
+
+    /// The directory for the location, which contains its `history.json` if it has one.
+    pub(crate) fn location_dir(
+        &self,
+        subspace_id: &impl Serialize,
+        path: &(impl sailce_data_model::Path + ?Sized),
+    ) -> io::Result<PathBuf>
+    {
+        let mut dir = self.subspace_dir(subspace_id)?;
+        dir.extend(path.components().map(|component| component_name(component.bytes())));
+        Ok(dir)
+    }
+
+    pub(crate) fn payload_file(
+        &self,
+        payload_digest: &impl Serialize,
+    ) -> io::Result<PathBuf>
+    {
+        Ok(self.payloads_dir().join(id_name(payload_digest)?))
+    }
+
+    /// A file in the same directory as the payload files, so that renaming it into place is
+    /// atomic.  The names of these never collide with those of payload files, because hex
+    /// doesn't have `-`.
+    pub(crate) fn temp_payload_file(
+        &self,
+        unique: u64,
+    ) -> PathBuf
+    {
+        self.payloads_dir().join(format!("{TEMP_PREFIX}{}-{unique}", std::process::id()))
+    }
+}
+
+
+/// The file of the history of the location of `location_dir`.
+pub(crate) fn history_file(location_dir: &Path) -> PathBuf
+{
+    location_dir.join(HISTORY_FILE)
+}
+
+
+/// The name that represents `id`.
+pub(crate) fn id_name(id: &impl Serialize) -> io::Result<String>
+{
+    Ok(hex(&serde_json::to_vec(id)?))
+}
+
+/// The ID that `name` represents, or `None` if `name` isn't one of ours.
+pub(crate) fn name_id<T: DeserializeOwned>(name: &str) -> Option<T>
+{
+    serde_json::from_slice(&unhex(name)?).ok()
+}
+
+/// The name of the directory that represents a `Component` of a location.
+pub(crate) fn component_name(bytes: &[u8]) -> String
+{
+    let mut name = String::with_capacity(bytes.len().saturating_mul(2).saturating_add(1));
+    name.push(COMPONENT_PREFIX);
+    name.push_str(&hex(bytes));
+    name
+}
+
+/// The `Component` that `name` represents, or `None` if `name` isn't one of ours.
+pub(crate) fn name_component(name: &str) -> Option<Vec<u8>>
+{
+    unhex(name.strip_prefix(COMPONENT_PREFIX)?)
+}
+
+
+fn hex(bytes: &[u8]) -> String
+{
+    bytes.iter().fold(String::with_capacity(bytes.len().saturating_mul(2)), |mut s, byte| {
+        let _ignored = write!(s, "{byte:02x}"); // Writing to a `String` can't fail.
+        s
+    })
+}
+
+fn unhex(name: &str) -> Option<Vec<u8>>
+{
+    /// Only lower-case, so that each byte-string has exactly one name.
+    fn digit(d: u8) -> Option<u8>
+    {
+        match d {
+            b'0' ..= b'9' => Some(d.wrapping_sub(b'0')),
+            b'a' ..= b'f' => Some(d.wrapping_sub(b'a').wrapping_add(10)),
+            _ => None,
+        }
+    }
+
+    let pairs = name.as_bytes().chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return None;
+    }
+    pairs
+        .map(|pair| match *pair {
+            [high, low] => Some((digit(high)? << 4_u8) | digit(low)?),
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,31 @@
+#![cfg_attr(unix, doc = include_str!("../README.md"))]
+#![cfg_attr(windows, doc = include_str!("..\\README.md"))]
+// Apply the `no_std` attribute unconditionally, to require explicit conditional `use` of
+// non-`core` items.
+#![no_std]
+// Warn about this one but avoid annoying hits for dev-dependencies.
+#![cfg_attr(test, allow(unused_crate_dependencies))]
+
+
+extern crate alloc;
+extern crate std;
+
+
+mod errors;
+pub use errors::{
+    GetError,
+    PutError,
+};
+
+mod layout;
+
+mod payload;
+pub use payload::CopyingPayload;
+
+mod record;
+
+mod store;
+pub use store::{
+    FsStore,
+    Snapshot,
+};
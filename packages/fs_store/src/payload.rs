@@ -0,0 +1,158 @@
+use {
+    crate::PutError,
+    alloc::vec,
+    sailce_data_model::{
+        payload::SeekFrom,
+        Payload,
+    },
+    std::{
+        fs::File,
+        io::{
+            self,
+            Write as _,
+        },
+    },
+};
+
+
+/// Wraps a given [`Payload`] so that what is read from it is also written to a file, so that
+/// hashing it can also store it without reading it twice.
+///
+/// This is what [`Params::hash_payload`](sailce_data_model::Params::hash_payload) is given when
+/// [`FsStore`](crate::FsStore) verifies a `Payload` that is being put, and so it's exposed only
+/// to be named in the error type.  Any parts that aren't read in order, from the beginning, are
+/// read again afterwards, to finish the copy.
+#[derive(Debug)]
+pub struct CopyingPayload<P: ?Sized>
+{
+    file:    File,
+    /// How much of the beginning of `inner` has been written to `file`.
+    copied:  u64,
+    /// The current position of `inner`.
+    pos:     u64,
+    /// Writing to `file` failed, which is reported after `inner` is done being read.
+    failure: Option<io::Error>,
+    inner:   P,
+}
+
+impl<P: Payload> CopyingPayload<P>
+{
+    pub(crate) fn new(
+        inner: P,
+        file: File,
+    ) -> Self
+    {
+        Self { file, copied: 0, pos: 0, failure: None, inner }
+    }
+
+    /// Finish copying whatever of `inner` hasn't been yet, and return the `file`.
+    pub(crate) async fn finish(mut self) -> Result<File, CopyError<P>>
+    {
+        if let Some(failure) = self.failure {
+            return Err(CopyError::Io(failure));
+        }
+        let len = self.inner.len().await;
+        if self.copied < len {
+            let _: u64 =
+                self.inner.seek(SeekFrom::Start(self.copied)).await.map_err(CopyError::Seek)?;
+            copy_remaining(&mut self.inner, self.copied, &mut self.file).await?;
+        }
+        Ok(self.file)
+    }
+}
+
+impl<P: Payload + ?Sized> Payload for CopyingPayload<P>
+{
+    type ReadError = P::ReadError;
+    type SeekError = P::SeekError;
+
+    #[inline]
+    async fn read(
+        &mut self,
+        buf: &mut [u8],
+    ) -> Result<usize, Self::ReadError>
+    {
+        let read = self.inner.read(buf).await?;
+        let end = self.pos.saturating_add(read.try_into().unwrap_or(u64::MAX));
+        // Only what continues the copied beginning can be written.
+        if self.failure.is_none() && (self.pos ..= end).contains(&self.copied) {
+            let skip = self.copied.saturating_sub(self.pos).try_into().unwrap_or(usize::MAX);
+            let new = buf.get(skip .. read).unwrap_or_default();
+            match self.file.write_all(new) {
+                Ok(()) => self.copied = end,
+                Err(error) => self.failure = Some(error),
+            }
+        }
+        self.pos = end;
+        Ok(read)
+    }
+
+    #[inline]
+    async fn seek(
+        &mut self,
+        pos: SeekFrom,
+    ) -> Result<u64, Self::SeekError>
+    {
+        let new_pos = self.inner.seek(pos).await?;
+        self.pos = new_pos;
+        Ok(new_pos)
+    }
+
+    #[inline]
+    async fn len(&self) -> u64
+    {
+        self.inner.len().await
+    }
+
+    #[inline]
+    async fn is_empty(&self) -> bool
+    {
+        self.inner.is_empty().await
+    }
+}
+
+
+/// Errors possibly from copying a `Payload` into a file.
+pub(crate) enum CopyError<P: Payload + ?Sized>
+{
+    Io(io::Error),
+    Read(P::ReadError),
+    Seek(P::SeekError),
+}
+
+impl<P: Payload + ?Sized> CopyError<P>
+{
+    pub(crate) fn into_put_error<D, H>(self) -> PutError<D, H, P::ReadError, P::SeekError>
+    {
+        match self {
+            CopyError::Io(error) => PutError::Io(error),
+            CopyError::Read(error) => PutError::Read(error),
+            CopyError::Seek(error) => PutError::Seek(error),
+        }
+    }
+}
+
+
+/// Copy all of `payload`, from its current position until its end, to `file`.  `pos` must be the
+/// current position.
+pub(crate) async fn copy_remaining<P: Payload + ?Sized>(
+    payload: &mut P,
+    mut pos: u64,
+    file: &mut File,
+) -> Result<(), CopyError<P>>
+{
+    const BUF_SIZE: u16 = 8 * 1024; // As `u16` enables `into` and avoiding `try_into`.
+
+    let len = payload.len().await;
+    let mut buf = vec![0; BUF_SIZE.into()];
+    while pos < len {
+        let read = payload.read(&mut buf).await.map_err(CopyError::Read)?;
+        if read == 0 {
+            // The `Payload` misbehaved by ending before its `len`.
+            return Err(CopyError::Io(io::ErrorKind::UnexpectedEof.into()));
+        }
+        file.write_all(buf.get(.. read).unwrap_or_default()).map_err(CopyError::Io)?;
+        pos = pos.saturating_add(read.try_into().unwrap_or(u64::MAX));
+    }
+    Ok(())
+}
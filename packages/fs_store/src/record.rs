@@ -0,0 +1,99 @@
+//! The contents of the `history.json` files.
+
+use {
+    crate::layout,
+    alloc::vec::Vec,
+    core::cmp::Ordering,
+    serde::{
+        de::DeserializeOwned,
+        Deserialize,
+        Serialize,
+    },
+    std::{
+        fs,
+        io,
+        path::Path,
+    },
+};
+
+
+/// What is stored of an `Entry`, besides its location.
+///
+/// The `auth_token` is kept as its JSON value so that it can be stored from a borrow, as given
+/// to `put`, without requiring the `AuthorisationToken` type to be `Clone`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[allow(clippy::field_scoped_visibility_modifiers)] // Just plain data, internal to the crate.
+pub(crate) struct Record<D>
+{
+    pub(crate) timestamp:      u64,
+    pub(crate) payload_digest: D,
+    pub(crate) payload_length: u64,
+    pub(crate) auth_token:     serde_json::Value,
+    /// The value of the change count when this was stored or when its payload was supplied, for
+    /// `entries_since`.
+    pub(crate) change_seq:     u64,
+}
+
+impl<D: Ord> Record<D>
+{
+    /// The same ordering as `Entry::cmp_newer_than`.
+    pub(crate) fn cmp_newer_than(
+        &self,
+        other: &Self,
+    ) -> Ordering
+    {
+        (self.timestamp, &self.payload_digest, self.payload_length).cmp(&(
+            other.timestamp,
+            &other.payload_digest,
+            other.payload_length,
+        ))
+    }
+
+    pub(crate) fn is_newer_than(
+        &self,
+        other: &Self,
+    ) -> bool
+    {
+        self.cmp_newer_than(other) == Ordering::Greater
+    }
+}
+
+
+/// All the `Record`s of a location, newest first, without any that are equally new.
+pub(crate) type History<D> = Vec<Record<D>>;
+
+
+/// The `History` in `location_dir`, which is empty if it has none.
+pub(crate) fn read<D: DeserializeOwned>(location_dir: &Path) -> io::Result<History<D>>
+{
+    match fs::read(layout::history_file(location_dir)) {
+        Ok(json) => Ok(serde_json::from_slice(&json)?),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(History::new()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Replace the `History` in `location_dir`, creating the directory if needed, or remove it if
+/// `history` is empty.
+///
+/// Written to a temporary file that is then renamed over the old, so that a crash can't leave
+/// a partially-written history.
+pub(crate) fn write<D: Serialize>(
+    location_dir: &Path,
+    history: &History<D>,
+) -> io::Result<()>
+{
+    let file = layout::history_file(location_dir);
+    if history.is_empty() {
+        match fs::remove_file(file) {
+            Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+            _ => Ok(()),
+        }
+    }
+    else {
+        fs::create_dir_all(location_dir)?;
+        let temp = file.with_extension("json.tmp");
+        fs::write(&temp, serde_json::to_vec(history)?)?;
+        fs::rename(temp, file)
+    }
+}
@@ -0,0 +1,1176 @@
+use {
+    crate::{
+        errors::{
+            GetError,
+            PutError,
+        },
+        layout::{
+            self,
+            Layout,
+        },
+        payload::{
+            self,
+            CopyingPayload,
+        },
+        record::{
+            self,
+            History,
+            Record,
+        },
+    },
+    alloc::{
+        borrow::ToOwned as _,
+        boxed::Box,
+        collections::BTreeSet,
+        string::{
+            String,
+            ToString as _,
+        },
+        vec,
+        vec::Vec,
+    },
+    core::{
+        borrow::Borrow,
+        fmt::{
+            self,
+            Debug,
+            Formatter,
+        },
+        iter,
+        marker::PhantomData,
+    },
+    sailce_data_model::{
+        path::Extra as _,
+        payload::{
+            File,
+            SeekFrom,
+        },
+        store::{
+            ChangeToken,
+            CheckpointExt,
+            DeleteExt,
+            JoinReport,
+        },
+        AuthorisedEntry,
+        Entry,
+        ParamsEntry,
+        Path,
+        Payload,
+        StoreAuthorisedEntry,
+        StoreExt,
+    },
+    serde::{
+        de::DeserializeOwned,
+        Serialize,
+    },
+    std::{
+        fs,
+        io,
+        path::PathBuf,
+        sync::{
+            Mutex,
+            PoisonError,
+        },
+    },
+};
+
+
+/// The `Path` type of the `Entry`s that [`FsStore`] gives.
+type StoredPath = Vec<Box<[u8]>>;
+
+type ParamsPutError<Params, P> = PutError<
+    <Params as sailce_data_model::Params>::PayloadDigest,
+    <Params as sailce_data_model::Params>::HashPayloadError<CopyingPayload<P>>,
+    <P as Payload>::ReadError,
+    <P as Payload>::SeekError,
+>;
+
+
+/// A [`StoreExt`] that persists a single Namespace as files and directories, as described by
+/// the `layout` module, so that it outlives the process and can be reopened.
+///
+/// Each location (the `SubspaceId` and `Path` of an `Entry`) is a nested directory that has a
+/// small history file of the `Entry`s written there, and each `Payload` is a file named by its
+/// digest, so that identical `Payload`s are stored once.  Putting a `Payload` streams it to a
+/// temporary file while hashing it, which is renamed into place only once its digest is verified.
+///
+/// Prefix pruning deletes the pruned history files when putting, and `get` and the iterators also
+/// check the prefixes, so that pruned `Entry`s are never given even if a crash interrupted the
+/// deleting.  `Payload` files are deleted only by [`Self::collect_garbage`], which is what
+/// enables [`restore`](CheckpointExt::restore) to be given a [`Snapshot`] of only the histories.
+///
+/// The `Params`' IDs, digests, and tokens are (de)serialized with JSON, to name and fill the
+/// files, and the digests must also be `Clone`.
+///
+/// The `async` methods block on the filesystem, instead of suspending.  The methods that can't
+/// return errors, because of the signatures of `StoreExt`, skip whatever fails, and the latest
+/// such error can be taken by [`Self::take_io_error`].
+///
+/// It is a logic error for multiple instances (in any processes) to use the same directory
+/// concurrently.  That's why this isn't `Clone`, since a clone would share the directory, and
+/// so [`Store::join_all`](sailce_data_model::store::async::Store::join_all) isn't available.
+/// Instead, [`open`](Self::open) one in a new directory, and [`join`](StoreExt::join) each of
+/// the others into it.
+///
+/// The `Payload`s that it gives are [`sailce_data_model::payload::File`]s of its files.
+pub struct FsStore<Params>
+where Params: sailce_data_model::Params + ?Sized
+{
+    layout:       Layout,
+    namespace_id: Params::NamespaceId, // Just to check against.
+    /// Count of mutations, for `StoreExt::change_token`.  Also persisted in its file.
+    change_count: u64,
+    /// For naming temporary files uniquely.
+    temp_count:   u64,
+    io_error:     Mutex<Option<io::Error>>,
+    params:       PhantomData<fn(&Params)>,
+}
+
+impl<Params> FsStore<Params>
+where
+    Params: sailce_data_model::Params + ?Sized,
+    Params::NamespaceId: Serialize,
+    Params::SubspaceId: Serialize + DeserializeOwned,
+    Params::PayloadDigest: Clone + Serialize + DeserializeOwned,
+    Params::AuthorisationToken: Serialize + DeserializeOwned,
+{
+    /// Open the Namespace of `namespace_id` in `base_dir`, creating it if it doesn't already
+    /// exist.  The same `base_dir` can have multiple Namespaces, each opened separately.
+    ///
+    /// # Errors
+    /// If the directories can't be created, or if the existing files can't be read.
+    #[inline]
+    pub fn open(
+        base_dir: impl AsRef<std::path::Path>,
+        namespace_id: &Params::NamespaceId,
+    ) -> io::Result<Self>
+    {
+        let layout = Layout::new(base_dir.as_ref(), namespace_id)?;
+        Self::finish_restore(&layout)?;
+        fs::create_dir_all(layout.entries_dir())?;
+        fs::create_dir_all(layout.payloads_dir())?;
+        let change_count = match fs::read_to_string(layout.change_count_file()) {
+            Ok(count) => count.trim().parse().map_err(|_e| invalid_data("bad change count"))?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => 0,
+            Err(error) => return Err(error),
+        };
+        Ok(Self {
+            layout,
+            namespace_id: namespace_id.clone(),
+            change_count,
+            temp_count: 0,
+            io_error: Mutex::new(None),
+            params: PhantomData,
+        })
+    }
+
+    /// The directory of the Namespace, within the `base_dir` that was given to
+    /// [`Self::open`].
+    #[must_use]
+    #[inline]
+    pub fn namespace_dir(&self) -> &std::path::Path
+    {
+        self.layout.namespace_dir()
+    }
+
+    /// Take the latest error from the filesystem that was skipped by a method that can't return
+    /// errors, e.g. [`iter`](StoreExt::iter), or `None` if there hasn't been one since the last
+    /// take.
+    #[must_use]
+    #[inline]
+    pub fn take_io_error(&self) -> Option<io::Error>
+    {
+        self.io_error.lock().unwrap_or_else(PoisonError::into_inner).take()
+    }
+
+    /// Delete the `Payload` files that aren't referred to by any stored `Entry`, i.e. those of
+    /// the `Entry`s that were deleted or pruned.  Returns how many were deleted.
+    ///
+    /// This isn't done automatically, because it must scan all of the histories, and because it
+    /// makes a [`Snapshot`] that was taken before unable to restore the `Payload`s of the
+    /// `Entry`s that have since been deleted or pruned (those would become like `Entry`s that
+    /// were put without their `Payload`s).
+    ///
+    /// # Errors
+    /// If reading the histories or deleting the files fails.  Nothing is deleted if reading the
+    /// histories failed.
+    #[inline]
+    pub fn collect_garbage(&mut self) -> io::Result<u64>
+    {
+        let mut referred = BTreeSet::<String>::new();
+        for location in self.locations() {
+            for record in record::read::<Params::PayloadDigest>(&location?.dir)? {
+                let _new: bool = referred.insert(layout::id_name(&record.payload_digest)?);
+            }
+        }
+        let mut count = 0_u64;
+        for file in fs::read_dir(self.layout.payloads_dir())? {
+            let file = file?;
+            let name = file.file_name();
+            let is_garbage = name.to_str().is_some_and(|name| {
+                layout::name_id::<Params::PayloadDigest>(name).is_some()
+                    && !referred.contains(name)
+            });
+            if is_garbage {
+                fs::remove_file(file.path())?;
+                count = count.saturating_add(1);
+            }
+        }
+        Ok(count)
+    }
+
+    fn debug_assert_namespace(
+        &self,
+        namespace_id: &Params::NamespaceId,
+    )
+    {
+        debug_assert!(*namespace_id == self.namespace_id, "must be the same Namespace");
+    }
+
+    fn skip_error(
+        &self,
+        error: io::Error,
+    )
+    {
+        *self.io_error.lock().unwrap_or_else(PoisonError::into_inner) = Some(error);
+    }
+
+    fn bump_change_count(&mut self) -> io::Result<u64>
+    {
+        let count = self.change_count.saturating_add(1);
+        fs::write(self.layout.change_count_file(), count.to_string())?;
+        self.change_count = count;
+        Ok(count)
+    }
+
+    fn locations(&self) -> Locations<Params::SubspaceId>
+    {
+        Locations::new(&self.layout.entries_dir())
+    }
+
+    /// Whether `record`, at `location_dir` which has `component_count` `Component`s, is
+    /// prefix-pruned by the newest at any of the prefixes.
+    fn is_pruned(
+        location_dir: &std::path::Path,
+        component_count: usize,
+        record: &Record<Params::PayloadDigest>,
+    ) -> io::Result<bool>
+    {
+        for prefix_dir in location_dir.ancestors().skip(1).take(component_count) {
+            if let Some(newest) = record::read(prefix_dir)?.first() {
+                if newest.is_newer_than(record) {
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    /// The newest at `location_dir`, unless it's been prefix-pruned.
+    fn newest_unpruned(
+        location_dir: &std::path::Path,
+        component_count: usize,
+    ) -> io::Result<Option<Record<Params::PayloadDigest>>>
+    {
+        let Some(newest) = record::read(location_dir)?.into_iter().next()
+        else {
+            return Ok(None);
+        };
+        Ok((!Self::is_pruned(location_dir, component_count, &newest)?).then_some(newest))
+    }
+
+    /// Delete the histories of the locations prefixed by `location_dir`'s whose newest are older
+    /// than `newest`, and the directories that become empty.  Returns whether any histories
+    /// were deleted.
+    fn prune_under(
+        location_dir: &std::path::Path,
+        newest: &Record<Params::PayloadDigest>,
+    ) -> io::Result<bool>
+    {
+        let mut pruned_any = false;
+        for child in fs::read_dir(location_dir)? {
+            let child = child?;
+            let is_location = child.file_type()?.is_dir()
+                && child.file_name().to_str().and_then(layout::name_component).is_some();
+            if is_location {
+                let child_dir = child.path();
+                let history = record::read::<Params::PayloadDigest>(&child_dir)?;
+                if history.first().is_some_and(|child_newest| newest.is_newer_than(child_newest))
+                {
+                    record::write::<Params::PayloadDigest>(&child_dir, &History::new())?;
+                    pruned_any = true;
+                }
+                pruned_any |= Self::prune_under(&child_dir, newest)?;
+                let _was_empty = remove_dir_if_empty(&child_dir)?;
+            }
+        }
+        Ok(pruned_any)
+    }
+
+    fn payload_of(
+        &self,
+        record: &Record<Params::PayloadDigest>,
+    ) -> io::Result<Option<File>>
+    {
+        match fs::File::open(self.layout.payload_file(&record.payload_digest)?) {
+            Ok(file) => File::new(file).map(Some),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn to_auth_entry(
+        namespace_id: &Params::NamespaceId,
+        subspace_id: Params::SubspaceId,
+        path: StoredPath,
+        record: Record<Params::PayloadDigest>,
+    ) -> io::Result<StoreAuthorisedEntry<Self>>
+    {
+        let entry = Entry {
+            namespace_id: namespace_id.clone(),
+            subspace_id,
+            path,
+            timestamp: record.timestamp.into(),
+            payload_digest: record.payload_digest,
+            payload_length: record.payload_length,
+        };
+        let auth_token = serde_json::from_value(record.auth_token)?;
+        AuthorisedEntry::new(entry, auth_token)
+            .ok_or_else(|| invalid_data("stored entry is not authorised"))
+    }
+
+    /// The implementation of `put`, `put_returning`, and `put_trusted`.  If `verify`, the digest
+    /// of `payload` is computed and checked against the entry's, else it's trusted.  Returns the
+    /// entry as it was stored.
+    async fn put_with<P: Payload>(
+        &mut self,
+        namespace_id: &Params::NamespaceId,
+        auth_entry: AuthorisedEntry<Params, impl Path, impl Borrow<Params::AuthorisationToken>>,
+        payload: Option<P>,
+        verify: bool,
+    ) -> Result<StoreAuthorisedEntry<Self>, ParamsPutError<Params, P>>
+    {
+        let (stored, _) = self.put_with_outcome(namespace_id, auth_entry, payload, verify).await?;
+        Ok(stored)
+    }
+
+    /// Like [`Self::put_with`], but also return what putting did, which `join` also needs.
+    async fn put_with_outcome<P: Payload>(
+        &mut self,
+        namespace_id: &Params::NamespaceId,
+        auth_entry: AuthorisedEntry<Params, impl Path, impl Borrow<Params::AuthorisationToken>>,
+        payload: Option<P>,
+        verify: bool,
+    ) -> Result<(StoreAuthorisedEntry<Self>, PutOutcome), ParamsPutError<Params, P>>
+    {
+        self.debug_assert_namespace(namespace_id);
+
+        let (entry, auth_token) = auth_entry.into_parts();
+        let path = StoredPath::from_path_limited::<Params, _, _>(&entry.path)
+            .map_err(PutError::PathLimit)?;
+        let record = Record {
+            timestamp:      entry.timestamp.μs_since_epoch,
+            payload_digest: entry.payload_digest,
+            payload_length: entry.payload_length,
+            auth_token:     serde_json::to_value(auth_token.borrow()).map_err(io::Error::from)?,
+            change_seq:     0, // Assigned when stored.
+        };
+        let location_dir = self.layout.location_dir(&entry.subspace_id, &path)?;
+        if Self::is_pruned(&location_dir, path.len(), &record)? {
+            // "Stored" only to be immediately pruned, so its payload isn't needed.
+            let stored = Self::to_auth_entry(namespace_id, entry.subspace_id, path, record)?;
+            return Ok((stored, PutOutcome::Superseded));
+        }
+
+        let supplied_payload = match payload {
+            Some(payload) => self.store_payload(payload, &record.payload_digest, verify).await?,
+            None => false,
+        };
+        let outcome = self.put_record(&location_dir, record.clone(), supplied_payload)?;
+        Ok((Self::to_auth_entry(namespace_id, entry.subspace_id, path, record)?, outcome))
+    }
+
+    /// Store `record` at `location_dir`, unless it's already there, and then prefix-prune with
+    /// it if it's the newest there.  Also a change if the `Payload` was `newly_supplied` for it.
+    /// It must not be prefix-pruned.
+    fn put_record(
+        &mut self,
+        location_dir: &std::path::Path,
+        mut record: Record<Params::PayloadDigest>,
+        newly_supplied: bool,
+    ) -> io::Result<PutOutcome>
+    {
+        let mut history = record::read(location_dir)?;
+        // Newest first, i.e. descending.
+        match history.binary_search_by(|stored| record.cmp_newer_than(stored)) {
+            Ok(index) => {
+                if newly_supplied {
+                    let change_seq = self.bump_change_count()?;
+                    if let Some(stored) = history.get_mut(index) {
+                        stored.change_seq = change_seq;
+                    }
+                    record::write(location_dir, &history)?;
+                }
+                Ok(if index == 0 { PutOutcome::Existing } else { PutOutcome::Superseded })
+            },
+            Err(index) => {
+                record.change_seq = self.bump_change_count()?;
+                history.insert(index, record);
+                record::write(location_dir, &history)?;
+                if let (0, Some(newest)) = (index, history.first()) {
+                    // Whatever was newest there before is overwritten.
+                    let overwrote = history.len() > 1;
+                    let pruned = Self::prune_under(location_dir, newest)?;
+                    Ok(PutOutcome::Current { removed_any: overwrote || pruned })
+                }
+                else {
+                    Ok(PutOutcome::Superseded)
+                }
+            },
+        }
+    }
+
+    /// Store `payload` as the file of `payload_digest`, verifying it if `verify`.  Returns
+    /// whether the file didn't already exist.
+    ///
+    /// A verified `payload` replaces the file even if it already exists, because that might
+    /// have been from an unverified `payload` that was wrong, which must not stay stored once
+    /// the correct one is given.  An unverified `payload` never replaces the file.
+    async fn store_payload<P: Payload>(
+        &mut self,
+        payload: P,
+        payload_digest: &Params::PayloadDigest,
+        verify: bool,
+    ) -> Result<bool, ParamsPutError<Params, P>>
+    {
+        let dest = self.layout.payload_file(payload_digest)?;
+        if !verify && dest.try_exists()? {
+            return Ok(false);
+        }
+        let temp = self.layout.temp_payload_file(self.temp_count);
+        self.temp_count = self.temp_count.wrapping_add(1);
+
+        match Self::write_payload(payload, payload_digest, verify, &temp).await {
+            Ok(()) => {
+                let existed = dest.try_exists()?;
+                if existed && !verify {
+                    fs::remove_file(&temp)?;
+                }
+                else {
+                    // Atomically replaces the existing, if any.
+                    fs::rename(&temp, &dest)?;
+                }
+                Ok(!existed)
+            },
+            Err(error) => {
+                let _ignored = fs::remove_file(&temp);
+                Err(error)
+            },
+        }
+    }
+
+    async fn write_payload<P: Payload>(
+        mut payload: P,
+        payload_digest: &Params::PayloadDigest,
+        verify: bool,
+        temp: &std::path::Path,
+    ) -> Result<(), ParamsPutError<Params, P>>
+    {
+        let mut file = fs::File::create(temp)?;
+        if verify {
+            let mut copying = CopyingPayload::new(payload, file);
+            let computed = Params::hash_payload(&mut copying).await.map_err(PutError::Hash)?;
+            if computed != *payload_digest {
+                return Err(PutError::WrongDigest { given: payload_digest.clone(), computed });
+            }
+            file = copying.finish().await.map_err(payload::CopyError::into_put_error)?;
+        }
+        else {
+            let _: u64 = payload.seek(SeekFrom::Start(0)).await.map_err(PutError::Seek)?;
+            payload::copy_remaining(&mut payload, 0, &mut file)
+                .await
+                .map_err(payload::CopyError::into_put_error)?;
+        }
+        Ok(file.sync_all()?)
+    }
+
+    /// Each stored `Entry`, with the `change_seq` of its newest record, that isn't
+    /// prefix-pruned.
+    fn iter_newest(
+        &self,
+        namespace_id: &Params::NamespaceId,
+    ) -> impl Iterator<Item = io::Result<(StoreAuthorisedEntry<Self>, u64)>> + '_
+    {
+        self.debug_assert_namespace(namespace_id);
+
+        let namespace_id = namespace_id.clone();
+        self.locations().filter_map(move |location| {
+            let location = match location {
+                Ok(location) => location,
+                Err(error) => return Some(Err(error)),
+            };
+            match Self::newest_unpruned(&location.dir, location.path.len()) {
+                Ok(Some(newest)) => {
+                    let change_seq = newest.change_seq;
+                    let Location { subspace_id, path, .. } = location;
+                    Some(
+                        Self::to_auth_entry(&namespace_id, subspace_id, path, newest)
+                            .map(|auth_entry| (auth_entry, change_seq)),
+                    )
+                },
+                Ok(None) => None,
+                Err(error) => Some(Err(error)),
+            }
+        })
+    }
+
+    fn iter_history_with(
+        &self,
+        namespace_id: &Params::NamespaceId,
+        subspace_id: &Params::SubspaceId,
+        path: &(impl Path + ?Sized),
+    ) -> io::Result<Vec<(StoreAuthorisedEntry<Self>, Option<File>)>>
+    {
+        let location_dir = self.layout.location_dir(subspace_id, path)?;
+        let component_count = path.components().len();
+        let history = record::read(&location_dir)?;
+        // When the newest is pruned, all the older are too.
+        if let Some(newest) = history.first() {
+            if Self::is_pruned(&location_dir, component_count, newest)? {
+                return Ok(Vec::new());
+            }
+        }
+        history
+            .into_iter()
+            .map(|record| {
+                let payload = self.payload_of(&record)?;
+                let path = StoredPath::from_path(path);
+                let auth_entry =
+                    Self::to_auth_entry(namespace_id, subspace_id.clone(), path, record)?;
+                Ok((auth_entry, payload))
+            })
+            .collect()
+    }
+
+    /// The current record of each location, with its payload, one location at a time.  Older
+    /// ones aren't needed by `join`, and the prefix-pruned ones are skipped so that they aren't
+    /// counted in its [`JoinReport`].
+    fn join_with(
+        &self,
+        namespace_id: &Params::NamespaceId,
+    ) -> impl Iterator<Item = io::Result<(StoreAuthorisedEntry<Self>, Option<File>)>> + '_
+    {
+        let namespace_id = namespace_id.clone();
+        self.locations().filter_map(move |location| {
+            let newest = location.and_then(|location| {
+                Self::newest_unpruned(&location.dir, location.path.len())?
+                    .map(|record| {
+                        let payload = self.payload_of(&record)?;
+                        let Location { subspace_id, path, .. } = location;
+                        let auth_entry =
+                            Self::to_auth_entry(&namespace_id, subspace_id, path, record)?;
+                        Ok((auth_entry, payload))
+                    })
+                    .transpose()
+            });
+            newest.transpose()
+        })
+    }
+
+    fn checkpoint_with(&self) -> io::Result<Vec<(PathBuf, Vec<u8>)>>
+    {
+        let entries_dir = self.layout.entries_dir();
+        self.locations()
+            .map(|location| {
+                let file = layout::history_file(&location?.dir);
+                let relative =
+                    file.strip_prefix(&entries_dir).map_err(|_e| invalid_data("path"))?;
+                Ok((relative.to_owned(), fs::read(&file)?))
+            })
+            .collect()
+    }
+
+    /// Write the histories to a separate directory, and only once that's complete, swap it with
+    /// `entries/`, so that a failure or crash doesn't lose the current histories.
+    fn restore_with(
+        &mut self,
+        histories: io::Result<Vec<(PathBuf, Vec<u8>)>>,
+    ) -> io::Result<()>
+    {
+        let histories = histories?;
+        let restoring_dir = self.layout.restoring_entries_dir();
+        remove_dir_all_if_exists(&restoring_dir)?;
+        fs::create_dir_all(&restoring_dir)?;
+        for (relative, contents) in histories {
+            let file = restoring_dir.join(relative);
+            if let Some(dir) = file.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(file, contents)?;
+        }
+        let replaced_dir = self.layout.replaced_entries_dir();
+        remove_dir_all_if_exists(&replaced_dir)?;
+        fs::rename(self.layout.entries_dir(), &replaced_dir)?;
+        // If a crash happens here, `Self::finish_restore` completes it when reopened.
+        if let Err(error) = fs::rename(&restoring_dir, self.layout.entries_dir()) {
+            let _ignored = fs::rename(&replaced_dir, self.layout.entries_dir()); // Undo.
+            return Err(error);
+        }
+        let _: u64 = self.bump_change_count()?;
+        // If this fails, `Self::finish_restore` does it when reopened.
+        let _ignored = remove_dir_all_if_exists(&replaced_dir);
+        Ok(())
+    }
+
+    /// Complete or undo a `restore` that was interrupted by a crash.  If `entries/` was already
+    /// renamed away, the restored one was complete and is renamed into place, else the restored
+    /// one might be incomplete and is deleted.
+    fn finish_restore(layout: &Layout) -> io::Result<()>
+    {
+        let restoring_dir = layout.restoring_entries_dir();
+        if !layout.entries_dir().try_exists()? && restoring_dir.try_exists()? {
+            fs::rename(&restoring_dir, layout.entries_dir())?;
+        }
+        remove_dir_all_if_exists(&restoring_dir)?;
+        remove_dir_all_if_exists(&layout.replaced_entries_dir())
+    }
+}
+
+impl<Params> StoreExt for FsStore<Params>
+where
+    Params: sailce_data_model::Params + ?Sized,
+    Params::NamespaceId: Serialize,
+    Params::SubspaceId: Serialize + DeserializeOwned,
+    Params::PayloadDigest: Clone + Serialize + DeserializeOwned,
+    Params::AuthorisationToken: Serialize + DeserializeOwned,
+{
+    type GetError = GetError;
+    type GetPayload = File;
+    type IterAuthToken = Params::AuthorisationToken;
+    type IterPath = StoredPath;
+    type JoinError = ParamsPutError<Params, File>;
+    type Params = Params;
+    type PutError<P: Payload + ?Sized> = ParamsPutError<Params, P>;
+
+    const CHANGE_TRACKING: bool = true;
+
+    #[inline]
+    async fn get(
+        &self,
+        namespace_id: &Params::NamespaceId,
+        subspace_id: &Params::SubspaceId,
+        path: &(impl Path + ?Sized),
+    ) -> Result<Option<Self::GetPayload>, Self::GetError>
+    {
+        self.debug_assert_namespace(namespace_id);
+
+        let location_dir = self.layout.location_dir(subspace_id, path)?;
+        match Self::newest_unpruned(&location_dir, path.components().len())? {
+            Some(newest) => match self.payload_of(&newest)? {
+                Some(payload) => Ok(Some(payload)),
+                None => Err(GetError::FoundEntryMissingPayload),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Reads only the history at the location, not the payload.
+    #[inline]
+    async fn get_entry(
+        &self,
+        namespace_id: &Params::NamespaceId,
+        subspace_id: &Params::SubspaceId,
+        path: &(impl Path + ?Sized),
+    ) -> Result<Option<ParamsEntry<Params, StoredPath>>, Self::GetError>
+    {
+        self.debug_assert_namespace(namespace_id);
+
+        let location_dir = self.layout.location_dir(subspace_id, path)?;
+        match Self::newest_unpruned(&location_dir, path.components().len())? {
+            Some(newest) => {
+                let path = StoredPath::from_path(path);
+                let auth_entry =
+                    Self::to_auth_entry(namespace_id, subspace_id.clone(), path, newest)?;
+                Ok(Some(auth_entry.into_parts().0))
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Reads only the history at the location, without converting its newest to an `Entry`.
+    #[inline]
+    async fn contains(
+        &self,
+        namespace_id: &Params::NamespaceId,
+        subspace_id: &Params::SubspaceId,
+        path: &(impl Path + ?Sized),
+    ) -> Result<bool, Self::GetError>
+    {
+        self.debug_assert_namespace(namespace_id);
+
+        let location_dir = self.layout.location_dir(subspace_id, path)?;
+        Ok(Self::newest_unpruned(&location_dir, path.components().len())?.is_some())
+    }
+
+    #[inline]
+    async fn put<P: Payload>(
+        &mut self,
+        namespace_id: &Params::NamespaceId,
+        auth_entry: AuthorisedEntry<Params, impl Path, impl Borrow<Params::AuthorisationToken>>,
+        payload: Option<P>,
+    ) -> Result<(), Self::PutError<P>>
+    {
+        self.put_with(namespace_id, auth_entry, payload, true).await.map(drop)
+    }
+
+    #[inline]
+    async fn put_returning<P: Payload>(
+        &mut self,
+        namespace_id: &Params::NamespaceId,
+        auth_entry: AuthorisedEntry<Params, impl Path, impl Borrow<Params::AuthorisationToken>>,
+        payload: Option<P>,
+    ) -> Result<Option<StoreAuthorisedEntry<Self>>, Self::PutError<P>>
+    {
+        self.put_with(namespace_id, auth_entry, payload, true).await.map(Some)
+    }
+
+    #[inline]
+    async fn put_trusted<P: Payload>(
+        &mut self,
+        namespace_id: &Params::NamespaceId,
+        auth_entry: AuthorisedEntry<Params, impl Path, impl Borrow<Params::AuthorisationToken>>,
+        payload: P,
+    ) -> Result<(), Self::PutError<P>>
+    {
+        self.put_with(namespace_id, auth_entry, Some(payload), false).await.map(drop)
+    }
+
+    #[inline]
+    async fn join(
+        &mut self,
+        namespace_id: &Params::NamespaceId,
+        other: &Self,
+        other_namespace_id: &Params::NamespaceId,
+    ) -> Result<JoinReport, Self::JoinError>
+    {
+        self.debug_assert_namespace(namespace_id);
+        other.debug_assert_namespace(other_namespace_id);
+        // The `Store` method ensures that they're the same.
+
+        let mut report = JoinReport::default();
+        for version in other.join_with(other_namespace_id) {
+            let (auth_entry, payload) = version?;
+            // `other`'s payloads were already verified when they were put into it.
+            let (_stored, outcome) =
+                self.put_with_outcome(namespace_id, auth_entry, payload, false).await?;
+            outcome.count_in(&mut report);
+        }
+        Ok(report)
+    }
+
+    #[inline]
+    async fn newest_includes_within_total_size<P: Path>(
+        &self,
+        namespace_id: &Params::NamespaceId,
+        max_count: Option<u64>,
+        entry: impl Borrow<ParamsEntry<Params, P>>,
+        max_size: Option<u64>,
+    ) -> bool
+    {
+        let entry = entry.borrow();
+        let mut newest = self
+            .iter(namespace_id)
+            .map(|auth_entry| auth_entry.into_parts().0)
+            .collect::<Vec<_>>();
+        newest.sort_by(|a, b| b.cmp_newer_than(a));
+        let max_count = max_count.map_or(usize::MAX, |max| max.try_into().unwrap_or(usize::MAX));
+
+        let mut total_size = Some(entry.payload_length);
+        for e in newest.iter().take(max_count) {
+            if e == entry {
+                return max_size.map_or(true, |max| total_size.is_some_and(|total| total <= max));
+            }
+            total_size = total_size.and_then(|total| total.checked_add(e.payload_length));
+        }
+        false
+    }
+
+    #[inline]
+    fn iter(
+        &self,
+        namespace_id: &Params::NamespaceId,
+    ) -> impl Iterator<Item = StoreAuthorisedEntry<Self>>
+    {
+        self.iter_newest(namespace_id).filter_map(|result| {
+            result.map(|(auth_entry, _)| auth_entry).map_err(|error| self.skip_error(error)).ok()
+        })
+    }
+
+    #[inline]
+    fn try_iter(
+        &self,
+        namespace_id: &Params::NamespaceId,
+    ) -> impl Iterator<Item = Result<StoreAuthorisedEntry<Self>, Self::GetError>>
+    {
+        self.iter_newest(namespace_id)
+            .map(|result| result.map(|(auth_entry, _)| auth_entry).map_err(GetError::Io))
+    }
+
+    /// Each Subspace is a directory, which is walked only until one of its current `Entry`s is
+    /// found.
+    #[inline]
+    fn subspaces(
+        &self,
+        namespace_id: &Params::NamespaceId,
+    ) -> impl Iterator<Item = Params::SubspaceId>
+    {
+        self.debug_assert_namespace(namespace_id);
+
+        let Locations { pending: subspaces, errors } = self.locations();
+        for error in errors {
+            self.skip_error(error);
+        }
+        subspaces.into_iter().filter_map(|subspace| {
+            let subspace_id = subspace.subspace_id.clone();
+            let has_current = Locations::within(subspace).any(|location| {
+                let newest = location.and_then(|location| {
+                    Self::newest_unpruned(&location.dir, location.path.len())
+                });
+                newest.map_err(|error| self.skip_error(error))
+                    .is_ok_and(|newest| newest.is_some())
+            });
+            has_current.then_some(subspace_id)
+        })
+    }
+
+    #[inline]
+    async fn iter_history(
+        &self,
+        namespace_id: &Params::NamespaceId,
+        subspace_id: &Params::SubspaceId,
+        path: &(impl Path + ?Sized),
+    ) -> impl Iterator<Item = (StoreAuthorisedEntry<Self>, Option<Self::GetPayload>)>
+    {
+        self.debug_assert_namespace(namespace_id);
+
+        self.iter_history_with(namespace_id, subspace_id, path)
+            .unwrap_or_else(|error| {
+                self.skip_error(error);
+                Vec::new()
+            })
+            .into_iter()
+    }
+
+    #[inline]
+    fn change_token(
+        &self,
+        namespace_id: &Params::NamespaceId,
+    ) -> ChangeToken
+    {
+        self.debug_assert_namespace(namespace_id);
+        ChangeToken::new(self.change_count)
+    }
+
+    #[inline]
+    fn entries_since(
+        &self,
+        namespace_id: &Params::NamespaceId,
+        token: ChangeToken,
+    ) -> (impl Iterator<Item = StoreAuthorisedEntry<Self>>, ChangeToken)
+    {
+        let changed = self.iter_newest(namespace_id).filter_map(move |result| match result {
+            Ok((auth_entry, change_seq)) => (change_seq > token.get()).then_some(auth_entry),
+            Err(error) => {
+                self.skip_error(error);
+                None
+            },
+        });
+        (changed, self.change_token(namespace_id))
+    }
+}
+
+impl<Params> DeleteExt for FsStore<Params>
+where
+    Params: sailce_data_model::Params + ?Sized,
+    Params::NamespaceId: Serialize,
+    Params::SubspaceId: Serialize + DeserializeOwned,
+    Params::PayloadDigest: Clone + Serialize + DeserializeOwned,
+    Params::AuthorisationToken: Serialize + DeserializeOwned,
+{
+    type DeleteError = io::Error;
+
+    #[inline]
+    async fn delete(
+        &mut self,
+        namespace_id: &Params::NamespaceId,
+        subspace_id: &Params::SubspaceId,
+        path: &(impl Path + ?Sized),
+    ) -> Result<bool, Self::DeleteError>
+    {
+        self.debug_assert_namespace(namespace_id);
+
+        let location_dir = self.layout.location_dir(subspace_id, path)?;
+        let component_count = path.components().len();
+        let Some(newest) = Self::newest_unpruned(&location_dir, component_count)?
+        else {
+            return Ok(false);
+        };
+        let _: u64 = self.bump_change_count()?;
+        // Those pruned by it stay pruned, and the older versions go with it.
+        let _pruned_any = Self::prune_under(&location_dir, &newest)?;
+        record::write::<Params::PayloadDigest>(&location_dir, &History::new())?;
+        for dir in location_dir.ancestors().take(component_count.saturating_add(1)) {
+            if !remove_dir_if_empty(dir)? {
+                break;
+            }
+        }
+        Ok(true)
+    }
+}
+
+impl<Params> CheckpointExt for FsStore<Params>
+where
+    Params: sailce_data_model::Params + ?Sized,
+    Params::NamespaceId: Serialize,
+    Params::SubspaceId: Serialize + DeserializeOwned,
+    Params::PayloadDigest: Clone + Serialize + DeserializeOwned,
+    Params::AuthorisationToken: Serialize + DeserializeOwned,
+{
+    type RestoreError = io::Error;
+    type Snapshot = Snapshot;
+
+    #[inline]
+    fn checkpoint(
+        &self,
+        namespace_id: &Params::NamespaceId,
+    ) -> Self::Snapshot
+    {
+        self.debug_assert_namespace(namespace_id);
+        Snapshot { histories: self.checkpoint_with() }
+    }
+
+    /// If this fails before the restored histories are swapped in, `self` is left unchanged.  If
+    /// a crash interrupts it, it's completed or undone when reopened.
+    #[inline]
+    fn restore(
+        &mut self,
+        namespace_id: &Params::NamespaceId,
+        snapshot: Self::Snapshot,
+    ) -> Result<(), Self::RestoreError>
+    {
+        self.debug_assert_namespace(namespace_id);
+        self.restore_with(snapshot.histories)
+    }
+}
+
+/// Manually implemented, to avoid requiring `Params: Debug`.
+impl<Params> Debug for FsStore<Params>
+where Params: sailce_data_model::Params + ?Sized
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        f.debug_struct("FsStore")
+            .field("namespace_dir", &self.layout.namespace_dir())
+            .field("change_count", &self.change_count)
+            .finish_non_exhaustive()
+    }
+}
+
+
+/// The [`CheckpointExt::Snapshot`] of [`FsStore`]: the contents of all its history files.
+///
+/// The `Payload` files aren't included, because they're not deleted until
+/// [`FsStore::collect_garbage`].  If reading the files failed, the error is returned by
+/// restoring, which then does nothing.
+#[derive(Debug)]
+pub struct Snapshot
+{
+    histories: io::Result<Vec<(PathBuf, Vec<u8>)>>,
+}
+
+
+/// What putting a record did to the current entries.
+enum PutOutcome
+{
+    /// It already was the current one at its location.
+    Existing,
+    /// It isn't current, because there's a newer one at a prefix of (or at) its location.
+    Superseded,
+    /// It became the current one at its location.
+    Current
+    {
+        /// Whether that removed any others, by overwriting or prefix-pruning them.
+        removed_any: bool,
+    },
+}
+
+impl PutOutcome
+{
+    fn count_in(
+        self,
+        report: &mut JoinReport,
+    )
+    {
+        match self {
+            Self::Existing => {},
+            Self::Superseded => report.superseded = report.superseded.saturating_add(1),
+            Self::Current { removed_any } => {
+                report.inserted = report.inserted.saturating_add(1);
+                if removed_any {
+                    report.pruned = report.pruned.saturating_add(1);
+                }
+            },
+        }
+    }
+}
+
+
+/// A location that has a history file.
+struct Location<S>
+{
+    subspace_id: S,
+    path:        StoredPath,
+    dir:         PathBuf,
+}
+
+/// Walks the directories of the locations, lazily, to give those that have history files.
+///
+/// The `pending` of a new one are the directories of the Subspaces.
+struct Locations<S>
+{
+    pending: Vec<Location<S>>,
+    errors:  Vec<io::Error>,
+}
+
+impl<S: DeserializeOwned + Clone> Locations<S>
+{
+    fn new(entries_dir: &std::path::Path) -> Self
+    {
+        let mut this = Self { pending: Vec::new(), errors: Vec::new() };
+        match fs::read_dir(entries_dir) {
+            Ok(subspaces) =>
+                for subspace in subspaces {
+                    match subspace {
+                        Ok(subspace) => {
+                            let name = subspace.file_name();
+                            if let Some(subspace_id) = name.to_str().and_then(layout::name_id) {
+                                this.pending.push(Location {
+                                    subspace_id,
+                                    path: StoredPath::new(),
+                                    dir: subspace.path(),
+                                });
+                            }
+                        },
+                        Err(error) => this.errors.push(error),
+                    }
+                },
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {},
+            Err(error) => this.errors.push(error),
+        }
+        this
+    }
+
+    /// Walk only `location` and those under it.
+    fn within(location: Location<S>) -> Self
+    {
+        Self { pending: vec![location], errors: Vec::new() }
+    }
+
+    /// Add the sub-directories of `location` to be walked, and return whether it has a history
+    /// file.
+    fn visit(
+        &mut self,
+        location: &Location<S>,
+    ) -> io::Result<bool>
+    {
+        let mut has_history = false;
+        for child in fs::read_dir(&location.dir)? {
+            let child = match child {
+                Ok(child) => child,
+                Err(error) => {
+                    self.errors.push(error);
+                    continue;
+                },
+            };
+            let name = child.file_name();
+            let Some(name) = name.to_str()
+            else {
+                continue; // Not one of ours.
+            };
+            if name == layout::HISTORY_FILE {
+                has_history = true;
+            }
+            else if let Some(component) = layout::name_component(name) {
+                match child.file_type() {
+                    Ok(file_type) if file_type.is_dir() => self.pending.push(Location {
+                        subspace_id: location.subspace_id.clone(),
+                        path:        location
+                            .path
+                            .iter()
+                            .cloned()
+                            .chain(iter::once(component.into_boxed_slice()))
+                            .collect(),
+                        dir:         child.path(),
+                    }),
+                    Ok(_) => {},
+                    Err(error) => self.errors.push(error),
+                }
+            }
+            else {
+                // Not one of ours, e.g. a temporary file.
+            }
+        }
+        Ok(has_history)
+    }
+}
+
+impl<S: DeserializeOwned + Clone> Iterator for Locations<S>
+{
+    type Item = io::Result<Location<S>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        loop {
+            if let Some(error) = self.errors.pop() {
+                return Some(Err(error));
+            }
+            let location = self.pending.pop()?;
+            match self.visit(&location) {
+                Ok(true) => return Some(Ok(location)),
+                Ok(false) => {},
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+}
+
+
+/// Remove `dir` if it's empty, and return whether it was.
+fn remove_dir_if_empty(dir: &std::path::Path) -> io::Result<bool>
+{
+    if fs::read_dir(dir)?.next().is_none() {
+        fs::remove_dir(dir)?;
+        Ok(true)
+    }
+    else {
+        Ok(false)
+    }
+}
+
+fn remove_dir_all_if_exists(dir: &std::path::Path) -> io::Result<()>
+{
+    match fs::remove_dir_all(dir) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+        _ => Ok(()),
+    }
+}
+
+fn invalid_data(msg: &'static str) -> io::Error
+{
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
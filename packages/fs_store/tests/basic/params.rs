@@ -0,0 +1,148 @@
+use {
+    crate::nz_usize,
+    sailce_data_model::{
+        payload::{
+            SeekFrom,
+            SeekOutOfBoundsError,
+        },
+        ParamsEntry,
+        Payload,
+    },
+    serde::{
+        Deserialize,
+        Serialize,
+    },
+    std::{
+        collections::hash_map::DefaultHasher,
+        convert::Infallible,
+        hash::Hasher as _,
+        num::NonZeroUsize,
+    },
+};
+
+
+pub(crate) type NamespaceName = String;
+pub(crate) type UserName = String;
+
+
+/// Only adequate for this test module.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Permission
+{
+    pub(crate) user:      UserName,
+    pub(crate) namespace: NamespaceName,
+}
+
+
+/// Represents our choice of the Willow parameters for this test module.  The types must be
+/// (de)serializable, for `FsStore`.
+pub(crate) struct Params;
+
+impl sailce_data_model::Params for Params
+{
+    type AuthorisationToken = Permission;
+    type HashPayloadError<P>
+        = HashPayloadError<P::SeekError, P::ReadError>
+    where P: Payload + ?Sized;
+    type NamespaceId = NamespaceName;
+    type PayloadDigest = u64;
+    type SubspaceId = UserName;
+
+    const MAX_COMPONENT_COUNT: NonZeroUsize = nz_usize(16);
+    const MAX_COMPONENT_LENGTH: NonZeroUsize = nz_usize(64);
+    const MAX_PATH_LENGTH: NonZeroUsize = nz_usize(1024);
+
+    /// Just for this testing module, use `std`'s hashing ability.  (This doesn't follow the
+    /// recommendation to use a secure hash function.)
+    async fn hash_payload<P>(
+        payload: &mut P
+    ) -> Result<Self::PayloadDigest, Self::HashPayloadError<P>>
+    where P: Payload + ?Sized
+    {
+        let _: u64 = payload.seek(SeekFrom::Start(0)).await.map_err(HashPayloadError::Seek)?;
+        let mut hasher = DefaultHasher::default();
+        let mut buf = [0; 3]; // Small, to exercise many reads.
+        loop {
+            let read = payload.read(&mut buf).await.map_err(HashPayloadError::Read)?;
+            if read == 0 {
+                break Ok(hasher.finish());
+            }
+            hasher.write(buf.get(.. read).unwrap_or_default());
+        }
+    }
+
+    fn is_authorised_write(
+        entry: &ParamsEntry<Self, impl sailce_data_model::Path>,
+        auth_token: &Self::AuthorisationToken,
+    ) -> bool
+    {
+        auth_token.namespace == entry.namespace_id && auth_token.user == entry.subspace_id
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) enum HashPayloadError<SeekError, ReadError>
+{
+    Seek(SeekError),
+    Read(ReadError),
+}
+
+
+/// An in-memory `Payload`, to give to `put`.
+#[derive(Clone, Debug)]
+pub(crate) struct Bytes
+{
+    bytes: Vec<u8>,
+    pos:   usize,
+}
+
+impl Bytes
+{
+    pub(crate) fn new(bytes: impl AsRef<[u8]>) -> Self
+    {
+        Self { bytes: bytes.as_ref().to_vec(), pos: 0 }
+    }
+}
+
+impl Payload for Bytes
+{
+    type ReadError = Infallible;
+    type SeekError = SeekOutOfBoundsError;
+
+    async fn read(
+        &mut self,
+        buf: &mut [u8],
+    ) -> Result<usize, Self::ReadError>
+    {
+        let avail = self.bytes.get(self.pos ..).unwrap_or_default();
+        let amount = avail.len().min(buf.len());
+        if let (Some(dest), Some(src)) = (buf.get_mut(.. amount), avail.get(.. amount)) {
+            dest.copy_from_slice(src);
+        }
+        self.pos = self.pos.saturating_add(amount);
+        Ok(amount)
+    }
+
+    async fn seek(
+        &mut self,
+        pos: SeekFrom,
+    ) -> Result<u64, Self::SeekError>
+    {
+        let len = self.len().await;
+        let new_pos = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => len.checked_sub(offset),
+            SeekFrom::Current(offset) =>
+                u64::try_from(self.pos).unwrap().checked_add_signed(offset),
+        }
+        .filter(|&new_pos| new_pos <= len)
+        .ok_or(SeekOutOfBoundsError { pos })?;
+        self.pos = new_pos.try_into().unwrap();
+        Ok(new_pos)
+    }
+
+    async fn len(&self) -> u64
+    {
+        self.bytes.len().try_into().unwrap()
+    }
+}
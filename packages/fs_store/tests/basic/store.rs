@@ -0,0 +1,693 @@
+use {
+    crate::params::{
+        Bytes,
+        NamespaceName,
+        Params,
+        Permission,
+        UserName,
+    },
+    sailce_data_model::{
+        payload,
+        store::{
+            sync,
+            JoinError,
+            PutError,
+        },
+        syncify::Syncify,
+        AuthorisedEntry,
+        Entry,
+        Params as _,
+        Payload as _,
+        Store,
+        StoreAuthorisedEntry,
+        Timestamp,
+    },
+    sailce_fs_store::FsStore,
+    std::{
+        fs,
+        future::Future,
+        path::PathBuf,
+    },
+};
+
+
+/// Represents using the [`pollster`] executor to block the thread waiting on a `Future`.
+struct Pollster;
+
+type TestStore = Store<NamespaceName, FsStore<Params>>;
+
+impl Syncify<Pollster> for TestStore
+{
+    type ExecutorData = ();
+
+    #[inline]
+    fn get_block_on_fn<'f, F>(&self) -> impl 'f + FnOnce(F, Self::ExecutorData) -> F::Output
+    where F: Future + 'f
+    {
+        fn adapt<F: Future>(
+            fut: F,
+            (): (),
+        ) -> F::Output
+        {
+            pollster::block_on(fut)
+        }
+
+        adapt
+    }
+
+    #[inline]
+    fn get_executor_data(&self) -> Self::ExecutorData {}
+}
+
+impl sync::Store<Pollster, Params, FsStore<Params>> for TestStore {}
+
+
+/// A fresh directory for a test, that is removed when dropped.
+struct TempDir(PathBuf);
+
+impl TempDir
+{
+    fn new(test_name: &str) -> Self
+    {
+        let dir = std::env::temp_dir()
+            .join(format!("sailce_fs_store-test-{test_name}-{}", std::process::id()));
+        let _ignored = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        Self(dir)
+    }
+}
+
+impl Drop for TempDir
+{
+    fn drop(&mut self)
+    {
+        let _ignored = fs::remove_dir_all(&self.0);
+    }
+}
+
+
+fn open(
+    dir: &TempDir,
+    namespace_id: &NamespaceName,
+) -> TestStore
+{
+    Store::new(namespace_id, FsStore::open(&dir.0, namespace_id).unwrap())
+}
+
+fn payload_and_digest(bytes: impl AsRef<[u8]>) -> (Bytes, u64)
+{
+    let mut payload = Bytes::new(bytes);
+    let digest = pollster::block_on(Params::hash_payload(&mut payload)).expect("should work");
+    (payload, digest)
+}
+
+fn path(components: &[&str]) -> Vec<Box<[u8]>>
+{
+    components.iter().map(|c| c.as_bytes().into()).collect()
+}
+
+fn auth_entry(
+    namespace_id: &NamespaceName,
+    user: &UserName,
+    path: &[&str],
+    timestamp: Timestamp,
+    payload: &[u8],
+) -> StoreAuthorisedEntry<FsStore<Params>>
+{
+    let (_, payload_digest) = payload_and_digest(payload);
+    AuthorisedEntry::new(
+        Entry {
+            namespace_id: namespace_id.clone(),
+            subspace_id: user.clone(),
+            path: self::path(path),
+            timestamp,
+            payload_digest,
+            payload_length: payload.len().try_into().unwrap(),
+        },
+        Permission { user: user.clone(), namespace: namespace_id.clone() },
+    )
+    .expect("auth should succeed")
+}
+
+/// The subspace, path, digest, and length of each `Entry`.
+type EntryParts = (UserName, Vec<Box<[u8]>>, u64, u64);
+
+fn all_entries(store: &TestStore) -> Vec<EntryParts>
+{
+    let mut entries = store
+        .iter()
+        .map(|auth_entry| {
+            let entry = auth_entry.into_parts().0;
+            (entry.subspace_id, entry.path, entry.payload_digest, entry.payload_length)
+        })
+        .collect::<Vec<_>>();
+    entries.sort();
+    entries
+}
+
+fn read_all(mut payload: payload::File) -> Vec<u8>
+{
+    let mut buf = [0; 64];
+    let len = pollster::block_on(payload.read(&mut buf)).unwrap();
+    buf.get(.. len).unwrap().to_vec()
+}
+
+
+#[test]
+#[allow(clippy::too_many_lines)]
+fn most_methods__sync_uses_async()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let dir = TempDir::new("most_methods");
+    let ns1 = "namespace-1".to_owned();
+    let user1 = "uno".to_owned();
+    let mut store = open(&dir, &ns1);
+    assert_eq!(store.namespace_id(), &ns1);
+
+    let (empty_payload, empty_payload_digest) = payload_and_digest([]);
+    let (another_payload, another_payload_digest) = payload_and_digest("foo bar");
+    let timestamp = Timestamp::now();
+    let ae1 = auth_entry(&ns1, &user1, &["some", "where"], timestamp, b"");
+    let ae2 = auth_entry(
+        &ns1,
+        &user1,
+        &["some", "where"],
+        (timestamp.μs_since_epoch + 1).into(),
+        b"foo bar",
+    );
+    let ae3 = auth_entry(&ns1, &user1, &["some"], (timestamp.μs_since_epoch + 2).into(), b"");
+
+    // Attempt to get non-existent.
+    assert!(matches!(store.get(&"nobody".to_owned(), &["nope"]), Ok(None)));
+    // Put without payload yet.
+    store.put(ae1.clone(), None::<Bytes>).unwrap();
+    // Attempt to get what was put without payload.
+    assert!(matches!(
+        store.get(&user1, &["some", "where"]),
+        Err(sailce_fs_store::GetError::FoundEntryMissingPayload)
+    ));
+    // Supply payload for what was already put.
+    store.put(ae1.clone(), Some(empty_payload.clone())).unwrap();
+    // Now its payload can be gotten.
+    {
+        let got = store.get(&user1, &["some", "where"]).unwrap().unwrap();
+        assert_eq!(read_all(got), b"");
+    }
+    // Weird redundant put of the same entry but without payload.
+    store.put(ae1.clone(), None::<Bytes>).unwrap();
+    // Redundant put of the same entry with same payload.
+    store.put(ae1.clone(), Some(empty_payload.clone())).unwrap();
+    // Its payload stays the same.
+    {
+        let got = store.get(&user1, &["some", "where"]).unwrap().unwrap();
+        assert_eq!(read_all(got), b"");
+    }
+    // Weird redundant put of the same entry but with different payload with mismatched digest.
+    assert!(matches!(
+        store.put(ae1.clone(), Some(another_payload.clone())),
+        Err(PutError::Put(sailce_fs_store::PutError::WrongDigest { given, computed }))
+            if given == empty_payload_digest && computed == another_payload_digest
+    ));
+    // Replace at same path.
+    store.put(ae2.clone(), Some(another_payload.clone())).unwrap();
+    // Different new contents.
+    {
+        let got = store.get(&user1, &["some", "where"]).unwrap().unwrap();
+        assert_eq!(pollster::block_on(got.len()), 7);
+        assert_eq!(read_all(got), b"foo bar");
+    }
+    // The older version is kept in the history.
+    assert_eq!(store.iter_history(&user1, &["some", "where"]).count(), 2);
+    // Non-existent at parent path.
+    assert!(matches!(store.get(&user1, &ae3.entry().path), Ok(None)));
+    // Prefix pruning at parent path.
+    store.put(ae3.clone(), Some(empty_payload.clone())).unwrap();
+    // Now parent-path payload can be gotten.
+    {
+        let got = store.get(&user1, &["some"]).unwrap().unwrap();
+        assert_eq!(read_all(got), b"");
+    }
+    // Now the deeper path is non-existent, because prefix pruning deleted it.
+    assert!(matches!(store.get(&user1, &["some", "where"]), Ok(None)));
+    assert_eq!(store.iter_history(&user1, &["some", "where"]).count(), 0);
+
+    // Which entries should be present now.
+    assert_eq!(all_entries(&store), [(user1.clone(), path(&["some"]), empty_payload_digest, 0)]);
+
+    // A different `Namespace` in the same directory.
+    let ns2 = "namespace-2".to_owned();
+    let mut store2 = open(&dir, &ns2);
+    assert_eq!(all_entries(&store2), []);
+    // Another store of the same `Namespace`, to join, in a different directory.
+    let dir3 = TempDir::new("most_methods-3");
+    let mut store3 = open(&dir3, &ns1);
+    // timestamp is less-than ae3's that prefix-pruned.
+    let ae4 = auth_entry(
+        &ns1,
+        &user1,
+        &["some", "where", "under", "a", "cloud"],
+        ae2.entry().timestamp,
+        b"foo bar",
+    );
+    // timestamp is greater-than ae3's that prefix-pruned.
+    let ae5 = auth_entry(
+        &ns1,
+        &user1,
+        &["some", "where", "over", "a", "rainbow"],
+        (ae3.entry().timestamp.μs_since_epoch + 1).into(),
+        b"foo bar",
+    );
+    store3.put(ae4.clone(), Some(another_payload.clone())).unwrap();
+    store3.put(ae5.clone(), Some(another_payload.clone())).unwrap();
+    assert_eq!(all_entries(&store3), [
+        (
+            user1.clone(),
+            path(&["some", "where", "over", "a", "rainbow"]),
+            another_payload_digest,
+            7
+        ),
+        (
+            user1.clone(),
+            path(&["some", "where", "under", "a", "cloud"]),
+            another_payload_digest,
+            7
+        ),
+    ]);
+
+    // Joining a different Namespace is rejected.
+    assert!(matches!(store.join(&store2), Err(JoinError::DifferentNamespace)));
+    assert!(matches!(store2.put(ae1.clone(), None::<Bytes>), Err(PutError::DifferentNamespace)));
+    // Join the two `Store`s into the first.
+    store.join(&store3).unwrap();
+    // Prefix pruning affects joined entry also, so that it's deleted.
+    assert!(matches!(store.get(&user1, &["some", "where", "under", "a", "cloud"]), Ok(None)));
+    // Joined entry which is newer than the prefix-pruned is present, with its payload.
+    {
+        let got = store.get(&user1, &["some", "where", "over", "a", "rainbow"]).unwrap().unwrap();
+        assert_eq!(read_all(got), b"foo bar");
+    }
+    assert_eq!(all_entries(&store), [
+        (user1.clone(), path(&["some"]), empty_payload_digest, 0),
+        (
+            user1.clone(),
+            path(&["some", "where", "over", "a", "rainbow"]),
+            another_payload_digest,
+            7
+        ),
+    ]);
+    // `store3` is unchanged.
+    assert_eq!(all_entries(&store3).len(), 2);
+    assert!(store.try_iter().all(|result| result.is_ok()));
+}
+
+
+#[test]
+fn reopen()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let dir = TempDir::new("reopen");
+    let ns1 = "namespace-1".to_owned();
+    let user1 = "uno".to_owned();
+    let (payload, digest) = payload_and_digest("persisted");
+    let token = {
+        let mut store = open(&dir, &ns1);
+        let ae1 = auth_entry(&ns1, &user1, &["a", ""], Timestamp::now(), b"persisted");
+        store.put(ae1, Some(payload)).unwrap();
+        store.change_token()
+    };
+    let store = open(&dir, &ns1);
+    assert_eq!(store.change_token(), token);
+    assert_eq!(all_entries(&store), [(user1.clone(), path(&["a", ""]), digest, 9)]);
+    let got = store.get(&user1, &["a", ""]).unwrap().unwrap();
+    assert_eq!(read_all(got), b"persisted");
+}
+
+
+#[test]
+fn delete_and_entries_since()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let dir = TempDir::new("delete");
+    let ns1 = "namespace-1".to_owned();
+    let user1 = "uno".to_owned();
+    let mut store = open(&dir, &ns1);
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| Timestamp::from(timestamp.μs_since_epoch + μs_after);
+
+    assert!(store.tracks_changes());
+    store.put(auth_entry(&ns1, &user1, &["x", "y"], at(0), b""), None::<Bytes>).unwrap();
+    let token = store.change_token();
+    store.put(auth_entry(&ns1, &user1, &["x"], at(1), b""), None::<Bytes>).unwrap();
+    store.put(auth_entry(&ns1, &user1, &["z"], at(2), b""), None::<Bytes>).unwrap();
+    let (since, _) = store.entries_since(token);
+    let mut since = since.map(|auth_entry| auth_entry.into_parts().0.path).collect::<Vec<_>>();
+    since.sort();
+    assert_eq!(since, [path(&["x"]), path(&["z"])]);
+
+    // Deleting doesn't resurrect what it pruned.
+    assert!(store.delete(&user1, &["x"]).unwrap());
+    assert!(!store.delete(&user1, &["x"]).unwrap());
+    assert!(!store.delete(&user1, &["x", "y"]).unwrap());
+    assert_eq!(all_entries(&store).len(), 1);
+
+    // A snapshot restores the histories.
+    let snapshot = store.checkpoint();
+    assert!(store.delete(&user1, &["z"]).unwrap());
+    assert!(store.is_empty());
+    store.restore(snapshot).unwrap();
+    assert_eq!(all_entries(&store).iter().map(|e| &e.1).collect::<Vec<_>>(), [&path(&["z"])]);
+}
+
+
+#[test]
+fn subspaces()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let dir = TempDir::new("subspaces");
+    let ns1 = "namespace-1".to_owned();
+    let [user1, user2, user3] = ["uno", "dos", "tres"].map(str::to_owned);
+    let mut store = open(&dir, &ns1);
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| Timestamp::from(timestamp.μs_since_epoch + μs_after);
+    let sorted_subspaces = |of: &TestStore| {
+        let mut subspaces = of.subspaces().collect::<Vec<_>>();
+        subspaces.sort();
+        subspaces
+    };
+
+    assert_eq!(store.subspaces().count(), 0);
+    store.put(auth_entry(&ns1, &user1, &["a", "b"], at(0), b""), None::<Bytes>).unwrap();
+    store.put(auth_entry(&ns1, &user1, &["c"], at(0), b""), None::<Bytes>).unwrap();
+    store.put(auth_entry(&ns1, &user2, &["a"], at(0), b""), None::<Bytes>).unwrap();
+    store.put(auth_entry(&ns1, &user3, &[], at(0), b""), None::<Bytes>).unwrap();
+    assert_eq!(sorted_subspaces(&store), [user2.clone(), user3.clone(), user1.clone()]);
+
+    // A Subspace whose `Entry`s were all removed isn't yielded.
+    assert!(store.delete(&user2, &["a"]).unwrap());
+    assert_eq!(sorted_subspaces(&store), [user3, user1]);
+}
+
+
+#[test]
+fn get_entry()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let dir = TempDir::new("get_entry");
+    let ns1 = "namespace-1".to_owned();
+    let user1 = "uno".to_owned();
+    let mut store = open(&dir, &ns1);
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| Timestamp::from(timestamp.μs_since_epoch + μs_after);
+
+    assert_eq!(store.get_entry(&user1, &["a", "b"]).unwrap(), None);
+    store.put(auth_entry(&ns1, &user1, &["a", "b"], at(0), b"later"), None::<Bytes>).unwrap();
+    let _missing_payload = store.get(&user1, &["a", "b"]).unwrap_err();
+    let (_, digest) = payload_and_digest("later");
+    let entry = store.get_entry(&user1, &["a", "b"]).unwrap().unwrap();
+    assert_eq!((entry.path, entry.timestamp), (path(&["a", "b"]), at(0)));
+    assert_eq!((entry.payload_digest, entry.payload_length), (digest, 5));
+
+    store.put(auth_entry(&ns1, &user1, &["a"], at(1), b""), None::<Bytes>).unwrap();
+    assert_eq!(store.get_entry(&user1, &["a", "b"]).unwrap(), None);
+    assert!(store.get_entry(&user1, &["a"]).unwrap().is_some());
+}
+
+
+#[test]
+fn contains()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let dir = TempDir::new("contains");
+    let ns1 = "namespace-1".to_owned();
+    let user1 = "uno".to_owned();
+    let mut store = open(&dir, &ns1);
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| Timestamp::from(timestamp.μs_since_epoch + μs_after);
+
+    assert!(!store.contains(&user1, &["a", "b"]).unwrap());
+    store.put(auth_entry(&ns1, &user1, &["a", "b"], at(0), b"later"), None::<Bytes>).unwrap();
+    let _missing_payload = store.get(&user1, &["a", "b"]).unwrap_err();
+    assert!(store.contains(&user1, &["a", "b"]).unwrap());
+    assert!(!store.contains(&user1, &["a"]).unwrap());
+
+    store.put(auth_entry(&ns1, &user1, &["a"], at(1), b""), None::<Bytes>).unwrap();
+    assert!(!store.contains(&user1, &["a", "b"]).unwrap());
+    assert!(store.contains(&user1, &["a"]).unwrap());
+}
+
+
+#[test]
+fn put_trusted_wrong_then_put_correct()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let dir = TempDir::new("put_trusted_wrong");
+    let ns1 = "namespace-1".to_owned();
+    let user1 = "uno".to_owned();
+    let mut store = open(&dir, &ns1);
+    let ae1 = auth_entry(&ns1, &user1, &["a"], Timestamp::now(), b"right");
+
+    // Trusted, though wrongly, so it's stored as if it were the correct payload.
+    store.put_trusted(ae1.clone(), Bytes::new("wrong")).unwrap();
+    {
+        let got = store.get(&user1, &["a"]).unwrap().unwrap();
+        assert_eq!(read_all(got), b"wrong");
+    }
+    // The verified correct payload replaces the wrong one.
+    store.put(ae1.clone(), Some(Bytes::new("right"))).unwrap();
+    {
+        let got = store.get(&user1, &["a"]).unwrap().unwrap();
+        assert_eq!(read_all(got), b"right");
+    }
+    // Another wrong trusted one doesn't replace the verified one.
+    store.put_trusted(ae1, Bytes::new("wrong")).unwrap();
+    {
+        let got = store.get(&user1, &["a"]).unwrap().unwrap();
+        assert_eq!(read_all(got), b"right");
+    }
+}
+
+
+#[test]
+fn reopen_after_interrupted_restore()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let dir = TempDir::new("interrupted_restore");
+    let ns1 = "namespace-1".to_owned();
+    let user1 = "uno".to_owned();
+    let namespace_dir = FsStore::<Params>::open(&dir.0, &ns1).unwrap().namespace_dir().to_owned();
+    let mut store = open(&dir, &ns1);
+    store.put(auth_entry(&ns1, &user1, &["a"], Timestamp::now(), b""), None::<Bytes>).unwrap();
+    drop(store);
+    // As if a crash happened after writing all of the restored, before it was renamed.
+    fs::create_dir_all(namespace_dir.join("entries-restoring")).unwrap();
+    assert_eq!(all_entries(&open(&dir, &ns1)).len(), 1);
+    assert!(!namespace_dir.join("entries-restoring").exists());
+    // As if a crash happened after the old was renamed away, before the restored was renamed.
+    fs::rename(namespace_dir.join("entries"), namespace_dir.join("entries-replaced")).unwrap();
+    fs::create_dir_all(namespace_dir.join("entries-restoring")).unwrap();
+    assert_eq!(all_entries(&open(&dir, &ns1)), []);
+    assert!(!namespace_dir.join("entries-replaced").exists());
+}
+
+
+#[test]
+fn collect_garbage()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let dir = TempDir::new("collect_garbage");
+    let ns1 = "namespace-1".to_owned();
+    let user1 = "uno".to_owned();
+    let mut store = open(&dir, &ns1);
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| Timestamp::from(timestamp.μs_since_epoch + μs_after);
+    let put = |to: &mut TestStore, path: &[&str], μs_after, payload: &str| {
+        let ae = auth_entry(&ns1, &user1, path, at(μs_after), payload.as_bytes());
+        to.put(ae, Some(Bytes::new(payload))).unwrap();
+    };
+    let mut fs_store = FsStore::<Params>::open(&dir.0, &ns1).unwrap();
+    let payloads_dir = fs_store.namespace_dir().join("payloads");
+    let payloads_count = || fs::read_dir(&payloads_dir).unwrap().count();
+
+    put(&mut store, &["a", "b"], 0, "one");
+    put(&mut store, &["c"], 1, "two");
+    put(&mut store, &["a"], 2, "");
+    // Pruned immediately, so its payload isn't written.
+    put(&mut store, &["a", "d"], 1, "three");
+    assert_eq!(payloads_count(), 3);
+    assert!(store.delete(&user1, &["c"]).unwrap());
+    // The payloads of the pruned and the deleted.
+    assert_eq!(fs_store.collect_garbage().unwrap(), 2);
+    assert_eq!(fs_store.collect_garbage().unwrap(), 0);
+    assert_eq!(payloads_count(), 1);
+    let got = store.get(&user1, &["a"]).unwrap().unwrap();
+    assert_eq!(read_all(got), b"");
+}
+
+
+#[test]
+fn put_streaming()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let dir = TempDir::new("put_streaming");
+    let ns1 = "namespace-1".to_owned();
+    let user1 = "uno".to_owned();
+    let mut store = open(&dir, &ns1);
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| Timestamp::from(timestamp.μs_since_epoch + μs_after);
+    let payloads_dir =
+        FsStore::<Params>::open(&dir.0, &ns1).unwrap().namespace_dir().join("payloads");
+
+    let ae1 = auth_entry(&ns1, &user1, &["a"], at(0), b"streamed");
+    store.put_streaming(ae1, Bytes::new("streamed")).unwrap();
+    {
+        let got = store.get(&user1, &["a"]).unwrap().unwrap();
+        assert_eq!(read_all(got), b"streamed");
+    }
+    // A wrong payload is detected, and nothing of it is stored, not even its temporary file.
+    let ae2 = auth_entry(&ns1, &user1, &["b"], at(1), b"expected");
+    assert!(matches!(
+        store.put_streaming(ae2, Bytes::new("unexpected")),
+        Err(PutError::Put(sailce_fs_store::PutError::WrongDigest { .. }))
+    ));
+    assert!(!store.contains(&user1, &["b"]).unwrap());
+    assert_eq!(fs::read_dir(payloads_dir).unwrap().count(), 1);
+}
+
+
+#[test]
+fn join_and_put_trusted()
+{
+    use sailce_data_model::store::{
+        sync::Store as _,
+        JoinReport,
+    };
+
+    let dir = TempDir::new("join");
+    let dir2 = TempDir::new("join-2");
+    let ns1 = "namespace-1".to_owned();
+    let user1 = "uno".to_owned();
+    let mut store = open(&dir, &ns1);
+    let mut store2 = open(&dir2, &ns1);
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| Timestamp::from(timestamp.μs_since_epoch + μs_after);
+
+    // `store` has the entry without its payload, which `store2` has.
+    let ae1 = auth_entry(&ns1, &user1, &["a"], at(0), b"one");
+    store.put(ae1.clone(), None::<Bytes>).unwrap();
+    store2.put_trusted(ae1, Bytes::new("one")).unwrap();
+    // `store2` has an entry without its payload, which `store` doesn't have at all.
+    store2.put(auth_entry(&ns1, &user1, &["b"], at(1), b"two"), None::<Bytes>).unwrap();
+    // `store` has a newer entry than `store2`'s at the same location.
+    store.put(auth_entry(&ns1, &user1, &["c"], at(3), b""), Some(Bytes::new(""))).unwrap();
+    store2.put(auth_entry(&ns1, &user1, &["c"], at(2), b""), Some(Bytes::new(""))).unwrap();
+
+    let token = store.change_token();
+    // Only `b` is new, and `store2`'s `c` is superseded by `store`'s.
+    let report = store.join_reporting(&store2).unwrap();
+    assert_eq!(report, JoinReport { inserted: 1, pruned: 0, superseded: 1 });
+    assert_ne!(store.change_token(), token);
+    {
+        let got = store.get(&user1, &["a"]).unwrap().unwrap();
+        assert_eq!(read_all(got), b"one");
+    }
+    assert!(matches!(
+        store.get(&user1, &["b"]),
+        Err(sailce_fs_store::GetError::FoundEntryMissingPayload)
+    ));
+    let newest = store.get_entry(&user1, &["c"]).unwrap().unwrap();
+    assert_eq!(newest.timestamp, at(3));
+    assert_eq!(store.iter_history(&user1, &["c"]).count(), 2);
+    assert_eq!(all_entries(&store).len(), 3);
+    // Joining again changes nothing.
+    let joined_token = store.change_token();
+    let rejoin_report = store.join_reporting(&store2).unwrap();
+    assert_eq!(rejoin_report, JoinReport { inserted: 0, pruned: 0, superseded: 1 });
+    assert_eq!(store.change_token(), joined_token);
+}
+
+
+#[test]
+fn iter_history()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let dir = TempDir::new("iter_history");
+    let ns1 = "namespace-1".to_owned();
+    let user1 = "uno".to_owned();
+    let mut store = open(&dir, &ns1);
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| Timestamp::from(timestamp.μs_since_epoch + μs_after);
+
+    assert_eq!(store.iter_history(&user1, &["a", "b"]).count(), 0);
+    let old = auth_entry(&ns1, &user1, &["a", "b"], at(0), b"old");
+    store.put(old, Some(Bytes::new("old"))).unwrap();
+    store.put(auth_entry(&ns1, &user1, &["a", "b"], at(2), b"new"), None::<Bytes>).unwrap();
+    let mid = auth_entry(&ns1, &user1, &["a", "b"], at(1), b"mid");
+    store.put(mid, Some(Bytes::new("mid"))).unwrap();
+    // Newest first, each with its payload if that's available.
+    let history = store
+        .iter_history(&user1, &["a", "b"])
+        .map(|(auth_entry, payload)| (auth_entry.into_parts().0.timestamp, payload.map(read_all)))
+        .collect::<Vec<_>>();
+    assert_eq!(history, [
+        (at(2), None),
+        (at(1), Some(b"mid".to_vec())),
+        (at(0), Some(b"old".to_vec()))
+    ]);
+    // The older versions aren't the current.
+    assert_eq!(all_entries(&store).len(), 1);
+    // None of them, after being pruned.
+    store.put(auth_entry(&ns1, &user1, &["a"], at(3), b""), None::<Bytes>).unwrap();
+    assert_eq!(store.iter_history(&user1, &["a", "b"]).count(), 0);
+    assert_eq!(store.iter_history(&user1, &["a"]).count(), 1);
+}
+
+
+#[test]
+fn checkpoint_and_restore()
+{
+    use sailce_data_model::store::sync::Store as _;
+
+    let dir = TempDir::new("checkpoint");
+    let ns1 = "namespace-1".to_owned();
+    let user1 = "uno".to_owned();
+    let mut store = open(&dir, &ns1);
+    let timestamp = Timestamp::now();
+    let at = |μs_after: u64| Timestamp::from(timestamp.μs_since_epoch + μs_after);
+
+    let ae1 = auth_entry(&ns1, &user1, &["a", "b"], at(0), b"one");
+    store.put(ae1, Some(Bytes::new("one"))).unwrap();
+    let ae2 = auth_entry(&ns1, &user1, &["c"], at(1), b"two");
+    store.put(ae2, Some(Bytes::new("two"))).unwrap();
+    let before = all_entries(&store);
+    let snapshot = store.checkpoint();
+
+    // Changes after the checkpoint, including pruning and deleting.
+    store.put(auth_entry(&ns1, &user1, &["a"], at(2), b""), None::<Bytes>).unwrap();
+    assert!(store.delete(&user1, &["c"]).unwrap());
+    store.put(auth_entry(&ns1, &user1, &["d"], at(3), b""), None::<Bytes>).unwrap();
+    assert_ne!(all_entries(&store), before);
+
+    let token = store.change_token();
+    store.restore(snapshot).unwrap();
+    assert_ne!(store.change_token(), token);
+    assert_eq!(all_entries(&store), before);
+    // With their payloads too.
+    {
+        let got = store.get(&user1, &["a", "b"]).unwrap().unwrap();
+        assert_eq!(read_all(got), b"one");
+    }
+    // And still after reopening.
+    drop(store);
+    assert_eq!(all_entries(&open(&dir, &ns1)), before);
+}
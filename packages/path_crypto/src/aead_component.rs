@@ -0,0 +1,99 @@
+//! The encrypted form of [`Component`]s that our AEAD [`Scheme`]s share, generic over the
+//! cipher: `nonce || ciphertext || tag`.
+
+use {
+    crate::{
+        DecryptError,
+        DestTooSmallError,
+        EncryptedComponent,
+        MakeEncryptedComponent as _,
+        Scheme,
+    },
+    aead::{
+        generic_array::typenum::Unsigned as _,
+        AeadInPlace,
+        Nonce,
+        Tag,
+    },
+    core::borrow::{
+        Borrow,
+        BorrowMut,
+    },
+    sailce_data_model::path::Component,
+};
+
+
+/// How much longer each encrypted form, by `C`, is than its plaintext.
+pub(crate) const fn overhead<C: AeadInPlace>() -> usize
+{
+    C::NonceSize::USIZE.saturating_add(C::TagSize::USIZE)
+}
+
+
+/// Encrypt `component` by `cipher` with the given `nonce` and `aad`, into where `get_dest` gives.
+pub(crate) fn seal<C: AeadInPlace, S: Scheme, Bytes: BorrowMut<[u8]>>(
+    cipher: &C,
+    nonce: &Nonce<C>,
+    aad: &[u8],
+    component: &Component<impl Borrow<[u8]>>,
+    get_dest: impl FnOnce(usize) -> Option<Bytes>,
+) -> Result<EncryptedComponent<Bytes, S>, DestTooSmallError>
+{
+    let plaintext = component.bytes();
+    let needed = plaintext.len().saturating_add(overhead::<C>());
+    let mut dest = get_dest(needed).ok_or(DestTooSmallError { needed })?;
+    let dest_slice = dest.borrow_mut();
+    if dest_slice.len() != needed {
+        return Err(DestTooSmallError { needed });
+    }
+    let (dest_nonce, rest) = dest_slice.split_at_mut(C::NonceSize::USIZE);
+    let (dest_ciphertext, dest_tag) = rest.split_at_mut(plaintext.len());
+    dest_nonce.copy_from_slice(nonce);
+    dest_ciphertext.copy_from_slice(plaintext);
+    // Only fails when the plaintext is longer than the cipher can do, which is far beyond any
+    // `Component`.
+    let tag = cipher
+        .encrypt_in_place_detached(nonce, aad, dest_ciphertext)
+        .ok()
+        .ok_or(DestTooSmallError { needed })?;
+    dest_tag.copy_from_slice(&tag);
+    Ok(S::synthesize_encrypted_component(dest))
+}
+
+
+/// The inverse of [`seal`], for the same `cipher` and `aad`.  The nonce is taken from the
+/// encrypted form.
+pub(crate) fn open<C: AeadInPlace, S: Scheme, Bytes: BorrowMut<[u8]>>(
+    cipher: &C,
+    aad: &[u8],
+    component: &EncryptedComponent<impl Borrow<[u8]>, S>,
+    get_dest: impl FnOnce(usize) -> Option<Bytes>,
+) -> Result<Component<Bytes>, DecryptError>
+{
+    let encrypted = component.bytes();
+    let ciphertext_len =
+        encrypted.len().checked_sub(overhead::<C>()).ok_or(DecryptError::Invalid)?;
+    let (nonce, rest) = encrypted.split_at(C::NonceSize::USIZE);
+    let (ciphertext, tag) = rest.split_at(ciphertext_len);
+
+    let needed = ciphertext_len;
+    let mut dest = get_dest(needed).ok_or(DestTooSmallError { needed })?;
+    let dest_slice = dest.borrow_mut();
+    if dest_slice.len() != needed {
+        return Err(DestTooSmallError { needed }.into());
+    }
+    dest_slice.copy_from_slice(ciphertext);
+    if cipher
+        .decrypt_in_place_detached(
+            Nonce::<C>::from_slice(nonce),
+            aad,
+            dest_slice,
+            Tag::<C>::from_slice(tag),
+        )
+        .is_err()
+    {
+        dest_slice.fill(0); // Don't leave unauthenticated plaintext.
+        return Err(DecryptError::Invalid);
+    }
+    Ok(Component { inner: dest })
+}
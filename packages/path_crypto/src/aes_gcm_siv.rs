@@ -0,0 +1,245 @@
+//! A concrete [`Scheme`] using AES-256-GCM-SIV for encryption and HMAC-SHA256 for key
+//! derivation.
+//!
+//! This is only available with our `"aes-gcm-siv"` package feature.
+//!
+//! AES-GCM-SIV is resistant to nonce misuse, which suits the deterministic encryption that `Path`
+//! encryption requires: encrypting the same plaintext with the same nonce only reveals that the
+//! plaintexts are equal, which is revealed anyway by determinism.  Still, the nonce is derived
+//! synthetically, by HMAC-SHA256, from the key and the plaintext, so that different plaintexts
+//! under the same key get different nonces.
+//!
+//! Each encrypted [`Component`] is `nonce || ciphertext || tag`, and so is [`OVERHEAD`] bytes
+//! longer than its plaintext.  The AES key, the nonces, and the next keys are each derived from
+//! the key by HMAC-SHA256 under distinct labels.
+//!
+//! With [`Cryptor::encrypt_component_with_aad`], the associated data is authenticated by
+//! AES-GCM-SIV and is also included in the synthetic nonce, under yet another label.  An empty
+//! associated data gives the same as without.
+//!
+//! Decrypting an encrypted form that is malformed or that fails authentication gives
+//! [`DecryptError::Invalid`].
+
+use {
+    crate::{
+        aead_component,
+        Cryptor,
+        DecryptError,
+        DestTooSmallError,
+        EncryptedComponent,
+        Key,
+        KeyDerivationFunction,
+        Scheme,
+    },
+    aes_gcm_siv::{
+        Aes256GcmSiv,
+        KeyInit as _,
+        Nonce,
+    },
+    core::{
+        borrow::{
+            Borrow,
+            BorrowMut,
+        },
+        fmt::{
+            self,
+            Debug,
+            Formatter,
+        },
+    },
+    hmac::{
+        Hmac,
+        Mac,
+    },
+    sailce_data_model::path::Component,
+    sha2::Sha256,
+};
+
+
+/// The length of the nonce that prefixes each encrypted form.
+pub const NONCE_LEN: usize = 12;
+/// The length of the authentication tag that suffixes each encrypted form.
+pub const TAG_LEN: usize = 16;
+/// How much longer each encrypted form is than its plaintext.
+pub const OVERHEAD: usize = NONCE_LEN + TAG_LEN;
+const _: () = assert!(OVERHEAD == aead_component::overhead::<Aes256GcmSiv>(), "consistent");
+
+const CIPHER_LABEL: &[u8] = b"sailce_path_crypto::aes_gcm_siv cipher";
+const NONCE_LABEL: &[u8] = b"sailce_path_crypto::aes_gcm_siv nonce";
+const AAD_NONCE_LABEL: &[u8] = b"sailce_path_crypto::aes_gcm_siv aad nonce";
+const KDF_LABEL: &[u8] = b"sailce_path_crypto::aes_gcm_siv key";
+
+
+/// The [`Scheme`] of AES-256-GCM-SIV and HMAC-SHA256.
+#[derive(Copy, Clone, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct AesGcmSivScheme;
+
+impl Scheme for AesGcmSivScheme
+{
+    type Cryptor = Self;
+    type KDF = Self;
+    type Key = AesGcmSivKey;
+}
+
+
+/// A 256-bit key of [`AesGcmSivScheme`].
+///
+/// Its `Debug` doesn't show the key material.  It isn't `Copy`, so that the key material isn't
+/// duplicated implicitly, and, with our `"zeroize"` package feature, it's zeroed when dropped.
+#[derive(Default, Clone, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)]
+pub struct AesGcmSivKey(pub [u8; 32]);
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for AesGcmSivKey
+{
+    #[inline]
+    fn zeroize(&mut self)
+    {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for AesGcmSivKey
+{
+    #[inline]
+    fn drop(&mut self)
+    {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for AesGcmSivKey {}
+
+impl Key for AesGcmSivKey
+{
+    type Scheme = AesGcmSivScheme;
+}
+
+impl Debug for AesGcmSivKey
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        f.write_str("AesGcmSivKey(..)")
+    }
+}
+
+
+/// HMAC-SHA256, keyed by `key`, of `label` followed by each of `data` in order.
+fn hmac(
+    key: &AesGcmSivKey,
+    label: &[u8],
+    data: &[&[u8]],
+) -> [u8; 32]
+{
+    // Only fails for key lengths that HMAC doesn't accept, and it accepts all.
+    #[allow(clippy::unreachable)]
+    let Ok(mut mac) = <Hmac<Sha256> as Mac>::new_from_slice(&key.0)
+    else {
+        unreachable!("HMAC accepts any key length");
+    };
+    mac.update(label);
+    for part in data {
+        mac.update(part);
+    }
+    mac.finalize().into_bytes().into()
+}
+
+fn cipher(key: &AesGcmSivKey) -> Aes256GcmSiv
+{
+    Aes256GcmSiv::new(&hmac(key, CIPHER_LABEL, &[]).into())
+}
+
+/// When `aad` isn't empty, it's length-prefixed under a label that isn't a prefix of the other
+/// label (nor vice versa), so that no nonce input with some `aad` can equal one with another.
+#[allow(clippy::big_endian_bytes)] // Any fixed order would do, for the length.
+fn synthetic_nonce(
+    key: &AesGcmSivKey,
+    aad: &[u8],
+    component: &[u8],
+) -> [u8; NONCE_LEN]
+{
+    let mac = if aad.is_empty() {
+        hmac(key, NONCE_LABEL, &[component])
+    }
+    else {
+        let aad_len = u64::try_from(aad.len()).unwrap_or(u64::MAX).to_be_bytes();
+        hmac(key, AAD_NONCE_LABEL, &[&aad_len, aad, component])
+    };
+    let mut nonce = [0; NONCE_LEN];
+    nonce.copy_from_slice(&mac[.. NONCE_LEN]);
+    nonce
+}
+
+
+impl Cryptor for AesGcmSivScheme
+{
+    type Scheme = Self;
+
+    /// Deterministic, by the synthetic nonce.
+    #[inline]
+    fn encrypt_component<Bytes: BorrowMut<[u8]>>(
+        key: &AesGcmSivKey,
+        component: &Component<impl Borrow<[u8]>>,
+        get_dest: impl FnOnce(usize) -> Option<Bytes>,
+    ) -> Result<EncryptedComponent<Bytes, Self>, DestTooSmallError>
+    {
+        Self::encrypt_component_with_aad(key, &[], component, get_dest)
+    }
+
+    #[inline]
+    fn decrypt_component<Bytes: BorrowMut<[u8]>>(
+        key: &AesGcmSivKey,
+        component: &EncryptedComponent<impl Borrow<[u8]>, Self>,
+        get_dest: impl FnOnce(usize) -> Option<Bytes>,
+    ) -> Result<Component<Bytes>, DecryptError>
+    {
+        Self::decrypt_component_with_aad(key, &[], component, get_dest)
+    }
+
+    /// Deterministic, by the synthetic nonce, which is also derived from `aad`.
+    #[inline]
+    fn encrypt_component_with_aad<Bytes: BorrowMut<[u8]>>(
+        key: &AesGcmSivKey,
+        aad: &[u8],
+        component: &Component<impl Borrow<[u8]>>,
+        get_dest: impl FnOnce(usize) -> Option<Bytes>,
+    ) -> Result<EncryptedComponent<Bytes, Self>, DestTooSmallError>
+    {
+        let nonce = synthetic_nonce(key, aad, component.bytes());
+        aead_component::seal(&cipher(key), &Nonce::from(nonce), aad, component, get_dest)
+    }
+
+    #[inline]
+    fn decrypt_component_with_aad<Bytes: BorrowMut<[u8]>>(
+        key: &AesGcmSivKey,
+        aad: &[u8],
+        component: &EncryptedComponent<impl Borrow<[u8]>, Self>,
+        get_dest: impl FnOnce(usize) -> Option<Bytes>,
+    ) -> Result<Component<Bytes>, DecryptError>
+    {
+        aead_component::open(&cipher(key), aad, component, get_dest)
+    }
+}
+
+impl KeyDerivationFunction for AesGcmSivScheme
+{
+    type Scheme = Self;
+
+    #[inline]
+    fn derive(
+        key: &AesGcmSivKey,
+        component: &Component<impl Borrow<[u8]>>,
+        dest: &mut AesGcmSivKey,
+    )
+    {
+        dest.0 = hmac(key, KDF_LABEL, &[component.bytes()]);
+    }
+}
@@ -0,0 +1,267 @@
+//! A concrete [`Scheme`] using XChaCha20-Poly1305 for encryption and HKDF-SHA256 for key
+//! derivation.
+//!
+//! This is only available with our `"chacha"` package feature.
+//!
+//! Each encrypted [`Component`] is `nonce || ciphertext || tag`, and so is [`OVERHEAD`] bytes
+//! longer than its plaintext.  The nonce is derived deterministically, by HKDF-SHA256, from both
+//! the key and the plaintext, so that the same plaintext under the same key always encrypts
+//! identically (as `Path` encryption requires) while different plaintexts under the same key
+//! get different nonces.  See [`NonceSource`](crate::crypt::NonceSource) for why that matters.
+//!
+//! Next keys are derived by HKDF-SHA256 with the key as the input keying material and the
+//! plaintext `Component` as the info, under a label that separates this from the nonce
+//! derivation.
+//!
+//! With [`Cryptor::encrypt_component_with_aad`], the associated data is authenticated by
+//! XChaCha20-Poly1305 and is also included in the derivation of the nonce, under yet another
+//! label, because reusing a nonce for the same plaintext with different associated data would
+//! reuse the one-time Poly1305 key too.  An empty associated data gives the same as without.
+//!
+//! Decrypting an encrypted form that is malformed or that fails authentication gives
+//! [`DecryptError::Invalid`].
+
+use {
+    crate::{
+        aead_component,
+        crypt::NonceCryptor,
+        Cryptor,
+        DecryptError,
+        DestTooSmallError,
+        EncryptedComponent,
+        Key,
+        KeyDerivationFunction,
+        Scheme,
+    },
+    chacha20poly1305::{
+        KeyInit as _,
+        XChaCha20Poly1305,
+        XNonce,
+    },
+    core::{
+        borrow::{
+            Borrow,
+            BorrowMut,
+        },
+        fmt::{
+            self,
+            Debug,
+            Formatter,
+        },
+    },
+    hkdf::Hkdf,
+    sailce_data_model::path::Component,
+    sha2::Sha256,
+};
+
+
+/// The length of the nonce that prefixes each encrypted form.
+pub const NONCE_LEN: usize = 24;
+/// The length of the authentication tag that suffixes each encrypted form.
+pub const TAG_LEN: usize = 16;
+/// How much longer each encrypted form is than its plaintext.
+pub const OVERHEAD: usize = NONCE_LEN + TAG_LEN;
+const _: () = assert!(OVERHEAD == aead_component::overhead::<XChaCha20Poly1305>(), "consistent");
+
+const NONCE_LABEL: &[u8] = b"sailce_path_crypto::chacha nonce";
+const AAD_NONCE_LABEL: &[u8] = b"sailce_path_crypto::chacha aad nonce";
+const KDF_LABEL: &[u8] = b"sailce_path_crypto::chacha key";
+
+
+/// The [`Scheme`] of XChaCha20-Poly1305 and HKDF-SHA256.
+#[derive(Copy, Clone, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct ChaChaScheme;
+
+impl Scheme for ChaChaScheme
+{
+    type Cryptor = Self;
+    type KDF = Self;
+    type Key = ChaChaKey;
+}
+
+
+/// A 256-bit key of [`ChaChaScheme`].
+///
+/// Its `Debug` doesn't show the key material.  It isn't `Copy`, so that the key material isn't
+/// duplicated implicitly, and, with our `"zeroize"` package feature, it's zeroed when dropped.
+#[derive(Default, Clone, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)]
+pub struct ChaChaKey(pub [u8; 32]);
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for ChaChaKey
+{
+    #[inline]
+    fn zeroize(&mut self)
+    {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for ChaChaKey
+{
+    #[inline]
+    fn drop(&mut self)
+    {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for ChaChaKey {}
+
+impl Key for ChaChaKey
+{
+    type Scheme = ChaChaScheme;
+}
+
+impl Debug for ChaChaKey
+{
+    #[inline]
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result
+    {
+        f.write_str("ChaChaKey(..)")
+    }
+}
+
+
+/// Fill `dest` by HKDF-SHA256 of `key`, with the info being each of `info` in order.
+fn hkdf_expand(
+    key: &ChaChaKey,
+    info: &[&[u8]],
+    dest: &mut [u8],
+)
+{
+    let hkdf = Hkdf::<Sha256>::new(None, &key.0);
+    // Only fails when `dest` is longer than 255 * 32 bytes, which ours never are.
+    #[allow(clippy::unreachable)]
+    if hkdf.expand_multi_info(info, dest).is_err() {
+        unreachable!("`dest` is short enough");
+    }
+}
+
+/// When `aad` isn't empty, it's length-prefixed under a label that isn't a prefix of the other
+/// label (nor vice versa), so that no info with some `aad` can equal one with another.
+#[allow(clippy::big_endian_bytes)] // Any fixed order would do, for the length.
+fn content_nonce(
+    key: &ChaChaKey,
+    aad: &[u8],
+    component: &[u8],
+) -> [u8; NONCE_LEN]
+{
+    let mut nonce = [0; NONCE_LEN];
+    if aad.is_empty() {
+        hkdf_expand(key, &[NONCE_LABEL, component], &mut nonce);
+    }
+    else {
+        let aad_len = u64::try_from(aad.len()).unwrap_or(u64::MAX).to_be_bytes();
+        hkdf_expand(key, &[AAD_NONCE_LABEL, &aad_len, aad, component], &mut nonce);
+    }
+    nonce
+}
+
+fn cipher(key: &ChaChaKey) -> XChaCha20Poly1305
+{
+    XChaCha20Poly1305::new(&key.0.into())
+}
+
+/// Encrypt with the given `nonce` and `aad`, for both [`Cryptor::encrypt_component_with_aad`]
+/// and [`NonceCryptor::encrypt_component_with_nonce`].
+fn seal<Bytes: BorrowMut<[u8]>>(
+    key: &ChaChaKey,
+    nonce: &[u8; NONCE_LEN],
+    aad: &[u8],
+    component: &Component<impl Borrow<[u8]>>,
+    get_dest: impl FnOnce(usize) -> Option<Bytes>,
+) -> Result<EncryptedComponent<Bytes, ChaChaScheme>, DestTooSmallError>
+{
+    aead_component::seal(&cipher(key), &XNonce::from(*nonce), aad, component, get_dest)
+}
+
+
+impl Cryptor for ChaChaScheme
+{
+    type Scheme = Self;
+
+    /// Deterministic, by deriving the nonce from the key and the content.
+    #[inline]
+    fn encrypt_component<Bytes: BorrowMut<[u8]>>(
+        key: &ChaChaKey,
+        component: &Component<impl Borrow<[u8]>>,
+        get_dest: impl FnOnce(usize) -> Option<Bytes>,
+    ) -> Result<EncryptedComponent<Bytes, Self>, DestTooSmallError>
+    {
+        Self::encrypt_component_with_aad(key, &[], component, get_dest)
+    }
+
+    #[inline]
+    fn decrypt_component<Bytes: BorrowMut<[u8]>>(
+        key: &ChaChaKey,
+        component: &EncryptedComponent<impl Borrow<[u8]>, Self>,
+        get_dest: impl FnOnce(usize) -> Option<Bytes>,
+    ) -> Result<Component<Bytes>, DecryptError>
+    {
+        Self::decrypt_component_with_aad(key, &[], component, get_dest)
+    }
+
+    /// Deterministic, by deriving the nonce from the key, `aad`, and the content.
+    #[inline]
+    fn encrypt_component_with_aad<Bytes: BorrowMut<[u8]>>(
+        key: &ChaChaKey,
+        aad: &[u8],
+        component: &Component<impl Borrow<[u8]>>,
+        get_dest: impl FnOnce(usize) -> Option<Bytes>,
+    ) -> Result<EncryptedComponent<Bytes, Self>, DestTooSmallError>
+    {
+        let nonce = content_nonce(key, aad, component.bytes());
+        seal(key, &nonce, aad, component, get_dest)
+    }
+
+    #[inline]
+    fn decrypt_component_with_aad<Bytes: BorrowMut<[u8]>>(
+        key: &ChaChaKey,
+        aad: &[u8],
+        component: &EncryptedComponent<impl Borrow<[u8]>, Self>,
+        get_dest: impl FnOnce(usize) -> Option<Bytes>,
+    ) -> Result<Component<Bytes>, DecryptError>
+    {
+        aead_component::open(&cipher(key), aad, component, get_dest)
+    }
+}
+
+impl NonceCryptor for ChaChaScheme
+{
+    type Nonce = [u8; NONCE_LEN];
+
+    /// The given `nonce` must never be used with the same `key` for a different `component`.
+    #[inline]
+    fn encrypt_component_with_nonce<Bytes: BorrowMut<[u8]>>(
+        key: &ChaChaKey,
+        nonce: &[u8; NONCE_LEN],
+        component: &Component<impl Borrow<[u8]>>,
+        get_dest: impl FnOnce(usize) -> Option<Bytes>,
+    ) -> Result<EncryptedComponent<Bytes, Self>, DestTooSmallError>
+    {
+        seal(key, nonce, &[], component, get_dest)
+    }
+}
+
+impl KeyDerivationFunction for ChaChaScheme
+{
+    type Scheme = Self;
+
+    #[inline]
+    fn derive(
+        key: &ChaChaKey,
+        component: &Component<impl Borrow<[u8]>>,
+        dest: &mut ChaChaKey,
+    )
+    {
+        hkdf_expand(key, &[KDF_LABEL, component.bytes()], &mut dest.0);
+    }
+}
@@ -53,6 +53,24 @@ where B: Borrow<[u8]>
     }
 }
 
+impl<B, S> EncryptedComponent<B, S>
+where
+    B: Borrow<[u8]>,
+    S: Scheme,
+{
+    /// Wrap `bytes` that are already the encrypted form of a component, e.g. as received from
+    /// elsewhere, via [`MakeEncryptedComponent::synthesize_encrypted_component`].
+    ///
+    /// No validation of the well-formedness of the ciphertext is possible without the key, and so
+    /// none is done.  Malformed bytes will only be detected, if at all, when decrypting.
+    #[inline]
+    #[must_use]
+    pub fn from_ciphertext(bytes: B) -> Self
+    {
+        S::synthesize_encrypted_component(bytes)
+    }
+}
+
 impl<Ba, Bb, S> PartialEq<EncryptedComponent<Bb, S>> for EncryptedComponent<Ba, S>
 where
     Ba: Borrow<[u8]>,
@@ -152,7 +170,8 @@ pub trait MakeEncryptedComponent: Scheme
     /// crate::Cryptor::encrypt_component) to create their return values, or by implementations of
     /// things like [`EncryptedPath::decrypt_components`](
     /// crate::EncryptedPath::decrypt_components) that must synthesize values of this type to pass
-    /// to [`Self::Cryptor::decrypt_component`](crate::Cryptor::decrypt_component).
+    /// to [`Self::Cryptor::decrypt_component`](crate::Cryptor::decrypt_component), or by
+    /// [`EncryptedComponent::from_ciphertext`] for bytes that were encrypted elsewhere.
     #[inline]
     fn synthesize_encrypted_component<B: Borrow<[u8]>>(bytes: B) -> EncryptedComponent<B, Self>
     {
@@ -1,8 +1,7 @@
+//! Aspects of encrypting & decrypting that are common to the other parts of this crate.
+
 use {
-    crate::{
-        KeyDerivationFunction as _,
-        Scheme,
-    },
+    crate::Scheme,
     core::borrow::BorrowMut,
     sailce_data_model::{
         path::Component,
@@ -11,11 +10,18 @@ use {
 };
 
 
+mod nonce;
+pub use nonce::*;
+
+
+/// `crypt_component(key_i, component_i, key_i_plus_1)` must also derive, into `key_i_plus_1`, the
+/// key for the next `Component`.  This is done by it, instead of here, because the plaintext
+/// that the derivation is from is the input when encrypting but is the output when decrypting.
 pub(crate) fn crypt_components_and_save_keys<'l, S, I, R>(
     path: &'l (impl Path + ?Sized),
     key_0: &'l S::Key,
     keys_dest: impl IntoIterator<IntoIter = I>,
-    mut crypt_component: impl (FnMut(&S::Key, &Component<&[u8]>) -> R) + 'l,
+    mut crypt_component: impl (FnMut(&S::Key, &Component<&[u8]>, &mut S::Key) -> R) + 'l,
 ) -> impl ExactSizeIterator<Item = R> + 'l
 where
     S: Scheme,
@@ -24,8 +30,7 @@ where
     let mut key_i = key_0;
 
     path.components().zip(keys_dest).map(move |(component_i, key_i_plus_1): (_, &'l mut _)| {
-        let result = crypt_component(key_i, &component_i);
-        S::KDF::derive(key_i, &component_i, key_i_plus_1);
+        let result = crypt_component(key_i, &component_i, key_i_plus_1);
         key_i = key_i_plus_1;
         result
     })
@@ -62,11 +67,13 @@ where
 }
 
 
+/// `crypt_component` must also derive the next key, the same as for
+/// [`crypt_components_and_save_keys`].
 pub(crate) fn crypt_components_with_key_space<'l, S: Scheme, R>(
     path: &'l (impl Path + ?Sized),
     key_0: &'l S::Key,
     key_space: [impl BorrowMut<S::Key> + 'l; 2],
-    mut crypt_component: impl (FnMut(&S::Key, &Component<&[u8]>) -> R) + 'l,
+    mut crypt_component: impl (FnMut(&S::Key, &Component<&[u8]>, &mut S::Key) -> R) + 'l,
 ) -> impl ExactSizeIterator<Item = R> + 'l
 {
     let mut key_0 = Some(key_0);
@@ -89,12 +96,11 @@ pub(crate) fn crypt_components_with_key_space<'l, S: Scheme, R>(
                     (key_b, key_a)
                 },
         };
-        let result = crypt_component(key_i, &component_i);
         // Reuse the space of the unused alternate `key_i_plus_1` for the next derived key and use
         // that as `key_i` next time, and reuse the space of now-unused `key_i` as `key_i_plus_1`
         // next time.  Two `Key` values are needed for the space, because `derive` needs to borrow
         // two keys simultaneously with one as `mut`, and so a single `Key` value wouldn't work.
-        S::KDF::derive(key_i, &component_i, key_i_plus_1);
+        let result = crypt_component(key_i, &component_i, key_i_plus_1);
         alt = !alt;
         result
     })
@@ -0,0 +1,125 @@
+use {
+    crate::{
+        Cryptor,
+        DestTooSmallError,
+        EncryptedComponent,
+        Scheme,
+    },
+    core::borrow::{
+        Borrow,
+        BorrowMut,
+    },
+    sailce_data_model::path::Component,
+};
+
+
+/// A source of the nonces ("numbers used once") for a [`NonceCryptor`], e.g. for AEAD
+/// algorithms.
+///
+/// # Deterministic vs. random nonces
+///
+/// The encryption of `Path`s must be deterministic: the same plaintext `Component` under the
+/// same key must always encrypt identically.  This is what preserves the prefix relationships
+/// and equality of encrypted `Path`s, which is what enables peers that can't decrypt to still
+/// compare them (e.g. for prefix pruning and for [`Area`](sailce_data_model::group::Area)s).
+/// Random nonces would give different encrypted forms each time and so would break that.
+///
+/// The trade-off of deterministic nonces is that equality of `Component`s, at the same position
+/// after equal preceding `Component`s, is revealed to those who can see the encrypted forms, but
+/// their contents aren't.  (The key-derivation sequence already reveals this for encrypted
+/// `Path`s, so deterministic nonces don't make that worse.)
+///
+/// The danger with nonces is reuse of the same nonce, under the same key, for different
+/// plaintexts, which is catastrophic for many AEAD algorithms (e.g. AES-GCM and
+/// ChaCha20-Poly1305, where it leaks the XOR of the plaintexts and enables forgeries).  A
+/// deterministic nonce that is derived from the content of the `Component` (by a
+/// collision-resistant function) avoids this, because different plaintexts get different nonces.
+/// A counter, or a fixed value, must never be used like this unless it's certain that each key
+/// is only ever used for a single plaintext.  A nonce-misuse-resistant algorithm (e.g.
+/// AES-GCM-SIV) is more robust against mistakes with this.
+pub trait NonceSource<S: Scheme>
+{
+    /// The type of nonces given.
+    type Nonce;
+
+    /// Return the nonce for encrypting `component`, that is at position `index` of its `Path`,
+    /// with `key`.
+    fn nonce(
+        &mut self,
+        key: &S::Key,
+        index: usize,
+        component: &[u8],
+    ) -> Self::Nonce;
+}
+
+
+/// A [`NonceSource`] that derives each nonce deterministically by calling the wrapped function
+/// with the key, the index, and the content of the `Component`.
+///
+/// The function should be collision-resistant over all of its arguments, e.g. a keyed
+/// cryptographic hash (MAC) of them, so that different `Component`s get different nonces.  See
+/// [`NonceSource`] for why.  (It's `Fn`, not `FnMut`, so that it can't depend on mutable state.)
+#[derive(Copy, Clone, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct Deterministic<F>(pub F);
+
+impl<S, F, N> NonceSource<S> for Deterministic<F>
+where
+    S: Scheme,
+    F: Fn(&S::Key, usize, &[u8]) -> N,
+{
+    type Nonce = N;
+
+    #[inline]
+    fn nonce(
+        &mut self,
+        key: &S::Key,
+        index: usize,
+        component: &[u8],
+    ) -> Self::Nonce
+    {
+        (self.0)(key, index, component)
+    }
+}
+
+
+/// A [`Cryptor`] for algorithms that use a nonce for each encryption.
+///
+/// Implementations must include the nonce in the encrypted form (e.g. as a prefix), or otherwise
+/// make it recoverable, so that [`Cryptor::decrypt_component`] can work without being given it.
+/// The plain [`Cryptor::encrypt_component`] of implementations still must be deterministic, e.g.
+/// by deriving the nonce from its arguments like [`Deterministic`] does.
+pub trait NonceCryptor: Cryptor
+{
+    /// The type of nonces used.
+    type Nonce;
+
+    /// Like [`Cryptor::encrypt_component`] but use the given `nonce`.
+    ///
+    /// # Errors
+    /// If the value returned by `get_dest` is `None` or is too small for the encrypted form.
+    fn encrypt_component_with_nonce<Bytes: BorrowMut<[u8]>>(
+        key: &<Self::Scheme as Scheme>::Key,
+        nonce: &Self::Nonce,
+        component: &Component<impl Borrow<[u8]>>,
+        get_dest: impl FnOnce(usize) -> Option<Bytes>,
+    ) -> Result<EncryptedComponent<Bytes, Self::Scheme>, DestTooSmallError>;
+
+    /// Like [`Self::encrypt_component_with_nonce`] but get the nonce from `nonce_source`, for
+    /// the `component` at position `index` of its `Path`.
+    ///
+    /// # Errors
+    /// If the value returned by `get_dest` is `None` or is too small for the encrypted form.
+    #[inline]
+    fn encrypt_component_from_source<Bytes: BorrowMut<[u8]>>(
+        key: &<Self::Scheme as Scheme>::Key,
+        nonce_source: &mut impl NonceSource<Self::Scheme, Nonce = Self::Nonce>,
+        index: usize,
+        component: &Component<impl Borrow<[u8]>>,
+        get_dest: impl FnOnce(usize) -> Option<Bytes>,
+    ) -> Result<EncryptedComponent<Bytes, Self::Scheme>, DestTooSmallError>
+    {
+        let nonce = nonce_source.nonce(key, index, component.bytes());
+        Self::encrypt_component_with_nonce(key, &nonce, component, get_dest)
+    }
+}
@@ -1,3 +1,13 @@
+#[cfg(feature = "alloc")]
+use {
+    crate::get_dest,
+    alloc::vec::Vec,
+};
+#[cfg(feature = "zeroize")]
+use {
+    crate::ZeroizingKey,
+    zeroize::Zeroize,
+};
 use {
     crate::{
         crypt::{
@@ -6,9 +16,10 @@ use {
             crypt_components_with_keys,
         },
         Cryptor as _,
-        DestTooSmallError,
+        DecryptError,
         EncryptedComponent,
         EncryptedPath,
+        KeyDerivationFunction as _,
         MakeEncryptedComponent as _,
         Scheme,
     },
@@ -30,7 +41,9 @@ where
 {
     /// The inverse of [`EncryptPath::encrypt_components`](
     /// crate::EncryptPath::encrypt_components).  The keys are automatically derived the same as
-    /// for `EncryptPath::encrypt_components` (not as some kind of inverse).
+    /// for `EncryptPath::encrypt_components` (not as some kind of inverse), i.e. from each
+    /// decrypted `Component`.  So, after an item that is an error, the keys for the remaining
+    /// items can't be derived and those items are not meaningful.
     ///
     /// **Note**: The same concern applies as noted by [`EncryptPath::encrypt_components`](
     /// crate::EncryptPath::encrypt_components).
@@ -39,7 +52,7 @@ where
         &'l self,
         key_0: &'l S::Key,
         get_dest: impl FnMut(usize) -> Option<B> + 'l,
-    ) -> impl ExactSizeIterator<Item = Result<Component<B>, DestTooSmallError>> + 'l
+    ) -> impl ExactSizeIterator<Item = Result<Component<B>, DecryptError>> + 'l
     where
         S::Key: Default,
         B: BorrowMut<[u8]>,
@@ -69,7 +82,7 @@ where
         key_0: &'l S::Key,
         keys_dest: impl IntoIterator<IntoIter = I>,
         mut get_dest: impl FnMut(usize) -> Option<B> + 'l,
-    ) -> impl ExactSizeIterator<Item = Result<Component<B>, DestTooSmallError>> + 'l
+    ) -> impl ExactSizeIterator<Item = Result<Component<B>, DecryptError>> + 'l
     where
         I: ExactSizeIterator<Item = &'l mut S::Key> + 'l,
         B: BorrowMut<[u8]>,
@@ -78,12 +91,8 @@ where
             &self.path,
             key_0,
             keys_dest,
-            move |key_i, component_i| {
-                S::Cryptor::decrypt_component(
-                    key_i,
-                    &synthesize_encrypted_component(*component_i),
-                    &mut get_dest,
-                )
+            move |key_i, component_i, key_i_plus_1| {
+                decrypt_and_derive::<S, _>(key_i, &[], component_i, key_i_plus_1, &mut get_dest)
             },
         )
     }
@@ -105,7 +114,7 @@ where
         &'r self,
         keys: impl IntoIterator<IntoIter = I>,
         mut get_dest: impl FnMut(usize) -> Option<B> + 'r,
-    ) -> impl ExactSizeIterator<Item = Result<Component<B>, DestTooSmallError>> + 'r
+    ) -> impl ExactSizeIterator<Item = Result<Component<B>, DecryptError>> + 'r
     where
         I: ExactSizeIterator<Item = &'k S::Key> + 'r,
         B: BorrowMut<[u8]>,
@@ -126,24 +135,238 @@ where
         &'l self,
         key_0: &'l S::Key,
         key_space: [impl BorrowMut<S::Key> + 'l; 2],
+        get_dest: impl FnMut(usize) -> Option<B> + 'l,
+    ) -> impl ExactSizeIterator<Item = Result<Component<B>, DecryptError>> + 'l
+    {
+        self.decrypt_components_with_key_space_and_aad(key_0, key_space, &[], get_dest)
+    }
+
+    /// The inverse of [`EncryptPath::encrypt_components_with_aad`](
+    /// crate::EncryptPath::encrypt_components_with_aad), which must be given the same `aad` as
+    /// was given to that.  Otherwise like [`Self::decrypt_components`].
+    ///
+    /// **Note**: The same concern applies as noted by [`EncryptPath::encrypt_components`](
+    /// crate::EncryptPath::encrypt_components).
+    #[inline]
+    pub fn decrypt_components_with_aad<'l, B>(
+        &'l self,
+        key_0: &'l S::Key,
+        aad: &'l [u8],
+        get_dest: impl FnMut(usize) -> Option<B> + 'l,
+    ) -> impl ExactSizeIterator<Item = Result<Component<B>, DecryptError>> + 'l
+    where
+        S::Key: Default,
+        B: BorrowMut<[u8]>,
+    {
+        self.decrypt_components_with_key_space_and_aad(
+            key_0,
+            [S::Key::default(), S::Key::default()],
+            aad,
+            get_dest,
+        )
+    }
+
+    /// Like [`Self::decrypt_components_with_aad`] but with temporary space for the derived keys
+    /// provided by `key_space`, like [`Self::decrypt_components_with_key_space`].
+    #[inline]
+    pub fn decrypt_components_with_key_space_and_aad<'l, B: BorrowMut<[u8]>>(
+        &'l self,
+        key_0: &'l S::Key,
+        key_space: [impl BorrowMut<S::Key> + 'l; 2],
+        aad: &'l [u8],
         mut get_dest: impl FnMut(usize) -> Option<B> + 'l,
-    ) -> impl ExactSizeIterator<Item = Result<Component<B>, DestTooSmallError>> + 'l
+    ) -> impl ExactSizeIterator<Item = Result<Component<B>, DecryptError>> + 'l
     {
         crypt_components_with_key_space::<S, _>(
             &self.path,
             key_0,
             key_space,
-            move |key_i, component_i| {
-                S::Cryptor::decrypt_component(
-                    key_i,
-                    &synthesize_encrypted_component(*component_i),
-                    &mut get_dest,
-                )
+            move |key_i, component_i, key_i_plus_1| {
+                decrypt_and_derive::<S, _>(key_i, aad, component_i, key_i_plus_1, &mut get_dest)
             },
         )
     }
 
-    // TODO: decrypt() et al that create new Path objects
+    /// Like [`Self::decrypt_components`] but the internal `Key` values are zeroed when the
+    /// returned iterator is dropped, the same as [`EncryptPath::encrypt_components_zeroizing`](
+    /// crate::EncryptPath::encrypt_components_zeroizing).
+    ///
+    /// Only available with our `"zeroize"` package feature.
+    #[cfg(feature = "zeroize")]
+    #[inline]
+    pub fn decrypt_components_zeroizing<'l, B>(
+        &'l self,
+        key_0: &'l S::Key,
+        get_dest: impl FnMut(usize) -> Option<B> + 'l,
+    ) -> impl ExactSizeIterator<Item = Result<Component<B>, DecryptError>> + 'l
+    where
+        S::Key: Zeroize + Default,
+        B: BorrowMut<[u8]>,
+    {
+        self.decrypt_components_with_key_space(
+            key_0,
+            [ZeroizingKey::<S::Key>::default(), ZeroizingKey::default()],
+            get_dest,
+        )
+    }
+
+    /// Like [`Self::decrypt_components`] but a new [`Path`] is created from the decrypted output
+    /// values.  The inverse of [`EncryptPath::encrypt`](crate::EncryptPath::encrypt).
+    ///
+    /// # Errors
+    /// If decrypting any `Component` can't fit in the buffer returned for it by `get_dest`, or
+    /// if any is invalid, as [`DecryptError`] tells.
+    #[inline]
+    pub fn decrypt<B, Q>(
+        &self,
+        key_0: &S::Key,
+        get_dest: impl FnMut(usize) -> Option<B>,
+    ) -> Result<Q, DecryptError>
+    where
+        S::Key: Default,
+        B: BorrowMut<[u8]>,
+        Q: Path + FromIterator<B>,
+    {
+        self.decrypt_components(key_0, get_dest)
+            .map(|result| result.map(|component| component.inner))
+            .collect()
+    }
+
+    /// Like [`Self::decrypt_components_with_aad`] but a new [`Path`] is created from the
+    /// decrypted output values.  The inverse of [`EncryptPath::encrypt_with_aad`](
+    /// crate::EncryptPath::encrypt_with_aad).
+    ///
+    /// # Errors
+    /// If decrypting any `Component` can't fit in the buffer returned for it by `get_dest`, or
+    /// if any is invalid, as [`DecryptError`] tells, including when the scheme authenticates
+    /// `aad` and it differs from what this was encrypted with.
+    #[inline]
+    pub fn decrypt_with_aad<B, Q>(
+        &self,
+        key_0: &S::Key,
+        aad: &[u8],
+        get_dest: impl FnMut(usize) -> Option<B>,
+    ) -> Result<Q, DecryptError>
+    where
+        S::Key: Default,
+        B: BorrowMut<[u8]>,
+        Q: Path + FromIterator<B>,
+    {
+        self.decrypt_components_with_aad(key_0, aad, get_dest)
+            .map(|result| result.map(|component| component.inner))
+            .collect()
+    }
+
+    /// Like [`Self::decrypt`] but each decrypted `Component` is a newly-allocated `Vec`, as by
+    /// [`get_dest::from_vec`].  The inverse of [`EncryptPath::encrypt_into_vecs`](
+    /// crate::EncryptPath::encrypt_into_vecs).
+    ///
+    /// Only available with our `"alloc"` package feature.
+    ///
+    /// # Errors
+    /// If allocating the `Vec` for any `Component` fails, or if any is invalid.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn decrypt_into_vecs(
+        &self,
+        key_0: &S::Key,
+    ) -> Result<Vec<Vec<u8>>, DecryptError>
+    where
+        S::Key: Default,
+    {
+        self.decrypt(key_0, get_dest::from_vec())
+    }
+
+    /// Like [`Self::decrypt_components_and_save_keys`] but a new [`Path`] is created from the
+    /// decrypted output values.
+    ///
+    /// # Errors
+    /// If decrypting any `Component` can't fit in the buffer returned for it by `get_dest`, or
+    /// if any is invalid, as [`DecryptError`] tells.
+    #[inline]
+    pub fn decrypt_and_save_keys<'l, I, B, Q>(
+        &'l self,
+        key_0: &'l S::Key,
+        keys_dest: impl IntoIterator<IntoIter = I>,
+        get_dest: impl FnMut(usize) -> Option<B> + 'l,
+    ) -> Result<Q, DecryptError>
+    where
+        I: ExactSizeIterator<Item = &'l mut S::Key> + 'l,
+        B: BorrowMut<[u8]>,
+        Q: Path + FromIterator<B>,
+    {
+        self.decrypt_components_and_save_keys(key_0, keys_dest, get_dest)
+            .map(|result| result.map(|component| component.inner))
+            .collect()
+    }
+
+    /// Like [`Self::decrypt_components_with_keys`] but a new [`Path`] is created from the
+    /// decrypted output values.
+    ///
+    /// # Errors
+    /// If decrypting any `Component` can't fit in the buffer returned for it by `get_dest`, or
+    /// if any is invalid, as [`DecryptError`] tells.
+    #[inline]
+    pub fn decrypt_with_keys<'k, I, B, Q>(
+        &self,
+        keys: impl IntoIterator<IntoIter = I>,
+        get_dest: impl FnMut(usize) -> Option<B>,
+    ) -> Result<Q, DecryptError>
+    where
+        I: ExactSizeIterator<Item = &'k S::Key>,
+        B: BorrowMut<[u8]>,
+        Q: Path + FromIterator<B>,
+    {
+        self.decrypt_components_with_keys(keys, get_dest)
+            .map(|result| result.map(|component| component.inner))
+            .collect()
+    }
+
+    /// Like [`Self::decrypt_components_with_key_space`] but a new [`Path`] is created from the
+    /// decrypted output values.
+    ///
+    /// # Errors
+    /// If decrypting any `Component` can't fit in the buffer returned for it by `get_dest`, or
+    /// if any is invalid, as [`DecryptError`] tells.
+    #[inline]
+    pub fn decrypt_with_key_space<B, Q>(
+        &self,
+        key_0: &S::Key,
+        key_space: [impl BorrowMut<S::Key>; 2],
+        get_dest: impl FnMut(usize) -> Option<B>,
+    ) -> Result<Q, DecryptError>
+    where
+        B: BorrowMut<[u8]>,
+        Q: Path + FromIterator<B>,
+    {
+        self.decrypt_components_with_key_space(key_0, key_space, get_dest)
+            .map(|result| result.map(|component| component.inner))
+            .collect()
+    }
+}
+
+
+/// Decrypt `component_i` and derive, from its decrypted form, the key for the next `Component`,
+/// the same as encrypting derives from the plaintext.  If decrypting fails, `key_i_plus_1` is
+/// left as is.
+fn decrypt_and_derive<S: Scheme, B: BorrowMut<[u8]>>(
+    key_i: &S::Key,
+    aad: &[u8],
+    component_i: &Component<&[u8]>,
+    key_i_plus_1: &mut S::Key,
+    get_dest: impl FnOnce(usize) -> Option<B>,
+) -> Result<Component<B>, DecryptError>
+{
+    let result = S::Cryptor::decrypt_component_with_aad(
+        key_i,
+        aad,
+        &synthesize_encrypted_component(*component_i),
+        get_dest,
+    );
+    if let Ok(decrypted) = &result {
+        S::KDF::derive(key_i, decrypted, key_i_plus_1);
+    }
+    result
 }
 
 
@@ -1,3 +1,13 @@
+#[cfg(feature = "alloc")]
+use {
+    crate::get_dest,
+    alloc::vec::Vec,
+};
+#[cfg(feature = "zeroize")]
+use {
+    crate::ZeroizingKey,
+    zeroize::Zeroize,
+};
 use {
     crate::{
         crypt::{
@@ -107,6 +117,39 @@ pub trait EncryptPath: Path
         (i, size)
     }
 
+    /// The size of a single byte-buffer needed to hold all of `self`'s components as encrypted,
+    /// i.e. the minimum size of a backing slice given to make a `get_dest` closure, such as via
+    /// [`get_dest::from_slice`](crate::get_dest::from_slice), given to
+    /// [`Self::encrypt_with_key_space`] (et al) for encrypting all of `self` to succeed.
+    ///
+    /// This does one pass of deriving the sequence of keys, which is needed because the size for
+    /// each component depends on its key, and then discards them.  Unlike
+    /// [`Self::derive_keys_and_size_needed`], this doesn't need somewhere to place them, but, so,
+    /// the same concern applies as noted by [`Self::encrypt_components`].  The sum saturates at
+    /// `usize::MAX`.
+    #[must_use]
+    #[inline]
+    fn total_size_needed<S>(
+        &self,
+        key_0: &S::Key,
+    ) -> usize
+    where
+        S: Scheme,
+        S::Key: Default,
+    {
+        crypt_components_with_key_space::<S, _>(
+            self,
+            key_0,
+            [S::Key::default(), S::Key::default()],
+            |key_i, component_i, key_i_plus_1| {
+                let size = S::Cryptor::size_needed_to_encrypt_component(key_i, component_i);
+                S::KDF::derive(key_i, component_i, key_i_plus_1);
+                size
+            },
+        )
+        .fold(0, usize::saturating_add)
+    }
+
     /// Given an initial key `key_0`, encrypt a `Path` as follows:
     /// - Encrypting the empty `Path` yields the empty `Path` again.
     /// - Encrypt a `Path` with a single `Component` `component_0` as `encrypt_component(key_0,
@@ -184,8 +227,10 @@ pub trait EncryptPath: Path
             self,
             key_0,
             keys_dest,
-            move |key_i, component_i| {
-                S::Cryptor::encrypt_component(key_i, component_i, &mut get_dest)
+            move |key_i, component_i, key_i_plus_1| {
+                let result = S::Cryptor::encrypt_component(key_i, component_i, &mut get_dest);
+                S::KDF::derive(key_i, component_i, key_i_plus_1);
+                result
             },
         )
     }
@@ -224,6 +269,46 @@ pub trait EncryptPath: Path
         &'l self,
         key_0: &'l S::Key,
         key_space: [impl BorrowMut<S::Key> + 'l; 2],
+        get_dest: impl FnMut(usize) -> Option<B> + 'l,
+    ) -> impl ExactSizeIterator<Item = Result<EncryptedComponent<B, S>, DestTooSmallError>> + 'l
+    {
+        self.encrypt_components_with_key_space_and_aad(key_0, key_space, &[], get_dest)
+    }
+
+    /// Like [`Self::encrypt_components`] but each `Component` is encrypted with the same `aad`,
+    /// by [`Cryptor::encrypt_component_with_aad`](crate::Cryptor::encrypt_component_with_aad),
+    /// which binds the encrypted `Path` to it (e.g. to a namespace's ID) with schemes that
+    /// support that.
+    ///
+    /// **Note**: The same concern applies as noted by [`Self::encrypt_components`].
+    #[inline]
+    fn encrypt_components_with_aad<'l, S, B>(
+        &'l self,
+        key_0: &'l S::Key,
+        aad: &'l [u8],
+        get_dest: impl FnMut(usize) -> Option<B> + 'l,
+    ) -> impl ExactSizeIterator<Item = Result<EncryptedComponent<B, S>, DestTooSmallError>> + 'l
+    where
+        S: Scheme,
+        S::Key: Default,
+        B: BorrowMut<[u8]>,
+    {
+        self.encrypt_components_with_key_space_and_aad(
+            key_0,
+            [S::Key::default(), S::Key::default()],
+            aad,
+            get_dest,
+        )
+    }
+
+    /// Like [`Self::encrypt_components_with_aad`] but with temporary space for the derived keys
+    /// provided by `key_space`, like [`Self::encrypt_components_with_key_space`].
+    #[inline]
+    fn encrypt_components_with_key_space_and_aad<'l, S: Scheme, B: BorrowMut<[u8]>>(
+        &'l self,
+        key_0: &'l S::Key,
+        key_space: [impl BorrowMut<S::Key> + 'l; 2],
+        aad: &'l [u8],
         mut get_dest: impl FnMut(usize) -> Option<B> + 'l,
     ) -> impl ExactSizeIterator<Item = Result<EncryptedComponent<B, S>, DestTooSmallError>> + 'l
     {
@@ -231,12 +316,43 @@ pub trait EncryptPath: Path
             self,
             key_0,
             key_space,
-            move |key_i, component_i| {
-                S::Cryptor::encrypt_component(key_i, component_i, &mut get_dest)
+            move |key_i, component_i, key_i_plus_1| {
+                let result = S::Cryptor::encrypt_component_with_aad(
+                    key_i,
+                    aad,
+                    component_i,
+                    &mut get_dest,
+                );
+                S::KDF::derive(key_i, component_i, key_i_plus_1);
+                result
             },
         )
     }
 
+    /// Like [`Self::encrypt_components`] but the internal `Key` values are zeroed when the
+    /// returned iterator is dropped, which mitigates that concern of `Self::encrypt_components`
+    /// (though the values might still have been copied when the iterator was moved).
+    ///
+    /// Only available with our `"zeroize"` package feature.
+    #[cfg(feature = "zeroize")]
+    #[inline]
+    fn encrypt_components_zeroizing<'l, S, B>(
+        &'l self,
+        key_0: &'l S::Key,
+        get_dest: impl FnMut(usize) -> Option<B> + 'l,
+    ) -> impl ExactSizeIterator<Item = Result<EncryptedComponent<B, S>, DestTooSmallError>> + 'l
+    where
+        S: Scheme,
+        S::Key: Zeroize + Default,
+        B: BorrowMut<[u8]>,
+    {
+        self.encrypt_components_with_key_space(
+            key_0,
+            [ZeroizingKey::<S::Key>::default(), ZeroizingKey::default()],
+            get_dest,
+        )
+    }
+
     /// Like [`Self::encrypt_components`] but a new [`Path`] is created from the encrypted output
     /// values.
     ///
@@ -263,6 +379,56 @@ pub trait EncryptPath: Path
         ))
     }
 
+    /// Like [`Self::encrypt_components_with_aad`] but a new [`Path`] is created from the
+    /// encrypted output values.
+    ///
+    /// **Note**: The same concern applies as noted by [`Self::encrypt_components`].
+    ///
+    /// # Errors
+    /// If encrypting any `Component` can't fit in the buffer returned for it by `get_dest`.
+    #[inline]
+    fn encrypt_with_aad<S, B, P>(
+        &self,
+        key_0: &S::Key,
+        aad: &[u8],
+        get_dest: impl FnMut(usize) -> Option<B>,
+    ) -> Result<EncryptedPath<P, S>, DestTooSmallError>
+    where
+        S: Scheme,
+        S::Key: Default,
+        B: BorrowMut<[u8]>,
+        P: Path + FromIterator<B>,
+    {
+        Ok(EncryptedPath::new(
+            self.encrypt_components_with_aad::<S, _>(key_0, aad, get_dest)
+                .map(|result| result.map(|encrypted_component| encrypted_component.inner))
+                .collect::<Result<P, _>>()?,
+        ))
+    }
+
+    /// Like [`Self::encrypt`] but each encrypted `Component` is a newly-allocated `Vec`, as by
+    /// [`get_dest::from_vec`], which is the common case of just wanting the encrypted `Path` as
+    /// owned bytes.  The `Vec`s, grouped in sequence, are its [`path`](EncryptedPath::path).
+    ///
+    /// Only available with our `"alloc"` package feature.
+    ///
+    /// **Note**: The same concern applies as noted by [`Self::encrypt_components`].
+    ///
+    /// # Errors
+    /// If allocating the `Vec` for any `Component` fails.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encrypt_into_vecs<S>(
+        &self,
+        key_0: &S::Key,
+    ) -> Result<EncryptedPath<Vec<Vec<u8>>, S>, DestTooSmallError>
+    where
+        S: Scheme,
+        S::Key: Default,
+    {
+        self.encrypt(key_0, get_dest::from_vec())
+    }
+
     /// Like [`Self::encrypt_components_and_save_keys`] but a new [`Path`] is created from the
     /// encrypted output values.
     ///
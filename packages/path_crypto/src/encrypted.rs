@@ -1,9 +1,19 @@
 // TODO: move all this into encrypt.rs?
 
 use {
-    crate::Scheme,
-    core::marker::PhantomData,
-    sailce_data_model::Path,
+    crate::{
+        EncryptedComponent,
+        Scheme,
+    },
+    core::{
+        borrow::Borrow,
+        cmp::Ordering,
+        marker::PhantomData,
+    },
+    sailce_data_model::{
+        path::Extra as _,
+        Path,
+    },
 };
 
 
@@ -15,6 +25,28 @@ use {
 ///
 /// The private field prevents directly constructing values of this type, to ensure that creating
 /// values is done properly by this crate's API.
+///
+/// # Ordering
+///
+/// `EncryptedPath`s are equal and ordered as `Path`s, i.e. lexicographically by the ciphertext
+/// bytes of their components, like [`EncryptedComponent`] is.  Because each component is
+/// encrypted deterministically with a key derived from only the components before it (when
+/// encrypted with the same initial key, key space, and additional authenticated data, if any),
+/// this ordering relates to the ordering of the plaintext `Path`s like so:
+///
+/// - Two `EncryptedPath`s are equal if and only if their plaintext `Path`s are.
+/// - One is a prefix of another if and only if its plaintext is a prefix of the other's
+///   plaintext, and so it's less, the same as for the plaintexts.  Therefore, all the
+///   encryptions of `Path`s that extend a common prefix are contiguous in this ordering, after
+///   that prefix's encryption, which is what prefix-based range queries over an encrypted
+///   `Store` rely on.
+/// - Otherwise, the ordering is determined by the first components that differ, i.e. between
+///   siblings whose ciphertexts are under the same key.  Those ciphertexts are pseudorandom, and
+///   so this **does not** match the plaintext ordering.  It's only consistent: the same siblings
+///   always sort the same way as each other for the same keys, but not in their plaintext order.
+///
+/// Nothing about the ordering holds between `EncryptedPath`s that were encrypted with different
+/// keys, key spaces, or additional authenticated data.
 #[derive(Debug)]
 #[allow(clippy::partial_pub_fields, clippy::exhaustive_structs)]
 pub struct EncryptedPath<Path, Scheme>
@@ -32,4 +64,68 @@ impl<P: Path, S: Scheme> EncryptedPath<P, S>
     {
         Self { path, _scheme: PhantomData }
     }
+
+    /// Rebuild an `EncryptedPath` from its already-encrypted `components`, e.g. as received over
+    /// a network, without needing the keys.
+    ///
+    /// No validation of the well-formedness of the ciphertexts is possible without the keys, and
+    /// so none is done.  Malformed components will only be detected, if at all, when decrypting.
+    #[inline]
+    pub fn from_encrypted_components<I, B>(components: I) -> Self
+    where
+        I: IntoIterator<Item = EncryptedComponent<B, S>>,
+        B: Borrow<[u8]>,
+        P: FromIterator<B>,
+    {
+        Self::new(components.into_iter().map(|component| component.inner).collect())
+    }
+}
+
+// These can't be `derive`d, because of the `Scheme` type parameter, and because they're by
+// `Component`s, regardless of the type of `Path`.
+
+impl<Pa, Pb, S> PartialEq<EncryptedPath<Pb, S>> for EncryptedPath<Pa, S>
+where
+    Pa: Path,
+    Pb: Path,
+{
+    #[inline]
+    fn eq(
+        &self,
+        other: &EncryptedPath<Pb, S>,
+    ) -> bool
+    {
+        self.path.eq_components(&other.path)
+    }
+}
+
+impl<P, S> Eq for EncryptedPath<P, S> where P: Path {}
+
+impl<Pa, Pb, S> PartialOrd<EncryptedPath<Pb, S>> for EncryptedPath<Pa, S>
+where
+    Pa: Path,
+    Pb: Path,
+{
+    #[inline]
+    fn partial_cmp(
+        &self,
+        other: &EncryptedPath<Pb, S>,
+    ) -> Option<Ordering>
+    {
+        Some(self.path.cmp_components(&other.path))
+    }
+}
+
+/// See the [ordering](EncryptedPath#ordering) for how this relates to plaintext ordering.
+impl<P, S> Ord for EncryptedPath<P, S>
+where P: Path
+{
+    #[inline]
+    fn cmp(
+        &self,
+        other: &Self,
+    ) -> Ordering
+    {
+        self.path.cmp_components(&other.path)
+    }
 }
@@ -1,6 +1,5 @@
-// TODO: Should have ones that give Box<[u8]>.  Maybe these should replace the Vec ones, i.e. not
-// have Vec ones?  They can interconvert without copying nor reallocating, when capacity==len,
-// right?
+// TODO: Maybe the Box<[u8]> ones should replace the Vec ones, i.e. not have Vec ones?  They can
+// interconvert without copying nor reallocating, when capacity==len.
 
 //! Premade helpers for making `get_dest` closures that return "destination" buffers for writing
 //! outputs, as used with [`Cryptor::encrypt_component`](crate::Cryptor::encrypt_component) or
@@ -94,7 +93,10 @@ mod alloc
             check_limit_count,
             check_limit_each,
         },
-        alloc::vec::Vec,
+        alloc::{
+            boxed::Box,
+            vec::Vec,
+        },
     };
 
 
@@ -145,6 +147,35 @@ mod alloc
     }
 
 
+    /// Make and return a `get_dest` closure that provides space as newly-allocated boxed slices
+    /// that are given as separately owned.
+    ///
+    /// Each is of exactly `needed_size` length, with no excess capacity, which is useful for
+    /// callers that want to keep the outputs as `Box<[u8]>`s without needing to convert from
+    /// `Vec`s that might reallocate to shed excess capacity.
+    #[inline]
+    pub fn from_boxed_slice() -> impl FnMut(usize) -> Option<Box<[u8]>>
+    {
+        from_boxed_slice_with_limits(None, None, None)
+    }
+
+    /// Like [`from_boxed_slice`] but with the same limits as [`from_vec_with_limits`].
+    ///
+    /// If allocating fails, the closure returns `None` instead of aborting.
+    #[inline]
+    pub fn from_boxed_slice_with_limits(
+        limit_count: Option<usize>,
+        limit_each: Option<usize>,
+        limit_total: Option<usize>,
+    ) -> impl FnMut(usize) -> Option<Box<[u8]>>
+    {
+        let mut from_vec = from_vec_with_limits(limit_count, limit_each, limit_total);
+        // `try_reserve_exact` might still give excess capacity, but that's rare, and then
+        // `into_boxed_slice` sheds it, which is still what's wanted.
+        move |needed_size| from_vec(needed_size).map(Vec::into_boxed_slice)
+    }
+
+
     /// Make and return a `get_dest` closure that provides space as newly-allocated [`Vec`]s that
     /// are given as borrows from a single collection of `Vec`s given by `vecs`.
     ///
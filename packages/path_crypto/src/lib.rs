@@ -20,13 +20,22 @@ use {
 };
 
 
+#[cfg(any(feature = "aes-gcm-siv", feature = "chacha"))]
+mod aead_component;
+
+#[cfg(feature = "aes-gcm-siv")]
+pub mod aes_gcm_siv;
+
+#[cfg(feature = "chacha")]
+pub mod chacha;
+
 mod component;
 pub use component::{
     EncryptedComponent,
     MakeEncryptedComponent,
 };
 
-mod crypt;
+pub mod crypt;
 
 mod decrypt;
 
@@ -38,6 +47,17 @@ pub use encrypted::EncryptedPath;
 
 pub mod get_dest;
 
+mod subspace;
+pub use subspace::CryptSubspace;
+
+#[cfg(feature = "zeroize")]
+mod zeroizing;
+#[cfg(feature = "zeroize")]
+pub use zeroizing::{
+    ZeroizingKey,
+    ZeroizingKeySpace,
+};
+
 
 /// A pair of specific algorithms for encryption-&-decryption and key derivation, for
 /// [`Path`](sailce_data_model::Path) [`Component`]s
@@ -73,6 +93,11 @@ pub trait Cryptor
     /// Encrypts a single [`Component`] with the given `key`.  Output into where `get_dest`
     /// gives as its return value.
     ///
+    /// This must be deterministic: the same `component` with the same `key` must always give the
+    /// same encrypted form, so that encrypted `Path`s preserve prefix relationships.  For
+    /// algorithms that need nonces, see [`crypt::NonceCryptor`] and [`crypt::NonceSource`] for
+    /// how to do this safely.
+    ///
     /// `get_dest` is called with the exact size needed for the output, and it either returns
     /// `Some` value to use as the destination of where to write the encrypted output bytes, or it
     /// returns `None` if it's unable to fulfill the needed size.
@@ -112,6 +137,32 @@ pub trait Cryptor
         }
     }
 
+    /// Like [`Self::encrypt_component`] but also binds the encrypted form to `aad`, the
+    /// "associated data", so that [`Self::decrypt_component_with_aad`] only succeeds when given
+    /// the same `aad`.  This enables domain separation, e.g. giving a namespace's ID as the `aad`
+    /// prevents the encrypted `Component`s of one namespace from being transplanted into another.
+    /// The `aad` isn't included in the encrypted form, and so it must be known when decrypting.
+    ///
+    /// This must be deterministic in `aad` too, the same as [`Self::encrypt_component`] must be.
+    ///
+    /// The default implementation ignores `aad` and just does [`Self::encrypt_component`], which
+    /// is appropriate for algorithms that can't authenticate associated data.  AEAD algorithms
+    /// should override this to use `aad` as their additional authenticated data, and an empty
+    /// `aad` should give the same encrypted form as [`Self::encrypt_component`].
+    ///
+    /// # Errors
+    /// The same as [`Self::encrypt_component`].
+    #[inline]
+    fn encrypt_component_with_aad<Bytes: BorrowMut<[u8]>>(
+        key: &<Self::Scheme as Scheme>::Key,
+        _aad: &[u8],
+        component: &Component<impl Borrow<[u8]>>,
+        get_dest: impl FnOnce(usize) -> Option<Bytes>,
+    ) -> Result<EncryptedComponent<Bytes, Self::Scheme>, DestTooSmallError>
+    {
+        Self::encrypt_component(key, component, get_dest)
+    }
+
     /// Decrypts a single [`EncryptedComponent`] with the given `key`.  Output into where
     /// `get_dest` gives as its return value.
     ///
@@ -127,18 +178,22 @@ pub trait Cryptor
     /// returned `Component` type.
     ///
     /// # Errors
-    /// If the value returned by `get_dest` is `None` or is too small for the decrypted form.
-    /// This can be used to determine what size is needed, by first giving a `get_dest` that just
-    /// returns `None` (or `Some` empty slice) and using the returned
-    /// [`DestTooSmallError::needed`] value.
+    /// [`DecryptError::DestTooSmall`] if the value returned by `get_dest` is `None` or is too
+    /// small for the decrypted form.  This can be used to determine what size is needed, by
+    /// first giving a `get_dest` that just returns `None` (or `Some` empty slice) and using the
+    /// returned [`DestTooSmallError::needed`] value.
+    ///
+    /// [`DecryptError::Invalid`] if `component` isn't a valid encrypted form, for algorithms that
+    /// can tell, e.g. when it fails authentication.
     fn decrypt_component<Bytes: BorrowMut<[u8]>>(
         key: &<Self::Scheme as Scheme>::Key,
         component: &EncryptedComponent<impl Borrow<[u8]>, Self::Scheme>,
         get_dest: impl FnOnce(usize) -> Option<Bytes>,
-    ) -> Result<Component<Bytes>, DestTooSmallError>;
+    ) -> Result<Component<Bytes>, DecryptError>;
 
     /// Return the size of the destination buffer that is needed for [`Self::decrypt_component`]
-    /// to succeed with the same arguments.
+    /// to succeed with the same arguments.  Or 0 if `component` is invalid and so can't succeed
+    /// with any size.
     #[inline]
     fn size_needed_to_decrypt_component(
         key: &<Self::Scheme as Scheme>::Key,
@@ -150,9 +205,32 @@ pub trait Cryptor
                 debug_assert_eq!(c.inner.len(), 0, "bad impl");
                 0
             },
-            Err(DestTooSmallError { needed }) => needed,
+            Err(DecryptError::DestTooSmall(DestTooSmallError { needed })) => needed,
+            Err(DecryptError::Invalid) => 0,
         }
     }
+
+    /// Like [`Self::decrypt_component`] but for a `component` that was encrypted by
+    /// [`Self::encrypt_component_with_aad`], which must be given the same `aad` as was given to
+    /// that.
+    ///
+    /// The default implementation ignores `aad` and just does [`Self::decrypt_component`].  AEAD
+    /// algorithms should override this, together with `Self::encrypt_component_with_aad`.
+    ///
+    /// # Errors
+    /// The same as [`Self::decrypt_component`].  An implementation that authenticates `aad`
+    /// also gives [`DecryptError::Invalid`] when `aad` differs from what the `component` was
+    /// encrypted with.
+    #[inline]
+    fn decrypt_component_with_aad<Bytes: BorrowMut<[u8]>>(
+        key: &<Self::Scheme as Scheme>::Key,
+        _aad: &[u8],
+        component: &EncryptedComponent<impl Borrow<[u8]>, Self::Scheme>,
+        get_dest: impl FnOnce(usize) -> Option<Bytes>,
+    ) -> Result<Component<Bytes>, DecryptError>
+    {
+        Self::decrypt_component(key, component, get_dest)
+    }
 }
 
 // TODO: impl std::error::Error for this, when `any(feature = "std", feature = "anticipate",
@@ -168,6 +246,30 @@ pub struct DestTooSmallError
 }
 
 
+// TODO: impl std::error::Error for this, when `any(feature = "std", feature = "anticipate",
+// rust_lib_feature = "error_in_core")`
+/// Error possibly returned by the decrypting methods of [`Cryptor`].
+#[derive(Copy, Clone, Debug)]
+#[allow(clippy::exhaustive_enums)]
+pub enum DecryptError
+{
+    /// The destination buffer was too small, the same as for encrypting.
+    DestTooSmall(DestTooSmallError),
+    /// The encrypted form is malformed or failed authentication, e.g. because it was tampered
+    /// with, or because the key or the associated data differ from what it was encrypted with.
+    Invalid,
+}
+
+impl From<DestTooSmallError> for DecryptError
+{
+    #[inline]
+    fn from(value: DestTooSmallError) -> Self
+    {
+        Self::DestTooSmall(value)
+    }
+}
+
+
 /// A specific algorithm for key derivation that must be non-invertible even for known
 /// [`Component`]s.
 pub trait KeyDerivationFunction
@@ -0,0 +1,83 @@
+use {
+    crate::{
+        Cryptor as _,
+        DecryptError,
+        DestTooSmallError,
+        EncryptedComponent,
+        KeyDerivationFunction as _,
+        Scheme,
+    },
+    core::borrow::{
+        Borrow,
+        BorrowMut,
+    },
+    sailce_data_model::path::Component,
+};
+
+
+impl<T> CryptSubspace for T where T: Scheme {}
+
+/// Methods to encrypt & decrypt [`SubspaceId`](sailce_data_model::Params::SubspaceId)s, as their
+/// byte-string representations, for all types that implement [`Scheme`].
+///
+/// This uses the same [`Cryptor`](crate::Cryptor) as for `Path` `Component`s, so that an
+/// encrypted [`Area`](sailce_data_model::group::Area) can be made from an encrypted Subspace
+/// plus an encrypted `Path` prefix.  An encrypted `SubspaceId` is represented as an
+/// [`EncryptedComponent`], because that's what the `Cryptor` produces.
+///
+/// The key given for a `SubspaceId` should be independent of the keys used for `Path`s, i.e. it
+/// should not be any `key_i` of a `Path`'s key-derivation sequence.  Otherwise, e.g. if the same
+/// key were used for a `SubspaceId` and as `key_0` of a `Path`, encrypted `SubspaceId`s would be
+/// equal to encrypted first `Component`s whenever their plaintext bytes are equal, which leaks
+/// information.  If it's desired to relate the two, [`Self::derive_path_key`] can be used to
+/// derive a `Path`'s `key_0` from the Subspace's key and `SubspaceId`, so that each Subspace has
+/// a different `Path` key-derivation sequence.
+pub trait CryptSubspace: Scheme
+{
+    /// Encrypt the bytes of a `SubspaceId` with the given `key`.  Output into where `get_dest`
+    /// gives as its return value, like [`Cryptor::encrypt_component`](
+    /// crate::Cryptor::encrypt_component).
+    ///
+    /// # Errors
+    /// If the value returned by `get_dest` is `None` or is too small for the encrypted form.
+    #[inline]
+    fn encrypt_subspace<B: BorrowMut<[u8]>>(
+        key: &Self::Key,
+        subspace_id: &[u8],
+        get_dest: impl FnOnce(usize) -> Option<B>,
+    ) -> Result<EncryptedComponent<B, Self>, DestTooSmallError>
+    {
+        Self::Cryptor::encrypt_component(key, &Component::from(subspace_id), get_dest)
+    }
+
+    /// The inverse of [`Self::encrypt_subspace`].  Output into where `get_dest` gives as its
+    /// return value, like [`Cryptor::decrypt_component`](crate::Cryptor::decrypt_component).
+    ///
+    /// # Errors
+    /// If the value returned by `get_dest` is `None` or is too small for the decrypted form, or
+    /// if `encrypted_subspace_id` is invalid, as [`DecryptError`] tells.
+    #[inline]
+    fn decrypt_subspace<B: BorrowMut<[u8]>>(
+        key: &Self::Key,
+        encrypted_subspace_id: &EncryptedComponent<impl Borrow<[u8]>, Self>,
+        get_dest: impl FnOnce(usize) -> Option<B>,
+    ) -> Result<B, DecryptError>
+    {
+        Self::Cryptor::decrypt_component(key, encrypted_subspace_id, get_dest).map(|c| c.inner)
+    }
+
+    /// Derive, into `*dest`, a `key_0` for encrypting the `Path`s in the Subspace of
+    /// `subspace_id`, from the `key` for encrypting `SubspaceId`s.
+    ///
+    /// This treats the plaintext `SubspaceId` like a `Component` that precedes the `Path`, for
+    /// [`KeyDerivationFunction::derive`](crate::KeyDerivationFunction::derive).
+    #[inline]
+    fn derive_path_key(
+        key: &Self::Key,
+        subspace_id: &[u8],
+        dest: &mut Self::Key,
+    )
+    {
+        Self::KDF::derive(key, &Component::from(subspace_id), dest);
+    }
+}
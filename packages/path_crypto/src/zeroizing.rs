@@ -0,0 +1,102 @@
+//! Containment of derived-key exposure, by zeroing temporary `Key` space when it's dropped.
+//!
+//! This is only available with our `"zeroize"` package feature.
+
+use {
+    core::borrow::{
+        Borrow,
+        BorrowMut,
+    },
+    zeroize::Zeroize,
+};
+
+
+/// A `Key` that is zeroed when dropped.
+///
+/// An array of two of these can be given directly as the `key_space` argument of
+/// [`EncryptPath::encrypt_components_with_key_space`](
+/// crate::EncryptPath::encrypt_components_with_key_space) and
+/// [`EncryptedPath::decrypt_components_with_key_space`](
+/// crate::EncryptedPath::decrypt_components_with_key_space), which moves the keys into the
+/// returned iterator so that they're zeroed when it's dropped.  That is what the `*_zeroizing`
+/// methods of those types do.
+#[derive(Default, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct ZeroizingKey<K: Zeroize>(pub K);
+
+impl<K: Zeroize> Drop for ZeroizingKey<K>
+{
+    #[inline]
+    fn drop(&mut self)
+    {
+        self.0.zeroize();
+    }
+}
+
+impl<K: Zeroize> Borrow<K> for ZeroizingKey<K>
+{
+    #[inline]
+    fn borrow(&self) -> &K
+    {
+        &self.0
+    }
+}
+
+impl<K: Zeroize> BorrowMut<K> for ZeroizingKey<K>
+{
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut K
+    {
+        &mut self.0
+    }
+}
+
+
+/// Space for the two derived keys that the `*_with_key_space` methods use, that is zeroed when
+/// dropped.
+///
+/// Unlike giving `[ZeroizingKey<K>; 2]`, this is borrowed by the iterator, via
+/// [`Self::key_space`], and so the same space can be reused for multiple `Path`s and be zeroed
+/// early, by [`Zeroize::zeroize`], when each is done.
+#[derive(Default, Debug)]
+pub struct ZeroizingKeySpace<K: Zeroize>
+{
+    keys: [K; 2],
+}
+
+impl<K: Zeroize> ZeroizingKeySpace<K>
+{
+    /// Use the given `keys` as the space.  Their values are ignored and overwritten.
+    #[inline]
+    #[must_use]
+    pub fn new(keys: [K; 2]) -> Self
+    {
+        Self { keys }
+    }
+
+    /// For giving as the `key_space` argument.
+    #[inline]
+    pub fn key_space(&mut self) -> [&mut K; 2]
+    {
+        let [a, b] = &mut self.keys;
+        [a, b]
+    }
+}
+
+impl<K: Zeroize> Zeroize for ZeroizingKeySpace<K>
+{
+    #[inline]
+    fn zeroize(&mut self)
+    {
+        self.keys.iter_mut().for_each(Zeroize::zeroize);
+    }
+}
+
+impl<K: Zeroize> Drop for ZeroizingKeySpace<K>
+{
+    #[inline]
+    fn drop(&mut self)
+    {
+        self.zeroize();
+    }
+}
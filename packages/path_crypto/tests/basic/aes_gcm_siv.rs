@@ -0,0 +1,234 @@
+use {
+    sailce_data_model::{
+        path::{
+            Component,
+            Extra as _,
+        },
+        Path as _,
+    },
+    sailce_path_crypto::{
+        aes_gcm_siv::{
+            AesGcmSivKey,
+            AesGcmSivScheme,
+            OVERHEAD,
+        },
+        get_dest,
+        DecryptError,
+        Cryptor as _,
+        EncryptPath as _,
+        EncryptedPath,
+        MakeEncryptedComponent as _,
+    },
+};
+
+
+#[test]
+fn deterministic()
+{
+    let key = AesGcmSivKey([3; 32]);
+    let encrypt = |component: &[u8]| {
+        AesGcmSivScheme::encrypt_component(
+            &key,
+            &Component::from(component),
+            get_dest::from_vec(),
+        )
+        .unwrap()
+    };
+
+    assert_eq!(encrypt(b"same").bytes(), encrypt(b"same").bytes());
+    assert_ne!(encrypt(b"same").bytes(), encrypt(b"diff").bytes());
+    let other_key = AesGcmSivKey([4; 32]);
+    let other =
+        AesGcmSivScheme::encrypt_component(&other_key, &Component::from(&b"same"[..]), |len| {
+            Some(vec![0; len])
+        })
+        .unwrap();
+    assert_ne!(encrypt(b"same").bytes(), other.bytes());
+}
+
+
+#[test]
+fn round_trip()
+{
+    let key_0 = AesGcmSivKey([9; 32]);
+    let path = ["some", "where", "", "else"];
+
+    let encrypted: EncryptedPath<Vec<Vec<u8>>, AesGcmSivScheme> =
+        path.encrypt(&key_0, get_dest::from_vec()).unwrap();
+    let again: EncryptedPath<Vec<Vec<u8>>, AesGcmSivScheme> =
+        path.encrypt(&key_0, get_dest::from_vec()).unwrap();
+    assert_eq!(again.path, encrypted.path);
+    let decrypted: Vec<Vec<u8>> = encrypted.decrypt(&key_0, get_dest::from_vec()).unwrap();
+    assert!(decrypted.eq_components(&path));
+}
+
+
+#[test]
+fn size_needed()
+{
+    let key = AesGcmSivKey::default();
+    for plaintext in [&b""[..], b"a", b"hello world"] {
+        let component = Component::from(plaintext);
+        let needed = AesGcmSivScheme::size_needed_to_encrypt_component(&key, &component);
+        assert_eq!(needed, plaintext.len() + OVERHEAD);
+        let encrypted =
+            AesGcmSivScheme::encrypt_component(&key, &component, get_dest::from_vec()).unwrap();
+        assert_eq!(encrypted.bytes().len(), needed);
+        let decrypted_needed =
+            AesGcmSivScheme::size_needed_to_decrypt_component(&key, &encrypted);
+        assert_eq!(decrypted_needed, plaintext.len());
+    }
+}
+
+
+#[test]
+fn total_size_needed()
+{
+    let key_0 = AesGcmSivKey([5; 32]);
+    let path = ["some", "where", "", "else"];
+    let total = path.total_size_needed::<AesGcmSivScheme>(&key_0);
+    assert_eq!(total, "somewhereelse".len() + path.len() * OVERHEAD);
+    let mut keys = <[AesGcmSivKey; 4]>::default();
+    assert_eq!(
+        path.derive_keys_and_size_needed::<AesGcmSivScheme>(&key_0, &mut keys, true),
+        (4, Some(total))
+    );
+    assert_eq!([""; 0].total_size_needed::<AesGcmSivScheme>(&key_0), 0);
+
+    let key_space = <[AesGcmSivKey; 2]>::default();
+    let mut exact = vec![0; total];
+    let encrypted: Result<EncryptedPath<Vec<&mut [u8]>, AesGcmSivScheme>, _> =
+        path.encrypt_with_key_space(&key_0, key_space.clone(), get_dest::from_slice(&mut exact));
+    assert!(encrypted.unwrap().path.eq_components(
+        &path.encrypt_into_vecs::<AesGcmSivScheme>(&key_0).unwrap().path
+    ));
+    let mut short = vec![0; total - 1];
+    let too_small: Result<EncryptedPath<Vec<&mut [u8]>, AesGcmSivScheme>, _> =
+        path.encrypt_with_key_space(&key_0, key_space, get_dest::from_slice(&mut short));
+    assert_eq!(too_small.map_err(|e| e.needed).err(), Some("else".len() + OVERHEAD));
+}
+
+
+#[test]
+fn rejects_invalid()
+{
+    let key = AesGcmSivKey::default();
+    let encrypted = AesGcmSivScheme::encrypt_component(
+        &key,
+        &Component::from(&b"data"[..]),
+        get_dest::from_vec(),
+    )
+    .unwrap();
+    let mut tampered = encrypted.bytes().to_vec();
+    *tampered.first_mut().unwrap() ^= 1;
+    let tampered = AesGcmSivScheme::synthesize_encrypted_component(tampered);
+    let unauthentic = AesGcmSivScheme::decrypt_component(&key, &tampered, get_dest::from_vec());
+    assert!(matches!(unauthentic, Err(DecryptError::Invalid)));
+}
+
+
+#[test]
+fn aad()
+{
+    let key = AesGcmSivKey([5; 32]);
+    let component = Component::from(&b"data"[..]);
+    let [ns_1, ns_2] = [&b"ns-1"[..], &b"ns-2"[..]];
+
+    let bound =
+        AesGcmSivScheme::encrypt_component_with_aad(&key, ns_1, &component, get_dest::from_vec())
+            .unwrap();
+    let decrypted =
+        AesGcmSivScheme::decrypt_component_with_aad(&key, ns_1, &bound, get_dest::from_vec())
+            .unwrap();
+    assert_eq!(decrypted.inner, b"data");
+
+    let transplanted =
+        AesGcmSivScheme::decrypt_component_with_aad(&key, ns_2, &bound, get_dest::from_vec());
+    assert!(matches!(transplanted, Err(DecryptError::Invalid)));
+    let without = AesGcmSivScheme::decrypt_component(&key, &bound, get_dest::from_vec());
+    assert!(matches!(without, Err(DecryptError::Invalid)));
+
+    let other =
+        AesGcmSivScheme::encrypt_component_with_aad(&key, ns_2, &component, get_dest::from_vec())
+            .unwrap();
+    assert_ne!(other.bytes(), bound.bytes());
+    let empty =
+        AesGcmSivScheme::encrypt_component_with_aad(&key, b"", &component, get_dest::from_vec())
+            .unwrap();
+    let none =
+        AesGcmSivScheme::encrypt_component(&key, &component, get_dest::from_vec()).unwrap();
+    assert_eq!(empty.bytes(), none.bytes());
+
+    // Through the `Path` methods.
+    let path = ["in", "namespace"];
+    let encrypted: EncryptedPath<Vec<Vec<u8>>, AesGcmSivScheme> =
+        path.encrypt_with_aad(&key, ns_1, get_dest::from_vec()).unwrap();
+    let round_trip: Vec<Vec<u8>> =
+        encrypted.decrypt_with_aad(&key, ns_1, get_dest::from_vec()).unwrap();
+    assert!(round_trip.eq_components(&path));
+}
+
+
+#[test]
+fn ordering()
+{
+    type Encrypted = EncryptedPath<Vec<Vec<u8>>, AesGcmSivScheme>;
+
+    let key = AesGcmSivKey([6; 32]);
+    let encrypt =
+        |path: &[&str]| -> Encrypted { path.encrypt(&key, get_dest::from_vec()).unwrap() };
+    let decrypt = |encrypted: &Encrypted| -> Vec<Vec<u8>> {
+        encrypted.decrypt(&key, get_dest::from_vec()).unwrap()
+    };
+    let plaintexts: [&[&str]; 6] =
+        [&["a"], &["a", "x"], &["a", "y"], &["a", "z"], &["b"], &["b", "x"]];
+
+    assert_eq!(encrypt(&["a", "x"]), encrypt(&["a", "x"]));
+    assert_ne!(encrypt(&["a", "x"]), encrypt(&["a", "y"]));
+
+    // A prefix's encryption is less than its extensions', the same as for the plaintexts.
+    for extension in [&["a", "x"][..], &["a", "y"], &["a", "z"]] {
+        assert!(encrypt(&["a"]) < encrypt(extension));
+    }
+
+    // Siblings sort by their differing components' ciphertexts, under the same key.
+    let [x, y] = [encrypt(&["a", "x"]), encrypt(&["a", "y"])];
+    assert_eq!(x.cmp(&y), x.path.last().cmp(&y.path.last()));
+
+    // Sorted, the extensions of each prefix are contiguous right after it.
+    let mut sorted = plaintexts.map(encrypt);
+    sorted.sort_unstable();
+    let in_order = sorted.iter().map(decrypt).collect::<Vec<_>>();
+    for prefix in [&["a"][..], &["b"]] {
+        let start = in_order.iter().position(|p| p.eq_components(&prefix)).unwrap();
+        let count = plaintexts.iter().filter(|p| p.starts_with(prefix)).count();
+        let (_, after) = in_order.split_at(start);
+        let (extending, rest) = after.split_at(count);
+        assert!(extending.iter().all(|p| prefix.is_prefix_of(p)));
+        assert!(!rest.iter().any(|p| prefix.is_prefix_of(p)));
+    }
+
+    // Consistently so, when encrypted again.
+    let mut again = plaintexts.map(encrypt);
+    again.sort_unstable();
+    assert_eq!(again.iter().map(decrypt).collect::<Vec<_>>(), in_order);
+}
+
+
+/// Isn't `Copy`, so it's not duplicated implicitly, and is zeroed when dropped.
+#[cfg(feature = "zeroize")]
+#[test]
+fn zeroize()
+{
+    use zeroize::{
+        Zeroize as _,
+        ZeroizeOnDrop,
+    };
+
+    fn zeroize_on_drop<K: ZeroizeOnDrop>(_: &K) {}
+
+    let mut key = AesGcmSivKey([7; 32]);
+    zeroize_on_drop(&key);
+    key.zeroize();
+    assert_eq!(key, AesGcmSivKey::default());
+}
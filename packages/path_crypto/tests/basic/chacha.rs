@@ -0,0 +1,161 @@
+use {
+    sailce_data_model::path::Component,
+    sailce_path_crypto::{
+        chacha::{
+            ChaChaKey,
+            ChaChaScheme,
+            OVERHEAD,
+        },
+        get_dest,
+        DecryptError,
+        Cryptor as _,
+        EncryptPath as _,
+        EncryptedPath,
+        MakeEncryptedComponent as _,
+    },
+};
+
+
+#[test]
+fn round_trip()
+{
+    let key_0 = ChaChaKey([7; 32]);
+    let path = ["some", "where", "", "else"];
+
+    let encrypted: EncryptedPath<Vec<Vec<u8>>, ChaChaScheme> =
+        path.encrypt(&key_0, get_dest::from_vec()).unwrap();
+    for (encrypted_component, component) in encrypted.path.iter().zip(path) {
+        assert_eq!(encrypted_component.len(), component.len() + OVERHEAD);
+    }
+    let decrypted = encrypted
+        .decrypt_components(&key_0, get_dest::from_vec())
+        .map(|result| result.unwrap().inner)
+        .collect::<Vec<_>>();
+    assert!(decrypted.iter().map(Vec::as_slice).eq(path.map(str::as_bytes)));
+
+    // Deterministic.
+    let again: EncryptedPath<Vec<Vec<u8>>, ChaChaScheme> =
+        path.encrypt(&key_0, get_dest::from_vec()).unwrap();
+    assert_eq!(again.path, encrypted.path);
+
+    // A different key gives a different encryption, which doesn't decrypt with the first key.
+    let other: EncryptedPath<Vec<Vec<u8>>, ChaChaScheme> =
+        path.encrypt(&ChaChaKey([8; 32]), get_dest::from_vec()).unwrap();
+    assert_ne!(other.path, encrypted.path);
+    let mut wrong = other.decrypt_components(&key_0, get_dest::from_vec());
+    assert!(matches!(wrong.next().unwrap(), Err(DecryptError::Invalid)));
+}
+
+
+#[test]
+fn same_content_differs_by_prefix()
+{
+    let key_0 = ChaChaKey([1; 32]);
+    let first: EncryptedPath<Vec<Vec<u8>>, ChaChaScheme> =
+        ["a", "same"].encrypt(&key_0, get_dest::from_vec()).unwrap();
+    let second: EncryptedPath<Vec<Vec<u8>>, ChaChaScheme> =
+        ["b", "same"].encrypt(&key_0, get_dest::from_vec()).unwrap();
+    assert_ne!(first.path.get(1), second.path.get(1));
+}
+
+
+#[test]
+fn dest_sizing()
+{
+    let key = ChaChaKey::default();
+    let component = Component::from(&b"hello"[..]);
+
+    let mut short = [0; 5 + OVERHEAD - 1];
+    let too_small =
+        ChaChaScheme::encrypt_component(&key, &component, get_dest::from_slice(&mut short));
+    assert_eq!(too_small.map_err(|e| e.needed).err(), Some(5 + OVERHEAD));
+
+    let encrypted =
+        ChaChaScheme::encrypt_component(&key, &component, get_dest::from_vec()).unwrap();
+    let mut shorter = [0; 4];
+    let also_too_small =
+        ChaChaScheme::decrypt_component(&key, &encrypted, get_dest::from_slice(&mut shorter));
+    assert!(matches!(also_too_small, Err(DecryptError::DestTooSmall(e)) if e.needed == 5));
+    let decrypted =
+        ChaChaScheme::decrypt_component(&key, &encrypted, get_dest::from_vec()).unwrap();
+    assert_eq!(decrypted.inner, b"hello");
+}
+
+
+#[test]
+fn rejects_invalid()
+{
+    let key = ChaChaKey::default();
+    let encrypted = ChaChaScheme::encrypt_component(
+        &key,
+        &Component::from(&b"data"[..]),
+        get_dest::from_vec(),
+    )
+    .unwrap();
+
+    let mut tampered = encrypted.bytes().to_vec();
+    *tampered.last_mut().unwrap() ^= 1;
+    let tampered = ChaChaScheme::synthesize_encrypted_component(tampered);
+    let unauthentic = ChaChaScheme::decrypt_component(&key, &tampered, get_dest::from_vec());
+    assert!(matches!(unauthentic, Err(DecryptError::Invalid)));
+
+    let truncated = ChaChaScheme::synthesize_encrypted_component(vec![0; OVERHEAD - 1]);
+    let malformed = ChaChaScheme::decrypt_component(&key, &truncated, get_dest::from_vec());
+    assert!(matches!(malformed, Err(DecryptError::Invalid)));
+}
+
+
+#[test]
+fn aad()
+{
+    let key_0 = ChaChaKey([3; 32]);
+    let path = ["in", "namespace"];
+
+    let bound: EncryptedPath<Vec<Vec<u8>>, ChaChaScheme> =
+        path.encrypt_with_aad(&key_0, b"ns-1", get_dest::from_vec()).unwrap();
+    let decrypted: Vec<Vec<u8>> =
+        bound.decrypt_with_aad(&key_0, b"ns-1", get_dest::from_vec()).unwrap();
+    assert!(decrypted.iter().map(Vec::as_slice).eq(path.map(str::as_bytes)));
+
+    // Can't be transplanted into another context, nor decrypted without the context.
+    let transplanted = bound.decrypt_with_aad::<Vec<u8>, Vec<_>>(
+        &key_0,
+        b"ns-2",
+        get_dest::from_vec(),
+    );
+    assert!(matches!(transplanted, Err(DecryptError::Invalid)));
+    let without = bound.decrypt::<Vec<u8>, Vec<_>>(&key_0, get_dest::from_vec());
+    assert!(matches!(without, Err(DecryptError::Invalid)));
+
+    // Deterministic, differs by the context, and empty is the same as none.
+    let again: EncryptedPath<Vec<Vec<u8>>, ChaChaScheme> =
+        path.encrypt_with_aad(&key_0, b"ns-1", get_dest::from_vec()).unwrap();
+    assert_eq!(again.path, bound.path);
+    let other: EncryptedPath<Vec<Vec<u8>>, ChaChaScheme> =
+        path.encrypt_with_aad(&key_0, b"ns-2", get_dest::from_vec()).unwrap();
+    assert_ne!(other.path, bound.path);
+    let empty: EncryptedPath<Vec<Vec<u8>>, ChaChaScheme> =
+        path.encrypt_with_aad(&key_0, b"", get_dest::from_vec()).unwrap();
+    let none: EncryptedPath<Vec<Vec<u8>>, ChaChaScheme> =
+        path.encrypt(&key_0, get_dest::from_vec()).unwrap();
+    assert_eq!(empty.path, none.path);
+}
+
+
+/// Isn't `Copy`, so it's not duplicated implicitly, and is zeroed when dropped.
+#[cfg(feature = "zeroize")]
+#[test]
+fn zeroize()
+{
+    use zeroize::{
+        Zeroize as _,
+        ZeroizeOnDrop,
+    };
+
+    fn zeroize_on_drop<K: ZeroizeOnDrop>(_: &K) {}
+
+    let mut key = ChaChaKey([7; 32]);
+    zeroize_on_drop(&key);
+    key.zeroize();
+    assert_eq!(key, ChaChaKey::default());
+}
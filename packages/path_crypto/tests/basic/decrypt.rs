@@ -0,0 +1,116 @@
+use {
+    crate::dummy::{
+        Xor,
+        XorKey,
+    },
+    sailce_data_model::path::Extra as _,
+    sailce_path_crypto::{
+        get_dest,
+        DecryptError,
+        EncryptPath as _,
+        EncryptedComponent,
+        EncryptedPath,
+    },
+};
+
+
+#[test]
+fn round_trip()
+{
+    let key_0 = XorKey(0x5A);
+    let path = ["some", "where", "", "else"];
+    let encrypted: EncryptedPath<Vec<Vec<u8>>, Xor> =
+        path.encrypt(&key_0, get_dest::from_vec()).unwrap();
+
+    let decrypted: Vec<Vec<u8>> = encrypted.decrypt(&key_0, get_dest::from_vec()).unwrap();
+    assert!(decrypted.eq_components(&path));
+
+    let mut keys = [XorKey::default(); 5];
+    let saved: Vec<Vec<u8>> =
+        encrypted.decrypt_and_save_keys(&key_0, &mut keys[1 ..], get_dest::from_vec()).unwrap();
+    assert!(saved.eq_components(&path));
+    keys[0] = key_0;
+    let mut derived = [XorKey::default(); 4];
+    assert_eq!(path.derive_keys::<Xor>(&key_0, &mut derived), 4);
+    assert_eq!(keys[1 ..], derived);
+
+    let with_keys: Vec<Vec<u8>> =
+        encrypted.decrypt_with_keys(&keys[.. 4], get_dest::from_vec()).unwrap();
+    assert!(with_keys.eq_components(&path));
+
+    let key_space = [XorKey::default(); 2];
+    let with_key_space: Vec<Vec<u8>> =
+        encrypted.decrypt_with_key_space(&key_0, key_space, get_dest::from_vec()).unwrap();
+    assert!(with_key_space.eq_components(&path));
+}
+
+
+#[test]
+fn into_vecs()
+{
+    let key_0 = XorKey(0x3C);
+    let path = ["a", "", "bcd"];
+    let encrypted = path.encrypt_into_vecs::<Xor>(&key_0).unwrap();
+    let via_get_dest: EncryptedPath<Vec<Vec<u8>>, Xor> =
+        path.encrypt(&key_0, get_dest::from_vec()).unwrap();
+    assert_eq!(encrypted.path, via_get_dest.path);
+    assert_eq!(encrypted.path.len(), 3);
+    assert!(!encrypted.path.eq_components(&path));
+
+    let decrypted = encrypted.decrypt_into_vecs(&key_0).unwrap();
+    assert!(decrypted.eq_components(&path));
+    assert_eq!(decrypted, [&b"a"[..], b"", b"bcd"]);
+
+    let empty = [""; 0].encrypt_into_vecs::<Xor>(&key_0).unwrap();
+    assert!(empty.path.is_empty());
+    assert!(empty.decrypt_into_vecs(&key_0).unwrap().is_empty());
+}
+
+
+#[test]
+fn aad_ignored_by_default()
+{
+    // `Xor` doesn't override the AAD methods, and so the AAD makes no difference.
+    let key_0 = XorKey(0x33);
+    let path = ["a", "b"];
+    let with: EncryptedPath<Vec<Vec<u8>>, Xor> =
+        path.encrypt_with_aad(&key_0, b"context", get_dest::from_vec()).unwrap();
+    let without: EncryptedPath<Vec<Vec<u8>>, Xor> =
+        path.encrypt(&key_0, get_dest::from_vec()).unwrap();
+    assert_eq!(with.path, without.path);
+
+    let decrypted: Vec<Vec<u8>> =
+        with.decrypt_with_aad(&key_0, b"other", get_dest::from_vec()).unwrap();
+    assert!(decrypted.eq_components(&path));
+}
+
+
+#[test]
+fn dest_too_small()
+{
+    let key_0 = XorKey(0x11);
+    let encrypted: EncryptedPath<Vec<Vec<u8>>, Xor> =
+        ["ab", "cdef"].encrypt(&key_0, get_dest::from_vec()).unwrap();
+    let limited = get_dest::from_vec_with_limits(None, Some(3), None);
+    let result = encrypted.decrypt::<_, Vec<Vec<u8>>>(&key_0, limited);
+    assert!(matches!(result, Err(DecryptError::DestTooSmall(e)) if e.needed == 4));
+}
+
+
+#[test]
+fn from_encrypted_components()
+{
+    let key_0 = XorKey(0x42);
+    let path = ["x", "yz"];
+    let encrypted: EncryptedPath<Vec<Vec<u8>>, Xor> =
+        path.encrypt(&key_0, get_dest::from_vec()).unwrap();
+
+    // As if received from elsewhere as raw bytes.
+    let received = encrypted.path.clone();
+    let rebuilt: EncryptedPath<Vec<Vec<u8>>, Xor> = EncryptedPath::from_encrypted_components(
+        received.into_iter().map(EncryptedComponent::from_ciphertext),
+    );
+    assert_eq!(rebuilt.path, encrypted.path);
+    let decrypted: Vec<Vec<u8>> = rebuilt.decrypt(&key_0, get_dest::from_vec()).unwrap();
+    assert!(decrypted.eq_components(&path));
+}
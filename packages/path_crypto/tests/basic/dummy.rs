@@ -0,0 +1,205 @@
+//! A dummy [`Scheme`] that is trivially insecure, just for testing the API.
+
+use {
+    sailce_data_model::path::Component,
+    sailce_path_crypto::{
+        crypt::NonceCryptor,
+        Cryptor,
+        DecryptError,
+        DestTooSmallError,
+        EncryptedComponent,
+        Key,
+        KeyDerivationFunction,
+        MakeEncryptedComponent as _,
+        Scheme,
+    },
+    std::borrow::{
+        Borrow,
+        BorrowMut,
+    },
+};
+
+
+/// XORs each byte with the key, and derives keys by mixing-in the bytes of a component.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Xor;
+
+#[derive(Default, Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) struct XorKey(pub(crate) u8);
+
+impl Scheme for Xor
+{
+    type Cryptor = Self;
+    type KDF = Self;
+    type Key = XorKey;
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for XorKey
+{
+    fn zeroize(&mut self)
+    {
+        self.0.zeroize();
+    }
+}
+
+impl Key for XorKey
+{
+    type Scheme = Xor;
+}
+
+fn xor_into<B: BorrowMut<[u8]>>(
+    key: XorKey,
+    src: &[u8],
+    get_dest: impl FnOnce(usize) -> Option<B>,
+) -> Result<B, DestTooSmallError>
+{
+    let needed = src.len();
+    let mut dest = get_dest(needed).ok_or(DestTooSmallError { needed })?;
+    let dest_slice = dest.borrow_mut();
+    if dest_slice.len() != needed {
+        return Err(DestTooSmallError { needed });
+    }
+    for (d, s) in dest_slice.iter_mut().zip(src) {
+        *d = s ^ key.0;
+    }
+    Ok(dest)
+}
+
+impl Cryptor for Xor
+{
+    type Scheme = Self;
+
+    fn encrypt_component<Bytes: BorrowMut<[u8]>>(
+        key: &XorKey,
+        component: &Component<impl Borrow<[u8]>>,
+        get_dest: impl FnOnce(usize) -> Option<Bytes>,
+    ) -> Result<EncryptedComponent<Bytes, Self>, DestTooSmallError>
+    {
+        xor_into(*key, component.bytes(), get_dest).map(Self::synthesize_encrypted_component)
+    }
+
+    fn decrypt_component<Bytes: BorrowMut<[u8]>>(
+        key: &XorKey,
+        component: &EncryptedComponent<impl Borrow<[u8]>, Self>,
+        get_dest: impl FnOnce(usize) -> Option<Bytes>,
+    ) -> Result<Component<Bytes>, DecryptError>
+    {
+        Ok(Component { inner: xor_into(*key, component.bytes(), get_dest)? })
+    }
+}
+
+impl KeyDerivationFunction for Xor
+{
+    type Scheme = Self;
+
+    fn derive(
+        key: &XorKey,
+        component: &Component<impl Borrow<[u8]>>,
+        dest: &mut XorKey,
+    )
+    {
+        let mixed = component.bytes().iter().fold(key.0, |acc, b| acc.rotate_left(3) ^ b);
+        *dest = XorKey(mixed.wrapping_add(1));
+    }
+}
+
+
+/// Like [`Xor`], but also XORs with a nonce that is prefixed to the encrypted form, like AEAD
+/// algorithms do.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct NoncedXor;
+
+#[derive(Default, Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) struct NoncedXorKey(pub(crate) u8);
+
+impl Scheme for NoncedXor
+{
+    type Cryptor = Self;
+    type KDF = Self;
+    type Key = NoncedXorKey;
+}
+
+impl Key for NoncedXorKey
+{
+    type Scheme = NoncedXor;
+}
+
+impl Cryptor for NoncedXor
+{
+    type Scheme = Self;
+
+    /// Deterministic, by deriving the nonce from the content.
+    fn encrypt_component<Bytes: BorrowMut<[u8]>>(
+        key: &NoncedXorKey,
+        component: &Component<impl Borrow<[u8]>>,
+        get_dest: impl FnOnce(usize) -> Option<Bytes>,
+    ) -> Result<EncryptedComponent<Bytes, Self>, DestTooSmallError>
+    {
+        let nonce = content_nonce(key, 0, component.bytes());
+        Self::encrypt_component_with_nonce(key, &nonce, component, get_dest)
+    }
+
+    fn decrypt_component<Bytes: BorrowMut<[u8]>>(
+        key: &NoncedXorKey,
+        component: &EncryptedComponent<impl Borrow<[u8]>, Self>,
+        get_dest: impl FnOnce(usize) -> Option<Bytes>,
+    ) -> Result<Component<Bytes>, DecryptError>
+    {
+        let (nonce, encrypted) = component.bytes().split_first().ok_or(DecryptError::Invalid)?;
+        Ok(Component { inner: xor_into(XorKey(key.0 ^ nonce), encrypted, get_dest)? })
+    }
+}
+
+impl NonceCryptor for NoncedXor
+{
+    type Nonce = u8;
+
+    fn encrypt_component_with_nonce<Bytes: BorrowMut<[u8]>>(
+        key: &NoncedXorKey,
+        nonce: &u8,
+        component: &Component<impl Borrow<[u8]>>,
+        get_dest: impl FnOnce(usize) -> Option<Bytes>,
+    ) -> Result<EncryptedComponent<Bytes, Self>, DestTooSmallError>
+    {
+        let src = component.bytes();
+        let needed = src.len().saturating_add(1);
+        let mut dest = get_dest(needed).ok_or(DestTooSmallError { needed })?;
+        let Some((dest_nonce, dest_rest)) = dest.borrow_mut().split_first_mut()
+        else {
+            return Err(DestTooSmallError { needed });
+        };
+        *dest_nonce = *nonce;
+        let _: &mut [u8] = xor_into(XorKey(key.0 ^ nonce), src, |_| Some(dest_rest))?;
+        Ok(Self::synthesize_encrypted_component(dest))
+    }
+}
+
+impl KeyDerivationFunction for NoncedXor
+{
+    type Scheme = Self;
+
+    fn derive(
+        key: &NoncedXorKey,
+        component: &Component<impl Borrow<[u8]>>,
+        dest: &mut NoncedXorKey,
+    )
+    {
+        let mut xor_dest = XorKey::default();
+        Xor::derive(&XorKey(key.0), component, &mut xor_dest);
+        *dest = NoncedXorKey(xor_dest.0);
+    }
+}
+
+/// A trivially-insecure deterministic derivation of a nonce from all of its arguments.
+#[allow(clippy::trivially_copy_pass_by_ref)] // Needed for use with `Deterministic`.
+pub(crate) fn content_nonce(
+    key: &NoncedXorKey,
+    index: usize,
+    component: &[u8],
+) -> u8
+{
+    #[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+    let index = index as u8;
+    component.iter().fold(key.0 ^ index, |acc, b| acc.rotate_left(5).wrapping_add(*b))
+}
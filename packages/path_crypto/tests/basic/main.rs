@@ -0,0 +1,26 @@
+#![cfg(test)] // Satisfy the `clippy::tests_outside_test_module` lint.
+#![cfg_attr(test, allow(unused_crate_dependencies))]
+#![allow(
+    non_snake_case,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::std_instead_of_core,
+    clippy::std_instead_of_alloc
+)]
+
+#[cfg(feature = "aes-gcm-siv")]
+mod aes_gcm_siv;
+
+#[cfg(feature = "chacha")]
+mod chacha;
+
+mod decrypt;
+
+mod dummy;
+
+mod nonce;
+
+mod subspace;
+
+#[cfg(feature = "zeroize")]
+mod zeroizing;
@@ -0,0 +1,51 @@
+use {
+    crate::dummy::{
+        content_nonce,
+        NoncedXor,
+        NoncedXorKey,
+    },
+    sailce_data_model::path::Component,
+    sailce_path_crypto::{
+        crypt::{
+            Deterministic,
+            NonceCryptor as _,
+        },
+        get_dest,
+        Cryptor as _,
+    },
+};
+
+
+#[test]
+fn deterministic()
+{
+    let key = NoncedXorKey(0x42);
+    let mut source = Deterministic(content_nonce);
+    let mut encrypt = |index, component: &[u8]| {
+        NoncedXor::encrypt_component_from_source(
+            &key,
+            &mut source,
+            index,
+            &Component::from(component),
+            get_dest::from_vec(),
+        )
+        .unwrap()
+    };
+
+    let first = encrypt(0, b"same");
+    let second = encrypt(0, b"same");
+    assert_eq!(first.bytes(), second.bytes());
+    assert_ne!(first.bytes(), encrypt(0, b"diff").bytes());
+    assert_ne!(first.bytes(), encrypt(1, b"same").bytes());
+
+    let decrypted = NoncedXor::decrypt_component(&key, &first, get_dest::from_vec()).unwrap();
+    assert_eq!(decrypted.inner, b"same");
+
+    // The plain `encrypt_component` is deterministic also.
+    let plain = |component: &[u8]| {
+        NoncedXor::encrypt_component(&key, &Component::from(component), get_dest::from_vec())
+            .unwrap()
+    };
+    assert_eq!(plain(b"same").bytes(), plain(b"same").bytes());
+    assert_eq!(plain(b"same").bytes(), encrypt(0, b"same").bytes());
+}
@@ -0,0 +1,89 @@
+use {
+    crate::dummy::{
+        Xor,
+        XorKey,
+    },
+    sailce_data_model::Path as _,
+    sailce_path_crypto::{
+        get_dest,
+        DecryptError,
+        CryptSubspace as _,
+        EncryptPath as _,
+        EncryptedPath,
+    },
+};
+
+
+#[test]
+fn round_trip()
+{
+    let key = XorKey(0x5A);
+    let subspace_id = b"some subspace";
+
+    let encrypted = Xor::encrypt_subspace(&key, subspace_id, get_dest::from_vec()).unwrap();
+    assert_ne!(encrypted.bytes(), subspace_id);
+    let decrypted = Xor::decrypt_subspace(&key, &encrypted, get_dest::from_vec()).unwrap();
+    assert_eq!(decrypted, subspace_id);
+    let boxed = Xor::decrypt_subspace(&key, &encrypted, get_dest::from_boxed_slice()).unwrap();
+    assert_eq!(&*boxed, subspace_id);
+
+    // Limited destinations.
+    let mut only_one = get_dest::from_boxed_slice_with_limits(Some(1), None, None);
+    let first = Xor::decrypt_subspace(&key, &encrypted, &mut only_one);
+    assert_eq!(first.ok().as_deref(), Some(&subspace_id[..]));
+    let second = Xor::decrypt_subspace(&key, &encrypted, &mut only_one);
+    assert!(
+        matches!(second, Err(DecryptError::DestTooSmall(e)) if e.needed == subspace_id.len())
+    );
+    let too_small = get_dest::from_boxed_slice_with_limits(None, Some(4), None);
+    let rejected = Xor::decrypt_subspace(&key, &encrypted, too_small);
+    assert!(
+        matches!(rejected, Err(DecryptError::DestTooSmall(e)) if e.needed == subspace_id.len())
+    );
+
+    // Too-small destination.
+    let mut buf = [0; 4];
+    let result = Xor::encrypt_subspace(&key, subspace_id, get_dest::from_slice(&mut buf));
+    assert_eq!(result.map_err(|e| e.needed).err(), Some(subspace_id.len()));
+}
+
+
+#[test]
+fn composes_with_path()
+{
+    let subspace_key = XorKey(0x33);
+    let subspace_id = b"alice";
+    let path = ["some", "where"];
+
+    let encrypted_subspace =
+        Xor::encrypt_subspace(&subspace_key, subspace_id, get_dest::from_vec()).unwrap();
+    let mut path_key_0 = XorKey::default();
+    Xor::derive_path_key(&subspace_key, subspace_id, &mut path_key_0);
+    assert_ne!(path_key_0, subspace_key, "should be independent");
+    let encrypted_path: EncryptedPath<Vec<Vec<u8>>, Xor> =
+        path.encrypt(&path_key_0, get_dest::from_vec()).unwrap();
+
+    // Decrypt both, deriving the path key again from only what's known by the decrypter.
+    let decrypted_subspace =
+        Xor::decrypt_subspace(&subspace_key, &encrypted_subspace, get_dest::from_vec()).unwrap();
+    assert_eq!(decrypted_subspace, subspace_id);
+    let mut rederived = XorKey::default();
+    Xor::derive_path_key(&subspace_key, &decrypted_subspace, &mut rederived);
+    assert_eq!(rederived, path_key_0);
+    let mut keys = [XorKey::default(); 2];
+    assert_eq!(path.derive_keys::<Xor>(&rederived, &mut keys[1 ..]), 1);
+    keys[0] = rederived;
+    let decrypted_path = encrypted_path
+        .decrypt_components_with_keys(&keys, get_dest::from_vec())
+        .map(|result| result.unwrap().inner)
+        .collect::<Vec<_>>();
+    assert!(decrypted_path.iter().map(Vec::as_slice).eq(path.map(str::as_bytes)));
+    assert_eq!(decrypted_path.components().len(), 2);
+
+    // A different Subspace's path key gives a different encrypted path.
+    let mut other_key_0 = XorKey::default();
+    Xor::derive_path_key(&subspace_key, b"bob", &mut other_key_0);
+    let other: EncryptedPath<Vec<Vec<u8>>, Xor> =
+        path.encrypt(&other_key_0, get_dest::from_vec()).unwrap();
+    assert_ne!(other.path, encrypted_path.path);
+}
@@ -0,0 +1,67 @@
+use {
+    crate::dummy::{
+        Xor,
+        XorKey,
+    },
+    sailce_path_crypto::{
+        get_dest,
+        EncryptPath as _,
+        EncryptedPath,
+        ZeroizingKey,
+        ZeroizingKeySpace,
+    },
+    zeroize::Zeroize as _,
+};
+
+
+#[test]
+fn same_as_not_zeroizing()
+{
+    let key_0 = XorKey(0x5A);
+    let path = ["some", "where", "else"];
+    let encrypted: EncryptedPath<Vec<Vec<u8>>, Xor> =
+        path.encrypt(&key_0, get_dest::from_vec()).unwrap();
+
+    let zeroizing = path
+        .encrypt_components_zeroizing::<Xor, _>(&key_0, get_dest::from_vec())
+        .map(|result| result.unwrap().bytes().to_vec())
+        .collect::<Vec<_>>();
+    assert_eq!(zeroizing, encrypted.path);
+
+    let decrypted = encrypted
+        .decrypt_components_zeroizing(&key_0, get_dest::from_vec())
+        .map(|result| result.unwrap().inner)
+        .collect::<Vec<_>>();
+    assert!(decrypted.iter().map(Vec::as_slice).eq(path.map(str::as_bytes)));
+
+    let owned = [ZeroizingKey(XorKey::default()), ZeroizingKey(XorKey::default())];
+    let with_owned = path
+        .encrypt_components_with_key_space::<Xor, _>(&key_0, owned, get_dest::from_vec())
+        .map(|result| result.unwrap().bytes().to_vec())
+        .collect::<Vec<_>>();
+    assert_eq!(with_owned, encrypted.path);
+}
+
+
+#[test]
+fn key_space()
+{
+    let key_0 = XorKey(0x33);
+    let path = ["a", "b"];
+    let mut space = ZeroizingKeySpace::new([XorKey(1), XorKey(2)]);
+
+    let decrypted = {
+        let encrypted: EncryptedPath<Vec<Vec<u8>>, Xor> =
+            path.encrypt(&key_0, get_dest::from_vec()).unwrap();
+        encrypted
+            .decrypt_components_with_key_space(&key_0, space.key_space(), get_dest::from_vec())
+            .map(|result| result.unwrap().inner)
+            .collect::<Vec<_>>()
+    };
+    assert!(decrypted.iter().map(Vec::as_slice).eq(path.map(str::as_bytes)));
+    // The space was used for derived keys.
+    assert_ne!(space.key_space().map(|key| *key), [XorKey(1), XorKey(2)]);
+
+    space.zeroize();
+    assert_eq!(space.key_space().map(|key| *key), [XorKey(0), XorKey(0)]);
+}